@@ -4,16 +4,41 @@
 // Use of this source code is governed by a BSD-style
 // license that can be found in the LICENSE file.
 
-/// CBOR validation utilities.
+/// CBOR encoding, decoding and validation utilities.
 ///
-/// This library provides strict validation for CBOR-encoded data to ensure
-/// it conforms to the subset of CBOR used by this package.
+/// This library provides encoding, decoding and strict validation for
+/// CBOR-encoded data within the subset of CBOR used by this package.
 library;
 
 import 'dart:typed_data';
 
 import 'src/generated/api/cbor.dart' as ffi;
 
+export 'src/generated/api/cbor.dart'
+    show
+        CborMapEntry,
+        CborValue,
+        CborValue_Array,
+        CborValue_Bool,
+        CborValue_Bytes,
+        CborValue_Int,
+        CborValue_Map,
+        CborValue_Null,
+        CborValue_Text,
+        CborValue_Uint;
+export 'src/generated/api/cddl.dart' show CddlSchema;
+
+/// Encodes a value into deterministic CBOR, sorting map entries by key.
+///
+/// Throws an exception on duplicate map keys or excessive nesting.
+Uint8List encode(ffi.CborValue value) => ffi.cborEncode(value: value);
+
+/// Decodes deterministic CBOR into a value.
+///
+/// Throws an exception if the bytes are outside the subset accepted by
+/// [verify].
+ffi.CborValue decode(Uint8List data) => ffi.cborDecode(data: data);
+
 /// Validates that the given bytes are well-formed CBOR.
 ///
 /// This performs strict validation including:
@@ -23,3 +48,22 @@ import 'src/generated/api/cbor.dart' as ffi;
 ///
 /// Throws an exception if the bytes are not valid CBOR.
 void verify(Uint8List data) => ffi.cborVerify(data: data);
+
+/// Validates CBOR against a rule of a CDDL (RFC 8610) schema.
+///
+/// Throws an exception naming the path of the first mismatch, e.g.
+/// `$[3][0]`. Use [CddlSchema.compile] to reuse a schema across calls.
+void validate(Uint8List data, {required String cddl, required String rule}) =>
+    ffi.cborValidate(data: data, cddl: cddl, rule: rule);
+
+/// Renders CBOR as RFC 8949 §8 diagnostic notation, e.g. `{1: "abc"}`.
+///
+/// Throws an exception if the bytes are not valid CBOR within the subset
+/// accepted by [verify].
+String toDiagnostic(Uint8List data) => ffi.cborToDiagnostic(data: data);
+
+/// Parses RFC 8949 §8 diagnostic notation into deterministic CBOR.
+///
+/// Supports `null`, `true`, `false`, integers, `"text"`, `h'hex'` byte
+/// strings, arrays and maps with integer keys.
+Uint8List fromDiagnostic(String text) => ffi.cborFromDiagnostic(text: text);
@@ -12,8 +12,10 @@ library;
 
 import 'dart:typed_data';
 
-import 'package:cbor/simple.dart' as cbor;
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart'
+    show PlatformInt64Util;
 
+import 'cbor.dart' as cbor;
 import 'src/generated/api/cose.dart' as ffi;
 import 'xdsa.dart'
     as xdsa
@@ -34,8 +36,58 @@ import 'xhpke.dart'
         PublicKeyInternal,
         FingerprintInternal;
 
-Uint8List _encode(Object? value) => Uint8List.fromList(cbor.cbor.encode(value));
-Object? _decode(Uint8List bytes) => cbor.cbor.decode(bytes);
+// Encodes a Dart value into deterministic CBOR via the Rust encoder.
+Uint8List _encode(Object? value) => cbor.encode(_toValue(value));
+
+// Decodes deterministic CBOR via the Rust decoder into a Dart value.
+Object? _decode(Uint8List bytes) => _fromValue(cbor.decode(bytes));
+
+// Converts a Dart value into a CBOR value. Supports null, bool, int, BigInt,
+// String, Uint8List (bytes), List (array) and Map with int keys.
+cbor.CborValue _toValue(Object? value) => switch (value) {
+  null => const cbor.CborValue.null_(),
+  cbor.CborValue v => v,
+  bool v => cbor.CborValue.bool(v),
+  int v => cbor.CborValue.int(PlatformInt64Util.from(v)),
+  BigInt v when v.isValidInt => cbor.CborValue.int(
+    PlatformInt64Util.from(v.toInt()),
+  ),
+  BigInt v => cbor.CborValue.uint(v),
+  String v => cbor.CborValue.text(v),
+  Uint8List v => cbor.CborValue.bytes(v),
+  List v => cbor.CborValue.array([for (final item in v) _toValue(item)]),
+  Map v => cbor.CborValue.map([
+    for (final entry in v.entries)
+      if (entry.key is int)
+        cbor.CborMapEntry(
+          key: PlatformInt64Util.from(entry.key as int),
+          value: _toValue(entry.value),
+        )
+      else
+        throw ArgumentError.value(
+          entry.key,
+          'key',
+          'CBOR map keys must be integers',
+        ),
+  ]),
+  _ => throw ArgumentError.value(value, 'value', 'not CBOR encodable'),
+};
+
+// Converts a CBOR value into the equivalent Dart value.
+Object? _fromValue(cbor.CborValue value) => switch (value) {
+  cbor.CborValue_Null() => null,
+  cbor.CborValue_Bool(:final field0) => field0,
+  cbor.CborValue_Int(:final field0) => field0.toInt(),
+  cbor.CborValue_Uint(:final field0) => field0,
+  cbor.CborValue_Text(:final field0) => field0,
+  cbor.CborValue_Bytes(:final field0) => field0,
+  cbor.CborValue_Array(:final field0) => [
+    for (final item in field0) _fromValue(item),
+  ],
+  cbor.CborValue_Map(:final field0) => {
+    for (final entry in field0) entry.key.toInt(): _fromValue(entry.value),
+  },
+};
 
 /// Creates a COSE_Sign1 digital signature with an embedded payload.
 ///
@@ -88,6 +140,8 @@ Uint8List signDetached({
 /// - [verifier]: The xDSA public key to verify against
 /// - [domain]: Application domain for replay protection
 /// - [maxDriftSecs]: Signatures more in the past or future are rejected
+/// - [schema]: A compiled CDDL schema the payload must match (null to skip)
+/// - [rule]: Name of the schema rule the payload must match
 ///
 /// Returns the CBOR-decoded embedded payload if verification succeeds.
 T verify<T>({
@@ -96,19 +150,29 @@ T verify<T>({
   required xdsa.PublicKey verifier,
   required Uint8List domain,
   int? maxDriftSecs,
-}) =>
-    _decode(
-          ffi.coseVerify(
-            msgToCheck: msgToCheck,
-            msgToAuth: _encode(msgToAuth),
-            verifier: verifier.inner,
-            domain: domain,
-            maxDriftSecs: maxDriftSecs != null
-                ? BigInt.from(maxDriftSecs)
-                : null,
-          ),
-        )
-        as T;
+  cbor.CddlSchema? schema,
+  String? rule,
+}) {
+  if ((schema == null) != (rule == null)) {
+    throw ArgumentError.value(
+      rule,
+      'rule',
+      'must be given together with schema',
+    );
+  }
+  return _decode(
+        ffi.coseVerify(
+          msgToCheck: msgToCheck,
+          msgToAuth: _encode(msgToAuth),
+          verifier: verifier.inner,
+          domain: domain,
+          maxDriftSecs: maxDriftSecs != null ? BigInt.from(maxDriftSecs) : null,
+          schema: schema,
+          rule: rule,
+        ),
+      )
+      as T;
+}
 
 /// Validates a COSE_Sign1 digital signature with a detached payload.
 ///
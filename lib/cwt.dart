@@ -8,9 +8,9 @@
 ///
 /// <https://datatracker.ietf.org/doc/html/rfc8392>
 ///
-/// Tokens carry a set of [Claims] encoded as a CBOR map. Standard CWT and
-/// EAT claims have typed accessors; custom claims use integer keys via
-/// `operator[]`.
+/// Tokens carry a set of [Claims], encoded deterministically as a CBOR map on
+/// the Rust side. Standard CWT and EAT claims have typed fields; custom claims
+/// use integer keys via `operator[]`.
 ///
 /// ## Example
 ///
@@ -48,8 +48,10 @@ library;
 
 import 'dart:typed_data';
 
-import 'package:cbor/simple.dart' as cbor;
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart'
+    show PlatformInt64Util;
 
+import 'cbor.dart' as cbor;
 import 'src/generated/api/cwt.dart' as ffi;
 import 'xdsa.dart'
     as xdsa
@@ -60,127 +62,176 @@ import 'xdsa.dart'
         SecretKeyInternal,
         PublicKeyInternal,
         FingerprintInternal;
-import 'xhpke.dart' as xhpke show PublicKey;
+import 'xhpke.dart' as xhpke show PublicKey, PublicKeyInternal;
 
-// COSE algorithm identifiers used in Confirm claim encoding.
-const int _algorithmIdXdsa = -70000;
-const int _algorithmIdXhpke = -70001;
+export 'src/generated/api/cwt.dart'
+    show
+        CwtClaims,
+        CwtConfirmKeyType,
+        CwtStatus,
+        EatMeasurement,
+        EatOemid,
+        EatSubmodule,
+        EatSubmoduleValue,
+        StatusList;
+
+/// Debug port state per RFC 9711 Section 4.2.9.
+typedef DebugState = ffi.EatDebugState;
 
-/// A CWT claims set with typed accessors for standard CWT (RFC 8392) and
-/// EAT (RFC 9711) claims.
+// Claim key of the EAT intended use, carried as a custom claim.
+const int _keyIntendedUse = 275;
+
+/// A CWT claims set with typed fields for standard CWT (RFC 8392) and EAT
+/// (RFC 9711) claims.
 ///
-/// Standard claims are exposed as typed properties. Custom or application-
-/// specific claims can be accessed via `operator[]` using their integer key.
+/// The claims are converted to the generated [ffi.CwtClaims] and encoded in
+/// Rust. Custom or application-specific claims can be accessed via
+/// `operator[]` using their integer key.
 class Claims {
-  final Map<int, Object?> _map;
-
   /// Creates an empty claims set.
-  Claims() : _map = {};
-
-  Claims._(this._map);
+  Claims();
+
+  /// Converts the typed claims decoded by Rust.
+  Claims.fromCwtClaims(ffi.CwtClaims claims)
+    : issuer = claims.iss,
+      subject = claims.sub,
+      audience = claims.aud,
+      expiration = claims.exp?.toInt(),
+      notBefore = claims.nbf?.toInt(),
+      issuedAt = claims.iat?.toInt(),
+      tokenId = claims.cti,
+      _confirm = claims.cnf,
+      eatNonce = claims.eatNonce,
+      ueid = claims.ueid,
+      _oemid = claims.oemid,
+      hwModel = claims.hwModel,
+      hwVersion = claims.hwVersion,
+      uptime = claims.uptime?.toInt(),
+      oemBoot = claims.oemBoot,
+      debugStatus = claims.dbgstat,
+      submods = List.of(claims.submods),
+      bootCount = claims.bootCount?.toInt(),
+      bootSeed = claims.bootSeed,
+      swName = claims.swName,
+      swVersion = claims.swVersion,
+      measurements = List.of(claims.measurements),
+      status = claims.status {
+    for (final claim in claims.custom) {
+      _custom[claim.key.toInt()] = cbor.decode(claim.value);
+    }
+  }
 
   /// Issuer: identifies the principal that issued the token (key 1).
-  String? get issuer => _map[1] as String?;
-  set issuer(String? value) => _set(1, value);
+  String? issuer;
 
   /// Subject: identifies the principal that is the subject of the token (key 2).
-  String? get subject => _map[2] as String?;
-  set subject(String? value) => _set(2, value);
+  String? subject;
 
   /// Audience: identifies the recipients the token is intended for (key 3).
-  String? get audience => _map[3] as String?;
-  set audience(String? value) => _set(3, value);
+  String? audience;
 
   /// Expiration: the time on or after which the token must not be accepted
   /// (key 4, Unix timestamp in seconds).
-  int? get expiration => _map[4] as int?;
-  set expiration(int? value) => _set(4, value);
+  int? expiration;
 
   /// NotBefore: the time before which the token must not be accepted
   /// (key 5, Unix timestamp in seconds).
-  int? get notBefore => _map[5] as int?;
-  set notBefore(int? value) => _set(5, value);
+  int? notBefore;
 
   /// IssuedAt: the time at which the token was issued
   /// (key 6, Unix timestamp in seconds).
-  int? get issuedAt => _map[6] as int?;
-  set issuedAt(int? value) => _set(6, value);
+  int? issuedAt;
 
   /// TokenID: a unique identifier for the token (key 7).
-  Uint8List? get tokenId => _asBytes(_map[7]);
-  set tokenId(Uint8List? value) => _set(7, value);
+  Uint8List? tokenId;
+
+  /// Confirm: the key bound to this token (key 8).
+  ffi.CwtConfirmKey? _confirm;
+
+  /// EatNonce: attestation challenge echoed by the entity (key 10).
+  Uint8List? eatNonce;
+
+  /// UEID: a globally unique device identifier (key 256).
+  Uint8List? ueid;
+
+  /// OEMID: hardware manufacturer identifier (key 258).
+  ffi.EatOemid? _oemid;
+
+  /// HwModel: product or board model identifier (key 259).
+  Uint8List? hwModel;
+
+  /// HwVersion: hardware revision identifier (key 260).
+  String? hwVersion;
+
+  /// Uptime: seconds since last boot (key 261).
+  int? uptime;
+
+  /// OemBoot: whether the boot chain is OEM-authorized (key 262).
+  bool? oemBoot;
+
+  /// DebugStatus: debug port state (key 263).
+  DebugState? debugStatus;
+
+  /// Submods: claims of the entity's submodules (key 266).
+  List<ffi.EatSubmodule> submods = [];
+
+  /// BootCount: number of times the device has booted (key 267).
+  int? bootCount;
+
+  /// BootSeed: random value unique to the current boot cycle (key 268).
+  Uint8List? bootSeed;
+
+  /// SwName: name of the firmware or software (key 270).
+  String? swName;
+
+  /// SwVersion: software version identifier (key 271).
+  String? swVersion;
+
+  /// Measurements: measurements of the software components (key 273).
+  List<ffi.EatMeasurement> measurements = [];
+
+  /// Status: entry of the token in a status list, for revocation (key 65535).
+  ffi.CwtStatus? status;
+
+  final Map<int, cbor.CborValue> _custom = {};
 
   /// Sets the Confirm claim to bind an xDSA public key to this token.
-  void setConfirmXdsa(xdsa.PublicKey key) {
-    _map[8] = {
-      1: {1: _algorithmIdXdsa, -2: key.toBytes()},
-    };
-  }
+  void setConfirmXdsa(xdsa.PublicKey key) =>
+      _confirm = ffi.CwtConfirmKey.xdsa(key.inner);
 
   /// Sets the Confirm claim to bind an xHPKE public key to this token.
-  void setConfirmXhpke(xhpke.PublicKey key) {
-    _map[8] = {
-      1: {1: _algorithmIdXhpke, -2: key.toBytes()},
-    };
-  }
+  void setConfirmXhpke(xhpke.PublicKey key) =>
+      _confirm = ffi.CwtConfirmKey.xhpke(key.inner);
 
   /// Extracts the bound xDSA public key from the Confirm claim, or null if
   /// absent or a different key type.
-  xdsa.PublicKey? getConfirmXdsa() {
-    final (kty, bytes) = _readConfirm();
-    if (kty != _algorithmIdXdsa || bytes == null) return null;
-    return xdsa.PublicKey.fromBytes(bytes);
-  }
+  xdsa.PublicKey? getConfirmXdsa() => switch (_confirm) {
+    ffi.CwtConfirmKey_Xdsa(:final field0) => xdsa.PublicKeyInternal.wrap(
+      field0,
+    ),
+    _ => null,
+  };
 
   /// Extracts the bound xHPKE public key from the Confirm claim, or null if
   /// absent or a different key type.
-  xhpke.PublicKey? getConfirmXhpke() {
-    final (kty, bytes) = _readConfirm();
-    if (kty != _algorithmIdXhpke || bytes == null) return null;
-    return xhpke.PublicKey.fromBytes(bytes);
-  }
-
-  /// Reads the Confirm claim's key type and raw key bytes.
-  /// Returns (null, null) if the claim is absent or malformed.
-  (int?, Uint8List?) _readConfirm() {
-    final cnf = _map[8];
-    if (cnf is! Map) return (null, null);
-    final coseKey = cnf[1];
-    if (coseKey is! Map) return (null, null);
-    final kty = coseKey[1];
-    if (kty is! int) return (null, null);
-    final x = _asBytes(coseKey[-2]);
-    if (x == null) return (null, null);
-    return (kty, x);
-  }
-
-  /// Reads a byte-string claim, normalizing to [Uint8List].
-  ///
-  /// Workaround for https://github.com/shamblett/cbor/issues/88:
-  /// package:cbor decodes CBOR byte strings (major type 2) as `Uint8Buffer`
-  /// (a `List<int>`) instead of [Uint8List].
-  static Uint8List? _asBytes(Object? v) {
-    if (v is Uint8List) return v;
-    if (v is List<int>) return Uint8List.fromList(v);
-    return null;
-  }
-
-  /// UEID: a globally unique device identifier (key 256).
-  Uint8List? get ueid => _asBytes(_map[256]);
-  set ueid(Uint8List? value) => _set(256, value);
+  xhpke.PublicKey? getConfirmXhpke() => switch (_confirm) {
+    ffi.CwtConfirmKey_Xhpke(:final field0) => xhpke.PublicKeyInternal.wrap(
+      field0,
+    ),
+    _ => null,
+  };
 
   /// OEMID: hardware manufacturer identifier (key 258).
   ///
   /// Use [setOemidRandom], [setOemidIeee], or [setOemidPen] to set.
-  /// The getter returns the raw CBOR value (Uint8List or int).
-  Object? get oemid => _map[258];
+  ffi.EatOemid? get oemid => _oemid;
 
   /// Sets OEMID to a 16-byte random manufacturer identifier.
   void setOemidRandom(Uint8List id) {
     if (id.length != 16) {
       throw ArgumentError.value(id.length, 'id.length', 'must be 16 bytes');
     }
-    _map[258] = id;
+    _oemid = ffi.EatOemid.random(id);
   }
 
   /// Sets OEMID to a 3-byte IEEE OUI/MA-L.
@@ -188,127 +239,74 @@ class Claims {
     if (id.length != 3) {
       throw ArgumentError.value(id.length, 'id.length', 'must be 3 bytes');
     }
-    _map[258] = id;
+    _oemid = ffi.EatOemid.ieee(id);
   }
 
   /// Sets OEMID to an IANA Private Enterprise Number.
-  void setOemidPen(int pen) => _map[258] = pen;
-
-  /// HwModel: product or board model identifier (key 259).
-  Uint8List? get hwModel => _asBytes(_map[259]);
-  set hwModel(Uint8List? value) => _set(259, value);
-
-  /// HwVersion: hardware revision identifier (key 260).
-  /// Stored as a 1-element CBOR array per RFC 9711 Section 4.2.5.
-  String? get hwVersion {
-    final v = _map[260];
-    if (v is List && v.isNotEmpty) return v[0] as String?;
-    return null;
-  }
-
-  set hwVersion(String? value) => _set(260, value != null ? [value] : null);
-
-  /// Uptime: seconds since last boot (key 261).
-  int? get uptime => _map[261] as int?;
-  set uptime(int? value) => _set(261, value);
-
-  /// OemBoot: whether the boot chain is OEM-authorized (key 262).
-  bool? get oemBoot => _map[262] as bool?;
-  set oemBoot(bool? value) => _set(262, value);
-
-  /// DebugStatus: debug port state (key 263).
-  DebugState? get debugStatus {
-    final v = _map[263];
-    if (v is! int || v < 0 || v > 4) return null;
-    return DebugState.values[v];
-  }
-
-  set debugStatus(DebugState? value) => _set(263, value?.index);
-
-  /// BootCount: number of times the device has booted (key 267).
-  int? get bootCount => _map[267] as int?;
-  set bootCount(int? value) => _set(267, value);
-
-  /// BootSeed: random value unique to the current boot cycle (key 268).
-  Uint8List? get bootSeed => _asBytes(_map[268]);
-  set bootSeed(Uint8List? value) => _set(268, value);
-
-  /// SwName: name of the firmware or software (key 270).
-  String? get swName => _map[270] as String?;
-  set swName(String? value) => _set(270, value);
-
-  /// SwVersion: software version identifier (key 271).
-  /// Stored as a 1-element CBOR array per RFC 9711 Section 4.2.7.
-  String? get swVersion {
-    final v = _map[271];
-    if (v is List && v.isNotEmpty) return v[0] as String?;
-    return null;
-  }
-
-  set swVersion(String? value) => _set(271, value != null ? [value] : null);
+  void setOemidPen(int pen) => _oemid = ffi.EatOemid.pen(BigInt.from(pen));
 
   /// IntendedUse: the token's purpose (key 275).
   IntendedUse? get intendedUse {
-    final v = _map[275];
-    if (v is! int || v < 1 || v > 5) return null;
-    return IntendedUse.values[v - 1];
+    final v = _custom[_keyIntendedUse];
+    if (v is! cbor.CborValue_Int) return null;
+    final use = v.field0.toInt();
+    if (use < 1 || use > IntendedUse.values.length) return null;
+    return IntendedUse.values[use - 1];
   }
 
-  set intendedUse(IntendedUse? value) =>
-      _set(275, value != null ? value.index + 1 : null);
+  set intendedUse(IntendedUse? value) => this[_keyIntendedUse] = value != null
+      ? cbor.CborValue.int(PlatformInt64Util.from(value.index + 1))
+      : null;
 
   /// Gets a custom claim by its integer key.
-  Object? operator [](int key) => _map[key];
-
-  /// Sets a custom claim by its integer key.
-  void operator []=(int key, Object? value) => _set(key, value);
+  cbor.CborValue? operator [](int key) => _custom[key];
 
-  void _set(int key, Object? value) {
+  /// Sets a custom claim by its integer key, which must not be the key of
+  /// any of the typed claims.
+  void operator []=(int key, cbor.CborValue? value) {
     if (value != null) {
-      _map[key] = value;
+      _custom[key] = value;
     } else {
-      _map.remove(key);
+      _custom.remove(key);
     }
   }
 
-  /// Encodes the claims to CBOR bytes.
-  Uint8List _encode() => Uint8List.fromList(cbor.cbor.encode(_map));
-
-  /// Decodes claims from CBOR bytes.
-  static Claims _decode(Uint8List bytes) {
-    final decoded = cbor.cbor.decode(bytes);
-    if (decoded is! Map) {
-      throw FormatException('CWT claims must be a CBOR map');
-    }
-    final map = <int, Object?>{};
-    for (final entry in decoded.entries) {
-      if (entry.key is! int) {
-        throw FormatException(
-          'CWT claim key must be an integer, got ${entry.key.runtimeType}',
-        );
-      }
-      map[entry.key as int] = entry.value;
-    }
-    return Claims._(map);
-  }
-}
-
-/// Debug port state per RFC 9711 Section 4.2.9.
-enum DebugState {
-  /// Debug is currently enabled.
-  enabled,
-
-  /// Debug is currently disabled.
-  disabled,
-
-  /// Debug was disabled at boot and has not been enabled since.
-  disabledSinceBoot,
-
-  /// Debug is disabled and cannot be re-enabled.
-  disabledPermanently,
+  /// Converts the claims to the typed claims encoded by Rust.
+  ffi.CwtClaims toCwtClaims() => ffi.CwtClaims(
+    iss: issuer,
+    sub: subject,
+    aud: audience,
+    exp: _bigInt(expiration),
+    nbf: _bigInt(notBefore),
+    iat: _bigInt(issuedAt),
+    cti: tokenId,
+    cnf: _confirm,
+    eatNonce: eatNonce,
+    ueid: ueid,
+    oemid: _oemid,
+    hwModel: hwModel,
+    hwVersion: hwVersion,
+    uptime: _bigInt(uptime),
+    oemBoot: oemBoot,
+    dbgstat: debugStatus,
+    submods: submods,
+    bootCount: _bigInt(bootCount),
+    bootSeed: bootSeed,
+    swName: swName,
+    swVersion: swVersion,
+    measurements: measurements,
+    status: status,
+    custom: [
+      for (final entry in _custom.entries)
+        ffi.CwtCustomClaim(
+          key: PlatformInt64Util.from(entry.key),
+          value: cbor.encode(entry.value),
+        ),
+    ],
+  );
 
-  /// All debug, including DMA-based, is permanently disabled.
-  disabledFullyPermanently,
+  static BigInt? _bigInt(int? value) =>
+      value != null ? BigInt.from(value) : null;
 }
 
 /// Token intended purpose per RFC 9711 Section 4.3.3.
@@ -340,28 +338,50 @@ Uint8List issue({
   required Claims claims,
   required xdsa.SecretKey signer,
   required Uint8List domain,
-}) => ffi.cwtIssue(
-  claimsCbor: claims._encode(),
+}) => ffi.cwtIssueClaims(
+  claims: claims.toCwtClaims(),
   signer: signer.inner,
   domain: domain,
 );
 
-/// Verifies a CWT's COSE signature and temporal validity, then returns the
-/// decoded claims.
+/// Verifies a CWT's COSE signature and checks its claims against the given
+/// policy, then returns the decoded claims.
 ///
 /// When [now] is provided (Unix timestamp in seconds), temporal claims are
 /// validated: nbf must be present and `nbf <= now`, and if exp is present
-/// then `now < exp`. When [now] is null, temporal validation is skipped.
+/// then `now < exp`, both relaxed by [leeway]. When [now] is null, temporal
+/// validation is skipped.
 ///
 /// - [token]: The serialized CWT
 /// - [verifier]: The xDSA public key to verify against
 /// - [domain]: Application-specific domain separator
 /// - [now]: Current Unix timestamp for temporal validation (null to skip)
+/// - [leeway]: Allowed clock skew in seconds
+/// - [maxAge]: Maximum seconds since iat (null to skip, requires [now])
+/// - [issuer]: Required iss value (null to accept any)
+/// - [audiences]: Accepted aud values (empty to accept any)
+/// - [requiredClaims]: Claim keys that must be present
+/// - [confirmKey]: Required type of the cnf key (null to accept any)
+/// - [eatNonce]: Expected eat_nonce challenge (null to skip)
+/// - [statusList]: A verified status list the token's status claim must be
+///   valid in (null to skip)
+/// - [schema]: A compiled CDDL schema the claims must match (null to skip)
+/// - [rule]: Name of the schema rule the claims must match
 Claims verify({
   required Uint8List token,
   required xdsa.PublicKey verifier,
   required Uint8List domain,
   int? now,
+  int leeway = 0,
+  int? maxAge,
+  String? issuer,
+  List<String> audiences = const [],
+  List<int> requiredClaims = const [],
+  ffi.CwtConfirmKeyType? confirmKey,
+  Uint8List? eatNonce,
+  ffi.StatusList? statusList,
+  cbor.CddlSchema? schema,
+  String? rule,
 }) {
   if (now != null && now < 0) {
     throw ArgumentError.value(
@@ -370,12 +390,40 @@ Claims verify({
       'must be a non-negative Unix timestamp',
     );
   }
-  return Claims._decode(
-    ffi.cwtVerify(
+  if (leeway < 0) {
+    throw ArgumentError.value(leeway, 'leeway', 'must be non-negative');
+  }
+  if (maxAge != null && maxAge < 0) {
+    throw ArgumentError.value(maxAge, 'maxAge', 'must be non-negative');
+  }
+  if (maxAge != null && now == null) {
+    throw ArgumentError.value(maxAge, 'maxAge', 'requires now');
+  }
+  if ((schema == null) != (rule == null)) {
+    throw ArgumentError.value(
+      rule,
+      'rule',
+      'must be given together with schema',
+    );
+  }
+  return Claims.fromCwtClaims(
+    ffi.cwtVerifyClaims(
       token: token,
       verifier: verifier.inner,
       domain: domain,
-      now: now != null ? BigInt.from(now) : null,
+      validation: ffi.CwtValidation(
+        now: now != null ? BigInt.from(now) : null,
+        leewaySecs: BigInt.from(leeway),
+        maxAgeSecs: maxAge != null ? BigInt.from(maxAge) : null,
+        issuer: issuer,
+        audiences: audiences,
+        requiredClaims: Int64List.fromList(requiredClaims),
+        confirmKey: confirmKey,
+        eatNonce: eatNonce,
+      ),
+      statusList: statusList,
+      schema: schema,
+      rule: rule,
     ),
   );
 }
@@ -393,4 +441,4 @@ xdsa.Fingerprint signer({required Uint8List token}) =>
 /// trusted until verified with [verify]. Use [signer] to extract the signer's
 /// fingerprint for key lookup.
 Claims peek({required Uint8List token}) =>
-    Claims._decode(ffi.cwtPeek(token: token));
+    Claims.fromCwtClaims(ffi.cwtPeekClaims(token: token));
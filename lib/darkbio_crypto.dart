@@ -42,6 +42,12 @@
 /// final key = hkdf.key(secret: secret, salt: salt, info: info);
 /// ```
 ///
+/// ## Errors
+///
+/// Fallible calls throw a [CryptoError], a sealed class with one subclass per
+/// kind of failure, e.g. [CryptoError_InvalidLength] for a malformed input or
+/// [CryptoError_SignatureMismatch] for a bad signature.
+///
 /// ## Available modules
 ///
 /// - **xdsa**: Composite ML-DSA-65 + Ed25519 signatures (quantum-resistant)
@@ -53,6 +59,7 @@
 /// - **argon2**: Password-based key derivation
 /// - **rand**: Cryptographically secure random bytes
 /// - **stream**: STREAM encryption with ChaCha20-Poly1305
+/// - **x509**: X.509 certificate templates, inspection and chain verification
 library;
 
 import 'dart:io';
@@ -61,6 +68,8 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 import 'src/generated/frb_generated.dart';
 
+export 'src/generated/api/error.dart';
+
 /// Initializes the crypto library. Call this once at app startup before
 /// using any crypto functions.
 Future<void> init() async {
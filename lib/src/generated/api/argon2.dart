@@ -31,3 +31,36 @@ Uint8List argon2Key({
   threads: threads,
   keyLength: keyLength,
 );
+
+/// Argon2Params are the Argon2id cost parameters used for password hashing.
+class Argon2Params {
+  /// Number of iterations
+  final int time;
+  /// Memory size in KiB
+  final int memory;
+  /// Degree of parallelism
+  final int threads;
+
+  const Argon2Params({
+    required this.time,
+    required this.memory,
+    required this.threads,
+  });
+
+  /// Returns the RFC 9106 second recommended option (t=3, m=64MiB, p=4),
+  /// suitable for memory constrained environments such as mobile devices.
+  static Argon2Params recommended() =>
+      RustLib.instance.api.crateApiArgon2Argon2ParamsRecommended();
+
+  @override
+  int get hashCode => time.hashCode ^ memory.hashCode ^ threads.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Argon2Params &&
+          runtimeType == other.runtimeType &&
+          time == other.time &&
+          memory == other.memory &&
+          threads == other.threads;
+}
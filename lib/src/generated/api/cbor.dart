@@ -5,6 +5,8 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'cbor.freezed.dart';
 
 /// Validates that the given bytes are well-formed CBOR.
 ///
@@ -16,3 +18,93 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 /// Returns an error if the bytes are not valid CBOR.
 void cborVerify({required List<int> data}) =>
     RustLib.instance.api.crateApiCborCborVerify(data: data);
+
+/// Encodes a value into deterministic CBOR, sorting map entries by key.
+///
+/// Returns an error on duplicate map keys or nesting beyond the profile's
+/// depth limit.
+Uint8List cborEncode({required CborValue value}) =>
+    RustLib.instance.api.crateApiCborCborEncode(value: value);
+
+/// Decodes deterministic CBOR into a value, rejecting anything outside the
+/// profile enforced by `cbor_verify`.
+CborValue cborDecode({required List<int> data}) =>
+    RustLib.instance.api.crateApiCborCborDecode(data: data);
+
+/// Validates CBOR data against a rule of a CDDL (RFC 8610) schema, reporting
+/// the path of the first mismatch, e.g. `$[3][0]`.
+///
+/// Compiles the schema on every call; use `CddlSchema` to reuse it.
+///
+/// - `data`: The CBOR data to validate
+/// - `cddl`: CDDL rule definitions
+/// - `rule`: Name of the rule the data must match
+void cborValidate({
+  required List<int> data,
+  required String cddl,
+  required String rule,
+}) => RustLib.instance.api.crateApiCborCborValidate(
+  data: data,
+  cddl: cddl,
+  rule: rule,
+);
+
+/// Renders CBOR as RFC 8949 §8 diagnostic notation, e.g. `{1: "abc", 2: h'0102'}`.
+///
+/// Returns an error if the bytes are not valid CBOR within the profile.
+String cborToDiagnostic({required List<int> data}) =>
+    RustLib.instance.api.crateApiCborCborToDiagnostic(data: data);
+
+/// Parses RFC 8949 §8 diagnostic notation into deterministic CBOR.
+///
+/// Supports `null`, `true`, `false`, integers, `"text"` with JSON escapes,
+/// `h'hex'` byte strings, arrays and maps with integer keys. Map entries are
+/// sorted into deterministic order.
+Uint8List cborFromDiagnostic({required String text}) =>
+    RustLib.instance.api.crateApiCborCborFromDiagnostic(text: text);
+
+/// CborMapEntry is a key-value pair of a CBOR map.
+class CborMapEntry {
+  /// Integer key
+  final PlatformInt64 key;
+  /// Value of the entry
+  final CborValue value;
+
+  const CborMapEntry({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CborMapEntry &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
+
+/// CborValue is a CBOR data item within the restricted deterministic profile:
+/// booleans, null, integers, text and byte strings, arrays, and maps with
+/// integer keys.
+@freezed
+sealed class CborValue with _$CborValue {
+  const CborValue._();
+
+  /// Null
+  const factory CborValue.null_() = CborValue_Null;
+  /// Boolean
+  const factory CborValue.bool(bool field0) = CborValue_Bool;
+  /// Integer in the i64 range
+  const factory CborValue.int(PlatformInt64 field0) = CborValue_Int;
+  /// Unsigned integer above the i64 range
+  const factory CborValue.uint(BigInt field0) = CborValue_Uint;
+  /// UTF-8 text string
+  const factory CborValue.text(String field0) = CborValue_Text;
+  /// Byte string
+  const factory CborValue.bytes(Uint8List field0) = CborValue_Bytes;
+  /// Array of items
+  const factory CborValue.array(List<CborValue> field0) = CborValue_Array;
+  /// Map with integer keys
+  const factory CborValue.map(List<CborMapEntry> field0) = CborValue_Map;
+}
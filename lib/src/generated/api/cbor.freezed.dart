@@ -0,0 +1,510 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'cbor.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$CborValue {
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CborValue);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'CborValue()';
+}
+
+}
+
+/// @nodoc
+class $CborValueCopyWith<$Res>  {
+$CborValueCopyWith(CborValue _, $Res Function(CborValue) __);
+}
+
+
+/// @nodoc
+
+class CborValue_Null extends CborValue {
+  const CborValue_Null(): super._();
+  
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CborValue_Null);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'CborValue.null_()';
+}
+
+}
+
+
+/// @nodoc
+
+class CborValue_Bool extends CborValue {
+  const CborValue_Bool(this.field0): super._();
+  
+ final  bool field0;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CborValue_BoolCopyWith<CborValue_Bool> get copyWith => _$CborValue_BoolCopyWithImpl<CborValue_Bool>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CborValue_Bool&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CborValue.bool(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CborValue_BoolCopyWith<$Res> implements $CborValueCopyWith<$Res> {
+  factory $CborValue_BoolCopyWith(CborValue_Bool value, $Res Function(CborValue_Bool) _then) = _$CborValue_BoolCopyWithImpl;
+@useResult
+$Res call({
+ bool field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CborValue_BoolCopyWithImpl<$Res>
+    implements $CborValue_BoolCopyWith<$Res> {
+  _$CborValue_BoolCopyWithImpl(this._self, this._then);
+
+  final CborValue_Bool _self;
+  final $Res Function(CborValue_Bool) _then;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CborValue_Bool(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CborValue_Int extends CborValue {
+  const CborValue_Int(this.field0): super._();
+  
+ final  PlatformInt64 field0;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CborValue_IntCopyWith<CborValue_Int> get copyWith => _$CborValue_IntCopyWithImpl<CborValue_Int>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CborValue_Int&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CborValue.int(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CborValue_IntCopyWith<$Res> implements $CborValueCopyWith<$Res> {
+  factory $CborValue_IntCopyWith(CborValue_Int value, $Res Function(CborValue_Int) _then) = _$CborValue_IntCopyWithImpl;
+@useResult
+$Res call({
+ PlatformInt64 field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CborValue_IntCopyWithImpl<$Res>
+    implements $CborValue_IntCopyWith<$Res> {
+  _$CborValue_IntCopyWithImpl(this._self, this._then);
+
+  final CborValue_Int _self;
+  final $Res Function(CborValue_Int) _then;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CborValue_Int(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CborValue_Uint extends CborValue {
+  const CborValue_Uint(this.field0): super._();
+  
+ final  BigInt field0;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CborValue_UintCopyWith<CborValue_Uint> get copyWith => _$CborValue_UintCopyWithImpl<CborValue_Uint>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CborValue_Uint&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CborValue.uint(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CborValue_UintCopyWith<$Res> implements $CborValueCopyWith<$Res> {
+  factory $CborValue_UintCopyWith(CborValue_Uint value, $Res Function(CborValue_Uint) _then) = _$CborValue_UintCopyWithImpl;
+@useResult
+$Res call({
+ BigInt field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CborValue_UintCopyWithImpl<$Res>
+    implements $CborValue_UintCopyWith<$Res> {
+  _$CborValue_UintCopyWithImpl(this._self, this._then);
+
+  final CborValue_Uint _self;
+  final $Res Function(CborValue_Uint) _then;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CborValue_Uint(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CborValue_Text extends CborValue {
+  const CborValue_Text(this.field0): super._();
+  
+ final  String field0;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CborValue_TextCopyWith<CborValue_Text> get copyWith => _$CborValue_TextCopyWithImpl<CborValue_Text>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CborValue_Text&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CborValue.text(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CborValue_TextCopyWith<$Res> implements $CborValueCopyWith<$Res> {
+  factory $CborValue_TextCopyWith(CborValue_Text value, $Res Function(CborValue_Text) _then) = _$CborValue_TextCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CborValue_TextCopyWithImpl<$Res>
+    implements $CborValue_TextCopyWith<$Res> {
+  _$CborValue_TextCopyWithImpl(this._self, this._then);
+
+  final CborValue_Text _self;
+  final $Res Function(CborValue_Text) _then;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CborValue_Text(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CborValue_Bytes extends CborValue {
+  const CborValue_Bytes(this.field0): super._();
+  
+ final  Uint8List field0;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CborValue_BytesCopyWith<CborValue_Bytes> get copyWith => _$CborValue_BytesCopyWithImpl<CborValue_Bytes>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CborValue_Bytes&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CborValue.bytes(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CborValue_BytesCopyWith<$Res> implements $CborValueCopyWith<$Res> {
+  factory $CborValue_BytesCopyWith(CborValue_Bytes value, $Res Function(CborValue_Bytes) _then) = _$CborValue_BytesCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CborValue_BytesCopyWithImpl<$Res>
+    implements $CborValue_BytesCopyWith<$Res> {
+  _$CborValue_BytesCopyWithImpl(this._self, this._then);
+
+  final CborValue_Bytes _self;
+  final $Res Function(CborValue_Bytes) _then;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CborValue_Bytes(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CborValue_Array extends CborValue {
+  const CborValue_Array(final List<CborValue> field0): _field0 = field0,super._();
+  
+ final  List<CborValue> _field0;
+ List<CborValue> get field0 {
+  if (_field0 is EqualUnmodifiableListView) return _field0;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_field0);
+}
+
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CborValue_ArrayCopyWith<CborValue_Array> get copyWith => _$CborValue_ArrayCopyWithImpl<CborValue_Array>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CborValue_Array&&const DeepCollectionEquality().equals(other._field0, _field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_field0));
+
+@override
+String toString() {
+  return 'CborValue.array(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CborValue_ArrayCopyWith<$Res> implements $CborValueCopyWith<$Res> {
+  factory $CborValue_ArrayCopyWith(CborValue_Array value, $Res Function(CborValue_Array) _then) = _$CborValue_ArrayCopyWithImpl;
+@useResult
+$Res call({
+ List<CborValue> field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CborValue_ArrayCopyWithImpl<$Res>
+    implements $CborValue_ArrayCopyWith<$Res> {
+  _$CborValue_ArrayCopyWithImpl(this._self, this._then);
+
+  final CborValue_Array _self;
+  final $Res Function(CborValue_Array) _then;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CborValue_Array(
+null == field0 ? _self._field0 : field0 // ignore: cast_nullable_to_non_nullable
+as List<CborValue>,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CborValue_Map extends CborValue {
+  const CborValue_Map(final List<CborMapEntry> field0): _field0 = field0,super._();
+  
+ final  List<CborMapEntry> _field0;
+ List<CborMapEntry> get field0 {
+  if (_field0 is EqualUnmodifiableListView) return _field0;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_field0);
+}
+
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CborValue_MapCopyWith<CborValue_Map> get copyWith => _$CborValue_MapCopyWithImpl<CborValue_Map>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CborValue_Map&&const DeepCollectionEquality().equals(other._field0, _field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_field0));
+
+@override
+String toString() {
+  return 'CborValue.map(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CborValue_MapCopyWith<$Res> implements $CborValueCopyWith<$Res> {
+  factory $CborValue_MapCopyWith(CborValue_Map value, $Res Function(CborValue_Map) _then) = _$CborValue_MapCopyWithImpl;
+@useResult
+$Res call({
+ List<CborMapEntry> field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CborValue_MapCopyWithImpl<$Res>
+    implements $CborValue_MapCopyWith<$Res> {
+  _$CborValue_MapCopyWithImpl(this._self, this._then);
+
+  final CborValue_Map _self;
+  final $Res Function(CborValue_Map) _then;
+
+/// Create a copy of CborValue
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CborValue_Map(
+null == field0 ? _self._field0 : field0 // ignore: cast_nullable_to_non_nullable
+as List<CborMapEntry>,
+  ));
+}
+
+
+}
+
+
+// dart format on
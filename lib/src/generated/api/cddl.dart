@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>>
+abstract class CddlSchema implements RustOpaqueInterface {
+  /// Compiles CDDL source into a schema.
+  ///
+  /// - `source`: CDDL rule definitions
+  static CddlSchema compile({required String source}) =>
+      RustLib.instance.api.crateApiCddlCddlSchemaCompile(source: source);
+
+  /// Validates CBOR data against a rule of the schema, reporting the path of
+  /// the first mismatch.
+  ///
+  /// - `data`: The CBOR data to validate
+  /// - `rule`: Name of the rule the data must match
+  void validate({required List<int> data, required String rule});
+}
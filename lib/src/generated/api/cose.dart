@@ -4,6 +4,7 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'cddl.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'xdsa.dart';
 import 'xhpke.dart';
@@ -48,20 +49,56 @@ Uint8List coseSignDetached({
 /// - `verifier`: The public key to verify against
 /// - `domain`: Application-specific domain separator
 /// - `max_drift_secs`: Maximum allowed clock drift (None for no time check)
+/// - `schema`: A compiled schema the payload must match (None to skip)
+/// - `rule`: Name of the schema rule the payload must match
 Uint8List coseVerify({
   required List<int> msgToCheck,
   required List<int> msgToAuth,
   required XdsaPublicKey verifier,
   required List<int> domain,
   BigInt? maxDriftSecs,
+  CddlSchema? schema,
+  String? rule,
 }) => RustLib.instance.api.crateApiCoseCoseVerify(
   msgToCheck: msgToCheck,
   msgToAuth: msgToAuth,
   verifier: verifier,
   domain: domain,
   maxDriftSecs: maxDriftSecs,
+  schema: schema,
+  rule: rule,
 );
 
+/// Verifies a COSE_Sign1 signature and returns the embedded payload together
+/// with the protected header fields.
+///
+/// - `msg_to_check`: The COSE_Sign1 structure to verify
+/// - `msg_to_auth`: Additional authenticated data (external AAD)
+/// - `verifier`: The public key to verify against
+/// - `domain`: Application-specific domain separator
+/// - `max_drift_secs`: Maximum allowed clock drift (None for no time check)
+CoseSign1Info coseVerifyInfo({
+  required List<int> msgToCheck,
+  required List<int> msgToAuth,
+  required XdsaPublicKey verifier,
+  required List<int> domain,
+  BigInt? maxDriftSecs,
+}) => RustLib.instance.api.crateApiCoseCoseVerifyInfo(
+  msgToCheck: msgToCheck,
+  msgToAuth: msgToAuth,
+  verifier: verifier,
+  domain: domain,
+  maxDriftSecs: maxDriftSecs,
+);
+
+/// Extracts the embedded payload and protected header fields from a
+/// COSE_Sign1 without verifying.
+///
+/// Warning: This does NOT verify the signature. The returned data is
+/// unauthenticated and should not be trusted until verified with `verify`.
+CoseSign1Info coseInspect({required List<int> signature}) =>
+    RustLib.instance.api.crateApiCoseCoseInspect(signature: signature);
+
 /// Verifies a COSE_Sign1 signature with a detached payload.
 ///
 /// - `msg_to_check`: The COSE_Sign1 structure to verify
@@ -187,3 +224,171 @@ Uint8List coseOpen({
   domain: domain,
   maxDriftSecs: maxDriftSecs,
 );
+
+/// Signs a message then encrypts it to multiple recipients at once, producing
+/// a COSE_Encrypt with one recipient structure per public key.
+///
+/// The signed message is encrypted a single time with ChaCha20-Poly1305 under a
+/// random content key, which is then sealed to each recipient with xHPKE.
+///
+/// - `msg_to_seal`: The payload to sign and encrypt
+/// - `msg_to_auth`: Additional authenticated data (external AAD)
+/// - `signer`: The private key to sign with
+/// - `recipients`: The public keys to encrypt to
+/// - `domain`: Application-specific domain separator
+Uint8List coseSealMulti({
+  required List<int> msgToSeal,
+  required List<int> msgToAuth,
+  required XdsaSecretKey signer,
+  required List<XhpkePublicKey> recipients,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiCoseCoseSealMulti(
+  msgToSeal: msgToSeal,
+  msgToAuth: msgToAuth,
+  signer: signer,
+  recipients: recipients,
+  domain: domain,
+);
+
+/// Decrypts a COSE_Encrypt using this recipient's entry and verifies the
+/// signed message inside.
+///
+/// - `msg_to_open`: The COSE_Encrypt structure to decrypt and verify
+/// - `msg_to_auth`: Additional authenticated data (external AAD)
+/// - `recipient`: The private key to decrypt with
+/// - `sender`: The public key to verify the signature against
+/// - `domain`: Application-specific domain separator
+/// - `max_drift_secs`: Maximum allowed clock drift (None for no time check)
+Uint8List coseOpenMulti({
+  required List<int> msgToOpen,
+  required List<int> msgToAuth,
+  required XhpkeSecretKey recipient,
+  required XdsaPublicKey sender,
+  required List<int> domain,
+  BigInt? maxDriftSecs,
+}) => RustLib.instance.api.crateApiCoseCoseOpenMulti(
+  msgToOpen: msgToOpen,
+  msgToAuth: msgToAuth,
+  recipient: recipient,
+  sender: sender,
+  domain: domain,
+  maxDriftSecs: maxDriftSecs,
+);
+
+/// Extracts the fingerprints of all recipients from a COSE_Encrypt without
+/// decrypting.
+List<XhpkeFingerprint> coseRecipients({required List<int> ciphertext}) =>
+    RustLib.instance.api.crateApiCoseCoseRecipients(ciphertext: ciphertext);
+
+/// Creates a COSE_Sign with an embedded payload, co-signed by several keys.
+/// At least one signer is required.
+///
+/// - `msg_to_embed`: The payload to embed and sign
+/// - `msg_to_auth`: Additional authenticated data (external AAD)
+/// - `signers`: The private keys to sign with
+/// - `domain`: Application-specific domain separator
+Uint8List coseSignMulti({
+  required List<int> msgToEmbed,
+  required List<int> msgToAuth,
+  required List<XdsaSecretKey> signers,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiCoseCoseSignMulti(
+  msgToEmbed: msgToEmbed,
+  msgToAuth: msgToAuth,
+  signers: signers,
+  domain: domain,
+);
+
+/// Appends a signature to an existing COSE_Sign, failing if the key already
+/// signed it.
+///
+/// - `msg_to_sign`: The COSE_Sign structure to co-sign
+/// - `msg_to_auth`: The same additional authenticated data used by the other signers
+/// - `signer`: The private key to sign with
+/// - `domain`: Application-specific domain separator
+Uint8List coseAddSignature({
+  required List<int> msgToSign,
+  required List<int> msgToAuth,
+  required XdsaSecretKey signer,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiCoseCoseAddSignature(
+  msgToSign: msgToSign,
+  msgToAuth: msgToAuth,
+  signer: signer,
+  domain: domain,
+);
+
+/// Extracts the fingerprints of all signers from a COSE_Sign without verifying.
+///
+/// The returned data is unauthenticated.
+List<XdsaFingerprint> coseSigners({required List<int> signature}) =>
+    RustLib.instance.api.crateApiCoseCoseSigners(signature: signature);
+
+/// Verifies that at least `threshold` of the given keys validly signed a
+/// COSE_Sign, returning the embedded payload. Signatures from other keys are
+/// ignored, and each key counts at most once.
+///
+/// - `msg_to_check`: The COSE_Sign structure to verify
+/// - `msg_to_auth`: Additional authenticated data (external AAD)
+/// - `verifiers`: The public keys whose signatures count towards the threshold
+/// - `threshold`: Minimum number of valid signatures required
+/// - `domain`: Application-specific domain separator
+/// - `max_drift_secs`: Maximum allowed clock drift (None for no time check)
+Uint8List coseVerifyThreshold({
+  required List<int> msgToCheck,
+  required List<int> msgToAuth,
+  required List<XdsaPublicKey> verifiers,
+  required BigInt threshold,
+  required List<int> domain,
+  BigInt? maxDriftSecs,
+}) => RustLib.instance.api.crateApiCoseCoseVerifyThreshold(
+  msgToCheck: msgToCheck,
+  msgToAuth: msgToAuth,
+  verifiers: verifiers,
+  threshold: threshold,
+  domain: domain,
+  maxDriftSecs: maxDriftSecs,
+);
+
+/// CoseSign1Info is the content of a COSE_Sign1 along with the fields of its
+/// protected header.
+class CoseSign1Info {
+  /// The embedded payload
+  final Uint8List payload;
+  /// Fingerprint of the signing key
+  final XdsaFingerprint signer;
+  /// Unix timestamp of the signature in seconds
+  final PlatformInt64 timestamp;
+  /// COSE algorithm identifier of the signature
+  final PlatformInt64 algorithm;
+  /// Domain the signature was verified against (None if not verified, as
+  /// the domain is not carried in the message)
+  final Uint8List? domain;
+
+  const CoseSign1Info({
+    required this.payload,
+    required this.signer,
+    required this.timestamp,
+    required this.algorithm,
+    required this.domain,
+  });
+
+  @override
+  int get hashCode =>
+      payload.hashCode ^
+      signer.hashCode ^
+      timestamp.hashCode ^
+      algorithm.hashCode ^
+      domain.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CoseSign1Info &&
+          runtimeType == other.runtimeType &&
+          payload == other.payload &&
+          signer == other.signer &&
+          timestamp == other.timestamp &&
+          algorithm == other.algorithm &&
+          domain == other.domain;
+}
@@ -4,8 +4,12 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'cddl.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 import 'xdsa.dart';
+import 'xhpke.dart';
+part 'cwt.freezed.dart';
 
 /// Issues a CWT by signing pre-encoded CBOR claims with COSE Sign1.
 ///
@@ -22,27 +26,33 @@ Uint8List cwtIssue({
   domain: domain,
 );
 
-/// Verifies a CWT's COSE signature and temporal validity, returning
-/// the raw CBOR-encoded claims.
-///
-/// When `now` is provided, temporal claims are validated: nbf must be
-/// present and `nbf <= now`, and if exp is present then `now < exp`.
-/// When `now` is `None`, temporal validation is skipped.
+/// Verifies a CWT's COSE signature and checks its claims against the
+/// validation policy, returning the raw CBOR-encoded claims.
 ///
 /// - `token`: The serialized CWT
 /// - `verifier`: The xDSA public key to verify against
 /// - `domain`: Application-specific domain separator
-/// - `now`: Current Unix timestamp for temporal validation (None to skip)
+/// - `validation`: The policy the claims must satisfy
+/// - `status_list`: A verified status list the token's status claim must be
+///   valid in (None to skip the status check)
+/// - `schema`: A compiled schema the claims must match (None to skip)
+/// - `rule`: Name of the schema rule the claims must match
 Uint8List cwtVerify({
   required List<int> token,
   required XdsaPublicKey verifier,
   required List<int> domain,
-  BigInt? now,
+  required CwtValidation validation,
+  StatusList? statusList,
+  CddlSchema? schema,
+  String? rule,
 }) => RustLib.instance.api.crateApiCwtCwtVerify(
   token: token,
   verifier: verifier,
   domain: domain,
-  now: now,
+  validation: validation,
+  statusList: statusList,
+  schema: schema,
+  rule: rule,
 );
 
 /// Extracts the signer's fingerprint from a CWT without verifying.
@@ -57,3 +67,688 @@ XdsaFingerprint cwtSigner({required List<int> token}) =>
 /// trusted until verified with `cwt_verify`.
 Uint8List cwtPeek({required List<int> token}) =>
     RustLib.instance.api.crateApiCwtCwtPeek(token: token);
+
+/// Issues a CWT from typed claims, encoding them deterministically in Rust.
+///
+/// - `claims`: The claims to sign
+/// - `signer`: The xDSA secret key to sign with
+/// - `domain`: Application-specific domain separator
+Uint8List cwtIssueClaims({
+  required CwtClaims claims,
+  required XdsaSecretKey signer,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiCwtCwtIssueClaims(
+  claims: claims,
+  signer: signer,
+  domain: domain,
+);
+
+/// Verifies a CWT's COSE signature and checks its claims against the
+/// validation policy, returning the decoded claims.
+///
+/// - `token`: The serialized CWT
+/// - `verifier`: The xDSA public key to verify against
+/// - `domain`: Application-specific domain separator
+/// - `validation`: The policy the claims must satisfy
+/// - `status_list`: A verified status list the token's status claim must be
+///   valid in (None to skip the status check)
+/// - `schema`: A compiled schema the claims must match (None to skip)
+/// - `rule`: Name of the schema rule the claims must match
+CwtClaims cwtVerifyClaims({
+  required List<int> token,
+  required XdsaPublicKey verifier,
+  required List<int> domain,
+  required CwtValidation validation,
+  StatusList? statusList,
+  CddlSchema? schema,
+  String? rule,
+}) => RustLib.instance.api.crateApiCwtCwtVerifyClaims(
+  token: token,
+  verifier: verifier,
+  domain: domain,
+  validation: validation,
+  statusList: statusList,
+  schema: schema,
+  rule: rule,
+);
+
+/// Extracts and decodes typed claims from a CWT without verifying the
+/// signature.
+///
+/// **Warning**: The returned claims are unauthenticated and should not be
+/// trusted until verified with `cwt_verify_claims`.
+CwtClaims cwtPeekClaims({required List<int> token}) =>
+    RustLib.instance.api.crateApiCwtCwtPeekClaims(token: token);
+
+/// Issues a CWT from typed claims that is signed by the issuer and then
+/// encrypted to the relying party, so the claims cannot be peeked at.
+///
+/// - `claims`: The claims to sign and encrypt
+/// - `signer`: The xDSA secret key to sign with
+/// - `recipient`: The xHPKE public key to encrypt to
+/// - `domain`: Application-specific domain separator
+Uint8List cwtIssueSealed({
+  required CwtClaims claims,
+  required XdsaSecretKey signer,
+  required XhpkePublicKey recipient,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiCwtCwtIssueSealed(
+  claims: claims,
+  signer: signer,
+  recipient: recipient,
+  domain: domain,
+);
+
+/// Decrypts a CWT issued by `cwt_issue_sealed`, verifies the issuer's
+/// signature and checks its claims against the validation policy, returning
+/// the decoded claims.
+///
+/// - `token`: The encrypted CWT
+/// - `recipient`: The xHPKE secret key to decrypt with
+/// - `issuer`: The xDSA public key to verify against
+/// - `domain`: Application-specific domain separator
+/// - `validation`: The policy the claims must satisfy
+CwtClaims cwtOpenSealed({
+  required List<int> token,
+  required XhpkeSecretKey recipient,
+  required XdsaPublicKey issuer,
+  required List<int> domain,
+  required CwtValidation validation,
+}) => RustLib.instance.api.crateApiCwtCwtOpenSealed(
+  token: token,
+  recipient: recipient,
+  issuer: issuer,
+  domain: domain,
+  validation: validation,
+);
+
+/// Verifies a delegation chain of CWTs, in which each token is signed by the
+/// xDSA key bound in the previous token's cnf claim, returning the leaf claims.
+///
+/// Every token's validity period must fall within that of its parent, and when
+/// `now` is provided each token must be valid at that time.
+///
+/// - `tokens`: The serialized CWTs, from the one signed by `root` to the leaf
+/// - `root`: The xDSA public key the first token must verify against
+/// - `domain`: Application-specific domain separator
+/// - `now`: Current Unix timestamp for temporal validation (None to skip)
+CwtClaims cwtVerifyChain({
+  required List<Uint8List> tokens,
+  required XdsaPublicKey root,
+  required List<int> domain,
+  BigInt? now,
+}) => RustLib.instance.api.crateApiCwtCwtVerifyChain(
+  tokens: tokens,
+  root: root,
+  domain: domain,
+  now: now,
+);
+
+/// Creates a proof-of-possession presentation of a CWT that binds an xDSA
+/// holder key via its cnf claim.
+///
+/// The holder signs the verifier's audience and nonce with COSE Sign1, using
+/// the SHA-256 hash of the token as external AAD, so the proof is bound to the
+/// token and cannot be mistaken for a CWT issued by the holder.
+///
+/// - `token`: The serialized CWT binding the holder's key
+/// - `holder`: The xDSA secret key confirmed by the token
+/// - `audience`: The verifier the presentation is intended for
+/// - `nonce`: The verifier's challenge
+/// - `domain`: Application-specific domain separator
+Uint8List cwtPresent({
+  required List<int> token,
+  required XdsaSecretKey holder,
+  required String audience,
+  required List<int> nonce,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiCwtCwtPresent(
+  token: token,
+  holder: holder,
+  audience: audience,
+  nonce: nonce,
+  domain: domain,
+);
+
+/// Verifies a presentation created by `cwt_present`, returning the token's
+/// claims.
+///
+/// Checks the issuer's signature and the validation policy on the token, then
+/// that the holder's proof is signed by the token's cnf key and carries the
+/// expected audience and nonce.
+///
+/// - `presentation`: The serialized presentation
+/// - `issuer`: The xDSA public key the token must verify against
+/// - `audience`: The audience the proof must be intended for
+/// - `nonce`: The challenge the proof must answer
+/// - `domain`: Application-specific domain separator
+/// - `validation`: The policy the token's claims must satisfy
+/// - `max_drift_secs`: Maximum allowed clock drift of the proof (None for no time check)
+CwtClaims cwtVerifyPresentation({
+  required List<int> presentation,
+  required XdsaPublicKey issuer,
+  required String audience,
+  required List<int> nonce,
+  required List<int> domain,
+  required CwtValidation validation,
+  BigInt? maxDriftSecs,
+}) => RustLib.instance.api.crateApiCwtCwtVerifyPresentation(
+  presentation: presentation,
+  issuer: issuer,
+  audience: audience,
+  nonce: nonce,
+  domain: domain,
+  validation: validation,
+  maxDriftSecs: maxDriftSecs,
+);
+
+/// Issues a selective disclosure CWT, replacing the disclosable claims with
+/// salted SHA-256 digests of their disclosures.
+///
+/// The claims a verifier relies on to process the token (keys 1 to 8 and the
+/// status at 65535) cannot be made disclosable.
+///
+/// - `claims`: The claims to sign
+/// - `disclosable`: Keys of the claims the holder may choose to reveal
+/// - `signer`: The xDSA secret key to sign with
+/// - `domain`: Application-specific domain separator
+SdCwt cwtIssueSd({
+  required CwtClaims claims,
+  required Int64List disclosable,
+  required XdsaSecretKey signer,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiCwtCwtIssueSd(
+  claims: claims,
+  disclosable: disclosable,
+  signer: signer,
+  domain: domain,
+);
+
+/// Creates a selective disclosure presentation revealing only the chosen
+/// claims of an SD-CWT.
+///
+/// - `token`: The serialized SD-CWT
+/// - `disclosures`: All disclosures received from the issuer
+/// - `reveal`: Keys of the claims to reveal
+Uint8List cwtPresentSd({
+  required List<int> token,
+  required List<Uint8List> disclosures,
+  required Int64List reveal,
+}) => RustLib.instance.api.crateApiCwtCwtPresentSd(
+  token: token,
+  disclosures: disclosures,
+  reveal: reveal,
+);
+
+/// Verifies a selective disclosure presentation, checking each disclosure
+/// against the digests signed by the issuer, and returns the always visible
+/// claims along with the disclosed ones. Disclosures of the claims that
+/// `cwt_issue_sd` refuses to redact are rejected.
+///
+/// - `presentation`: The serialized presentation
+/// - `verifier`: The xDSA public key to verify against
+/// - `domain`: Application-specific domain separator
+/// - `validation`: The policy the disclosed claims must satisfy
+CwtClaims cwtVerifySd({
+  required List<int> presentation,
+  required XdsaPublicKey verifier,
+  required List<int> domain,
+  required CwtValidation validation,
+}) => RustLib.instance.api.crateApiCwtCwtVerifySd(
+  presentation: presentation,
+  verifier: verifier,
+  domain: domain,
+  validation: validation,
+);
+
+/// CwtClaims is a typed set of CWT claims (RFC 8392) with the common EAT
+/// claims (RFC 9711), encoded deterministically on the Rust side.
+class CwtClaims {
+  /// Issuer (key 1)
+  final String? iss;
+  /// Subject (key 2)
+  final String? sub;
+  /// Audience (key 3)
+  final String? aud;
+  /// Expiration time as Unix timestamp (key 4)
+  final BigInt? exp;
+  /// Not-before time as Unix timestamp (key 5)
+  final BigInt? nbf;
+  /// Issued-at time as Unix timestamp (key 6)
+  final BigInt? iat;
+  /// Unique token identifier (key 7)
+  final Uint8List? cti;
+  /// Confirmation key bound to the token (key 8)
+  final CwtConfirmKey? cnf;
+  /// Attestation challenge echoed by the entity (key 10)
+  final Uint8List? eatNonce;
+  /// Universal entity identifier (key 256)
+  final Uint8List? ueid;
+  /// Hardware manufacturer identifier (key 258)
+  final EatOemid? oemid;
+  /// Hardware model identifier (key 259)
+  final Uint8List? hwModel;
+  /// Hardware version (key 260)
+  final String? hwVersion;
+  /// Seconds since the last boot (key 261)
+  final BigInt? uptime;
+  /// Whether the boot chain is OEM-authorized (key 262)
+  final bool? oemBoot;
+  /// Debug port state (key 263)
+  final EatDebugState? dbgstat;
+  /// Claims of the entity's submodules (key 266)
+  final List<EatSubmodule> submods;
+  /// Number of times the device has booted (key 267)
+  final BigInt? bootCount;
+  /// Random value unique to the current boot cycle (key 268)
+  final Uint8List? bootSeed;
+  /// Software name (key 270)
+  final String? swName;
+  /// Software version (key 271)
+  final String? swVersion;
+  /// Measurements of the entity's software components (key 273)
+  final List<EatMeasurement> measurements;
+  /// Entry of the token in a status list, for revocation (key 65535)
+  final CwtStatus? status;
+  /// Application specific claims, which must not reuse the keys above
+  final List<CwtCustomClaim> custom;
+
+  const CwtClaims({
+    required this.iss,
+    required this.sub,
+    required this.aud,
+    required this.exp,
+    required this.nbf,
+    required this.iat,
+    required this.cti,
+    required this.cnf,
+    required this.eatNonce,
+    required this.ueid,
+    required this.oemid,
+    required this.hwModel,
+    required this.hwVersion,
+    required this.uptime,
+    required this.oemBoot,
+    required this.dbgstat,
+    required this.submods,
+    required this.bootCount,
+    required this.bootSeed,
+    required this.swName,
+    required this.swVersion,
+    required this.measurements,
+    required this.status,
+    required this.custom,
+  });
+
+  @override
+  int get hashCode =>
+      iss.hashCode ^
+      sub.hashCode ^
+      aud.hashCode ^
+      exp.hashCode ^
+      nbf.hashCode ^
+      iat.hashCode ^
+      cti.hashCode ^
+      cnf.hashCode ^
+      eatNonce.hashCode ^
+      ueid.hashCode ^
+      oemid.hashCode ^
+      hwModel.hashCode ^
+      hwVersion.hashCode ^
+      uptime.hashCode ^
+      oemBoot.hashCode ^
+      dbgstat.hashCode ^
+      submods.hashCode ^
+      bootCount.hashCode ^
+      bootSeed.hashCode ^
+      swName.hashCode ^
+      swVersion.hashCode ^
+      measurements.hashCode ^
+      status.hashCode ^
+      custom.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CwtClaims &&
+          runtimeType == other.runtimeType &&
+          iss == other.iss &&
+          sub == other.sub &&
+          aud == other.aud &&
+          exp == other.exp &&
+          nbf == other.nbf &&
+          iat == other.iat &&
+          cti == other.cti &&
+          cnf == other.cnf &&
+          eatNonce == other.eatNonce &&
+          ueid == other.ueid &&
+          oemid == other.oemid &&
+          hwModel == other.hwModel &&
+          hwVersion == other.hwVersion &&
+          uptime == other.uptime &&
+          oemBoot == other.oemBoot &&
+          dbgstat == other.dbgstat &&
+          submods == other.submods &&
+          bootCount == other.bootCount &&
+          bootSeed == other.bootSeed &&
+          swName == other.swName &&
+          swVersion == other.swVersion &&
+          measurements == other.measurements &&
+          status == other.status &&
+          custom == other.custom;
+}
+
+/// CwtConfirmKey is a public key bound to a token by the cnf claim.
+@freezed
+sealed class CwtConfirmKey with _$CwtConfirmKey {
+  const CwtConfirmKey._();
+
+  /// xDSA signing key, e.g. for proof-of-possession
+  const factory CwtConfirmKey.xdsa(XdsaPublicKey field0) = CwtConfirmKey_Xdsa;
+  /// xHPKE encryption key
+  const factory CwtConfirmKey.xhpke(
+    XhpkePublicKey field0,
+  ) = CwtConfirmKey_Xhpke;
+}
+
+/// CwtConfirmKeyType selects the kind of key a token's cnf claim must hold.
+enum CwtConfirmKeyType {
+  /// xDSA signing key
+  xdsa,
+  /// xHPKE encryption key
+  xhpke,
+}
+
+/// CwtCustomClaim is an application specific claim with an integer key.
+class CwtCustomClaim {
+  /// Claim key
+  final PlatformInt64 key;
+  /// CBOR-encoded claim value
+  final Uint8List value;
+
+  const CwtCustomClaim({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CwtCustomClaim &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
+
+/// CwtStatus references the entry of a token in a status list.
+class CwtStatus {
+  /// Index of the token's status in the list
+  final BigInt idx;
+  /// URI of the status list token, as found in its sub claim
+  final String uri;
+
+  const CwtStatus({required this.idx, required this.uri});
+
+  @override
+  int get hashCode => idx.hashCode ^ uri.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CwtStatus &&
+          runtimeType == other.runtimeType &&
+          idx == other.idx &&
+          uri == other.uri;
+}
+
+/// CwtValidation is the policy a token's claims are checked against after
+/// its signature verifies. Each failed check maps to a distinct error.
+class CwtValidation {
+  /// Current Unix timestamp for temporal validation (None to skip). When
+  /// set, nbf must be present and `nbf <= now`, and if exp is present then
+  /// `now < exp`, both relaxed by `leeway_secs`.
+  final BigInt? now;
+  /// Allowed clock skew in seconds between the issuer and the verifier
+  final BigInt leewaySecs;
+  /// Maximum age in seconds since iat (None to skip). Setting it without
+  /// `now` fails every check.
+  final BigInt? maxAgeSecs;
+  /// Required iss value (None to accept any)
+  final String? issuer;
+  /// Accepted aud values, one of which must match (empty to accept any)
+  final List<String> audiences;
+  /// Claim keys that must be present
+  final Int64List requiredClaims;
+  /// Required type of the cnf key (None to accept any or none)
+  final CwtConfirmKeyType? confirmKey;
+  /// Expected eat_nonce challenge (None to skip)
+  final Uint8List? eatNonce;
+
+  const CwtValidation({
+    required this.now,
+    required this.leewaySecs,
+    required this.maxAgeSecs,
+    required this.issuer,
+    required this.audiences,
+    required this.requiredClaims,
+    required this.confirmKey,
+    required this.eatNonce,
+  });
+
+  /// Returns a policy that only checks temporal validity at `now`, without
+  /// leeway or any other constraints.
+  static CwtValidation at({BigInt? now}) =>
+      RustLib.instance.api.crateApiCwtCwtValidationAt(now: now);
+
+  @override
+  int get hashCode =>
+      now.hashCode ^
+      leewaySecs.hashCode ^
+      maxAgeSecs.hashCode ^
+      issuer.hashCode ^
+      audiences.hashCode ^
+      requiredClaims.hashCode ^
+      confirmKey.hashCode ^
+      eatNonce.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CwtValidation &&
+          runtimeType == other.runtimeType &&
+          now == other.now &&
+          leewaySecs == other.leewaySecs &&
+          maxAgeSecs == other.maxAgeSecs &&
+          issuer == other.issuer &&
+          audiences == other.audiences &&
+          requiredClaims == other.requiredClaims &&
+          confirmKey == other.confirmKey &&
+          eatNonce == other.eatNonce;
+}
+
+/// EatDebugState is the state of the entity's debug facilities.
+enum EatDebugState {
+  /// Debug is currently enabled
+  enabled,
+  /// Debug is currently disabled
+  disabled,
+  /// Debug was disabled at boot and has not been enabled since
+  disabledSinceBoot,
+  /// Debug is disabled and cannot be re-enabled
+  disabledPermanently,
+  /// All debug, including DMA-based, is permanently disabled
+  disabledFullyPermanently,
+}
+
+/// EatMeasurement is a measurement of a software component, tagged with the
+/// CoAP content format of its encoding.
+class EatMeasurement {
+  /// CoAP content format of the measurement
+  final BigInt contentType;
+  /// Encoded measurement
+  final Uint8List content;
+
+  const EatMeasurement({required this.contentType, required this.content});
+
+  @override
+  int get hashCode => contentType.hashCode ^ content.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EatMeasurement &&
+          runtimeType == other.runtimeType &&
+          contentType == other.contentType &&
+          content == other.content;
+}
+
+/// EatOemid identifies the hardware manufacturer in one of the three RFC 9711
+/// formats.
+@freezed
+sealed class EatOemid with _$EatOemid {
+  const EatOemid._();
+
+  /// 16-byte random manufacturer identifier
+  const factory EatOemid.random(Uint8List field0) = EatOemid_Random;
+  /// 3-byte IEEE OUI/MA-L
+  const factory EatOemid.ieee(Uint8List field0) = EatOemid_Ieee;
+  /// IANA Private Enterprise Number
+  const factory EatOemid.pen(BigInt field0) = EatOemid_Pen;
+}
+
+/// EatSubmodule is a named submodule of the attested entity.
+class EatSubmodule {
+  /// Submodule name
+  final String name;
+  /// Submodule claims or token
+  final EatSubmoduleValue value;
+
+  const EatSubmodule({required this.name, required this.value});
+
+  @override
+  int get hashCode => name.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EatSubmodule &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          value == other.value;
+}
+
+/// EatSubmoduleValue is the attestation of a submodule, either inline or as
+/// a separate EAT.
+@freezed
+sealed class EatSubmoduleValue with _$EatSubmoduleValue {
+  const EatSubmoduleValue._();
+
+  /// Claims set embedded in the parent token
+  const factory EatSubmoduleValue.claims(
+    CwtClaims field0,
+  ) = EatSubmoduleValue_Claims;
+  /// Separately signed EAT embedded in the parent token
+  const factory EatSubmoduleValue.embedded(
+    Uint8List field0,
+  ) = EatSubmoduleValue_Embedded;
+  /// Digest of an EAT conveyed separately from the parent token
+  const factory EatSubmoduleValue.detached({
+    required PlatformInt64 alg,
+    required Uint8List digest,
+  }) = EatSubmoduleValue_Detached;
+}
+
+/// SdCwt is a selective disclosure CWT along with the disclosures of its
+/// redacted claims, which the issuer hands over to the holder.
+class SdCwt {
+  /// The serialized CWT, carrying salted digests in place of redacted claims
+  final Uint8List token;
+  /// One disclosure per redacted claim
+  final List<Uint8List> disclosures;
+
+  const SdCwt({required this.token, required this.disclosures});
+
+  @override
+  int get hashCode => token.hashCode ^ disclosures.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SdCwt &&
+          runtimeType == other.runtimeType &&
+          token == other.token &&
+          disclosures == other.disclosures;
+}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>>
+abstract class StatusList implements RustOpaqueInterface {
+  /// Returns the number of bits per entry.
+  int bits();
+
+  /// Returns the status at `idx`.
+  int get({required BigInt idx});
+
+  /// Returns whether the list has no entries.
+  bool isEmpty();
+
+  /// Signs the status list as a CWT, with the list compressed using zlib.
+  ///
+  /// - `signer`: The xDSA secret key to sign with
+  /// - `domain`: Application-specific domain separator
+  /// - `iat`: Issuance time as Unix timestamp
+  /// - `exp`: Time after which the list must not be used (None for no expiry)
+  /// - `ttl`: Seconds the list may be cached before refetching (None to omit)
+  Uint8List issue({
+    required XdsaSecretKey signer,
+    required List<int> domain,
+    required BigInt iat,
+    BigInt? exp,
+    BigInt? ttl,
+  });
+
+  /// Returns the number of entries in the list.
+  BigInt len();
+
+  /// Creates a status list with every entry set to valid.
+  ///
+  /// - `uri`: URI the status list token will be published at
+  /// - `bits`: Number of bits per entry (1, 2, 4 or 8)
+  /// - `len`: Number of entries, rounded up to fill the last byte
+  factory StatusList({
+    required String uri,
+    required int bits,
+    required BigInt len,
+  }) => RustLib.instance.api.crateApiCwtStatusListNew(
+    uri: uri,
+    bits: bits,
+    len: len,
+  );
+
+  /// Sets the status at `idx`, which must fit in `bits` bits.
+  void set({required BigInt idx, required int status});
+
+  /// Returns the URI the status list is published at.
+  String uri();
+
+  /// Verifies a status list token and extracts the list from it.
+  ///
+  /// - `token`: The serialized status list token
+  /// - `verifier`: The xDSA public key of the status list issuer
+  /// - `domain`: Application-specific domain separator
+  /// - `now`: Current Unix timestamp to check nbf, exp and ttl against (None
+  ///   to skip)
+  ///
+  /// A list is rejected once `now` reaches its exp or its iat plus ttl, as
+  /// well as before its nbf.
+  static StatusList verify({
+    required List<int> token,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    BigInt? now,
+  }) => RustLib.instance.api.crateApiCwtStatusListVerify(
+    token: token,
+    verifier: verifier,
+    domain: domain,
+    now: now,
+  );
+}
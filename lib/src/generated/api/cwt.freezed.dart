@@ -0,0 +1,594 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'cwt.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$CwtConfirmKey {
+
+ Object? get field0;
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CwtConfirmKey&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CwtConfirmKey(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+class $CwtConfirmKeyCopyWith<$Res>  {
+$CwtConfirmKeyCopyWith(CwtConfirmKey _, $Res Function(CwtConfirmKey) __);
+}
+
+
+/// @nodoc
+
+class CwtConfirmKey_Xdsa extends CwtConfirmKey {
+  const CwtConfirmKey_Xdsa(this.field0): super._();
+  
+ final  XdsaPublicKey field0;
+
+/// Create a copy of CwtConfirmKey
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CwtConfirmKey_XdsaCopyWith<CwtConfirmKey_Xdsa> get copyWith => _$CwtConfirmKey_XdsaCopyWithImpl<CwtConfirmKey_Xdsa>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CwtConfirmKey_Xdsa&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CwtConfirmKey.xdsa(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CwtConfirmKey_XdsaCopyWith<$Res> implements $CwtConfirmKeyCopyWith<$Res> {
+  factory $CwtConfirmKey_XdsaCopyWith(CwtConfirmKey_Xdsa value, $Res Function(CwtConfirmKey_Xdsa) _then) = _$CwtConfirmKey_XdsaCopyWithImpl;
+@useResult
+$Res call({
+ XdsaPublicKey field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CwtConfirmKey_XdsaCopyWithImpl<$Res>
+    implements $CwtConfirmKey_XdsaCopyWith<$Res> {
+  _$CwtConfirmKey_XdsaCopyWithImpl(this._self, this._then);
+
+  final CwtConfirmKey_Xdsa _self;
+  final $Res Function(CwtConfirmKey_Xdsa) _then;
+
+/// Create a copy of CwtConfirmKey
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CwtConfirmKey_Xdsa(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as XdsaPublicKey,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CwtConfirmKey_Xhpke extends CwtConfirmKey {
+  const CwtConfirmKey_Xhpke(this.field0): super._();
+  
+ final  XhpkePublicKey field0;
+
+/// Create a copy of CwtConfirmKey
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CwtConfirmKey_XhpkeCopyWith<CwtConfirmKey_Xhpke> get copyWith => _$CwtConfirmKey_XhpkeCopyWithImpl<CwtConfirmKey_Xhpke>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CwtConfirmKey_Xhpke&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CwtConfirmKey.xhpke(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CwtConfirmKey_XhpkeCopyWith<$Res> implements $CwtConfirmKeyCopyWith<$Res> {
+  factory $CwtConfirmKey_XhpkeCopyWith(CwtConfirmKey_Xhpke value, $Res Function(CwtConfirmKey_Xhpke) _then) = _$CwtConfirmKey_XhpkeCopyWithImpl;
+@useResult
+$Res call({
+ XhpkePublicKey field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CwtConfirmKey_XhpkeCopyWithImpl<$Res>
+    implements $CwtConfirmKey_XhpkeCopyWith<$Res> {
+  _$CwtConfirmKey_XhpkeCopyWithImpl(this._self, this._then);
+
+  final CwtConfirmKey_Xhpke _self;
+  final $Res Function(CwtConfirmKey_Xhpke) _then;
+
+/// Create a copy of CwtConfirmKey
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CwtConfirmKey_Xhpke(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as XhpkePublicKey,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+mixin _$EatOemid {
+
+ Object? get field0;
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EatOemid&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'EatOemid(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+class $EatOemidCopyWith<$Res>  {
+$EatOemidCopyWith(EatOemid _, $Res Function(EatOemid) __);
+}
+
+
+/// @nodoc
+
+class EatOemid_Random extends EatOemid {
+  const EatOemid_Random(this.field0): super._();
+  
+ final  Uint8List field0;
+
+/// Create a copy of EatOemid
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$EatOemid_RandomCopyWith<EatOemid_Random> get copyWith => _$EatOemid_RandomCopyWithImpl<EatOemid_Random>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EatOemid_Random&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'EatOemid.random(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $EatOemid_RandomCopyWith<$Res> implements $EatOemidCopyWith<$Res> {
+  factory $EatOemid_RandomCopyWith(EatOemid_Random value, $Res Function(EatOemid_Random) _then) = _$EatOemid_RandomCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$EatOemid_RandomCopyWithImpl<$Res>
+    implements $EatOemid_RandomCopyWith<$Res> {
+  _$EatOemid_RandomCopyWithImpl(this._self, this._then);
+
+  final EatOemid_Random _self;
+  final $Res Function(EatOemid_Random) _then;
+
+/// Create a copy of EatOemid
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(EatOemid_Random(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class EatOemid_Ieee extends EatOemid {
+  const EatOemid_Ieee(this.field0): super._();
+  
+ final  Uint8List field0;
+
+/// Create a copy of EatOemid
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$EatOemid_IeeeCopyWith<EatOemid_Ieee> get copyWith => _$EatOemid_IeeeCopyWithImpl<EatOemid_Ieee>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EatOemid_Ieee&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'EatOemid.ieee(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $EatOemid_IeeeCopyWith<$Res> implements $EatOemidCopyWith<$Res> {
+  factory $EatOemid_IeeeCopyWith(EatOemid_Ieee value, $Res Function(EatOemid_Ieee) _then) = _$EatOemid_IeeeCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$EatOemid_IeeeCopyWithImpl<$Res>
+    implements $EatOemid_IeeeCopyWith<$Res> {
+  _$EatOemid_IeeeCopyWithImpl(this._self, this._then);
+
+  final EatOemid_Ieee _self;
+  final $Res Function(EatOemid_Ieee) _then;
+
+/// Create a copy of EatOemid
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(EatOemid_Ieee(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class EatOemid_Pen extends EatOemid {
+  const EatOemid_Pen(this.field0): super._();
+  
+ final  BigInt field0;
+
+/// Create a copy of EatOemid
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$EatOemid_PenCopyWith<EatOemid_Pen> get copyWith => _$EatOemid_PenCopyWithImpl<EatOemid_Pen>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EatOemid_Pen&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'EatOemid.pen(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $EatOemid_PenCopyWith<$Res> implements $EatOemidCopyWith<$Res> {
+  factory $EatOemid_PenCopyWith(EatOemid_Pen value, $Res Function(EatOemid_Pen) _then) = _$EatOemid_PenCopyWithImpl;
+@useResult
+$Res call({
+ BigInt field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$EatOemid_PenCopyWithImpl<$Res>
+    implements $EatOemid_PenCopyWith<$Res> {
+  _$EatOemid_PenCopyWithImpl(this._self, this._then);
+
+  final EatOemid_Pen _self;
+  final $Res Function(EatOemid_Pen) _then;
+
+/// Create a copy of EatOemid
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(EatOemid_Pen(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+mixin _$EatSubmoduleValue {
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EatSubmoduleValue);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EatSubmoduleValue()';
+}
+
+}
+
+/// @nodoc
+class $EatSubmoduleValueCopyWith<$Res>  {
+$EatSubmoduleValueCopyWith(EatSubmoduleValue _, $Res Function(EatSubmoduleValue) __);
+}
+
+
+/// @nodoc
+
+class EatSubmoduleValue_Claims extends EatSubmoduleValue {
+  const EatSubmoduleValue_Claims(this.field0): super._();
+  
+ final  CwtClaims field0;
+
+/// Create a copy of EatSubmoduleValue
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$EatSubmoduleValue_ClaimsCopyWith<EatSubmoduleValue_Claims> get copyWith => _$EatSubmoduleValue_ClaimsCopyWithImpl<EatSubmoduleValue_Claims>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EatSubmoduleValue_Claims&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'EatSubmoduleValue.claims(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $EatSubmoduleValue_ClaimsCopyWith<$Res> implements $EatSubmoduleValueCopyWith<$Res> {
+  factory $EatSubmoduleValue_ClaimsCopyWith(EatSubmoduleValue_Claims value, $Res Function(EatSubmoduleValue_Claims) _then) = _$EatSubmoduleValue_ClaimsCopyWithImpl;
+@useResult
+$Res call({
+ CwtClaims field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$EatSubmoduleValue_ClaimsCopyWithImpl<$Res>
+    implements $EatSubmoduleValue_ClaimsCopyWith<$Res> {
+  _$EatSubmoduleValue_ClaimsCopyWithImpl(this._self, this._then);
+
+  final EatSubmoduleValue_Claims _self;
+  final $Res Function(EatSubmoduleValue_Claims) _then;
+
+/// Create a copy of EatSubmoduleValue
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(EatSubmoduleValue_Claims(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as CwtClaims,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class EatSubmoduleValue_Embedded extends EatSubmoduleValue {
+  const EatSubmoduleValue_Embedded(this.field0): super._();
+  
+ final  Uint8List field0;
+
+/// Create a copy of EatSubmoduleValue
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$EatSubmoduleValue_EmbeddedCopyWith<EatSubmoduleValue_Embedded> get copyWith => _$EatSubmoduleValue_EmbeddedCopyWithImpl<EatSubmoduleValue_Embedded>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EatSubmoduleValue_Embedded&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'EatSubmoduleValue.embedded(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $EatSubmoduleValue_EmbeddedCopyWith<$Res> implements $EatSubmoduleValueCopyWith<$Res> {
+  factory $EatSubmoduleValue_EmbeddedCopyWith(EatSubmoduleValue_Embedded value, $Res Function(EatSubmoduleValue_Embedded) _then) = _$EatSubmoduleValue_EmbeddedCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$EatSubmoduleValue_EmbeddedCopyWithImpl<$Res>
+    implements $EatSubmoduleValue_EmbeddedCopyWith<$Res> {
+  _$EatSubmoduleValue_EmbeddedCopyWithImpl(this._self, this._then);
+
+  final EatSubmoduleValue_Embedded _self;
+  final $Res Function(EatSubmoduleValue_Embedded) _then;
+
+/// Create a copy of EatSubmoduleValue
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(EatSubmoduleValue_Embedded(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class EatSubmoduleValue_Detached extends EatSubmoduleValue {
+  const EatSubmoduleValue_Detached({required this.alg, required this.digest}): super._();
+  
+ final  PlatformInt64 alg;
+ final  Uint8List digest;
+
+/// Create a copy of EatSubmoduleValue
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$EatSubmoduleValue_DetachedCopyWith<EatSubmoduleValue_Detached> get copyWith => _$EatSubmoduleValue_DetachedCopyWithImpl<EatSubmoduleValue_Detached>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EatSubmoduleValue_Detached&&(identical(other.alg, alg) || other.alg == alg)&&(identical(other.digest, digest) || other.digest == digest));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,alg,digest);
+
+@override
+String toString() {
+  return 'EatSubmoduleValue.detached(alg: $alg, digest: $digest)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $EatSubmoduleValue_DetachedCopyWith<$Res> implements $EatSubmoduleValueCopyWith<$Res> {
+  factory $EatSubmoduleValue_DetachedCopyWith(EatSubmoduleValue_Detached value, $Res Function(EatSubmoduleValue_Detached) _then) = _$EatSubmoduleValue_DetachedCopyWithImpl;
+@useResult
+$Res call({
+ PlatformInt64 alg,  Uint8List digest
+});
+
+
+
+
+}
+/// @nodoc
+class _$EatSubmoduleValue_DetachedCopyWithImpl<$Res>
+    implements $EatSubmoduleValue_DetachedCopyWith<$Res> {
+  _$EatSubmoduleValue_DetachedCopyWithImpl(this._self, this._then);
+
+  final EatSubmoduleValue_Detached _self;
+  final $Res Function(EatSubmoduleValue_Detached) _then;
+
+/// Create a copy of EatSubmoduleValue
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? alg = null,Object? digest = null,}) {
+  return _then(EatSubmoduleValue_Detached(
+alg: null == alg ? _self.alg : alg // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
+digest: null == digest ? _self.digest : digest // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+
+// dart format on
@@ -0,0 +1,119 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+/// CryptoError is the structured failure returned by every fallible call,
+/// surfaced in Dart as a sealed exception hierarchy.
+@freezed
+sealed class CryptoError with _$CryptoError implements FrbException {
+  const CryptoError._();
+
+  /// A fixed-size input (key, seed, signature, fingerprint) had the wrong size.
+  const factory CryptoError.invalidLength({
+    required BigInt expected,
+    required BigInt got,
+  }) = CryptoError_InvalidLength;
+  /// A key, signature or certificate could not be parsed.
+  const factory CryptoError.invalidEncoding({
+    required String details,
+  }) = CryptoError_InvalidEncoding;
+  /// A parameter was outside the values the call accepts, e.g. an empty key
+  /// list or a zero threshold.
+  const factory CryptoError.invalidArgument({
+    required String details,
+  }) = CryptoError_InvalidArgument;
+  /// CBOR data was malformed or outside the restricted deterministic profile.
+  const factory CryptoError.cborInvalid({
+    required String details,
+  }) = CryptoError_CborInvalid;
+  /// A signature did not verify against the given key and domain. Domains are
+  /// not carried in messages, so a wrong domain cannot be told apart from a
+  /// wrong key or tampered data.
+  const factory CryptoError.signatureMismatch({
+    required String details,
+  }) = CryptoError_SignatureMismatch;
+  /// A message was signed by or encrypted to a different key than provided.
+  const factory CryptoError.keyMismatch({
+    required String details,
+  }) = CryptoError_KeyMismatch;
+  /// Encryption could not be performed.
+  const factory CryptoError.encryptionFailed({
+    required String details,
+  }) = CryptoError_EncryptionFailed;
+  /// Authenticated decryption failed (wrong key, domain or tampered data).
+  const factory CryptoError.decryptionFailed({
+    required String details,
+  }) = CryptoError_DecryptionFailed;
+  /// The validity period ended at `exp`, before `now`.
+  const factory CryptoError.expired({
+    required BigInt exp,
+    required BigInt now,
+  }) = CryptoError_Expired;
+  /// The validity period starts at `nbf`, after `now`.
+  const factory CryptoError.notYetValid({
+    required BigInt nbf,
+    required BigInt now,
+  }) = CryptoError_NotYetValid;
+  /// The signature timestamp drifted further from the current time than allowed.
+  const factory CryptoError.staleSignature({
+    required BigInt drift,
+    required BigInt maxDrift,
+  }) = CryptoError_StaleSignature;
+  /// A token's claims were missing or malformed.
+  const factory CryptoError.invalidClaims({
+    required String details,
+  }) = CryptoError_InvalidClaims;
+  /// A certificate could not be issued or failed a policy check.
+  const factory CryptoError.invalidCertificate({
+    required String details,
+  }) = CryptoError_InvalidCertificate;
+  /// Reading or writing the underlying data failed.
+  const factory CryptoError.io({required String details}) = CryptoError_Io;
+  /// A claim required by the validation policy was absent.
+  const factory CryptoError.missingClaim({
+    required PlatformInt64 key,
+  }) = CryptoError_MissingClaim;
+  /// The token was issued by someone other than the expected issuer.
+  const factory CryptoError.issuerMismatch({
+    required String expected,
+    required String got,
+  }) = CryptoError_IssuerMismatch;
+  /// The token's audience was not among the accepted ones.
+  const factory CryptoError.audienceMismatch({
+    required String got,
+  }) = CryptoError_AudienceMismatch;
+  /// The token was issued at `iat`, more than `max_age` seconds before `now`.
+  const factory CryptoError.tokenTooOld({
+    required BigInt iat,
+    required BigInt maxAge,
+    required BigInt now,
+  }) = CryptoError_TokenTooOld;
+  /// The token's confirmation key was not of the required type.
+  const factory CryptoError.confirmKeyMismatch({
+    required String details,
+  }) = CryptoError_ConfirmKeyMismatch;
+  /// A proof answered a different challenge than the expected nonce.
+  const factory CryptoError.nonceMismatch({
+    required String details,
+  }) = CryptoError_NonceMismatch;
+  /// The token's entry in its status list is not valid (revoked or suspended).
+  const factory CryptoError.revoked({
+    required BigInt idx,
+    required int status,
+  }) = CryptoError_Revoked;
+  /// A CDDL schema could not be compiled or evaluated.
+  const factory CryptoError.invalidSchema({
+    required String details,
+  }) = CryptoError_InvalidSchema;
+  /// A CBOR value did not match its schema at `path`.
+  const factory CryptoError.schemaMismatch({
+    required String path,
+    required String details,
+  }) = CryptoError_SchemaMismatch;
+}
@@ -0,0 +1,1494 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'error.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$CryptoError {
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'CryptoError()';
+}
+
+}
+
+/// @nodoc
+class $CryptoErrorCopyWith<$Res>  {
+$CryptoErrorCopyWith(CryptoError _, $Res Function(CryptoError) __);
+}
+
+
+/// @nodoc
+
+class CryptoError_InvalidLength extends CryptoError {
+  const CryptoError_InvalidLength({required this.expected, required this.got}): super._();
+  
+ final  BigInt expected;
+ final  BigInt got;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_InvalidLengthCopyWith<CryptoError_InvalidLength> get copyWith => _$CryptoError_InvalidLengthCopyWithImpl<CryptoError_InvalidLength>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_InvalidLength&&(identical(other.expected, expected) || other.expected == expected)&&(identical(other.got, got) || other.got == got));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,expected,got);
+
+@override
+String toString() {
+  return 'CryptoError.invalidLength(expected: $expected, got: $got)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_InvalidLengthCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_InvalidLengthCopyWith(CryptoError_InvalidLength value, $Res Function(CryptoError_InvalidLength) _then) = _$CryptoError_InvalidLengthCopyWithImpl;
+@useResult
+$Res call({
+ BigInt expected,  BigInt got
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_InvalidLengthCopyWithImpl<$Res>
+    implements $CryptoError_InvalidLengthCopyWith<$Res> {
+  _$CryptoError_InvalidLengthCopyWithImpl(this._self, this._then);
+
+  final CryptoError_InvalidLength _self;
+  final $Res Function(CryptoError_InvalidLength) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? expected = null,Object? got = null,}) {
+  return _then(CryptoError_InvalidLength(
+expected: null == expected ? _self.expected : expected // ignore: cast_nullable_to_non_nullable
+as BigInt,
+got: null == got ? _self.got : got // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_InvalidEncoding extends CryptoError {
+  const CryptoError_InvalidEncoding({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_InvalidEncodingCopyWith<CryptoError_InvalidEncoding> get copyWith => _$CryptoError_InvalidEncodingCopyWithImpl<CryptoError_InvalidEncoding>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_InvalidEncoding&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.invalidEncoding(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_InvalidEncodingCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_InvalidEncodingCopyWith(CryptoError_InvalidEncoding value, $Res Function(CryptoError_InvalidEncoding) _then) = _$CryptoError_InvalidEncodingCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_InvalidEncodingCopyWithImpl<$Res>
+    implements $CryptoError_InvalidEncodingCopyWith<$Res> {
+  _$CryptoError_InvalidEncodingCopyWithImpl(this._self, this._then);
+
+  final CryptoError_InvalidEncoding _self;
+  final $Res Function(CryptoError_InvalidEncoding) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_InvalidEncoding(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_InvalidArgument extends CryptoError {
+  const CryptoError_InvalidArgument({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_InvalidArgumentCopyWith<CryptoError_InvalidArgument> get copyWith => _$CryptoError_InvalidArgumentCopyWithImpl<CryptoError_InvalidArgument>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_InvalidArgument&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.invalidArgument(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_InvalidArgumentCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_InvalidArgumentCopyWith(CryptoError_InvalidArgument value, $Res Function(CryptoError_InvalidArgument) _then) = _$CryptoError_InvalidArgumentCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_InvalidArgumentCopyWithImpl<$Res>
+    implements $CryptoError_InvalidArgumentCopyWith<$Res> {
+  _$CryptoError_InvalidArgumentCopyWithImpl(this._self, this._then);
+
+  final CryptoError_InvalidArgument _self;
+  final $Res Function(CryptoError_InvalidArgument) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_InvalidArgument(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_CborInvalid extends CryptoError {
+  const CryptoError_CborInvalid({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_CborInvalidCopyWith<CryptoError_CborInvalid> get copyWith => _$CryptoError_CborInvalidCopyWithImpl<CryptoError_CborInvalid>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_CborInvalid&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.cborInvalid(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_CborInvalidCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_CborInvalidCopyWith(CryptoError_CborInvalid value, $Res Function(CryptoError_CborInvalid) _then) = _$CryptoError_CborInvalidCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_CborInvalidCopyWithImpl<$Res>
+    implements $CryptoError_CborInvalidCopyWith<$Res> {
+  _$CryptoError_CborInvalidCopyWithImpl(this._self, this._then);
+
+  final CryptoError_CborInvalid _self;
+  final $Res Function(CryptoError_CborInvalid) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_CborInvalid(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_SignatureMismatch extends CryptoError {
+  const CryptoError_SignatureMismatch({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_SignatureMismatchCopyWith<CryptoError_SignatureMismatch> get copyWith => _$CryptoError_SignatureMismatchCopyWithImpl<CryptoError_SignatureMismatch>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_SignatureMismatch&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.signatureMismatch(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_SignatureMismatchCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_SignatureMismatchCopyWith(CryptoError_SignatureMismatch value, $Res Function(CryptoError_SignatureMismatch) _then) = _$CryptoError_SignatureMismatchCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_SignatureMismatchCopyWithImpl<$Res>
+    implements $CryptoError_SignatureMismatchCopyWith<$Res> {
+  _$CryptoError_SignatureMismatchCopyWithImpl(this._self, this._then);
+
+  final CryptoError_SignatureMismatch _self;
+  final $Res Function(CryptoError_SignatureMismatch) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_SignatureMismatch(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_KeyMismatch extends CryptoError {
+  const CryptoError_KeyMismatch({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_KeyMismatchCopyWith<CryptoError_KeyMismatch> get copyWith => _$CryptoError_KeyMismatchCopyWithImpl<CryptoError_KeyMismatch>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_KeyMismatch&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.keyMismatch(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_KeyMismatchCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_KeyMismatchCopyWith(CryptoError_KeyMismatch value, $Res Function(CryptoError_KeyMismatch) _then) = _$CryptoError_KeyMismatchCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_KeyMismatchCopyWithImpl<$Res>
+    implements $CryptoError_KeyMismatchCopyWith<$Res> {
+  _$CryptoError_KeyMismatchCopyWithImpl(this._self, this._then);
+
+  final CryptoError_KeyMismatch _self;
+  final $Res Function(CryptoError_KeyMismatch) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_KeyMismatch(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_EncryptionFailed extends CryptoError {
+  const CryptoError_EncryptionFailed({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_EncryptionFailedCopyWith<CryptoError_EncryptionFailed> get copyWith => _$CryptoError_EncryptionFailedCopyWithImpl<CryptoError_EncryptionFailed>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_EncryptionFailed&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.encryptionFailed(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_EncryptionFailedCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_EncryptionFailedCopyWith(CryptoError_EncryptionFailed value, $Res Function(CryptoError_EncryptionFailed) _then) = _$CryptoError_EncryptionFailedCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_EncryptionFailedCopyWithImpl<$Res>
+    implements $CryptoError_EncryptionFailedCopyWith<$Res> {
+  _$CryptoError_EncryptionFailedCopyWithImpl(this._self, this._then);
+
+  final CryptoError_EncryptionFailed _self;
+  final $Res Function(CryptoError_EncryptionFailed) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_EncryptionFailed(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_DecryptionFailed extends CryptoError {
+  const CryptoError_DecryptionFailed({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_DecryptionFailedCopyWith<CryptoError_DecryptionFailed> get copyWith => _$CryptoError_DecryptionFailedCopyWithImpl<CryptoError_DecryptionFailed>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_DecryptionFailed&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.decryptionFailed(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_DecryptionFailedCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_DecryptionFailedCopyWith(CryptoError_DecryptionFailed value, $Res Function(CryptoError_DecryptionFailed) _then) = _$CryptoError_DecryptionFailedCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_DecryptionFailedCopyWithImpl<$Res>
+    implements $CryptoError_DecryptionFailedCopyWith<$Res> {
+  _$CryptoError_DecryptionFailedCopyWithImpl(this._self, this._then);
+
+  final CryptoError_DecryptionFailed _self;
+  final $Res Function(CryptoError_DecryptionFailed) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_DecryptionFailed(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_Expired extends CryptoError {
+  const CryptoError_Expired({required this.exp, required this.now}): super._();
+  
+ final  BigInt exp;
+ final  BigInt now;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_ExpiredCopyWith<CryptoError_Expired> get copyWith => _$CryptoError_ExpiredCopyWithImpl<CryptoError_Expired>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_Expired&&(identical(other.exp, exp) || other.exp == exp)&&(identical(other.now, now) || other.now == now));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,exp,now);
+
+@override
+String toString() {
+  return 'CryptoError.expired(exp: $exp, now: $now)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_ExpiredCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_ExpiredCopyWith(CryptoError_Expired value, $Res Function(CryptoError_Expired) _then) = _$CryptoError_ExpiredCopyWithImpl;
+@useResult
+$Res call({
+ BigInt exp,  BigInt now
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_ExpiredCopyWithImpl<$Res>
+    implements $CryptoError_ExpiredCopyWith<$Res> {
+  _$CryptoError_ExpiredCopyWithImpl(this._self, this._then);
+
+  final CryptoError_Expired _self;
+  final $Res Function(CryptoError_Expired) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? exp = null,Object? now = null,}) {
+  return _then(CryptoError_Expired(
+exp: null == exp ? _self.exp : exp // ignore: cast_nullable_to_non_nullable
+as BigInt,
+now: null == now ? _self.now : now // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_NotYetValid extends CryptoError {
+  const CryptoError_NotYetValid({required this.nbf, required this.now}): super._();
+  
+ final  BigInt nbf;
+ final  BigInt now;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_NotYetValidCopyWith<CryptoError_NotYetValid> get copyWith => _$CryptoError_NotYetValidCopyWithImpl<CryptoError_NotYetValid>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_NotYetValid&&(identical(other.nbf, nbf) || other.nbf == nbf)&&(identical(other.now, now) || other.now == now));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,nbf,now);
+
+@override
+String toString() {
+  return 'CryptoError.notYetValid(nbf: $nbf, now: $now)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_NotYetValidCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_NotYetValidCopyWith(CryptoError_NotYetValid value, $Res Function(CryptoError_NotYetValid) _then) = _$CryptoError_NotYetValidCopyWithImpl;
+@useResult
+$Res call({
+ BigInt nbf,  BigInt now
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_NotYetValidCopyWithImpl<$Res>
+    implements $CryptoError_NotYetValidCopyWith<$Res> {
+  _$CryptoError_NotYetValidCopyWithImpl(this._self, this._then);
+
+  final CryptoError_NotYetValid _self;
+  final $Res Function(CryptoError_NotYetValid) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? nbf = null,Object? now = null,}) {
+  return _then(CryptoError_NotYetValid(
+nbf: null == nbf ? _self.nbf : nbf // ignore: cast_nullable_to_non_nullable
+as BigInt,
+now: null == now ? _self.now : now // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_StaleSignature extends CryptoError {
+  const CryptoError_StaleSignature({required this.drift, required this.maxDrift}): super._();
+  
+ final  BigInt drift;
+ final  BigInt maxDrift;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_StaleSignatureCopyWith<CryptoError_StaleSignature> get copyWith => _$CryptoError_StaleSignatureCopyWithImpl<CryptoError_StaleSignature>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_StaleSignature&&(identical(other.drift, drift) || other.drift == drift)&&(identical(other.maxDrift, maxDrift) || other.maxDrift == maxDrift));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,drift,maxDrift);
+
+@override
+String toString() {
+  return 'CryptoError.staleSignature(drift: $drift, maxDrift: $maxDrift)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_StaleSignatureCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_StaleSignatureCopyWith(CryptoError_StaleSignature value, $Res Function(CryptoError_StaleSignature) _then) = _$CryptoError_StaleSignatureCopyWithImpl;
+@useResult
+$Res call({
+ BigInt drift,  BigInt maxDrift
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_StaleSignatureCopyWithImpl<$Res>
+    implements $CryptoError_StaleSignatureCopyWith<$Res> {
+  _$CryptoError_StaleSignatureCopyWithImpl(this._self, this._then);
+
+  final CryptoError_StaleSignature _self;
+  final $Res Function(CryptoError_StaleSignature) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? drift = null,Object? maxDrift = null,}) {
+  return _then(CryptoError_StaleSignature(
+drift: null == drift ? _self.drift : drift // ignore: cast_nullable_to_non_nullable
+as BigInt,
+maxDrift: null == maxDrift ? _self.maxDrift : maxDrift // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_InvalidClaims extends CryptoError {
+  const CryptoError_InvalidClaims({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_InvalidClaimsCopyWith<CryptoError_InvalidClaims> get copyWith => _$CryptoError_InvalidClaimsCopyWithImpl<CryptoError_InvalidClaims>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_InvalidClaims&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.invalidClaims(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_InvalidClaimsCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_InvalidClaimsCopyWith(CryptoError_InvalidClaims value, $Res Function(CryptoError_InvalidClaims) _then) = _$CryptoError_InvalidClaimsCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_InvalidClaimsCopyWithImpl<$Res>
+    implements $CryptoError_InvalidClaimsCopyWith<$Res> {
+  _$CryptoError_InvalidClaimsCopyWithImpl(this._self, this._then);
+
+  final CryptoError_InvalidClaims _self;
+  final $Res Function(CryptoError_InvalidClaims) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_InvalidClaims(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_InvalidCertificate extends CryptoError {
+  const CryptoError_InvalidCertificate({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_InvalidCertificateCopyWith<CryptoError_InvalidCertificate> get copyWith => _$CryptoError_InvalidCertificateCopyWithImpl<CryptoError_InvalidCertificate>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_InvalidCertificate&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.invalidCertificate(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_InvalidCertificateCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_InvalidCertificateCopyWith(CryptoError_InvalidCertificate value, $Res Function(CryptoError_InvalidCertificate) _then) = _$CryptoError_InvalidCertificateCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_InvalidCertificateCopyWithImpl<$Res>
+    implements $CryptoError_InvalidCertificateCopyWith<$Res> {
+  _$CryptoError_InvalidCertificateCopyWithImpl(this._self, this._then);
+
+  final CryptoError_InvalidCertificate _self;
+  final $Res Function(CryptoError_InvalidCertificate) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_InvalidCertificate(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_Io extends CryptoError {
+  const CryptoError_Io({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_IoCopyWith<CryptoError_Io> get copyWith => _$CryptoError_IoCopyWithImpl<CryptoError_Io>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_Io&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.io(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_IoCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_IoCopyWith(CryptoError_Io value, $Res Function(CryptoError_Io) _then) = _$CryptoError_IoCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_IoCopyWithImpl<$Res>
+    implements $CryptoError_IoCopyWith<$Res> {
+  _$CryptoError_IoCopyWithImpl(this._self, this._then);
+
+  final CryptoError_Io _self;
+  final $Res Function(CryptoError_Io) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_Io(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_MissingClaim extends CryptoError {
+  const CryptoError_MissingClaim({required this.key}): super._();
+  
+ final  PlatformInt64 key;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_MissingClaimCopyWith<CryptoError_MissingClaim> get copyWith => _$CryptoError_MissingClaimCopyWithImpl<CryptoError_MissingClaim>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_MissingClaim&&(identical(other.key, key) || other.key == key));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key);
+
+@override
+String toString() {
+  return 'CryptoError.missingClaim(key: $key)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_MissingClaimCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_MissingClaimCopyWith(CryptoError_MissingClaim value, $Res Function(CryptoError_MissingClaim) _then) = _$CryptoError_MissingClaimCopyWithImpl;
+@useResult
+$Res call({
+ PlatformInt64 key
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_MissingClaimCopyWithImpl<$Res>
+    implements $CryptoError_MissingClaimCopyWith<$Res> {
+  _$CryptoError_MissingClaimCopyWithImpl(this._self, this._then);
+
+  final CryptoError_MissingClaim _self;
+  final $Res Function(CryptoError_MissingClaim) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? key = null,}) {
+  return _then(CryptoError_MissingClaim(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_IssuerMismatch extends CryptoError {
+  const CryptoError_IssuerMismatch({required this.expected, required this.got}): super._();
+  
+ final  String expected;
+ final  String got;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_IssuerMismatchCopyWith<CryptoError_IssuerMismatch> get copyWith => _$CryptoError_IssuerMismatchCopyWithImpl<CryptoError_IssuerMismatch>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_IssuerMismatch&&(identical(other.expected, expected) || other.expected == expected)&&(identical(other.got, got) || other.got == got));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,expected,got);
+
+@override
+String toString() {
+  return 'CryptoError.issuerMismatch(expected: $expected, got: $got)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_IssuerMismatchCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_IssuerMismatchCopyWith(CryptoError_IssuerMismatch value, $Res Function(CryptoError_IssuerMismatch) _then) = _$CryptoError_IssuerMismatchCopyWithImpl;
+@useResult
+$Res call({
+ String expected,  String got
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_IssuerMismatchCopyWithImpl<$Res>
+    implements $CryptoError_IssuerMismatchCopyWith<$Res> {
+  _$CryptoError_IssuerMismatchCopyWithImpl(this._self, this._then);
+
+  final CryptoError_IssuerMismatch _self;
+  final $Res Function(CryptoError_IssuerMismatch) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? expected = null,Object? got = null,}) {
+  return _then(CryptoError_IssuerMismatch(
+expected: null == expected ? _self.expected : expected // ignore: cast_nullable_to_non_nullable
+as String,
+got: null == got ? _self.got : got // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_AudienceMismatch extends CryptoError {
+  const CryptoError_AudienceMismatch({required this.got}): super._();
+  
+ final  String got;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_AudienceMismatchCopyWith<CryptoError_AudienceMismatch> get copyWith => _$CryptoError_AudienceMismatchCopyWithImpl<CryptoError_AudienceMismatch>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_AudienceMismatch&&(identical(other.got, got) || other.got == got));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,got);
+
+@override
+String toString() {
+  return 'CryptoError.audienceMismatch(got: $got)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_AudienceMismatchCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_AudienceMismatchCopyWith(CryptoError_AudienceMismatch value, $Res Function(CryptoError_AudienceMismatch) _then) = _$CryptoError_AudienceMismatchCopyWithImpl;
+@useResult
+$Res call({
+ String got
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_AudienceMismatchCopyWithImpl<$Res>
+    implements $CryptoError_AudienceMismatchCopyWith<$Res> {
+  _$CryptoError_AudienceMismatchCopyWithImpl(this._self, this._then);
+
+  final CryptoError_AudienceMismatch _self;
+  final $Res Function(CryptoError_AudienceMismatch) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? got = null,}) {
+  return _then(CryptoError_AudienceMismatch(
+got: null == got ? _self.got : got // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_TokenTooOld extends CryptoError {
+  const CryptoError_TokenTooOld({required this.iat, required this.maxAge, required this.now}): super._();
+  
+ final  BigInt iat;
+ final  BigInt maxAge;
+ final  BigInt now;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_TokenTooOldCopyWith<CryptoError_TokenTooOld> get copyWith => _$CryptoError_TokenTooOldCopyWithImpl<CryptoError_TokenTooOld>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_TokenTooOld&&(identical(other.iat, iat) || other.iat == iat)&&(identical(other.maxAge, maxAge) || other.maxAge == maxAge)&&(identical(other.now, now) || other.now == now));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,iat,maxAge,now);
+
+@override
+String toString() {
+  return 'CryptoError.tokenTooOld(iat: $iat, maxAge: $maxAge, now: $now)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_TokenTooOldCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_TokenTooOldCopyWith(CryptoError_TokenTooOld value, $Res Function(CryptoError_TokenTooOld) _then) = _$CryptoError_TokenTooOldCopyWithImpl;
+@useResult
+$Res call({
+ BigInt iat,  BigInt maxAge,  BigInt now
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_TokenTooOldCopyWithImpl<$Res>
+    implements $CryptoError_TokenTooOldCopyWith<$Res> {
+  _$CryptoError_TokenTooOldCopyWithImpl(this._self, this._then);
+
+  final CryptoError_TokenTooOld _self;
+  final $Res Function(CryptoError_TokenTooOld) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? iat = null,Object? maxAge = null,Object? now = null,}) {
+  return _then(CryptoError_TokenTooOld(
+iat: null == iat ? _self.iat : iat // ignore: cast_nullable_to_non_nullable
+as BigInt,
+maxAge: null == maxAge ? _self.maxAge : maxAge // ignore: cast_nullable_to_non_nullable
+as BigInt,
+now: null == now ? _self.now : now // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_ConfirmKeyMismatch extends CryptoError {
+  const CryptoError_ConfirmKeyMismatch({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_ConfirmKeyMismatchCopyWith<CryptoError_ConfirmKeyMismatch> get copyWith => _$CryptoError_ConfirmKeyMismatchCopyWithImpl<CryptoError_ConfirmKeyMismatch>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_ConfirmKeyMismatch&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.confirmKeyMismatch(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_ConfirmKeyMismatchCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_ConfirmKeyMismatchCopyWith(CryptoError_ConfirmKeyMismatch value, $Res Function(CryptoError_ConfirmKeyMismatch) _then) = _$CryptoError_ConfirmKeyMismatchCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_ConfirmKeyMismatchCopyWithImpl<$Res>
+    implements $CryptoError_ConfirmKeyMismatchCopyWith<$Res> {
+  _$CryptoError_ConfirmKeyMismatchCopyWithImpl(this._self, this._then);
+
+  final CryptoError_ConfirmKeyMismatch _self;
+  final $Res Function(CryptoError_ConfirmKeyMismatch) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_ConfirmKeyMismatch(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_NonceMismatch extends CryptoError {
+  const CryptoError_NonceMismatch({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_NonceMismatchCopyWith<CryptoError_NonceMismatch> get copyWith => _$CryptoError_NonceMismatchCopyWithImpl<CryptoError_NonceMismatch>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_NonceMismatch&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.nonceMismatch(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_NonceMismatchCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_NonceMismatchCopyWith(CryptoError_NonceMismatch value, $Res Function(CryptoError_NonceMismatch) _then) = _$CryptoError_NonceMismatchCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_NonceMismatchCopyWithImpl<$Res>
+    implements $CryptoError_NonceMismatchCopyWith<$Res> {
+  _$CryptoError_NonceMismatchCopyWithImpl(this._self, this._then);
+
+  final CryptoError_NonceMismatch _self;
+  final $Res Function(CryptoError_NonceMismatch) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_NonceMismatch(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_Revoked extends CryptoError {
+  const CryptoError_Revoked({required this.idx, required this.status}): super._();
+  
+ final  BigInt idx;
+ final  int status;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_RevokedCopyWith<CryptoError_Revoked> get copyWith => _$CryptoError_RevokedCopyWithImpl<CryptoError_Revoked>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_Revoked&&(identical(other.idx, idx) || other.idx == idx)&&(identical(other.status, status) || other.status == status));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,idx,status);
+
+@override
+String toString() {
+  return 'CryptoError.revoked(idx: $idx, status: $status)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_RevokedCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_RevokedCopyWith(CryptoError_Revoked value, $Res Function(CryptoError_Revoked) _then) = _$CryptoError_RevokedCopyWithImpl;
+@useResult
+$Res call({
+ BigInt idx,  int status
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_RevokedCopyWithImpl<$Res>
+    implements $CryptoError_RevokedCopyWith<$Res> {
+  _$CryptoError_RevokedCopyWithImpl(this._self, this._then);
+
+  final CryptoError_Revoked _self;
+  final $Res Function(CryptoError_Revoked) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? idx = null,Object? status = null,}) {
+  return _then(CryptoError_Revoked(
+idx: null == idx ? _self.idx : idx // ignore: cast_nullable_to_non_nullable
+as BigInt,
+status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_InvalidSchema extends CryptoError {
+  const CryptoError_InvalidSchema({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_InvalidSchemaCopyWith<CryptoError_InvalidSchema> get copyWith => _$CryptoError_InvalidSchemaCopyWithImpl<CryptoError_InvalidSchema>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_InvalidSchema&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'CryptoError.invalidSchema(details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_InvalidSchemaCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_InvalidSchemaCopyWith(CryptoError_InvalidSchema value, $Res Function(CryptoError_InvalidSchema) _then) = _$CryptoError_InvalidSchemaCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_InvalidSchemaCopyWithImpl<$Res>
+    implements $CryptoError_InvalidSchemaCopyWith<$Res> {
+  _$CryptoError_InvalidSchemaCopyWithImpl(this._self, this._then);
+
+  final CryptoError_InvalidSchema _self;
+  final $Res Function(CryptoError_InvalidSchema) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(CryptoError_InvalidSchema(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CryptoError_SchemaMismatch extends CryptoError {
+  const CryptoError_SchemaMismatch({required this.path, required this.details}): super._();
+  
+ final  String path;
+ final  String details;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CryptoError_SchemaMismatchCopyWith<CryptoError_SchemaMismatch> get copyWith => _$CryptoError_SchemaMismatchCopyWithImpl<CryptoError_SchemaMismatch>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CryptoError_SchemaMismatch&&(identical(other.path, path) || other.path == path)&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,path,details);
+
+@override
+String toString() {
+  return 'CryptoError.schemaMismatch(path: $path, details: $details)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CryptoError_SchemaMismatchCopyWith<$Res> implements $CryptoErrorCopyWith<$Res> {
+  factory $CryptoError_SchemaMismatchCopyWith(CryptoError_SchemaMismatch value, $Res Function(CryptoError_SchemaMismatch) _then) = _$CryptoError_SchemaMismatchCopyWithImpl;
+@useResult
+$Res call({
+ String path,  String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$CryptoError_SchemaMismatchCopyWithImpl<$Res>
+    implements $CryptoError_SchemaMismatchCopyWith<$Res> {
+  _$CryptoError_SchemaMismatchCopyWithImpl(this._self, this._then);
+
+  final CryptoError_SchemaMismatch _self;
+  final $Res Function(CryptoError_SchemaMismatch) _then;
+
+/// Create a copy of CryptoError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? path = null,Object? details = null,}) {
+  return _then(CryptoError_SchemaMismatch(
+path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+// dart format on
@@ -4,6 +4,7 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'argon2.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 /// Encrypts plaintext using the STREAM construction with ChaCha20-Poly1305.
@@ -25,3 +26,149 @@ Uint8List streamDecrypt({
   key: key,
   ciphertext: ciphertext,
 );
+
+/// Encrypts the file at `in_path` into `out_path` using the STREAM construction
+/// with ChaCha20-Poly1305, without moving any data across the FFI boundary.
+///
+/// The ciphertext is written to a temporary file next to `out_path` that is
+/// only renamed into place once complete, and removed on failure.
+///
+/// - `key`: 32-byte key that should never be reused across streams
+/// - `in_path`: Path of the plaintext file to encrypt
+/// - `out_path`: Path of the ciphertext file to create or overwrite
+/// - `progress`: Sink receiving the number of plaintext bytes processed so far
+Stream<BigInt> streamEncryptFile({
+  required List<int> key,
+  required String inPath,
+  required String outPath,
+}) => RustLib.instance.api.crateApiStreamStreamEncryptFile(
+  key: key,
+  inPath: inPath,
+  outPath: outPath,
+);
+
+/// Decrypts the file at `in_path` into `out_path` using the STREAM construction
+/// with ChaCha20-Poly1305, without moving any data across the FFI boundary.
+///
+/// The plaintext is written to a temporary file next to `out_path` that is only
+/// renamed into place after the final chunk authenticates. On failure it gets
+/// removed, so truncated or tampered data never leaves partial plaintext behind.
+///
+/// - `key`: 32-byte key that must match the key used for encryption
+/// - `in_path`: Path of the ciphertext file to decrypt
+/// - `out_path`: Path of the plaintext file to create or overwrite
+/// - `progress`: Sink receiving the number of plaintext bytes processed so far
+Stream<BigInt> streamDecryptFile({
+  required List<int> key,
+  required String inPath,
+  required String outPath,
+}) => RustLib.instance.api.crateApiStreamStreamDecryptFile(
+  key: key,
+  inPath: inPath,
+  outPath: outPath,
+);
+
+/// Encrypts plaintext with a password, producing a self-describing envelope
+/// of an authenticated header followed by the STREAM ciphertext.
+///
+/// The header carries the format version, the Argon2id parameters and salt,
+/// and an HMAC-SHA256 tag over them keyed from the password.
+///
+/// - `password`: The password to encrypt with
+/// - `plaintext`: The data to encrypt
+/// - `params`: Argon2id cost parameters for deriving the keys
+Uint8List streamSealWithPassword({
+  required List<int> password,
+  required List<int> plaintext,
+  required Argon2Params params,
+}) => RustLib.instance.api.crateApiStreamStreamSealWithPassword(
+  password: password,
+  plaintext: plaintext,
+  params: params,
+);
+
+/// Decrypts an envelope produced by `stream_seal_with_password`.
+///
+/// The Argon2id parameters are read from the unauthenticated header, so they
+/// are rejected before any key derivation if any of them is below `min_params`
+/// or above `max_params`. This way a tampered header can neither downgrade the
+/// cost of guessing the password, nor exhaust the memory or time of the device.
+///
+/// - `password`: The password to decrypt with
+/// - `sealed`: The envelope to decrypt
+/// - `min_params`: Minimum Argon2id cost parameters to accept
+/// - `max_params`: Maximum Argon2id cost parameters to accept
+Uint8List streamOpenWithPassword({
+  required List<int> password,
+  required List<int> sealed,
+  required Argon2Params minParams,
+  required Argon2Params maxParams,
+}) => RustLib.instance.api.crateApiStreamStreamOpenWithPassword(
+  password: password,
+  sealed: sealed,
+  minParams: minParams,
+  maxParams: maxParams,
+);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>>
+abstract class StreamDecryptor implements RustOpaqueInterface {
+  /// Terminates the stream, authenticating and returning the plaintext of
+  /// the final chunk. Fails if the stream was truncated.
+  Uint8List finish();
+
+  /// Creates a decryptor with the 32-byte key used for encryption.
+  factory StreamDecryptor({required List<int> key}) =>
+      RustLib.instance.api.crateApiStreamStreamDecryptorNew(key: key);
+
+  /// Feeds more ciphertext into the stream, returning the plaintext of all
+  /// chunks that were authenticated. The returned data may be empty.
+  Uint8List push({required List<int> chunk});
+}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>>
+abstract class StreamEncryptor implements RustOpaqueInterface {
+  /// Terminates the stream, returning the final ciphertext chunk. No more
+  /// data can be pushed afterwards.
+  Uint8List finish();
+
+  /// Creates an encryptor with a 32-byte key that must never be reused
+  /// across streams.
+  factory StreamEncryptor({required List<int> key}) =>
+      RustLib.instance.api.crateApiStreamStreamEncryptorNew(key: key);
+
+  /// Feeds more plaintext into the stream, returning any ciphertext that
+  /// became available. The returned data may be empty.
+  Uint8List push({required List<int> chunk});
+}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>>
+abstract class StreamReader implements RustOpaqueInterface {
+  /// Creates a random access reader over an in-memory ciphertext.
+  ///
+  /// - `key`: 32-byte key that must match the key used for encryption
+  /// - `ciphertext`: Complete STREAM ciphertext to read from
+  static StreamReader fromBytes({
+    required List<int> key,
+    required List<int> ciphertext,
+  }) => RustLib.instance.api.crateApiStreamStreamReaderFromBytes(
+    key: key,
+    ciphertext: ciphertext,
+  );
+
+  /// Returns whether the plaintext is empty.
+  bool isEmpty();
+
+  /// Returns the total length of the plaintext.
+  BigInt len();
+
+  /// Opens a random access reader over the ciphertext file at `path`.
+  ///
+  /// - `key`: 32-byte key that must match the key used for encryption
+  /// - `path`: Path of the ciphertext file to read from
+  static StreamReader open({required List<int> key, required String path}) =>
+      RustLib.instance.api.crateApiStreamStreamReaderOpen(key: key, path: path);
+
+  /// Decrypts up to `len` bytes of plaintext starting at `offset`. The result
+  /// is shorter than `len` if the range extends past the end of the plaintext.
+  Future<Uint8List> readAt({required BigInt offset, required BigInt len});
+}
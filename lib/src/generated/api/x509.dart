@@ -0,0 +1,386 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'xdsa.dart';
+import 'xhpke.dart';
+part 'x509.freezed.dart';
+
+/// Verifies a certificate chain from a leaf up to one of the trusted roots,
+/// returning the leaf key and the validated path.
+///
+/// Issuers are located by distinguished name among the intermediates and
+/// roots, skipping those whose subject key identifier contradicts the child's
+/// authority key identifier. If several candidates match, each resulting path
+/// is tried until one verifies, up to 64 candidates and 10 certificates per
+/// path. Every link must be signed by its issuer's xDSA key, every issuer must
+/// be a CA whose path length constraint admits the CAs below it, every
+/// certificate must carry the key usage of its role, and all of them must be
+/// valid at `now`.
+///
+/// - `leaf`: The DER-encoded end-entity certificate (xDSA or xHPKE)
+/// - `intermediates`: DER-encoded intermediate CA certificates, in any order
+/// - `roots`: DER-encoded self-signed xDSA root certificates to trust
+/// - `now`: Unix timestamp to check validity at (None for the current time)
+CertChain x509VerifyChain({
+  required List<int> leaf,
+  required List<Uint8List> intermediates,
+  required List<Uint8List> roots,
+  BigInt? now,
+}) => RustLib.instance.api.crateApiX509X509VerifyChain(
+  leaf: leaf,
+  intermediates: intermediates,
+  roots: roots,
+  now: now,
+);
+
+/// Parses a DER or PEM encoded xDSA or xHPKE certificate without verifying
+/// its signature.
+///
+/// **Warning**: The returned content is unauthenticated and should not be
+/// trusted until the certificate is verified, e.g. with `x509_verify_chain`.
+///
+/// - `cert`: The DER-encoded certificate, or its PEM encoding, which may be
+///   preceded by whitespace or a UTF-8 byte order mark
+CertInfo x509Parse({required List<int> cert}) =>
+    RustLib.instance.api.crateApiX509X509Parse(cert: cert);
+
+/// CertChain is a certificate path validated up to a trusted root.
+class CertChain {
+  /// The leaf certificate's subject key
+  final CertPublicKey leafKey;
+  /// DER-encoded certificates of the path, from the leaf to the root
+  final List<Uint8List> path;
+
+  const CertChain({required this.leafKey, required this.path});
+
+  @override
+  int get hashCode => leafKey.hashCode ^ path.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CertChain &&
+          runtimeType == other.runtimeType &&
+          leafKey == other.leafKey &&
+          path == other.path;
+}
+
+/// CertExtension is a custom certificate extension.
+class CertExtension {
+  /// Dotted extension OID, outside the reserved 2.5.29 arc
+  final String oid;
+  /// Whether the extension is marked critical
+  final bool critical;
+  /// DER-encoded extension payload (inside the OCTET STRING)
+  final Uint8List value;
+
+  const CertExtension({
+    required this.oid,
+    required this.critical,
+    required this.value,
+  });
+
+  @override
+  int get hashCode => oid.hashCode ^ critical.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CertExtension &&
+          runtimeType == other.runtimeType &&
+          oid == other.oid &&
+          critical == other.critical &&
+          value == other.value;
+}
+
+/// CertInfo is the content of a certificate, read without verifying it.
+class CertInfo {
+  /// Subject distinguished name
+  final CertName subject;
+  /// Issuer distinguished name
+  final CertName issuer;
+  /// Serial number (big-endian)
+  final Uint8List serial;
+  /// Validity start time (Unix timestamp)
+  final BigInt notBefore;
+  /// Validity end time (Unix timestamp)
+  final BigInt notAfter;
+  /// Whether this is a CA certificate
+  final bool isCa;
+  /// Maximum intermediate CAs allowed (only if is_ca is true)
+  final int? pathLen;
+  /// subjectAltName DNS names
+  final List<String> dnsNames;
+  /// subjectAltName URIs
+  final List<String> uris;
+  /// subjectAltName email addresses
+  final List<String> emails;
+  /// keyUsage bits
+  final List<CertKeyUsage> keyUsage;
+  /// Dotted extendedKeyUsage purpose OIDs
+  final List<String> extendedKeyUsage;
+  /// Extensions other than the standard ones above
+  final List<CertExtension> extensions;
+  /// Kind of the subject key
+  final CertKeyType keyType;
+  /// 32-byte fingerprint of the subject key
+  final Uint8List fingerprint;
+  /// Subject key identifier (SHA-1 of the subject key)
+  final Uint8List? subjectKeyId;
+  /// Authority key identifier, matching the issuer's subject key identifier
+  final Uint8List? issuerKeyId;
+
+  const CertInfo({
+    required this.subject,
+    required this.issuer,
+    required this.serial,
+    required this.notBefore,
+    required this.notAfter,
+    required this.isCa,
+    required this.pathLen,
+    required this.dnsNames,
+    required this.uris,
+    required this.emails,
+    required this.keyUsage,
+    required this.extendedKeyUsage,
+    required this.extensions,
+    required this.keyType,
+    required this.fingerprint,
+    required this.subjectKeyId,
+    required this.issuerKeyId,
+  });
+
+  @override
+  int get hashCode =>
+      subject.hashCode ^
+      issuer.hashCode ^
+      serial.hashCode ^
+      notBefore.hashCode ^
+      notAfter.hashCode ^
+      isCa.hashCode ^
+      pathLen.hashCode ^
+      dnsNames.hashCode ^
+      uris.hashCode ^
+      emails.hashCode ^
+      keyUsage.hashCode ^
+      extendedKeyUsage.hashCode ^
+      extensions.hashCode ^
+      keyType.hashCode ^
+      fingerprint.hashCode ^
+      subjectKeyId.hashCode ^
+      issuerKeyId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CertInfo &&
+          runtimeType == other.runtimeType &&
+          subject == other.subject &&
+          issuer == other.issuer &&
+          serial == other.serial &&
+          notBefore == other.notBefore &&
+          notAfter == other.notAfter &&
+          isCa == other.isCa &&
+          pathLen == other.pathLen &&
+          dnsNames == other.dnsNames &&
+          uris == other.uris &&
+          emails == other.emails &&
+          keyUsage == other.keyUsage &&
+          extendedKeyUsage == other.extendedKeyUsage &&
+          extensions == other.extensions &&
+          keyType == other.keyType &&
+          fingerprint == other.fingerprint &&
+          subjectKeyId == other.subjectKeyId &&
+          issuerKeyId == other.issuerKeyId;
+}
+
+/// CertKeyType is the kind of subject key a certificate holds.
+enum CertKeyType {
+  /// xDSA signing key
+  xdsa,
+  /// xHPKE encryption key
+  xhpke,
+}
+
+/// CertKeyUsage is a bit of the X.509 keyUsage extension (RFC 5280 §4.2.1.3).
+enum CertKeyUsage {
+  digitalSignature,
+  nonRepudiation,
+  keyEncipherment,
+  dataEncipherment,
+  keyAgreement,
+  keyCertSign,
+  crlSign,
+  encipherOnly,
+  decipherOnly,
+}
+
+/// CertName is an X.509 distinguished name. Attributes left as None are
+/// omitted; at least one must be set.
+class CertName {
+  /// Common name (CN)
+  final String? commonName;
+  /// Organization (O)
+  final String? organization;
+  /// Organizational unit (OU)
+  final String? organizationalUnit;
+  /// Country (C)
+  final String? country;
+  /// State or province (ST)
+  final String? province;
+  /// Locality (L)
+  final String? locality;
+  /// Serial number of the named entity (not of the certificate)
+  final String? serialNumber;
+
+  const CertName({
+    required this.commonName,
+    required this.organization,
+    required this.organizationalUnit,
+    required this.country,
+    required this.province,
+    required this.locality,
+    required this.serialNumber,
+  });
+
+  /// Creates a name holding only a common name (CN).
+  static CertName cn({required String commonName}) =>
+      RustLib.instance.api.crateApiX509CertNameCn(commonName: commonName);
+
+  @override
+  int get hashCode =>
+      commonName.hashCode ^
+      organization.hashCode ^
+      organizationalUnit.hashCode ^
+      country.hashCode ^
+      province.hashCode ^
+      locality.hashCode ^
+      serialNumber.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CertName &&
+          runtimeType == other.runtimeType &&
+          commonName == other.commonName &&
+          organization == other.organization &&
+          organizationalUnit == other.organizationalUnit &&
+          country == other.country &&
+          province == other.province &&
+          locality == other.locality &&
+          serialNumber == other.serialNumber;
+}
+
+/// CertPublicKey is the subject key of a verified certificate.
+@freezed
+sealed class CertPublicKey with _$CertPublicKey {
+  const CertPublicKey._();
+
+  /// xDSA signing key
+  const factory CertPublicKey.xdsa(XdsaPublicKey field0) = CertPublicKey_Xdsa;
+  /// xHPKE encryption key
+  const factory CertPublicKey.xhpke(
+    XhpkePublicKey field0,
+  ) = CertPublicKey_Xhpke;
+}
+
+/// CertTemplate holds the parameters of a certificate to issue.
+class CertTemplate {
+  /// Subject distinguished name
+  final CertName subject;
+  /// Issuer distinguished name, matching the signer's certificate subject
+  final CertName issuer;
+  /// Validity start time (Unix timestamp)
+  final BigInt notBefore;
+  /// Validity end time (Unix timestamp)
+  final BigInt notAfter;
+  /// Whether this is a CA certificate
+  final bool isCa;
+  /// Maximum intermediate CAs allowed (only if is_ca is true)
+  final int? pathLen;
+  /// subjectAltName DNS names
+  final List<String> dnsNames;
+  /// subjectAltName URIs
+  final List<String> uris;
+  /// subjectAltName email addresses
+  final List<String> emails;
+  /// keyUsage bits; empty for the key type's default. Must include the
+  /// default bits, which verification requires.
+  final List<CertKeyUsage> keyUsage;
+  /// Dotted extendedKeyUsage purpose OIDs, omitted if empty
+  final List<String> extendedKeyUsage;
+  /// Custom extensions
+  final List<CertExtension> extensions;
+
+  const CertTemplate({
+    required this.subject,
+    required this.issuer,
+    required this.notBefore,
+    required this.notAfter,
+    required this.isCa,
+    required this.pathLen,
+    required this.dnsNames,
+    required this.uris,
+    required this.emails,
+    required this.keyUsage,
+    required this.extendedKeyUsage,
+    required this.extensions,
+  });
+
+  /// Creates a template for a leaf certificate with no extensions beyond the
+  /// defaults.
+  ///
+  /// - `subject`: Subject distinguished name
+  /// - `issuer`: Issuer distinguished name
+  /// - `not_before`: Validity start time (Unix timestamp)
+  /// - `not_after`: Validity end time (Unix timestamp)
+  static CertTemplate leaf({
+    required CertName subject,
+    required CertName issuer,
+    required BigInt notBefore,
+    required BigInt notAfter,
+  }) => RustLib.instance.api.crateApiX509CertTemplateLeaf(
+    subject: subject,
+    issuer: issuer,
+    notBefore: notBefore,
+    notAfter: notAfter,
+  );
+
+  @override
+  int get hashCode =>
+      subject.hashCode ^
+      issuer.hashCode ^
+      notBefore.hashCode ^
+      notAfter.hashCode ^
+      isCa.hashCode ^
+      pathLen.hashCode ^
+      dnsNames.hashCode ^
+      uris.hashCode ^
+      emails.hashCode ^
+      keyUsage.hashCode ^
+      extendedKeyUsage.hashCode ^
+      extensions.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CertTemplate &&
+          runtimeType == other.runtimeType &&
+          subject == other.subject &&
+          issuer == other.issuer &&
+          notBefore == other.notBefore &&
+          notAfter == other.notAfter &&
+          isCa == other.isCa &&
+          pathLen == other.pathLen &&
+          dnsNames == other.dnsNames &&
+          uris == other.uris &&
+          emails == other.emails &&
+          keyUsage == other.keyUsage &&
+          extendedKeyUsage == other.extendedKeyUsage &&
+          extensions == other.extensions;
+}
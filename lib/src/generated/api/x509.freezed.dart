@@ -0,0 +1,166 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'x509.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$CertPublicKey {
+
+ Object? get field0;
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CertPublicKey&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CertPublicKey(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+class $CertPublicKeyCopyWith<$Res>  {
+$CertPublicKeyCopyWith(CertPublicKey _, $Res Function(CertPublicKey) __);
+}
+
+
+/// @nodoc
+
+class CertPublicKey_Xdsa extends CertPublicKey {
+  const CertPublicKey_Xdsa(this.field0): super._();
+  
+ final  XdsaPublicKey field0;
+
+/// Create a copy of CertPublicKey
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CertPublicKey_XdsaCopyWith<CertPublicKey_Xdsa> get copyWith => _$CertPublicKey_XdsaCopyWithImpl<CertPublicKey_Xdsa>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CertPublicKey_Xdsa&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CertPublicKey.xdsa(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CertPublicKey_XdsaCopyWith<$Res> implements $CertPublicKeyCopyWith<$Res> {
+  factory $CertPublicKey_XdsaCopyWith(CertPublicKey_Xdsa value, $Res Function(CertPublicKey_Xdsa) _then) = _$CertPublicKey_XdsaCopyWithImpl;
+@useResult
+$Res call({
+ XdsaPublicKey field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CertPublicKey_XdsaCopyWithImpl<$Res>
+    implements $CertPublicKey_XdsaCopyWith<$Res> {
+  _$CertPublicKey_XdsaCopyWithImpl(this._self, this._then);
+
+  final CertPublicKey_Xdsa _self;
+  final $Res Function(CertPublicKey_Xdsa) _then;
+
+/// Create a copy of CertPublicKey
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CertPublicKey_Xdsa(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as XdsaPublicKey,
+  ));
+}
+
+
+}
+
+
+/// @nodoc
+
+class CertPublicKey_Xhpke extends CertPublicKey {
+  const CertPublicKey_Xhpke(this.field0): super._();
+  
+ final  XhpkePublicKey field0;
+
+/// Create a copy of CertPublicKey
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CertPublicKey_XhpkeCopyWith<CertPublicKey_Xhpke> get copyWith => _$CertPublicKey_XhpkeCopyWithImpl<CertPublicKey_Xhpke>(this, _$identity);
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CertPublicKey_Xhpke&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'CertPublicKey.xhpke(field0: $field0)';
+}
+
+}
+
+/// @nodoc
+abstract mixin class $CertPublicKey_XhpkeCopyWith<$Res> implements $CertPublicKeyCopyWith<$Res> {
+  factory $CertPublicKey_XhpkeCopyWith(CertPublicKey_Xhpke value, $Res Function(CertPublicKey_Xhpke) _then) = _$CertPublicKey_XhpkeCopyWithImpl;
+@useResult
+$Res call({
+ XhpkePublicKey field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$CertPublicKey_XhpkeCopyWithImpl<$Res>
+    implements $CertPublicKey_XhpkeCopyWith<$Res> {
+  _$CertPublicKey_XhpkeCopyWithImpl(this._self, this._then);
+
+  final CertPublicKey_Xhpke _self;
+  final $Res Function(CertPublicKey_Xhpke) _then;
+
+/// Create a copy of CertPublicKey
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(CertPublicKey_Xhpke(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as XhpkePublicKey,
+  ));
+}
+
+
+}
+
+
+// dart format on
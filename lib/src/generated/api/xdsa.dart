@@ -5,6 +5,7 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'x509.dart';
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>>
 abstract class XdsaFingerprint implements RustOpaqueInterface {
@@ -27,22 +28,40 @@ abstract class XdsaPublicKey implements RustOpaqueInterface {
 
   /// Parses a public key from a DER-encoded certificate, verifying the signature.
   /// Returns the key along with validity start and end timestamps (Unix seconds).
+  ///
+  /// - `der`: The DER-encoded certificate
+  /// - `signer`: The issuer's xDSA public key
+  /// - `now`: Unix timestamp the certificate must be valid at (None to skip)
+  /// - `leeway_secs`: Clock skew tolerated at both ends of the validity period
   static (XdsaPublicKey, BigInt, BigInt) fromCertDer({
     required List<int> der,
     required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
   }) => RustLib.instance.api.crateApiXdsaXdsaPublicKeyFromCertDer(
     der: der,
     signer: signer,
+    now: now,
+    leewaySecs: leewaySecs,
   );
 
   /// Parses a public key from a PEM-encoded certificate, verifying the signature.
   /// Returns the key along with validity start and end timestamps (Unix seconds).
+  ///
+  /// - `pem`: The PEM-encoded certificate
+  /// - `signer`: The issuer's xDSA public key
+  /// - `now`: Unix timestamp the certificate must be valid at (None to skip)
+  /// - `leeway_secs`: Clock skew tolerated at both ends of the validity period
   static (XdsaPublicKey, BigInt, BigInt) fromCertPem({
     required String pem,
     required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
   }) => RustLib.instance.api.crateApiXdsaXdsaPublicKeyFromCertPem(
     pem: pem,
     signer: signer,
+    now: now,
+    leewaySecs: leewaySecs,
   );
 
   /// Parses a DER-encoded public key.
@@ -57,43 +76,23 @@ abstract class XdsaPublicKey implements RustOpaqueInterface {
   Uint8List toBytes();
 
   /// Generates a DER-encoded X.509 certificate for this public key,
-  /// signed by the given xDSA secret key with the specified validity period.
+  /// signed by the given xDSA secret key.
   ///
   /// - `signer`: The xDSA secret key to sign the certificate
-  /// - `subject_name`: The subject's common name (CN)
-  /// - `issuer_name`: The issuer's common name (CN)
-  /// - `not_before`: Certificate validity start time (Unix timestamp)
-  /// - `not_after`: Certificate validity end time (Unix timestamp)
-  /// - `is_ca`: Whether this is a CA certificate
-  /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+  /// - `template`: The certificate's names, validity and extensions
   Uint8List toCertDer({
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   });
 
   /// Generates a PEM-encoded X.509 certificate for this public key,
-  /// signed by the given xDSA secret key with the specified validity period.
+  /// signed by the given xDSA secret key.
   ///
   /// - `signer`: The xDSA secret key to sign the certificate
-  /// - `subject_name`: The subject's common name (CN)
-  /// - `issuer_name`: The issuer's common name (CN)
-  /// - `not_before`: Certificate validity start time (Unix timestamp)
-  /// - `not_after`: Certificate validity end time (Unix timestamp)
-  /// - `is_ca`: Whether this is a CA certificate
-  /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+  /// - `template`: The certificate's names, validity and extensions
   String toCertPem({
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   });
 
   /// Serializes the public key to DER format.
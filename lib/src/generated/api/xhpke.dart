@@ -5,8 +5,109 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'stream.dart';
+import 'x509.dart';
 import 'xdsa.dart';
 
+/// Encrypts a large message to a public key by sealing a fresh random STREAM
+/// payload key with xHPKE and encrypting the message itself with STREAM.
+///
+/// Returns the 1168-byte header followed by the STREAM ciphertext.
+///
+/// - `public_key`: The recipient's public key
+/// - `plaintext`: The data to encrypt
+/// - `domain`: Application-specific domain separator
+Uint8List xhpkeSealStream({
+  required XhpkePublicKey publicKey,
+  required List<int> plaintext,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiXhpkeXhpkeSealStream(
+  publicKey: publicKey,
+  plaintext: plaintext,
+  domain: domain,
+);
+
+/// Decrypts a message produced by `xhpke_seal_stream`.
+///
+/// - `secret_key`: The recipient's secret key
+/// - `sealed`: The header and STREAM ciphertext to decrypt
+/// - `domain`: Application-specific domain separator
+Uint8List xhpkeOpenStream({
+  required XhpkeSecretKey secretKey,
+  required List<int> sealed,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiXhpkeXhpkeOpenStream(
+  secretKey: secretKey,
+  sealed: sealed,
+  domain: domain,
+);
+
+/// Creates an incremental encryptor to a public key, using a fresh random
+/// STREAM payload key sealed with xHPKE.
+///
+/// Returns the encryptor and the 1168-byte header that must be transmitted
+/// before the ciphertext it produces.
+(StreamEncryptor, Uint8List) xhpkeNewStreamEncryptor({
+  required XhpkePublicKey publicKey,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiXhpkeXhpkeNewStreamEncryptor(
+  publicKey: publicKey,
+  domain: domain,
+);
+
+/// Creates an incremental decryptor from the 1168-byte header produced by
+/// `xhpke_new_stream_encryptor` or `xhpke_seal_stream`.
+StreamDecryptor xhpkeNewStreamDecryptor({
+  required XhpkeSecretKey secretKey,
+  required List<int> header,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiXhpkeXhpkeNewStreamDecryptor(
+  secretKey: secretKey,
+  header: header,
+  domain: domain,
+);
+
+/// Encrypts the file at `in_path` into `out_path` to a public key, in the
+/// same format as `xhpke_seal_stream`, without moving any data across the FFI
+/// boundary.
+///
+/// - `public_key`: The recipient's public key
+/// - `in_path`: Path of the plaintext file to encrypt
+/// - `out_path`: Path of the sealed file to create or overwrite
+/// - `domain`: Application-specific domain separator
+/// - `progress`: Sink receiving the number of plaintext bytes processed so far
+Stream<BigInt> xhpkeSealStreamFile({
+  required XhpkePublicKey publicKey,
+  required String inPath,
+  required String outPath,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiXhpkeXhpkeSealStreamFile(
+  publicKey: publicKey,
+  inPath: inPath,
+  outPath: outPath,
+  domain: domain,
+);
+
+/// Decrypts a file produced by `xhpke_seal_stream_file` into `out_path`. The
+/// plaintext is only moved into place after the final chunk authenticates.
+///
+/// - `secret_key`: The recipient's secret key
+/// - `in_path`: Path of the sealed file to decrypt
+/// - `out_path`: Path of the plaintext file to create or overwrite
+/// - `domain`: Application-specific domain separator
+/// - `progress`: Sink receiving the number of plaintext bytes processed so far
+Stream<BigInt> xhpkeOpenStreamFile({
+  required XhpkeSecretKey secretKey,
+  required String inPath,
+  required String outPath,
+  required List<int> domain,
+}) => RustLib.instance.api.crateApiXhpkeXhpkeOpenStreamFile(
+  secretKey: secretKey,
+  inPath: inPath,
+  outPath: outPath,
+  domain: domain,
+);
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>>
 abstract class XhpkeFingerprint implements RustOpaqueInterface {
   /// Creates a fingerprint from a 32-byte array.
@@ -28,22 +129,40 @@ abstract class XhpkePublicKey implements RustOpaqueInterface {
 
   /// Parses a public key from a DER-encoded certificate, verifying the xDSA signature.
   /// Returns the key along with validity start and end timestamps (Unix seconds).
+  ///
+  /// - `der`: The DER-encoded certificate
+  /// - `signer`: The issuer's xDSA public key
+  /// - `now`: Unix timestamp the certificate must be valid at (None to skip)
+  /// - `leeway_secs`: Clock skew tolerated at both ends of the validity period
   static (XhpkePublicKey, BigInt, BigInt) fromCertDer({
     required List<int> der,
     required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
   }) => RustLib.instance.api.crateApiXhpkeXhpkePublicKeyFromCertDer(
     der: der,
     signer: signer,
+    now: now,
+    leewaySecs: leewaySecs,
   );
 
   /// Parses a public key from a PEM-encoded certificate, verifying the xDSA signature.
   /// Returns the key along with validity start and end timestamps (Unix seconds).
+  ///
+  /// - `pem`: The PEM-encoded certificate
+  /// - `signer`: The issuer's xDSA public key
+  /// - `now`: Unix timestamp the certificate must be valid at (None to skip)
+  /// - `leeway_secs`: Clock skew tolerated at both ends of the validity period
   static (XhpkePublicKey, BigInt, BigInt) fromCertPem({
     required String pem,
     required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
   }) => RustLib.instance.api.crateApiXhpkeXhpkePublicKeyFromCertPem(
     pem: pem,
     signer: signer,
+    now: now,
+    leewaySecs: leewaySecs,
   );
 
   /// Parses a DER-encoded public key.
@@ -79,43 +198,27 @@ abstract class XhpkePublicKey implements RustOpaqueInterface {
   Uint8List toBytes();
 
   /// Generates a DER-encoded X.509 certificate for this public key,
-  /// signed by the given xDSA secret key with the specified validity period.
+  /// signed by the given xDSA secret key.
+  ///
+  /// xHPKE certificates are always end-entity certificates.
   ///
   /// - `signer`: The xDSA secret key to sign the certificate
-  /// - `subject_name`: The subject's common name (CN)
-  /// - `issuer_name`: The issuer's common name (CN)
-  /// - `not_before`: Certificate validity start time (Unix timestamp)
-  /// - `not_after`: Certificate validity end time (Unix timestamp)
-  /// - `is_ca`: Whether this is a CA certificate
-  /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+  /// - `template`: The certificate's names, validity and extensions
   Uint8List toCertDer({
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   });
 
   /// Generates a PEM-encoded X.509 certificate for this public key,
-  /// signed by the given xDSA secret key with the specified validity period.
+  /// signed by the given xDSA secret key.
+  ///
+  /// xHPKE certificates are always end-entity certificates.
   ///
   /// - `signer`: The xDSA secret key to sign the certificate
-  /// - `subject_name`: The subject's common name (CN)
-  /// - `issuer_name`: The issuer's common name (CN)
-  /// - `not_before`: Certificate validity start time (Unix timestamp)
-  /// - `not_after`: Certificate validity end time (Unix timestamp)
-  /// - `is_ca`: Whether this is a CA certificate
-  /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+  /// - `template`: The certificate's names, validity and extensions
   String toCertPem({
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   });
 
   /// Serializes the public key to DER format.
@@ -173,6 +276,18 @@ abstract class XhpkeSecretKey implements RustOpaqueInterface {
     required List<int> domain,
   });
 
+  /// Decrypts a message produced by `xhpke_seal_multi`, locating the content
+  /// key wrapped for this key by its fingerprint.
+  ///
+  /// - `sealed`: The multi-recipient message to decrypt
+  /// - `msg_to_auth`: Additional authenticated data (not encrypted)
+  /// - `domain`: Application-specific domain separator
+  Uint8List openMulti({
+    required List<int> sealed,
+    required List<int> msgToAuth,
+    required List<int> domain,
+  });
+
   /// Returns the public key corresponding to this private key.
   XhpkePublicKey publicKey();
 
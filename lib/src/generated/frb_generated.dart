@@ -5,12 +5,15 @@
 
 import 'api/argon2.dart';
 import 'api/cbor.dart';
+import 'api/cddl.dart';
 import 'api/cose.dart';
 import 'api/cwt.dart';
+import 'api/error.dart';
 import 'api/hkdf.dart';
 import 'api/rand.dart';
 import 'api/rsa.dart';
 import 'api/stream.dart';
+import 'api/x509.dart';
 import 'api/xdsa.dart';
 import 'api/xhpke.dart';
 import 'dart:async';
@@ -125,7 +128,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1684258094;
+  int get rustContentHash => 1906905369;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -136,6 +139,27 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Argon2Params crateApiArgon2Argon2ParamsRecommended();
+
+  CddlSchema crateApiCddlCddlSchemaCompile({required String source});
+
+  void crateApiCddlCddlSchemaValidate({
+    required CddlSchema that,
+    required List<int> data,
+    required String rule,
+  });
+
+  CertName crateApiX509CertNameCn({required String commonName});
+
+  CertTemplate crateApiX509CertTemplateLeaf({
+    required CertName subject,
+    required CertName issuer,
+    required BigInt notBefore,
+    required BigInt notAfter,
+  });
+
+  CwtValidation crateApiCwtCwtValidationAt({BigInt? now});
+
   RsaFingerprint crateApiRsaRsaFingerprintFromBytes({required List<int> bytes});
 
   Uint8List crateApiRsaRsaFingerprintToBytes({required RsaFingerprint that});
@@ -191,6 +215,86 @@ abstract class RustLibApi extends BaseApi {
 
   Uint8List crateApiRsaRsaSignatureToBytes({required RsaSignature that});
 
+  int crateApiCwtStatusListBits({required StatusList that});
+
+  int crateApiCwtStatusListGet({required StatusList that, required BigInt idx});
+
+  bool crateApiCwtStatusListIsEmpty({required StatusList that});
+
+  Uint8List crateApiCwtStatusListIssue({
+    required StatusList that,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+    required BigInt iat,
+    BigInt? exp,
+    BigInt? ttl,
+  });
+
+  BigInt crateApiCwtStatusListLen({required StatusList that});
+
+  StatusList crateApiCwtStatusListNew({
+    required String uri,
+    required int bits,
+    required BigInt len,
+  });
+
+  void crateApiCwtStatusListSet({
+    required StatusList that,
+    required BigInt idx,
+    required int status,
+  });
+
+  String crateApiCwtStatusListUri({required StatusList that});
+
+  StatusList crateApiCwtStatusListVerify({
+    required List<int> token,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    BigInt? now,
+  });
+
+  Uint8List crateApiStreamStreamDecryptorFinish({
+    required StreamDecryptor that,
+  });
+
+  StreamDecryptor crateApiStreamStreamDecryptorNew({required List<int> key});
+
+  Uint8List crateApiStreamStreamDecryptorPush({
+    required StreamDecryptor that,
+    required List<int> chunk,
+  });
+
+  Uint8List crateApiStreamStreamEncryptorFinish({
+    required StreamEncryptor that,
+  });
+
+  StreamEncryptor crateApiStreamStreamEncryptorNew({required List<int> key});
+
+  Uint8List crateApiStreamStreamEncryptorPush({
+    required StreamEncryptor that,
+    required List<int> chunk,
+  });
+
+  StreamReader crateApiStreamStreamReaderFromBytes({
+    required List<int> key,
+    required List<int> ciphertext,
+  });
+
+  bool crateApiStreamStreamReaderIsEmpty({required StreamReader that});
+
+  BigInt crateApiStreamStreamReaderLen({required StreamReader that});
+
+  StreamReader crateApiStreamStreamReaderOpen({
+    required List<int> key,
+    required String path,
+  });
+
+  Future<Uint8List> crateApiStreamStreamReaderReadAt({
+    required StreamReader that,
+    required BigInt offset,
+    required BigInt len,
+  });
+
   XdsaFingerprint crateApiXdsaXdsaFingerprintFromBytes({
     required List<int> bytes,
   });
@@ -206,11 +310,15 @@ abstract class RustLibApi extends BaseApi {
   (XdsaPublicKey, BigInt, BigInt) crateApiXdsaXdsaPublicKeyFromCertDer({
     required List<int> der,
     required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
   });
 
   (XdsaPublicKey, BigInt, BigInt) crateApiXdsaXdsaPublicKeyFromCertPem({
     required String pem,
     required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
   });
 
   XdsaPublicKey crateApiXdsaXdsaPublicKeyFromDer({required List<int> der});
@@ -222,23 +330,13 @@ abstract class RustLibApi extends BaseApi {
   Uint8List crateApiXdsaXdsaPublicKeyToCertDer({
     required XdsaPublicKey that,
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   });
 
   String crateApiXdsaXdsaPublicKeyToCertPem({
     required XdsaPublicKey that,
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   });
 
   Uint8List crateApiXdsaXdsaPublicKeyToDer({required XdsaPublicKey that});
@@ -301,11 +399,15 @@ abstract class RustLibApi extends BaseApi {
   (XhpkePublicKey, BigInt, BigInt) crateApiXhpkeXhpkePublicKeyFromCertDer({
     required List<int> der,
     required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
   });
 
   (XhpkePublicKey, BigInt, BigInt) crateApiXhpkeXhpkePublicKeyFromCertPem({
     required String pem,
     required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
   });
 
   XhpkePublicKey crateApiXhpkeXhpkePublicKeyFromDer({required List<int> der});
@@ -329,23 +431,13 @@ abstract class RustLibApi extends BaseApi {
   Uint8List crateApiXhpkeXhpkePublicKeyToCertDer({
     required XhpkePublicKey that,
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   });
 
   String crateApiXhpkeXhpkePublicKeyToCertPem({
     required XhpkePublicKey that,
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   });
 
   Uint8List crateApiXhpkeXhpkePublicKeyToDer({required XhpkePublicKey that});
@@ -386,6 +478,13 @@ abstract class RustLibApi extends BaseApi {
     required List<int> domain,
   });
 
+  Uint8List crateApiXhpkeXhpkeSecretKeyOpenMulti({
+    required XhpkeSecretKey that,
+    required List<int> sealed,
+    required List<int> msgToAuth,
+    required List<int> domain,
+  });
+
   XhpkePublicKey crateApiXhpkeXhpkeSecretKeyPublicKey({
     required XhpkeSecretKey that,
   });
@@ -411,8 +510,29 @@ abstract class RustLibApi extends BaseApi {
     required BigInt keyLength,
   });
 
+  CborValue crateApiCborCborDecode({required List<int> data});
+
+  Uint8List crateApiCborCborEncode({required CborValue value});
+
+  Uint8List crateApiCborCborFromDiagnostic({required String text});
+
+  String crateApiCborCborToDiagnostic({required List<int> data});
+
+  void crateApiCborCborValidate({
+    required List<int> data,
+    required String cddl,
+    required String rule,
+  });
+
   void crateApiCborCborVerify({required List<int> data});
 
+  Uint8List crateApiCoseCoseAddSignature({
+    required List<int> msgToSign,
+    required List<int> msgToAuth,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+  });
+
   Uint8List crateApiCoseCoseDecrypt({
     required List<int> msgToOpen,
     required List<int> msgToAuth,
@@ -427,6 +547,8 @@ abstract class RustLibApi extends BaseApi {
     required List<int> domain,
   });
 
+  CoseSign1Info crateApiCoseCoseInspect({required List<int> signature});
+
   Uint8List crateApiCoseCoseOpen({
     required List<int> msgToOpen,
     required List<int> msgToAuth,
@@ -436,10 +558,23 @@ abstract class RustLibApi extends BaseApi {
     BigInt? maxDriftSecs,
   });
 
+  Uint8List crateApiCoseCoseOpenMulti({
+    required List<int> msgToOpen,
+    required List<int> msgToAuth,
+    required XhpkeSecretKey recipient,
+    required XdsaPublicKey sender,
+    required List<int> domain,
+    BigInt? maxDriftSecs,
+  });
+
   Uint8List crateApiCoseCosePeek({required List<int> signature});
 
   XhpkeFingerprint crateApiCoseCoseRecipient({required List<int> ciphertext});
 
+  List<XhpkeFingerprint> crateApiCoseCoseRecipients({
+    required List<int> ciphertext,
+  });
+
   Uint8List crateApiCoseCoseSeal({
     required List<int> msgToSeal,
     required List<int> msgToAuth,
@@ -448,6 +583,14 @@ abstract class RustLibApi extends BaseApi {
     required List<int> domain,
   });
 
+  Uint8List crateApiCoseCoseSealMulti({
+    required List<int> msgToSeal,
+    required List<int> msgToAuth,
+    required XdsaSecretKey signer,
+    required List<XhpkePublicKey> recipients,
+    required List<int> domain,
+  });
+
   Uint8List crateApiCoseCoseSign({
     required List<int> msgToEmbed,
     required List<int> msgToAuth,
@@ -461,14 +604,25 @@ abstract class RustLibApi extends BaseApi {
     required List<int> domain,
   });
 
+  Uint8List crateApiCoseCoseSignMulti({
+    required List<int> msgToEmbed,
+    required List<int> msgToAuth,
+    required List<XdsaSecretKey> signers,
+    required List<int> domain,
+  });
+
   XdsaFingerprint crateApiCoseCoseSigner({required List<int> signature});
 
+  List<XdsaFingerprint> crateApiCoseCoseSigners({required List<int> signature});
+
   Uint8List crateApiCoseCoseVerify({
     required List<int> msgToCheck,
     required List<int> msgToAuth,
     required XdsaPublicKey verifier,
     required List<int> domain,
     BigInt? maxDriftSecs,
+    CddlSchema? schema,
+    String? rule,
   });
 
   void crateApiCoseCoseVerifyDetached({
@@ -479,23 +633,121 @@ abstract class RustLibApi extends BaseApi {
     BigInt? maxDriftSecs,
   });
 
+  CoseSign1Info crateApiCoseCoseVerifyInfo({
+    required List<int> msgToCheck,
+    required List<int> msgToAuth,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    BigInt? maxDriftSecs,
+  });
+
+  Uint8List crateApiCoseCoseVerifyThreshold({
+    required List<int> msgToCheck,
+    required List<int> msgToAuth,
+    required List<XdsaPublicKey> verifiers,
+    required BigInt threshold,
+    required List<int> domain,
+    BigInt? maxDriftSecs,
+  });
+
   Uint8List crateApiCwtCwtIssue({
     required List<int> claimsCbor,
     required XdsaSecretKey signer,
     required List<int> domain,
   });
 
+  Uint8List crateApiCwtCwtIssueClaims({
+    required CwtClaims claims,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+  });
+
+  SdCwt crateApiCwtCwtIssueSd({
+    required CwtClaims claims,
+    required Int64List disclosable,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+  });
+
+  Uint8List crateApiCwtCwtIssueSealed({
+    required CwtClaims claims,
+    required XdsaSecretKey signer,
+    required XhpkePublicKey recipient,
+    required List<int> domain,
+  });
+
+  CwtClaims crateApiCwtCwtOpenSealed({
+    required List<int> token,
+    required XhpkeSecretKey recipient,
+    required XdsaPublicKey issuer,
+    required List<int> domain,
+    required CwtValidation validation,
+  });
+
   Uint8List crateApiCwtCwtPeek({required List<int> token});
 
+  CwtClaims crateApiCwtCwtPeekClaims({required List<int> token});
+
+  Uint8List crateApiCwtCwtPresent({
+    required List<int> token,
+    required XdsaSecretKey holder,
+    required String audience,
+    required List<int> nonce,
+    required List<int> domain,
+  });
+
+  Uint8List crateApiCwtCwtPresentSd({
+    required List<int> token,
+    required List<Uint8List> disclosures,
+    required Int64List reveal,
+  });
+
   XdsaFingerprint crateApiCwtCwtSigner({required List<int> token});
 
   Uint8List crateApiCwtCwtVerify({
     required List<int> token,
     required XdsaPublicKey verifier,
     required List<int> domain,
+    required CwtValidation validation,
+    StatusList? statusList,
+    CddlSchema? schema,
+    String? rule,
+  });
+
+  CwtClaims crateApiCwtCwtVerifyChain({
+    required List<Uint8List> tokens,
+    required XdsaPublicKey root,
+    required List<int> domain,
     BigInt? now,
   });
 
+  CwtClaims crateApiCwtCwtVerifyClaims({
+    required List<int> token,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    required CwtValidation validation,
+    StatusList? statusList,
+    CddlSchema? schema,
+    String? rule,
+  });
+
+  CwtClaims crateApiCwtCwtVerifyPresentation({
+    required List<int> presentation,
+    required XdsaPublicKey issuer,
+    required String audience,
+    required List<int> nonce,
+    required List<int> domain,
+    required CwtValidation validation,
+    BigInt? maxDriftSecs,
+  });
+
+  CwtClaims crateApiCwtCwtVerifySd({
+    required List<int> presentation,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    required CwtValidation validation,
+  });
+
   Uint8List crateApiHkdfHkdfExpand({
     required List<int> prk,
     required List<int> info,
@@ -523,11 +775,90 @@ abstract class RustLibApi extends BaseApi {
     required List<int> ciphertext,
   });
 
+  Stream<BigInt> crateApiStreamStreamDecryptFile({
+    required List<int> key,
+    required String inPath,
+    required String outPath,
+  });
+
   Uint8List crateApiStreamStreamEncrypt({
     required List<int> key,
     required List<int> plaintext,
   });
 
+  Stream<BigInt> crateApiStreamStreamEncryptFile({
+    required List<int> key,
+    required String inPath,
+    required String outPath,
+  });
+
+  Uint8List crateApiStreamStreamOpenWithPassword({
+    required List<int> password,
+    required List<int> sealed,
+    required Argon2Params minParams,
+    required Argon2Params maxParams,
+  });
+
+  Uint8List crateApiStreamStreamSealWithPassword({
+    required List<int> password,
+    required List<int> plaintext,
+    required Argon2Params params,
+  });
+
+  CertInfo crateApiX509X509Parse({required List<int> cert});
+
+  CertChain crateApiX509X509VerifyChain({
+    required List<int> leaf,
+    required List<Uint8List> intermediates,
+    required List<Uint8List> roots,
+    BigInt? now,
+  });
+
+  StreamDecryptor crateApiXhpkeXhpkeNewStreamDecryptor({
+    required XhpkeSecretKey secretKey,
+    required List<int> header,
+    required List<int> domain,
+  });
+
+  (StreamEncryptor, Uint8List) crateApiXhpkeXhpkeNewStreamEncryptor({
+    required XhpkePublicKey publicKey,
+    required List<int> domain,
+  });
+
+  Uint8List crateApiXhpkeXhpkeOpenStream({
+    required XhpkeSecretKey secretKey,
+    required List<int> sealed,
+    required List<int> domain,
+  });
+
+  Stream<BigInt> crateApiXhpkeXhpkeOpenStreamFile({
+    required XhpkeSecretKey secretKey,
+    required String inPath,
+    required String outPath,
+    required List<int> domain,
+  });
+
+  Uint8List crateApiXhpkeXhpkeSealStream({
+    required XhpkePublicKey publicKey,
+    required List<int> plaintext,
+    required List<int> domain,
+  });
+
+  Stream<BigInt> crateApiXhpkeXhpkeSealStreamFile({
+    required XhpkePublicKey publicKey,
+    required String inPath,
+    required String outPath,
+    required List<int> domain,
+  });
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_CddlSchema;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_CddlSchema;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CddlSchemaPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_RsaFingerprint;
 
@@ -561,6 +892,40 @@ abstract class RustLibApi extends BaseApi {
 
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsaSignaturePtr;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_StatusList;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_StatusList;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_StatusListPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_StreamDecryptor;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_StreamDecryptor;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_StreamDecryptorPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_StreamEncryptor;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_StreamEncryptor;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_StreamEncryptorPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_StreamReader;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_StreamReader;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_StreamReaderPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_XdsaFingerprint;
 
@@ -651,81 +1016,245 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  RsaFingerprint crateApiRsaRsaFingerprintFromBytes({
-    required List<int> bytes,
-  }) {
+  Argon2Params crateApiArgon2Argon2ParamsRecommended() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(bytes, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_argon2_params,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiRsaRsaFingerprintFromBytesConstMeta,
-        argValues: [bytes],
+        constMeta: kCrateApiArgon2Argon2ParamsRecommendedConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRsaRsaFingerprintFromBytesConstMeta =>
-      const TaskConstMeta(
-        debugName: "RsaFingerprint_from_bytes",
-        argNames: ["bytes"],
-      );
+  TaskConstMeta get kCrateApiArgon2Argon2ParamsRecommendedConstMeta =>
+      const TaskConstMeta(debugName: "Argon2Params_recommended", argNames: []);
 
   @override
-  Uint8List crateApiRsaRsaFingerprintToBytes({required RsaFingerprint that}) {
+  CddlSchema crateApiCddlCddlSchemaCompile({required String source}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-            that,
-            serializer,
-          );
+          sse_encode_String(source, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiRsaRsaFingerprintToBytesConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiCddlCddlSchemaCompileConstMeta,
+        argValues: [source],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRsaRsaFingerprintToBytesConstMeta =>
+  TaskConstMeta get kCrateApiCddlCddlSchemaCompileConstMeta =>
       const TaskConstMeta(
-        debugName: "RsaFingerprint_to_bytes",
-        argNames: ["that"],
+        debugName: "CddlSchema_compile",
+        argNames: ["source"],
       );
 
   @override
-  RsaFingerprint crateApiRsaRsaPublicKeyFingerprint({
-    required RsaPublicKey that,
+  void crateApiCddlCddlSchemaValidate({
+    required CddlSchema that,
+    required List<int> data,
+    required String rule,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
             that,
             serializer,
           );
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_String(rule, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCddlCddlSchemaValidateConstMeta,
+        argValues: [that, data, rule],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCddlCddlSchemaValidateConstMeta =>
+      const TaskConstMeta(
+        debugName: "CddlSchema_validate",
+        argNames: ["that", "data", "rule"],
+      );
+
+  @override
+  CertName crateApiX509CertNameCn({required String commonName}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(commonName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cert_name,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiX509CertNameCnConstMeta,
+        argValues: [commonName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiX509CertNameCnConstMeta =>
+      const TaskConstMeta(debugName: "CertName_cn", argNames: ["commonName"]);
+
+  @override
+  CertTemplate crateApiX509CertTemplateLeaf({
+    required CertName subject,
+    required CertName issuer,
+    required BigInt notBefore,
+    required BigInt notAfter,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cert_name(subject, serializer);
+          sse_encode_box_autoadd_cert_name(issuer, serializer);
+          sse_encode_u_64(notBefore, serializer);
+          sse_encode_u_64(notAfter, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cert_template,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiX509CertTemplateLeafConstMeta,
+        argValues: [subject, issuer, notBefore, notAfter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiX509CertTemplateLeafConstMeta =>
+      const TaskConstMeta(
+        debugName: "CertTemplate_leaf",
+        argNames: ["subject", "issuer", "notBefore", "notAfter"],
+      );
+
+  @override
+  CwtValidation crateApiCwtCwtValidationAt({BigInt? now}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_64(now, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cwt_validation,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCwtCwtValidationAtConstMeta,
+        argValues: [now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtValidationAtConstMeta =>
+      const TaskConstMeta(debugName: "CwtValidation_at", argNames: ["now"]);
+
+  @override
+  RsaFingerprint crateApiRsaRsaFingerprintFromBytes({
+    required List<int> bytes,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiRsaRsaFingerprintFromBytesConstMeta,
+        argValues: [bytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRsaRsaFingerprintFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "RsaFingerprint_from_bytes",
+        argNames: ["bytes"],
+      );
+
+  @override
+  Uint8List crateApiRsaRsaFingerprintToBytes({required RsaFingerprint that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRsaRsaFingerprintToBytesConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRsaRsaFingerprintToBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "RsaFingerprint_to_bytes",
+        argNames: ["that"],
+      );
+
+  @override
+  RsaFingerprint crateApiRsaRsaPublicKeyFingerprint({
+    required RsaPublicKey that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint,
+          decodeErrorData: null,
         ),
         constMeta: kCrateApiRsaRsaPublicKeyFingerprintConstMeta,
         argValues: [that],
@@ -747,12 +1276,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
         constMeta: kCrateApiRsaRsaPublicKeyFromBytesConstMeta,
         argValues: [bytes],
@@ -774,12 +1303,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_list_prim_u_8_loose(der, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
         constMeta: kCrateApiRsaRsaPublicKeyFromDerConstMeta,
         argValues: [der],
@@ -801,12 +1330,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(pem, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
         constMeta: kCrateApiRsaRsaPublicKeyFromPemConstMeta,
         argValues: [pem],
@@ -831,7 +1360,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -860,7 +1389,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -886,7 +1415,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -921,11 +1450,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             signature,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
         constMeta: kCrateApiRsaRsaPublicKeyVerifyConstMeta,
         argValues: [that, message, signature],
@@ -952,7 +1481,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -979,12 +1508,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
         constMeta: kCrateApiRsaRsaSecretKeyFromBytesConstMeta,
         argValues: [bytes],
@@ -1006,12 +1535,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_list_prim_u_8_loose(der, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
         constMeta: kCrateApiRsaRsaSecretKeyFromDerConstMeta,
         argValues: [der],
@@ -1033,12 +1562,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(pem, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
         constMeta: kCrateApiRsaRsaSecretKeyFromPemConstMeta,
         argValues: [pem],
@@ -1059,7 +1588,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -1086,7 +1615,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -1120,7 +1649,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_list_prim_u_8_loose(message, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -1150,7 +1679,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -1179,7 +1708,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -1205,7 +1734,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1228,12 +1757,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
         constMeta: kCrateApiRsaRsaSignatureFromBytesConstMeta,
         argValues: [bytes],
@@ -1258,7 +1787,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -1278,4338 +1807,9930 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  XdsaFingerprint crateApiXdsaXdsaFingerprintFromBytes({
-    required List<int> bytes,
-  }) {
+  int crateApiCwtStatusListBits({required StatusList that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_u_8,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXdsaXdsaFingerprintFromBytesConstMeta,
-        argValues: [bytes],
+        constMeta: kCrateApiCwtStatusListBitsConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaFingerprintFromBytesConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaFingerprint_from_bytes",
-        argNames: ["bytes"],
-      );
+  TaskConstMeta get kCrateApiCwtStatusListBitsConstMeta =>
+      const TaskConstMeta(debugName: "StatusList_bits", argNames: ["that"]);
 
   @override
-  Uint8List crateApiXdsaXdsaFingerprintToBytes({
-    required XdsaFingerprint that,
+  int crateApiCwtStatusListGet({
+    required StatusList that,
+    required BigInt idx,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+          sse_encode_usize(idx, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_u_8,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaFingerprintToBytesConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiCwtStatusListGetConstMeta,
+        argValues: [that, idx],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaFingerprintToBytesConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaFingerprint_to_bytes",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiCwtStatusListGetConstMeta => const TaskConstMeta(
+    debugName: "StatusList_get",
+    argNames: ["that", "idx"],
+  );
 
   @override
-  XdsaFingerprint crateApiXdsaXdsaPublicKeyFingerprint({
-    required XdsaPublicKey that,
-  }) {
+  bool crateApiCwtStatusListIsEmpty({required StatusList that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyFingerprintConstMeta,
+        constMeta: kCrateApiCwtStatusListIsEmptyConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFingerprintConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaPublicKey_fingerprint",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiCwtStatusListIsEmptyConstMeta =>
+      const TaskConstMeta(debugName: "StatusList_is_empty", argNames: ["that"]);
 
   @override
-  XdsaPublicKey crateApiXdsaXdsaPublicKeyFromBytes({required List<int> bytes}) {
+  Uint8List crateApiCwtStatusListIssue({
+    required StatusList that,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+    required BigInt iat,
+    BigInt? exp,
+    BigInt? ttl,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_u_64(iat, serializer);
+          sse_encode_opt_box_autoadd_u_64(exp, serializer);
+          sse_encode_opt_box_autoadd_u_64(ttl, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyFromBytesConstMeta,
-        argValues: [bytes],
+        constMeta: kCrateApiCwtStatusListIssueConstMeta,
+        argValues: [that, signer, domain, iat, exp, ttl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFromBytesConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaPublicKey_from_bytes",
-        argNames: ["bytes"],
-      );
+  TaskConstMeta get kCrateApiCwtStatusListIssueConstMeta => const TaskConstMeta(
+    debugName: "StatusList_issue",
+    argNames: ["that", "signer", "domain", "iat", "exp", "ttl"],
+  );
 
   @override
-  (XdsaPublicKey, BigInt, BigInt) crateApiXdsaXdsaPublicKeyFromCertDer({
-    required List<int> der,
-    required XdsaPublicKey signer,
-  }) {
+  BigInt crateApiCwtStatusListLen({required StatusList that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(der, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-            signer,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+            that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyFromCertDerConstMeta,
-        argValues: [der, signer],
+        constMeta: kCrateApiCwtStatusListLenConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFromCertDerConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaPublicKey_from_cert_der",
-        argNames: ["der", "signer"],
-      );
+  TaskConstMeta get kCrateApiCwtStatusListLenConstMeta =>
+      const TaskConstMeta(debugName: "StatusList_len", argNames: ["that"]);
 
   @override
-  (XdsaPublicKey, BigInt, BigInt) crateApiXdsaXdsaPublicKeyFromCertPem({
-    required String pem,
-    required XdsaPublicKey signer,
+  StatusList crateApiCwtStatusListNew({
+    required String uri,
+    required int bits,
+    required BigInt len,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pem, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-            signer,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+          sse_encode_String(uri, serializer);
+          sse_encode_u_8(bits, serializer);
+          sse_encode_usize(len, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64,
-          decodeErrorData: sse_decode_String,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyFromCertPemConstMeta,
-        argValues: [pem, signer],
+        constMeta: kCrateApiCwtStatusListNewConstMeta,
+        argValues: [uri, bits, len],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFromCertPemConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaPublicKey_from_cert_pem",
-        argNames: ["pem", "signer"],
-      );
+  TaskConstMeta get kCrateApiCwtStatusListNewConstMeta => const TaskConstMeta(
+    debugName: "StatusList_new",
+    argNames: ["uri", "bits", "len"],
+  );
 
   @override
-  XdsaPublicKey crateApiXdsaXdsaPublicKeyFromDer({required List<int> der}) {
+  void crateApiCwtStatusListSet({
+    required StatusList that,
+    required BigInt idx,
+    required int status,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(der, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+            that,
+            serializer,
+          );
+          sse_encode_usize(idx, serializer);
+          sse_encode_u_8(status, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyFromDerConstMeta,
-        argValues: [der],
+        constMeta: kCrateApiCwtStatusListSetConstMeta,
+        argValues: [that, idx, status],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFromDerConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaPublicKey_from_der",
-        argNames: ["der"],
-      );
+  TaskConstMeta get kCrateApiCwtStatusListSetConstMeta => const TaskConstMeta(
+    debugName: "StatusList_set",
+    argNames: ["that", "idx", "status"],
+  );
 
   @override
-  XdsaPublicKey crateApiXdsaXdsaPublicKeyFromPem({required String pem}) {
+  String crateApiCwtStatusListUri({required StatusList that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pem, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyFromPemConstMeta,
-        argValues: [pem],
+        constMeta: kCrateApiCwtStatusListUriConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFromPemConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaPublicKey_from_pem",
-        argNames: ["pem"],
-      );
+  TaskConstMeta get kCrateApiCwtStatusListUriConstMeta =>
+      const TaskConstMeta(debugName: "StatusList_uri", argNames: ["that"]);
 
   @override
-  Uint8List crateApiXdsaXdsaPublicKeyToBytes({required XdsaPublicKey that}) {
+  StatusList crateApiCwtStatusListVerify({
+    required List<int> token,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    BigInt? now,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(token, serializer);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-            that,
+            verifier,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_opt_box_autoadd_u_64(now, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyToBytesConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiCwtStatusListVerifyConstMeta,
+        argValues: [token, verifier, domain, now],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyToBytesConstMeta =>
+  TaskConstMeta get kCrateApiCwtStatusListVerifyConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaPublicKey_to_bytes",
-        argNames: ["that"],
+        debugName: "StatusList_verify",
+        argNames: ["token", "verifier", "domain", "now"],
       );
 
   @override
-  Uint8List crateApiXdsaXdsaPublicKeyToCertDer({
-    required XdsaPublicKey that,
-    required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+  Uint8List crateApiStreamStreamDecryptorFinish({
+    required StreamDecryptor that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
             that,
             serializer,
           );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-            signer,
-            serializer,
-          );
-          sse_encode_String(subjectName, serializer);
-          sse_encode_String(issuerName, serializer);
-          sse_encode_u_64(notBefore, serializer);
-          sse_encode_u_64(notAfter, serializer);
-          sse_encode_bool(isCa, serializer);
-          sse_encode_opt_box_autoadd_u_8(pathLen, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyToCertDerConstMeta,
-        argValues: [
-          that,
-          signer,
-          subjectName,
-          issuerName,
-          notBefore,
-          notAfter,
-          isCa,
-          pathLen,
-        ],
+        constMeta: kCrateApiStreamStreamDecryptorFinishConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyToCertDerConstMeta =>
+  TaskConstMeta get kCrateApiStreamStreamDecryptorFinishConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaPublicKey_to_cert_der",
-        argNames: [
-          "that",
-          "signer",
-          "subjectName",
-          "issuerName",
-          "notBefore",
-          "notAfter",
-          "isCa",
-          "pathLen",
-        ],
+        debugName: "StreamDecryptor_finish",
+        argNames: ["that"],
       );
 
   @override
-  String crateApiXdsaXdsaPublicKeyToCertPem({
-    required XdsaPublicKey that,
-    required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
-  }) {
+  StreamDecryptor crateApiStreamStreamDecryptorNew({required List<int> key}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-            signer,
-            serializer,
-          );
-          sse_encode_String(subjectName, serializer);
-          sse_encode_String(issuerName, serializer);
-          sse_encode_u_64(notBefore, serializer);
-          sse_encode_u_64(notAfter, serializer);
-          sse_encode_bool(isCa, serializer);
-          sse_encode_opt_box_autoadd_u_8(pathLen, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+          sse_encode_list_prim_u_8_loose(key, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyToCertPemConstMeta,
-        argValues: [
-          that,
-          signer,
-          subjectName,
-          issuerName,
-          notBefore,
-          notAfter,
-          isCa,
-          pathLen,
-        ],
+        constMeta: kCrateApiStreamStreamDecryptorNewConstMeta,
+        argValues: [key],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyToCertPemConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaPublicKey_to_cert_pem",
-        argNames: [
-          "that",
-          "signer",
-          "subjectName",
-          "issuerName",
-          "notBefore",
-          "notAfter",
-          "isCa",
-          "pathLen",
-        ],
-      );
+  TaskConstMeta get kCrateApiStreamStreamDecryptorNewConstMeta =>
+      const TaskConstMeta(debugName: "StreamDecryptor_new", argNames: ["key"]);
 
   @override
-  Uint8List crateApiXdsaXdsaPublicKeyToDer({required XdsaPublicKey that}) {
+  Uint8List crateApiStreamStreamDecryptorPush({
+    required StreamDecryptor that,
+    required List<int> chunk,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+          sse_encode_list_prim_u_8_loose(chunk, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyToDerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiStreamStreamDecryptorPushConstMeta,
+        argValues: [that, chunk],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyToDerConstMeta =>
+  TaskConstMeta get kCrateApiStreamStreamDecryptorPushConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaPublicKey_to_der",
-        argNames: ["that"],
+        debugName: "StreamDecryptor_push",
+        argNames: ["that", "chunk"],
       );
 
   @override
-  String crateApiXdsaXdsaPublicKeyToPem({required XdsaPublicKey that}) {
+  Uint8List crateApiStreamStreamEncryptorFinish({
+    required StreamEncryptor that,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyToPemConstMeta,
+        constMeta: kCrateApiStreamStreamEncryptorFinishConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyToPemConstMeta =>
+  TaskConstMeta get kCrateApiStreamStreamEncryptorFinishConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaPublicKey_to_pem",
+        debugName: "StreamEncryptor_finish",
         argNames: ["that"],
       );
 
   @override
-  void crateApiXdsaXdsaPublicKeyVerify({
-    required XdsaPublicKey that,
-    required List<int> message,
-    required XdsaSignature signature,
-  }) {
+  StreamEncryptor crateApiStreamStreamEncryptorNew({required List<int> key}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-            that,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(message, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-            signature,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+          sse_encode_list_prim_u_8_loose(key, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaPublicKeyVerifyConstMeta,
-        argValues: [that, message, signature],
+        constMeta: kCrateApiStreamStreamEncryptorNewConstMeta,
+        argValues: [key],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyVerifyConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaPublicKey_verify",
-        argNames: ["that", "message", "signature"],
-      );
+  TaskConstMeta get kCrateApiStreamStreamEncryptorNewConstMeta =>
+      const TaskConstMeta(debugName: "StreamEncryptor_new", argNames: ["key"]);
 
   @override
-  XdsaFingerprint crateApiXdsaXdsaSecretKeyFingerprint({
-    required XdsaSecretKey that,
+  Uint8List crateApiStreamStreamEncryptorPush({
+    required StreamEncryptor that,
+    required List<int> chunk,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+          sse_encode_list_prim_u_8_loose(chunk, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaSecretKeyFingerprintConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiStreamStreamEncryptorPushConstMeta,
+        argValues: [that, chunk],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyFingerprintConstMeta =>
+  TaskConstMeta get kCrateApiStreamStreamEncryptorPushConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaSecretKey_fingerprint",
-        argNames: ["that"],
+        debugName: "StreamEncryptor_push",
+        argNames: ["that", "chunk"],
       );
 
   @override
-  XdsaSecretKey crateApiXdsaXdsaSecretKeyFromBytes({required List<int> bytes}) {
+  StreamReader crateApiStreamStreamReaderFromBytes({
+    required List<int> key,
+    required List<int> ciphertext,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+          sse_encode_list_prim_u_8_loose(key, serializer);
+          sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey,
-          decodeErrorData: sse_decode_String,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaSecretKeyFromBytesConstMeta,
-        argValues: [bytes],
+        constMeta: kCrateApiStreamStreamReaderFromBytesConstMeta,
+        argValues: [key, ciphertext],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyFromBytesConstMeta =>
+  TaskConstMeta get kCrateApiStreamStreamReaderFromBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaSecretKey_from_bytes",
-        argNames: ["bytes"],
+        debugName: "StreamReader_from_bytes",
+        argNames: ["key", "ciphertext"],
       );
 
   @override
-  XdsaSecretKey crateApiXdsaXdsaSecretKeyFromDer({required List<int> der}) {
+  bool crateApiStreamStreamReaderIsEmpty({required StreamReader that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(der, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXdsaXdsaSecretKeyFromDerConstMeta,
-        argValues: [der],
+        constMeta: kCrateApiStreamStreamReaderIsEmptyConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyFromDerConstMeta =>
+  TaskConstMeta get kCrateApiStreamStreamReaderIsEmptyConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaSecretKey_from_der",
-        argNames: ["der"],
+        debugName: "StreamReader_is_empty",
+        argNames: ["that"],
       );
 
   @override
-  XdsaSecretKey crateApiXdsaXdsaSecretKeyFromPem({required String pem}) {
+  BigInt crateApiStreamStreamReaderLen({required StreamReader that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pem, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXdsaXdsaSecretKeyFromPemConstMeta,
-        argValues: [pem],
+        constMeta: kCrateApiStreamStreamReaderLenConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyFromPemConstMeta =>
-      const TaskConstMeta(
-        debugName: "XdsaSecretKey_from_pem",
-        argNames: ["pem"],
-      );
+  TaskConstMeta get kCrateApiStreamStreamReaderLenConstMeta =>
+      const TaskConstMeta(debugName: "StreamReader_len", argNames: ["that"]);
 
   @override
-  XdsaSecretKey crateApiXdsaXdsaSecretKeyGenerate() {
+  StreamReader crateApiStreamStreamReaderOpen({
+    required List<int> key,
+    required String path,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+          sse_encode_list_prim_u_8_loose(key, serializer);
+          sse_encode_String(path, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey,
-          decodeErrorData: null,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaSecretKeyGenerateConstMeta,
-        argValues: [],
+        constMeta: kCrateApiStreamStreamReaderOpenConstMeta,
+        argValues: [key, path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyGenerateConstMeta =>
-      const TaskConstMeta(debugName: "XdsaSecretKey_generate", argNames: []);
+  TaskConstMeta get kCrateApiStreamStreamReaderOpenConstMeta =>
+      const TaskConstMeta(
+        debugName: "StreamReader_open",
+        argNames: ["key", "path"],
+      );
 
   @override
-  XdsaPublicKey crateApiXdsaXdsaSecretKeyPublicKey({
-    required XdsaSecretKey that,
+  Future<Uint8List> crateApiStreamStreamReaderReadAt({
+    required StreamReader that,
+    required BigInt offset,
+    required BigInt len,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+          sse_encode_u_64(offset, serializer);
+          sse_encode_u_64(len, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaSecretKeyPublicKeyConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiStreamStreamReaderReadAtConstMeta,
+        argValues: [that, offset, len],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyPublicKeyConstMeta =>
+  TaskConstMeta get kCrateApiStreamStreamReaderReadAtConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaSecretKey_public_key",
-        argNames: ["that"],
+        debugName: "StreamReader_read_at",
+        argNames: ["that", "offset", "len"],
       );
 
   @override
-  XdsaSignature crateApiXdsaXdsaSecretKeySign({
-    required XdsaSecretKey that,
-    required List<int> message,
+  XdsaFingerprint crateApiXdsaXdsaFingerprintFromBytes({
+    required List<int> bytes,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-            that,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(message, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature,
-          decodeErrorData: null,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaSecretKeySignConstMeta,
-        argValues: [that, message],
+        constMeta: kCrateApiXdsaXdsaFingerprintFromBytesConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSecretKeySignConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaFingerprintFromBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaSecretKey_sign",
-        argNames: ["that", "message"],
+        debugName: "XdsaFingerprint_from_bytes",
+        argNames: ["bytes"],
       );
 
   @override
-  Uint8List crateApiXdsaXdsaSecretKeyToBytes({required XdsaSecretKey that}) {
+  Uint8List crateApiXdsaXdsaFingerprintToBytes({
+    required XdsaFingerprint that,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiXdsaXdsaSecretKeyToBytesConstMeta,
+        constMeta: kCrateApiXdsaXdsaFingerprintToBytesConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyToBytesConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaFingerprintToBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaSecretKey_to_bytes",
+        debugName: "XdsaFingerprint_to_bytes",
         argNames: ["that"],
       );
 
   @override
-  Uint8List crateApiXdsaXdsaSecretKeyToDer({required XdsaSecretKey that}) {
+  XdsaFingerprint crateApiXdsaXdsaPublicKeyFingerprint({
+    required XdsaPublicKey that,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiXdsaXdsaSecretKeyToDerConstMeta,
+        constMeta: kCrateApiXdsaXdsaPublicKeyFingerprintConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyToDerConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFingerprintConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaSecretKey_to_der",
+        debugName: "XdsaPublicKey_fingerprint",
         argNames: ["that"],
       );
 
   @override
-  String crateApiXdsaXdsaSecretKeyToPem({required XdsaSecretKey that}) {
+  XdsaPublicKey crateApiXdsaXdsaPublicKeyFromBytes({required List<int> bytes}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaSecretKeyToPemConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiXdsaXdsaPublicKeyFromBytesConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyToPemConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFromBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaSecretKey_to_pem",
-        argNames: ["that"],
+        debugName: "XdsaPublicKey_from_bytes",
+        argNames: ["bytes"],
       );
 
   @override
-  XdsaSignature crateApiXdsaXdsaSignatureFromBytes({required List<int> bytes}) {
+  (XdsaPublicKey, BigInt, BigInt) crateApiXdsaXdsaPublicKeyFromCertDer({
+    required List<int> der,
+    required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+          sse_encode_list_prim_u_8_loose(der, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            signer,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_u_64(now, serializer);
+          sse_encode_u_64(leewaySecs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature,
-          decodeErrorData: sse_decode_String,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaSignatureFromBytesConstMeta,
-        argValues: [bytes],
+        constMeta: kCrateApiXdsaXdsaPublicKeyFromCertDerConstMeta,
+        argValues: [der, signer, now, leewaySecs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSignatureFromBytesConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFromCertDerConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaSignature_from_bytes",
-        argNames: ["bytes"],
+        debugName: "XdsaPublicKey_from_cert_der",
+        argNames: ["der", "signer", "now", "leewaySecs"],
       );
 
   @override
-  Uint8List crateApiXdsaXdsaSignatureToBytes({required XdsaSignature that}) {
+  (XdsaPublicKey, BigInt, BigInt) crateApiXdsaXdsaPublicKeyFromCertPem({
+    required String pem,
+    required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-            that,
+          sse_encode_String(pem, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            signer,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+          sse_encode_opt_box_autoadd_u_64(now, serializer);
+          sse_encode_u_64(leewaySecs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXdsaXdsaSignatureToBytesConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiXdsaXdsaPublicKeyFromCertPemConstMeta,
+        argValues: [pem, signer, now, leewaySecs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXdsaXdsaSignatureToBytesConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFromCertPemConstMeta =>
       const TaskConstMeta(
-        debugName: "XdsaSignature_to_bytes",
-        argNames: ["that"],
+        debugName: "XdsaPublicKey_from_cert_pem",
+        argNames: ["pem", "signer", "now", "leewaySecs"],
       );
 
   @override
-  XhpkeFingerprint crateApiXhpkeXhpkeFingerprintFromBytes({
-    required List<int> bytes,
-  }) {
+  XdsaPublicKey crateApiXdsaXdsaPublicKeyFromDer({required List<int> der}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+          sse_encode_list_prim_u_8_loose(der, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint,
-          decodeErrorData: sse_decode_String,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkeFingerprintFromBytesConstMeta,
-        argValues: [bytes],
+        constMeta: kCrateApiXdsaXdsaPublicKeyFromDerConstMeta,
+        argValues: [der],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeFingerprintFromBytesConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFromDerConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeFingerprint_from_bytes",
-        argNames: ["bytes"],
+        debugName: "XdsaPublicKey_from_der",
+        argNames: ["der"],
       );
 
   @override
-  Uint8List crateApiXhpkeXhpkeFingerprintToBytes({
-    required XhpkeFingerprint that,
-  }) {
+  XdsaPublicKey crateApiXdsaXdsaPublicKeyFromPem({required String pem}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+          sse_encode_String(pem, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkeFingerprintToBytesConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiXdsaXdsaPublicKeyFromPemConstMeta,
+        argValues: [pem],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeFingerprintToBytesConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyFromPemConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeFingerprint_to_bytes",
-        argNames: ["that"],
+        debugName: "XdsaPublicKey_from_pem",
+        argNames: ["pem"],
       );
 
   @override
-  XhpkeFingerprint crateApiXhpkeXhpkePublicKeyFingerprint({
-    required XhpkePublicKey that,
-  }) {
+  Uint8List crateApiXdsaXdsaPublicKeyToBytes({required XdsaPublicKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyFingerprintConstMeta,
+        constMeta: kCrateApiXdsaXdsaPublicKeyToBytesConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFingerprintConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyToBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_fingerprint",
+        debugName: "XdsaPublicKey_to_bytes",
         argNames: ["that"],
       );
 
   @override
-  XhpkePublicKey crateApiXhpkeXhpkePublicKeyFromBytes({
-    required List<int> bytes,
+  Uint8List crateApiXdsaXdsaPublicKeyToCertDer({
+    required XdsaPublicKey that,
+    required XdsaSecretKey signer,
+    required CertTemplate template,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_box_autoadd_cert_template(template, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyFromBytesConstMeta,
-        argValues: [bytes],
+        constMeta: kCrateApiXdsaXdsaPublicKeyToCertDerConstMeta,
+        argValues: [that, signer, template],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFromBytesConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyToCertDerConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_from_bytes",
-        argNames: ["bytes"],
+        debugName: "XdsaPublicKey_to_cert_der",
+        argNames: ["that", "signer", "template"],
       );
 
   @override
-  (XhpkePublicKey, BigInt, BigInt) crateApiXhpkeXhpkePublicKeyFromCertDer({
-    required List<int> der,
-    required XdsaPublicKey signer,
+  String crateApiXdsaXdsaPublicKeyToCertPem({
+    required XdsaPublicKey that,
+    required XdsaSecretKey signer,
+    required CertTemplate template,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(der, serializer);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
             signer,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+          sse_encode_box_autoadd_cert_template(template, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyFromCertDerConstMeta,
-        argValues: [der, signer],
+        constMeta: kCrateApiXdsaXdsaPublicKeyToCertPemConstMeta,
+        argValues: [that, signer, template],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFromCertDerConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyToCertPemConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_from_cert_der",
-        argNames: ["der", "signer"],
+        debugName: "XdsaPublicKey_to_cert_pem",
+        argNames: ["that", "signer", "template"],
       );
 
   @override
-  (XhpkePublicKey, BigInt, BigInt) crateApiXhpkeXhpkePublicKeyFromCertPem({
-    required String pem,
-    required XdsaPublicKey signer,
-  }) {
+  Uint8List crateApiXdsaXdsaPublicKeyToDer({required XdsaPublicKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pem, serializer);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-            signer,
+            that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyFromCertPemConstMeta,
-        argValues: [pem, signer],
+        constMeta: kCrateApiXdsaXdsaPublicKeyToDerConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFromCertPemConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyToDerConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_from_cert_pem",
-        argNames: ["pem", "signer"],
+        debugName: "XdsaPublicKey_to_der",
+        argNames: ["that"],
       );
 
   @override
-  XhpkePublicKey crateApiXhpkeXhpkePublicKeyFromDer({required List<int> der}) {
+  String crateApiXdsaXdsaPublicKeyToPem({required XdsaPublicKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(der, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyFromDerConstMeta,
-        argValues: [der],
+        constMeta: kCrateApiXdsaXdsaPublicKeyToPemConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFromDerConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyToPemConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_from_der",
-        argNames: ["der"],
+        debugName: "XdsaPublicKey_to_pem",
+        argNames: ["that"],
       );
 
   @override
-  XhpkePublicKey crateApiXhpkeXhpkePublicKeyFromPem({required String pem}) {
+  void crateApiXdsaXdsaPublicKeyVerify({
+    required XdsaPublicKey that,
+    required List<int> message,
+    required XdsaSignature signature,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pem, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(message, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+            signature,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyFromPemConstMeta,
-        argValues: [pem],
+        constMeta: kCrateApiXdsaXdsaPublicKeyVerifyConstMeta,
+        argValues: [that, message, signature],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFromPemConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaPublicKeyVerifyConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_from_pem",
-        argNames: ["pem"],
+        debugName: "XdsaPublicKey_verify",
+        argNames: ["that", "message", "signature"],
       );
 
   @override
-  (XhpkeSender, Uint8List) crateApiXhpkeXhpkePublicKeyNewSender({
-    required XhpkePublicKey that,
-    required List<int> domain,
+  XdsaFingerprint crateApiXdsaXdsaSecretKeyFingerprint({
+    required XdsaSecretKey that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
             that,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_sender_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyNewSenderConstMeta,
-        argValues: [that, domain],
+        constMeta: kCrateApiXdsaXdsaSecretKeyFingerprintConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyNewSenderConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyFingerprintConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_new_sender",
-        argNames: ["that", "domain"],
+        debugName: "XdsaSecretKey_fingerprint",
+        argNames: ["that"],
       );
 
   @override
-  (Uint8List, Uint8List) crateApiXhpkeXhpkePublicKeySeal({
-    required XhpkePublicKey that,
-    required List<int> msgToSeal,
-    required List<int> msgToAuth,
-    required List<int> domain,
-  }) {
+  XdsaSecretKey crateApiXdsaXdsaSecretKeyFromBytes({required List<int> bytes}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-            that,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(msgToSeal, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeySealConstMeta,
-        argValues: [that, msgToSeal, msgToAuth, domain],
+        constMeta: kCrateApiXdsaXdsaSecretKeyFromBytesConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeySealConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyFromBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_seal",
-        argNames: ["that", "msgToSeal", "msgToAuth", "domain"],
+        debugName: "XdsaSecretKey_from_bytes",
+        argNames: ["bytes"],
       );
 
   @override
-  Uint8List crateApiXhpkeXhpkePublicKeyToBytes({required XhpkePublicKey that}) {
+  XdsaSecretKey crateApiXdsaXdsaSecretKeyFromDer({required List<int> der}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+          sse_encode_list_prim_u_8_loose(der, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyToBytesConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiXdsaXdsaSecretKeyFromDerConstMeta,
+        argValues: [der],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyToBytesConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyFromDerConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_to_bytes",
-        argNames: ["that"],
+        debugName: "XdsaSecretKey_from_der",
+        argNames: ["der"],
       );
 
   @override
-  Uint8List crateApiXhpkeXhpkePublicKeyToCertDer({
-    required XhpkePublicKey that,
-    required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
-  }) {
+  XdsaSecretKey crateApiXdsaXdsaSecretKeyFromPem({required String pem}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-            signer,
-            serializer,
-          );
-          sse_encode_String(subjectName, serializer);
-          sse_encode_String(issuerName, serializer);
-          sse_encode_u_64(notBefore, serializer);
-          sse_encode_u_64(notAfter, serializer);
-          sse_encode_bool(isCa, serializer);
-          sse_encode_opt_box_autoadd_u_8(pathLen, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+          sse_encode_String(pem, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyToCertDerConstMeta,
-        argValues: [
-          that,
-          signer,
-          subjectName,
-          issuerName,
-          notBefore,
-          notAfter,
-          isCa,
-          pathLen,
-        ],
+        constMeta: kCrateApiXdsaXdsaSecretKeyFromPemConstMeta,
+        argValues: [pem],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyToCertDerConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyFromPemConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_to_cert_der",
-        argNames: [
-          "that",
-          "signer",
-          "subjectName",
-          "issuerName",
-          "notBefore",
-          "notAfter",
-          "isCa",
-          "pathLen",
-        ],
+        debugName: "XdsaSecretKey_from_pem",
+        argNames: ["pem"],
       );
 
   @override
-  String crateApiXhpkeXhpkePublicKeyToCertPem({
-    required XhpkePublicKey that,
-    required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
-  }) {
+  XdsaSecretKey crateApiXdsaXdsaSecretKeyGenerate() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-            signer,
-            serializer,
-          );
-          sse_encode_String(subjectName, serializer);
-          sse_encode_String(issuerName, serializer);
-          sse_encode_u_64(notBefore, serializer);
-          sse_encode_u_64(notAfter, serializer);
-          sse_encode_bool(isCa, serializer);
-          sse_encode_opt_box_autoadd_u_8(pathLen, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyToCertPemConstMeta,
-        argValues: [
-          that,
-          signer,
-          subjectName,
-          issuerName,
-          notBefore,
-          notAfter,
-          isCa,
-          pathLen,
-        ],
+        constMeta: kCrateApiXdsaXdsaSecretKeyGenerateConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyToCertPemConstMeta =>
-      const TaskConstMeta(
-        debugName: "XhpkePublicKey_to_cert_pem",
-        argNames: [
-          "that",
-          "signer",
-          "subjectName",
-          "issuerName",
-          "notBefore",
-          "notAfter",
-          "isCa",
-          "pathLen",
-        ],
-      );
+  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyGenerateConstMeta =>
+      const TaskConstMeta(debugName: "XdsaSecretKey_generate", argNames: []);
 
   @override
-  Uint8List crateApiXhpkeXhpkePublicKeyToDer({required XhpkePublicKey that}) {
+  XdsaPublicKey crateApiXdsaXdsaSecretKeyPublicKey({
+    required XdsaSecretKey that,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyToDerConstMeta,
+        constMeta: kCrateApiXdsaXdsaSecretKeyPublicKeyConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyToDerConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyPublicKeyConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_to_der",
+        debugName: "XdsaSecretKey_public_key",
         argNames: ["that"],
       );
 
   @override
-  String crateApiXhpkeXhpkePublicKeyToPem({required XhpkePublicKey that}) {
+  XdsaSignature crateApiXdsaXdsaSecretKeySign({
+    required XdsaSecretKey that,
+    required List<int> message,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+          sse_encode_list_prim_u_8_loose(message, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkePublicKeyToPemConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiXdsaXdsaSecretKeySignConstMeta,
+        argValues: [that, message],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyToPemConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSecretKeySignConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkePublicKey_to_pem",
-        argNames: ["that"],
+        debugName: "XdsaSecretKey_sign",
+        argNames: ["that", "message"],
       );
 
   @override
-  Uint8List crateApiXhpkeXhpkeReceiverOpen({
-    required XhpkeReceiver that,
-    required List<int> msgToOpen,
-    required List<int> msgToAuth,
-  }) {
+  Uint8List crateApiXdsaXdsaSecretKeyToBytes({required XdsaSecretKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
             that,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(msgToOpen, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkeReceiverOpenConstMeta,
-        argValues: [that, msgToOpen, msgToAuth],
+        constMeta: kCrateApiXdsaXdsaSecretKeyToBytesConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeReceiverOpenConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyToBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeReceiver_open",
-        argNames: ["that", "msgToOpen", "msgToAuth"],
+        debugName: "XdsaSecretKey_to_bytes",
+        argNames: ["that"],
       );
 
   @override
-  XhpkeFingerprint crateApiXhpkeXhpkeSecretKeyFingerprint({
-    required XhpkeSecretKey that,
-  }) {
+  Uint8List crateApiXdsaXdsaSecretKeyToDer({required XdsaSecretKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyFingerprintConstMeta,
+        constMeta: kCrateApiXdsaXdsaSecretKeyToDerConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyFingerprintConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyToDerConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSecretKey_fingerprint",
+        debugName: "XdsaSecretKey_to_der",
         argNames: ["that"],
       );
 
   @override
-  XhpkeSecretKey crateApiXhpkeXhpkeSecretKeyFromBytes({
-    required List<int> bytes,
-  }) {
+  String crateApiXdsaXdsaSecretKeyToPem({required XdsaSecretKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyFromBytesConstMeta,
-        argValues: [bytes],
+        constMeta: kCrateApiXdsaXdsaSecretKeyToPemConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyFromBytesConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSecretKeyToPemConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSecretKey_from_bytes",
-        argNames: ["bytes"],
+        debugName: "XdsaSecretKey_to_pem",
+        argNames: ["that"],
       );
 
   @override
-  XhpkeSecretKey crateApiXhpkeXhpkeSecretKeyFromDer({required List<int> der}) {
+  XdsaSignature crateApiXdsaXdsaSignatureFromBytes({required List<int> bytes}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(der, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey,
-          decodeErrorData: sse_decode_String,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyFromDerConstMeta,
-        argValues: [der],
+        constMeta: kCrateApiXdsaXdsaSignatureFromBytesConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyFromDerConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSignatureFromBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSecretKey_from_der",
-        argNames: ["der"],
+        debugName: "XdsaSignature_from_bytes",
+        argNames: ["bytes"],
       );
 
   @override
-  XhpkeSecretKey crateApiXhpkeXhpkeSecretKeyFromPem({required String pem}) {
+  Uint8List crateApiXdsaXdsaSignatureToBytes({required XdsaSignature that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pem, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyFromPemConstMeta,
-        argValues: [pem],
+        constMeta: kCrateApiXdsaXdsaSignatureToBytesConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyFromPemConstMeta =>
+  TaskConstMeta get kCrateApiXdsaXdsaSignatureToBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSecretKey_from_pem",
-        argNames: ["pem"],
+        debugName: "XdsaSignature_to_bytes",
+        argNames: ["that"],
       );
 
   @override
-  XhpkeSecretKey crateApiXhpkeXhpkeSecretKeyGenerate() {
+  XhpkeFingerprint crateApiXhpkeXhpkeFingerprintFromBytes({
+    required List<int> bytes,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey,
-          decodeErrorData: null,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyGenerateConstMeta,
-        argValues: [],
+        constMeta: kCrateApiXhpkeXhpkeFingerprintFromBytesConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyGenerateConstMeta =>
-      const TaskConstMeta(debugName: "XhpkeSecretKey_generate", argNames: []);
+  TaskConstMeta get kCrateApiXhpkeXhpkeFingerprintFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeFingerprint_from_bytes",
+        argNames: ["bytes"],
+      );
 
   @override
-  XhpkeReceiver crateApiXhpkeXhpkeSecretKeyNewReceiver({
-    required XhpkeSecretKey that,
-    required List<int> encapKey,
-    required List<int> domain,
+  Uint8List crateApiXhpkeXhpkeFingerprintToBytes({
+    required XhpkeFingerprint that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
             that,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(encapKey, serializer);
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyNewReceiverConstMeta,
-        argValues: [that, encapKey, domain],
+        constMeta: kCrateApiXhpkeXhpkeFingerprintToBytesConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyNewReceiverConstMeta =>
+  TaskConstMeta get kCrateApiXhpkeXhpkeFingerprintToBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSecretKey_new_receiver",
-        argNames: ["that", "encapKey", "domain"],
+        debugName: "XhpkeFingerprint_to_bytes",
+        argNames: ["that"],
       );
 
   @override
-  Uint8List crateApiXhpkeXhpkeSecretKeyOpen({
-    required XhpkeSecretKey that,
-    required List<int> sessionKey,
-    required List<int> msgToOpen,
-    required List<int> msgToAuth,
-    required List<int> domain,
+  XhpkeFingerprint crateApiXhpkeXhpkePublicKeyFingerprint({
+    required XhpkePublicKey that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
             that,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(sessionKey, serializer);
-          sse_encode_list_prim_u_8_loose(msgToOpen, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyOpenConstMeta,
-        argValues: [that, sessionKey, msgToOpen, msgToAuth, domain],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyFingerprintConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyOpenConstMeta =>
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFingerprintConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSecretKey_open",
-        argNames: ["that", "sessionKey", "msgToOpen", "msgToAuth", "domain"],
+        debugName: "XhpkePublicKey_fingerprint",
+        argNames: ["that"],
       );
 
   @override
-  XhpkePublicKey crateApiXhpkeXhpkeSecretKeyPublicKey({
-    required XhpkeSecretKey that,
+  XhpkePublicKey crateApiXhpkeXhpkePublicKeyFromBytes({
+    required List<int> bytes,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyPublicKeyConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyFromBytesConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyPublicKeyConstMeta =>
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFromBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSecretKey_public_key",
-        argNames: ["that"],
+        debugName: "XhpkePublicKey_from_bytes",
+        argNames: ["bytes"],
       );
 
   @override
-  Uint8List crateApiXhpkeXhpkeSecretKeyToBytes({required XhpkeSecretKey that}) {
+  (XhpkePublicKey, BigInt, BigInt) crateApiXhpkeXhpkePublicKeyFromCertDer({
+    required List<int> der,
+    required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-            that,
+          sse_encode_list_prim_u_8_loose(der, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            signer,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+          sse_encode_opt_box_autoadd_u_64(now, serializer);
+          sse_encode_u_64(leewaySecs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyToBytesConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyFromCertDerConstMeta,
+        argValues: [der, signer, now, leewaySecs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyToBytesConstMeta =>
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFromCertDerConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSecretKey_to_bytes",
-        argNames: ["that"],
+        debugName: "XhpkePublicKey_from_cert_der",
+        argNames: ["der", "signer", "now", "leewaySecs"],
       );
 
   @override
-  Uint8List crateApiXhpkeXhpkeSecretKeyToDer({required XhpkeSecretKey that}) {
+  (XhpkePublicKey, BigInt, BigInt) crateApiXhpkeXhpkePublicKeyFromCertPem({
+    required String pem,
+    required XdsaPublicKey signer,
+    BigInt? now,
+    required BigInt leewaySecs,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-            that,
+          sse_encode_String(pem, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            signer,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+          sse_encode_opt_box_autoadd_u_64(now, serializer);
+          sse_encode_u_64(leewaySecs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyToDerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyFromCertPemConstMeta,
+        argValues: [pem, signer, now, leewaySecs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyToDerConstMeta =>
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFromCertPemConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSecretKey_to_der",
-        argNames: ["that"],
+        debugName: "XhpkePublicKey_from_cert_pem",
+        argNames: ["pem", "signer", "now", "leewaySecs"],
       );
 
   @override
-  String crateApiXhpkeXhpkeSecretKeyToPem({required XhpkeSecretKey that}) {
+  XhpkePublicKey crateApiXhpkeXhpkePublicKeyFromDer({required List<int> der}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+          sse_encode_list_prim_u_8_loose(der, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSecretKeyToPemConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyFromDerConstMeta,
+        argValues: [der],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyToPemConstMeta =>
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFromDerConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSecretKey_to_pem",
-        argNames: ["that"],
+        debugName: "XhpkePublicKey_from_der",
+        argNames: ["der"],
       );
 
   @override
-  Uint8List crateApiXhpkeXhpkeSenderSeal({
-    required XhpkeSender that,
-    required List<int> msgToSeal,
-    required List<int> msgToAuth,
-  }) {
+  XhpkePublicKey crateApiXhpkeXhpkePublicKeyFromPem({required String pem}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-            that,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(msgToSeal, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+          sse_encode_String(pem, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiXhpkeXhpkeSenderSealConstMeta,
-        argValues: [that, msgToSeal, msgToAuth],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyFromPemConstMeta,
+        argValues: [pem],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhpkeXhpkeSenderSealConstMeta =>
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyFromPemConstMeta =>
       const TaskConstMeta(
-        debugName: "XhpkeSender_seal",
-        argNames: ["that", "msgToSeal", "msgToAuth"],
+        debugName: "XhpkePublicKey_from_pem",
+        argNames: ["pem"],
       );
 
   @override
-  Uint8List crateApiArgon2Argon2Key({
-    required List<int> password,
-    required List<int> salt,
-    required int time,
-    required int memory,
-    required int threads,
-    required BigInt keyLength,
+  (XhpkeSender, Uint8List) crateApiXhpkeXhpkePublicKeyNewSender({
+    required XhpkePublicKey that,
+    required List<int> domain,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(password, serializer);
-          sse_encode_list_prim_u_8_loose(salt, serializer);
-          sse_encode_u_32(time, serializer);
-          sse_encode_u_32(memory, serializer);
-          sse_encode_u_32(threads, serializer);
-          sse_encode_usize(keyLength, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_sender_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiArgon2Argon2KeyConstMeta,
-        argValues: [password, salt, time, memory, threads, keyLength],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyNewSenderConstMeta,
+        argValues: [that, domain],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiArgon2Argon2KeyConstMeta => const TaskConstMeta(
-    debugName: "argon2_key",
-    argNames: ["password", "salt", "time", "memory", "threads", "keyLength"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyNewSenderConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkePublicKey_new_sender",
+        argNames: ["that", "domain"],
+      );
 
   @override
-  void crateApiCborCborVerify({required List<int> data}) {
+  (Uint8List, Uint8List) crateApiXhpkeXhpkePublicKeySeal({
+    required XhpkePublicKey that,
+    required List<int> msgToSeal,
+    required List<int> msgToAuth,
+    required List<int> domain,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(msgToSeal, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiCborCborVerifyConstMeta,
-        argValues: [data],
+        constMeta: kCrateApiXhpkeXhpkePublicKeySealConstMeta,
+        argValues: [that, msgToSeal, msgToAuth, domain],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCborCborVerifyConstMeta =>
-      const TaskConstMeta(debugName: "cbor_verify", argNames: ["data"]);
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeySealConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkePublicKey_seal",
+        argNames: ["that", "msgToSeal", "msgToAuth", "domain"],
+      );
 
   @override
-  Uint8List crateApiCoseCoseDecrypt({
-    required List<int> msgToOpen,
-    required List<int> msgToAuth,
-    required XhpkeSecretKey recipient,
-    required List<int> domain,
-  }) {
+  Uint8List crateApiXhpkeXhpkePublicKeyToBytes({required XhpkePublicKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(msgToOpen, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-            recipient,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            that,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCoseCoseDecryptConstMeta,
-        argValues: [msgToOpen, msgToAuth, recipient, domain],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyToBytesConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCoseDecryptConstMeta => const TaskConstMeta(
-    debugName: "cose_decrypt",
-    argNames: ["msgToOpen", "msgToAuth", "recipient", "domain"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyToBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkePublicKey_to_bytes",
+        argNames: ["that"],
+      );
 
   @override
-  Uint8List crateApiCoseCoseEncrypt({
-    required List<int> sign1,
-    required List<int> msgToAuth,
-    required XhpkePublicKey recipient,
-    required List<int> domain,
+  Uint8List crateApiXhpkeXhpkePublicKeyToCertDer({
+    required XhpkePublicKey that,
+    required XdsaSecretKey signer,
+    required CertTemplate template,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(sign1, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-            recipient,
+            that,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_box_autoadd_cert_template(template, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiCoseCoseEncryptConstMeta,
-        argValues: [sign1, msgToAuth, recipient, domain],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyToCertDerConstMeta,
+        argValues: [that, signer, template],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCoseEncryptConstMeta => const TaskConstMeta(
-    debugName: "cose_encrypt",
-    argNames: ["sign1", "msgToAuth", "recipient", "domain"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyToCertDerConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkePublicKey_to_cert_der",
+        argNames: ["that", "signer", "template"],
+      );
 
   @override
-  Uint8List crateApiCoseCoseOpen({
-    required List<int> msgToOpen,
-    required List<int> msgToAuth,
-    required XhpkeSecretKey recipient,
-    required XdsaPublicKey sender,
-    required List<int> domain,
-    BigInt? maxDriftSecs,
+  String crateApiXhpkeXhpkePublicKeyToCertPem({
+    required XhpkePublicKey that,
+    required XdsaSecretKey signer,
+    required CertTemplate template,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(msgToOpen, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-            recipient,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            that,
             serializer,
           );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-            sender,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          sse_encode_opt_box_autoadd_u_64(maxDriftSecs, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+          sse_encode_box_autoadd_cert_template(template, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiCoseCoseOpenConstMeta,
-        argValues: [
-          msgToOpen,
-          msgToAuth,
-          recipient,
-          sender,
-          domain,
-          maxDriftSecs,
-        ],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyToCertPemConstMeta,
+        argValues: [that, signer, template],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCoseOpenConstMeta => const TaskConstMeta(
-    debugName: "cose_open",
-    argNames: [
-      "msgToOpen",
-      "msgToAuth",
-      "recipient",
-      "sender",
-      "domain",
-      "maxDriftSecs",
-    ],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyToCertPemConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkePublicKey_to_cert_pem",
+        argNames: ["that", "signer", "template"],
+      );
 
   @override
-  Uint8List crateApiCoseCosePeek({required List<int> signature}) {
+  Uint8List crateApiXhpkeXhpkePublicKeyToDer({required XhpkePublicKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(signature, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCoseCosePeekConstMeta,
-        argValues: [signature],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyToDerConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCosePeekConstMeta =>
-      const TaskConstMeta(debugName: "cose_peek", argNames: ["signature"]);
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyToDerConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkePublicKey_to_der",
+        argNames: ["that"],
+      );
 
   @override
-  XhpkeFingerprint crateApiCoseCoseRecipient({required List<int> ciphertext}) {
+  String crateApiXhpkeXhpkePublicKeyToPem({required XhpkePublicKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(ciphertext, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCoseCoseRecipientConstMeta,
-        argValues: [ciphertext],
+        constMeta: kCrateApiXhpkeXhpkePublicKeyToPemConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCoseRecipientConstMeta => const TaskConstMeta(
-    debugName: "cose_recipient",
-    argNames: ["ciphertext"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkePublicKeyToPemConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkePublicKey_to_pem",
+        argNames: ["that"],
+      );
 
   @override
-  Uint8List crateApiCoseCoseSeal({
-    required List<int> msgToSeal,
+  Uint8List crateApiXhpkeXhpkeReceiverOpen({
+    required XhpkeReceiver that,
+    required List<int> msgToOpen,
     required List<int> msgToAuth,
-    required XdsaSecretKey signer,
-    required XhpkePublicKey recipient,
-    required List<int> domain,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(msgToSeal, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-            signer,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-            recipient,
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+            that,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+          sse_encode_list_prim_u_8_loose(msgToOpen, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiCoseCoseSealConstMeta,
-        argValues: [msgToSeal, msgToAuth, signer, recipient, domain],
+        constMeta: kCrateApiXhpkeXhpkeReceiverOpenConstMeta,
+        argValues: [that, msgToOpen, msgToAuth],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCoseSealConstMeta => const TaskConstMeta(
-    debugName: "cose_seal",
-    argNames: ["msgToSeal", "msgToAuth", "signer", "recipient", "domain"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkeReceiverOpenConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeReceiver_open",
+        argNames: ["that", "msgToOpen", "msgToAuth"],
+      );
 
   @override
-  Uint8List crateApiCoseCoseSign({
-    required List<int> msgToEmbed,
-    required List<int> msgToAuth,
-    required XdsaSecretKey signer,
-    required List<int> domain,
+  XhpkeFingerprint crateApiXhpkeXhpkeSecretKeyFingerprint({
+    required XhpkeSecretKey that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(msgToEmbed, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-            signer,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            that,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCoseCoseSignConstMeta,
-        argValues: [msgToEmbed, msgToAuth, signer, domain],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyFingerprintConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCoseSignConstMeta => const TaskConstMeta(
-    debugName: "cose_sign",
-    argNames: ["msgToEmbed", "msgToAuth", "signer", "domain"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyFingerprintConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSecretKey_fingerprint",
+        argNames: ["that"],
+      );
 
   @override
-  Uint8List crateApiCoseCoseSignDetached({
-    required List<int> msgToAuth,
-    required XdsaSecretKey signer,
-    required List<int> domain,
+  XhpkeSecretKey crateApiXhpkeXhpkeSecretKeyFromBytes({
+    required List<int> bytes,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-            signer,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiCoseCoseSignDetachedConstMeta,
-        argValues: [msgToAuth, signer, domain],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyFromBytesConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCoseSignDetachedConstMeta =>
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyFromBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "cose_sign_detached",
-        argNames: ["msgToAuth", "signer", "domain"],
+        debugName: "XhpkeSecretKey_from_bytes",
+        argNames: ["bytes"],
       );
 
   @override
-  XdsaFingerprint crateApiCoseCoseSigner({required List<int> signature}) {
+  XhpkeSecretKey crateApiXhpkeXhpkeSecretKeyFromDer({required List<int> der}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(signature, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+          sse_encode_list_prim_u_8_loose(der, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
-          decodeErrorData: sse_decode_String,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiCoseCoseSignerConstMeta,
-        argValues: [signature],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyFromDerConstMeta,
+        argValues: [der],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCoseSignerConstMeta =>
-      const TaskConstMeta(debugName: "cose_signer", argNames: ["signature"]);
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyFromDerConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSecretKey_from_der",
+        argNames: ["der"],
+      );
 
   @override
-  Uint8List crateApiCoseCoseVerify({
-    required List<int> msgToCheck,
-    required List<int> msgToAuth,
-    required XdsaPublicKey verifier,
-    required List<int> domain,
-    BigInt? maxDriftSecs,
-  }) {
+  XhpkeSecretKey crateApiXhpkeXhpkeSecretKeyFromPem({required String pem}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(msgToCheck, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-            verifier,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          sse_encode_opt_box_autoadd_u_64(maxDriftSecs, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+          sse_encode_String(pem, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiCoseCoseVerifyConstMeta,
-        argValues: [msgToCheck, msgToAuth, verifier, domain, maxDriftSecs],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyFromPemConstMeta,
+        argValues: [pem],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCoseVerifyConstMeta => const TaskConstMeta(
-    debugName: "cose_verify",
-    argNames: ["msgToCheck", "msgToAuth", "verifier", "domain", "maxDriftSecs"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyFromPemConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSecretKey_from_pem",
+        argNames: ["pem"],
+      );
 
   @override
-  void crateApiCoseCoseVerifyDetached({
-    required List<int> msgToCheck,
-    required List<int> msgToAuth,
-    required XdsaPublicKey verifier,
-    required List<int> domain,
-    BigInt? maxDriftSecs,
-  }) {
+  XhpkeSecretKey crateApiXhpkeXhpkeSecretKeyGenerate() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(msgToCheck, serializer);
-          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-            verifier,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(domain, serializer);
-          sse_encode_opt_box_autoadd_u_64(maxDriftSecs, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCoseCoseVerifyDetachedConstMeta,
-        argValues: [msgToCheck, msgToAuth, verifier, domain, maxDriftSecs],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyGenerateConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoseCoseVerifyDetachedConstMeta =>
-      const TaskConstMeta(
-        debugName: "cose_verify_detached",
-        argNames: [
-          "msgToCheck",
-          "msgToAuth",
-          "verifier",
-          "domain",
-          "maxDriftSecs",
-        ],
-      );
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyGenerateConstMeta =>
+      const TaskConstMeta(debugName: "XhpkeSecretKey_generate", argNames: []);
 
   @override
-  Uint8List crateApiCwtCwtIssue({
-    required List<int> claimsCbor,
-    required XdsaSecretKey signer,
+  XhpkeReceiver crateApiXhpkeXhpkeSecretKeyNewReceiver({
+    required XhpkeSecretKey that,
+    required List<int> encapKey,
     required List<int> domain,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(claimsCbor, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-            signer,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            that,
             serializer,
           );
+          sse_encode_list_prim_u_8_loose(encapKey, serializer);
           sse_encode_list_prim_u_8_loose(domain, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiCwtCwtIssueConstMeta,
-        argValues: [claimsCbor, signer, domain],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyNewReceiverConstMeta,
+        argValues: [that, encapKey, domain],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCwtCwtIssueConstMeta => const TaskConstMeta(
-    debugName: "cwt_issue",
-    argNames: ["claimsCbor", "signer", "domain"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyNewReceiverConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSecretKey_new_receiver",
+        argNames: ["that", "encapKey", "domain"],
+      );
 
   @override
-  Uint8List crateApiCwtCwtPeek({required List<int> token}) {
+  Uint8List crateApiXhpkeXhpkeSecretKeyOpen({
+    required XhpkeSecretKey that,
+    required List<int> sessionKey,
+    required List<int> msgToOpen,
+    required List<int> msgToAuth,
+    required List<int> domain,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(token, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(sessionKey, serializer);
+          sse_encode_list_prim_u_8_loose(msgToOpen, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiCwtCwtPeekConstMeta,
-        argValues: [token],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCwtCwtPeekConstMeta =>
-      const TaskConstMeta(debugName: "cwt_peek", argNames: ["token"]);
-
-  @override
-  XdsaFingerprint crateApiCwtCwtSigner({required List<int> token}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(token, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiCwtCwtSignerConstMeta,
-        argValues: [token],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyOpenConstMeta,
+        argValues: [that, sessionKey, msgToOpen, msgToAuth, domain],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCwtCwtSignerConstMeta =>
-      const TaskConstMeta(debugName: "cwt_signer", argNames: ["token"]);
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyOpenConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSecretKey_open",
+        argNames: ["that", "sessionKey", "msgToOpen", "msgToAuth", "domain"],
+      );
 
   @override
-  Uint8List crateApiCwtCwtVerify({
-    required List<int> token,
-    required XdsaPublicKey verifier,
+  Uint8List crateApiXhpkeXhpkeSecretKeyOpenMulti({
+    required XhpkeSecretKey that,
+    required List<int> sealed,
+    required List<int> msgToAuth,
     required List<int> domain,
-    BigInt? now,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(token, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-            verifier,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            that,
             serializer,
           );
+          sse_encode_list_prim_u_8_loose(sealed, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
           sse_encode_list_prim_u_8_loose(domain, serializer);
-          sse_encode_opt_box_autoadd_u_64(now, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiCwtCwtVerifyConstMeta,
-        argValues: [token, verifier, domain, now],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyOpenMultiConstMeta,
+        argValues: [that, sealed, msgToAuth, domain],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCwtCwtVerifyConstMeta => const TaskConstMeta(
-    debugName: "cwt_verify",
-    argNames: ["token", "verifier", "domain", "now"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyOpenMultiConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSecretKey_open_multi",
+        argNames: ["that", "sealed", "msgToAuth", "domain"],
+      );
 
   @override
-  Uint8List crateApiHkdfHkdfExpand({
-    required List<int> prk,
-    required List<int> info,
-    required BigInt length,
+  XhpkePublicKey crateApiXhpkeXhpkeSecretKeyPublicKey({
+    required XhpkeSecretKey that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(prk, serializer);
-          sse_encode_list_prim_u_8_loose(info, serializer);
-          sse_encode_usize(length, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiHkdfHkdfExpandConstMeta,
-        argValues: [prk, info, length],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyPublicKeyConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHkdfHkdfExpandConstMeta => const TaskConstMeta(
-    debugName: "hkdf_expand",
-    argNames: ["prk", "info", "length"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyPublicKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSecretKey_public_key",
+        argNames: ["that"],
+      );
 
   @override
-  Uint8List crateApiHkdfHkdfExtract({
-    required List<int> secret,
-    required List<int> salt,
-  }) {
+  Uint8List crateApiXhpkeXhpkeSecretKeyToBytes({required XhpkeSecretKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(secret, serializer);
-          sse_encode_list_prim_u_8_loose(salt, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiHkdfHkdfExtractConstMeta,
-        argValues: [secret, salt],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyToBytesConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHkdfHkdfExtractConstMeta => const TaskConstMeta(
-    debugName: "hkdf_extract",
-    argNames: ["secret", "salt"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyToBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSecretKey_to_bytes",
+        argNames: ["that"],
+      );
 
   @override
-  Uint8List crateApiHkdfHkdfKey({
-    required List<int> secret,
-    required List<int> salt,
-    required List<int> info,
-    required BigInt length,
-  }) {
+  Uint8List crateApiXhpkeXhpkeSecretKeyToDer({required XhpkeSecretKey that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(secret, serializer);
-          sse_encode_list_prim_u_8_loose(salt, serializer);
-          sse_encode_list_prim_u_8_loose(info, serializer);
-          sse_encode_usize(length, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiHkdfHkdfKeyConstMeta,
-        argValues: [secret, salt, info, length],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyToDerConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHkdfHkdfKeyConstMeta => const TaskConstMeta(
-    debugName: "hkdf_key",
-    argNames: ["secret", "salt", "info", "length"],
-  );
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyToDerConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSecretKey_to_der",
+        argNames: ["that"],
+      );
 
   @override
-  Future<void> crateApiInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiXhpkeXhpkeSecretKeyToPem({required XhpkeSecretKey that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            that,
             serializer,
-            funcId: 97,
-            port: port_,
           );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiXhpkeXhpkeSecretKeyToPemConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiXhpkeXhpkeSecretKeyToPemConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSecretKey_to_pem",
+        argNames: ["that"],
+      );
 
   @override
-  Uint8List crateApiRandRandomBytes({required BigInt length}) {
+  Uint8List crateApiXhpkeXhpkeSenderSeal({
+    required XhpkeSender that,
+    required List<int> msgToSeal,
+    required List<int> msgToAuth,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_usize(length, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(msgToSeal, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiRandRandomBytesConstMeta,
-        argValues: [length],
+        constMeta: kCrateApiXhpkeXhpkeSenderSealConstMeta,
+        argValues: [that, msgToSeal, msgToAuth],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRandRandomBytesConstMeta =>
-      const TaskConstMeta(debugName: "random_bytes", argNames: ["length"]);
+  TaskConstMeta get kCrateApiXhpkeXhpkeSenderSealConstMeta =>
+      const TaskConstMeta(
+        debugName: "XhpkeSender_seal",
+        argNames: ["that", "msgToSeal", "msgToAuth"],
+      );
 
   @override
-  Uint8List crateApiStreamStreamDecrypt({
-    required List<int> key,
-    required List<int> ciphertext,
-  }) {
-    return handler.executeSync(
+  Uint8List crateApiArgon2Argon2Key({
+    required List<int> password,
+    required List<int> salt,
+    required int time,
+    required int memory,
+    required int threads,
+    required BigInt keyLength,
+  }) {
+    return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(ciphertext, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+          sse_encode_list_prim_u_8_loose(password, serializer);
+          sse_encode_list_prim_u_8_loose(salt, serializer);
+          sse_encode_u_32(time, serializer);
+          sse_encode_u_32(memory, serializer);
+          sse_encode_u_32(threads, serializer);
+          sse_encode_usize(keyLength, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiStreamStreamDecryptConstMeta,
-        argValues: [key, ciphertext],
+        constMeta: kCrateApiArgon2Argon2KeyConstMeta,
+        argValues: [password, salt, time, memory, threads, keyLength],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStreamStreamDecryptConstMeta =>
-      const TaskConstMeta(
-        debugName: "stream_decrypt",
-        argNames: ["key", "ciphertext"],
-      );
+  TaskConstMeta get kCrateApiArgon2Argon2KeyConstMeta => const TaskConstMeta(
+    debugName: "argon2_key",
+    argNames: ["password", "salt", "time", "memory", "threads", "keyLength"],
+  );
 
   @override
-  Uint8List crateApiStreamStreamEncrypt({
-    required List<int> key,
-    required List<int> plaintext,
-  }) {
+  CborValue crateApiCborCborDecode({required List<int> data}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(plaintext, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
           return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 100,
+            funcId: 105,
           )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_cbor_value,
+          decodeErrorData: sse_decode_crypto_error,
         ),
-        constMeta: kCrateApiStreamStreamEncryptConstMeta,
-        argValues: [key, plaintext],
+        constMeta: kCrateApiCborCborDecodeConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStreamStreamEncryptConstMeta =>
-      const TaskConstMeta(
-        debugName: "stream_encrypt",
-        argNames: ["key", "plaintext"],
-      );
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_RsaFingerprint => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint;
+  TaskConstMeta get kCrateApiCborCborDecodeConstMeta =>
+      const TaskConstMeta(debugName: "cbor_decode", argNames: ["data"]);
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_RsaFingerprint => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint;
+  @override
+  Uint8List crateApiCborCborEncode({required CborValue value}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cbor_value(value, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCborCborEncodeConstMeta,
+        argValues: [value],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_RsaPublicKey => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey;
+  TaskConstMeta get kCrateApiCborCborEncodeConstMeta =>
+      const TaskConstMeta(debugName: "cbor_encode", argNames: ["value"]);
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_RsaPublicKey => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey;
+  @override
+  Uint8List crateApiCborCborFromDiagnostic({required String text}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCborCborFromDiagnosticConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_RsaSecretKey => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey;
+  TaskConstMeta get kCrateApiCborCborFromDiagnosticConstMeta =>
+      const TaskConstMeta(
+        debugName: "cbor_from_diagnostic",
+        argNames: ["text"],
+      );
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_RsaSecretKey => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey;
+  @override
+  String crateApiCborCborToDiagnostic({required List<int> data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCborCborToDiagnosticConstMeta,
+        argValues: [data],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_RsaSignature => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature;
+  TaskConstMeta get kCrateApiCborCborToDiagnosticConstMeta =>
+      const TaskConstMeta(debugName: "cbor_to_diagnostic", argNames: ["data"]);
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_RsaSignature => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature;
+  @override
+  void crateApiCborCborValidate({
+    required List<int> data,
+    required String cddl,
+    required String rule,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_String(cddl, serializer);
+          sse_encode_String(rule, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCborCborValidateConstMeta,
+        argValues: [data, cddl, rule],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_XdsaFingerprint => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint;
+  TaskConstMeta get kCrateApiCborCborValidateConstMeta => const TaskConstMeta(
+    debugName: "cbor_validate",
+    argNames: ["data", "cddl", "rule"],
+  );
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_XdsaFingerprint => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint;
+  @override
+  void crateApiCborCborVerify({required List<int> data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCborCborVerifyConstMeta,
+        argValues: [data],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_XdsaPublicKey => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey;
+  TaskConstMeta get kCrateApiCborCborVerifyConstMeta =>
+      const TaskConstMeta(debugName: "cbor_verify", argNames: ["data"]);
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_XdsaPublicKey => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey;
+  @override
+  Uint8List crateApiCoseCoseAddSignature({
+    required List<int> msgToSign,
+    required List<int> msgToAuth,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToSign, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseAddSignatureConstMeta,
+        argValues: [msgToSign, msgToAuth, signer, domain],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_XdsaSecretKey => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey;
+  TaskConstMeta get kCrateApiCoseCoseAddSignatureConstMeta =>
+      const TaskConstMeta(
+        debugName: "cose_add_signature",
+        argNames: ["msgToSign", "msgToAuth", "signer", "domain"],
+      );
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_XdsaSecretKey => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey;
+  @override
+  Uint8List crateApiCoseCoseDecrypt({
+    required List<int> msgToOpen,
+    required List<int> msgToAuth,
+    required XhpkeSecretKey recipient,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToOpen, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            recipient,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseDecryptConstMeta,
+        argValues: [msgToOpen, msgToAuth, recipient, domain],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
+  TaskConstMeta get kCrateApiCoseCoseDecryptConstMeta => const TaskConstMeta(
+    debugName: "cose_decrypt",
+    argNames: ["msgToOpen", "msgToAuth", "recipient", "domain"],
+  );
+
+  @override
+  Uint8List crateApiCoseCoseEncrypt({
+    required List<int> sign1,
+    required List<int> msgToAuth,
+    required XhpkePublicKey recipient,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(sign1, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            recipient,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseEncryptConstMeta,
+        argValues: [sign1, msgToAuth, recipient, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseEncryptConstMeta => const TaskConstMeta(
+    debugName: "cose_encrypt",
+    argNames: ["sign1", "msgToAuth", "recipient", "domain"],
+  );
+
+  @override
+  CoseSign1Info crateApiCoseCoseInspect({required List<int> signature}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(signature, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cose_sign1_info,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseInspectConstMeta,
+        argValues: [signature],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseInspectConstMeta =>
+      const TaskConstMeta(debugName: "cose_inspect", argNames: ["signature"]);
+
+  @override
+  Uint8List crateApiCoseCoseOpen({
+    required List<int> msgToOpen,
+    required List<int> msgToAuth,
+    required XhpkeSecretKey recipient,
+    required XdsaPublicKey sender,
+    required List<int> domain,
+    BigInt? maxDriftSecs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToOpen, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            recipient,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            sender,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_opt_box_autoadd_u_64(maxDriftSecs, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseOpenConstMeta,
+        argValues: [
+          msgToOpen,
+          msgToAuth,
+          recipient,
+          sender,
+          domain,
+          maxDriftSecs,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseOpenConstMeta => const TaskConstMeta(
+    debugName: "cose_open",
+    argNames: [
+      "msgToOpen",
+      "msgToAuth",
+      "recipient",
+      "sender",
+      "domain",
+      "maxDriftSecs",
+    ],
+  );
+
+  @override
+  Uint8List crateApiCoseCoseOpenMulti({
+    required List<int> msgToOpen,
+    required List<int> msgToAuth,
+    required XhpkeSecretKey recipient,
+    required XdsaPublicKey sender,
+    required List<int> domain,
+    BigInt? maxDriftSecs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToOpen, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            recipient,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            sender,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_opt_box_autoadd_u_64(maxDriftSecs, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseOpenMultiConstMeta,
+        argValues: [
+          msgToOpen,
+          msgToAuth,
+          recipient,
+          sender,
+          domain,
+          maxDriftSecs,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseOpenMultiConstMeta => const TaskConstMeta(
+    debugName: "cose_open_multi",
+    argNames: [
+      "msgToOpen",
+      "msgToAuth",
+      "recipient",
+      "sender",
+      "domain",
+      "maxDriftSecs",
+    ],
+  );
+
+  @override
+  Uint8List crateApiCoseCosePeek({required List<int> signature}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(signature, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCosePeekConstMeta,
+        argValues: [signature],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCosePeekConstMeta =>
+      const TaskConstMeta(debugName: "cose_peek", argNames: ["signature"]);
+
+  @override
+  XhpkeFingerprint crateApiCoseCoseRecipient({required List<int> ciphertext}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseRecipientConstMeta,
+        argValues: [ciphertext],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseRecipientConstMeta => const TaskConstMeta(
+    debugName: "cose_recipient",
+    argNames: ["ciphertext"],
+  );
+
+  @override
+  List<XhpkeFingerprint> crateApiCoseCoseRecipients({
+    required List<int> ciphertext,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseRecipientsConstMeta,
+        argValues: [ciphertext],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseRecipientsConstMeta => const TaskConstMeta(
+    debugName: "cose_recipients",
+    argNames: ["ciphertext"],
+  );
+
+  @override
+  Uint8List crateApiCoseCoseSeal({
+    required List<int> msgToSeal,
+    required List<int> msgToAuth,
+    required XdsaSecretKey signer,
+    required XhpkePublicKey recipient,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToSeal, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            recipient,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseSealConstMeta,
+        argValues: [msgToSeal, msgToAuth, signer, recipient, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseSealConstMeta => const TaskConstMeta(
+    debugName: "cose_seal",
+    argNames: ["msgToSeal", "msgToAuth", "signer", "recipient", "domain"],
+  );
+
+  @override
+  Uint8List crateApiCoseCoseSealMulti({
+    required List<int> msgToSeal,
+    required List<int> msgToAuth,
+    required XdsaSecretKey signer,
+    required List<XhpkePublicKey> recipients,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToSeal, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            recipients,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseSealMultiConstMeta,
+        argValues: [msgToSeal, msgToAuth, signer, recipients, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseSealMultiConstMeta => const TaskConstMeta(
+    debugName: "cose_seal_multi",
+    argNames: ["msgToSeal", "msgToAuth", "signer", "recipients", "domain"],
+  );
+
+  @override
+  Uint8List crateApiCoseCoseSign({
+    required List<int> msgToEmbed,
+    required List<int> msgToAuth,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToEmbed, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseSignConstMeta,
+        argValues: [msgToEmbed, msgToAuth, signer, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseSignConstMeta => const TaskConstMeta(
+    debugName: "cose_sign",
+    argNames: ["msgToEmbed", "msgToAuth", "signer", "domain"],
+  );
+
+  @override
+  Uint8List crateApiCoseCoseSignDetached({
+    required List<int> msgToAuth,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 123,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseSignDetachedConstMeta,
+        argValues: [msgToAuth, signer, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseSignDetachedConstMeta =>
+      const TaskConstMeta(
+        debugName: "cose_sign_detached",
+        argNames: ["msgToAuth", "signer", "domain"],
+      );
+
+  @override
+  Uint8List crateApiCoseCoseSignMulti({
+    required List<int> msgToEmbed,
+    required List<int> msgToAuth,
+    required List<XdsaSecretKey> signers,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToEmbed, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signers,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseSignMultiConstMeta,
+        argValues: [msgToEmbed, msgToAuth, signers, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseSignMultiConstMeta => const TaskConstMeta(
+    debugName: "cose_sign_multi",
+    argNames: ["msgToEmbed", "msgToAuth", "signers", "domain"],
+  );
+
+  @override
+  XdsaFingerprint crateApiCoseCoseSigner({required List<int> signature}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(signature, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 125,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseSignerConstMeta,
+        argValues: [signature],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseSignerConstMeta =>
+      const TaskConstMeta(debugName: "cose_signer", argNames: ["signature"]);
+
+  @override
+  List<XdsaFingerprint> crateApiCoseCoseSigners({
+    required List<int> signature,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(signature, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseSignersConstMeta,
+        argValues: [signature],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseSignersConstMeta =>
+      const TaskConstMeta(debugName: "cose_signers", argNames: ["signature"]);
+
+  @override
+  Uint8List crateApiCoseCoseVerify({
+    required List<int> msgToCheck,
+    required List<int> msgToAuth,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    BigInt? maxDriftSecs,
+    CddlSchema? schema,
+    String? rule,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToCheck, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            verifier,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_opt_box_autoadd_u_64(maxDriftSecs, serializer);
+          sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+            schema,
+            serializer,
+          );
+          sse_encode_opt_String(rule, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 127,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseVerifyConstMeta,
+        argValues: [
+          msgToCheck,
+          msgToAuth,
+          verifier,
+          domain,
+          maxDriftSecs,
+          schema,
+          rule,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseVerifyConstMeta => const TaskConstMeta(
+    debugName: "cose_verify",
+    argNames: [
+      "msgToCheck",
+      "msgToAuth",
+      "verifier",
+      "domain",
+      "maxDriftSecs",
+      "schema",
+      "rule",
+    ],
+  );
+
+  @override
+  void crateApiCoseCoseVerifyDetached({
+    required List<int> msgToCheck,
+    required List<int> msgToAuth,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    BigInt? maxDriftSecs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToCheck, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            verifier,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_opt_box_autoadd_u_64(maxDriftSecs, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 128,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseVerifyDetachedConstMeta,
+        argValues: [msgToCheck, msgToAuth, verifier, domain, maxDriftSecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseVerifyDetachedConstMeta =>
+      const TaskConstMeta(
+        debugName: "cose_verify_detached",
+        argNames: [
+          "msgToCheck",
+          "msgToAuth",
+          "verifier",
+          "domain",
+          "maxDriftSecs",
+        ],
+      );
+
+  @override
+  CoseSign1Info crateApiCoseCoseVerifyInfo({
+    required List<int> msgToCheck,
+    required List<int> msgToAuth,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    BigInt? maxDriftSecs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToCheck, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            verifier,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_opt_box_autoadd_u_64(maxDriftSecs, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 129,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cose_sign1_info,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseVerifyInfoConstMeta,
+        argValues: [msgToCheck, msgToAuth, verifier, domain, maxDriftSecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseVerifyInfoConstMeta => const TaskConstMeta(
+    debugName: "cose_verify_info",
+    argNames: ["msgToCheck", "msgToAuth", "verifier", "domain", "maxDriftSecs"],
+  );
+
+  @override
+  Uint8List crateApiCoseCoseVerifyThreshold({
+    required List<int> msgToCheck,
+    required List<int> msgToAuth,
+    required List<XdsaPublicKey> verifiers,
+    required BigInt threshold,
+    required List<int> domain,
+    BigInt? maxDriftSecs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(msgToCheck, serializer);
+          sse_encode_list_prim_u_8_loose(msgToAuth, serializer);
+          sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            verifiers,
+            serializer,
+          );
+          sse_encode_usize(threshold, serializer);
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_opt_box_autoadd_u_64(maxDriftSecs, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 130,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCoseCoseVerifyThresholdConstMeta,
+        argValues: [
+          msgToCheck,
+          msgToAuth,
+          verifiers,
+          threshold,
+          domain,
+          maxDriftSecs,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoseCoseVerifyThresholdConstMeta =>
+      const TaskConstMeta(
+        debugName: "cose_verify_threshold",
+        argNames: [
+          "msgToCheck",
+          "msgToAuth",
+          "verifiers",
+          "threshold",
+          "domain",
+          "maxDriftSecs",
+        ],
+      );
+
+  @override
+  Uint8List crateApiCwtCwtIssue({
+    required List<int> claimsCbor,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(claimsCbor, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 131,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtIssueConstMeta,
+        argValues: [claimsCbor, signer, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtIssueConstMeta => const TaskConstMeta(
+    debugName: "cwt_issue",
+    argNames: ["claimsCbor", "signer", "domain"],
+  );
+
+  @override
+  Uint8List crateApiCwtCwtIssueClaims({
+    required CwtClaims claims,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cwt_claims(claims, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtIssueClaimsConstMeta,
+        argValues: [claims, signer, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtIssueClaimsConstMeta => const TaskConstMeta(
+    debugName: "cwt_issue_claims",
+    argNames: ["claims", "signer", "domain"],
+  );
+
+  @override
+  SdCwt crateApiCwtCwtIssueSd({
+    required CwtClaims claims,
+    required Int64List disclosable,
+    required XdsaSecretKey signer,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cwt_claims(claims, serializer);
+          sse_encode_list_prim_i_64_loose(disclosable, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 133,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sd_cwt,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtIssueSdConstMeta,
+        argValues: [claims, disclosable, signer, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtIssueSdConstMeta => const TaskConstMeta(
+    debugName: "cwt_issue_sd",
+    argNames: ["claims", "disclosable", "signer", "domain"],
+  );
+
+  @override
+  Uint8List crateApiCwtCwtIssueSealed({
+    required CwtClaims claims,
+    required XdsaSecretKey signer,
+    required XhpkePublicKey recipient,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cwt_claims(claims, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            signer,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            recipient,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtIssueSealedConstMeta,
+        argValues: [claims, signer, recipient, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtIssueSealedConstMeta => const TaskConstMeta(
+    debugName: "cwt_issue_sealed",
+    argNames: ["claims", "signer", "recipient", "domain"],
+  );
+
+  @override
+  CwtClaims crateApiCwtCwtOpenSealed({
+    required List<int> token,
+    required XhpkeSecretKey recipient,
+    required XdsaPublicKey issuer,
+    required List<int> domain,
+    required CwtValidation validation,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(token, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            recipient,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            issuer,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_box_autoadd_cwt_validation(validation, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 135,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cwt_claims,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtOpenSealedConstMeta,
+        argValues: [token, recipient, issuer, domain, validation],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtOpenSealedConstMeta => const TaskConstMeta(
+    debugName: "cwt_open_sealed",
+    argNames: ["token", "recipient", "issuer", "domain", "validation"],
+  );
+
+  @override
+  Uint8List crateApiCwtCwtPeek({required List<int> token}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(token, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 136,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtPeekConstMeta,
+        argValues: [token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtPeekConstMeta =>
+      const TaskConstMeta(debugName: "cwt_peek", argNames: ["token"]);
+
+  @override
+  CwtClaims crateApiCwtCwtPeekClaims({required List<int> token}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(token, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 137,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cwt_claims,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtPeekClaimsConstMeta,
+        argValues: [token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtPeekClaimsConstMeta =>
+      const TaskConstMeta(debugName: "cwt_peek_claims", argNames: ["token"]);
+
+  @override
+  Uint8List crateApiCwtCwtPresent({
+    required List<int> token,
+    required XdsaSecretKey holder,
+    required String audience,
+    required List<int> nonce,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(token, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+            holder,
+            serializer,
+          );
+          sse_encode_String(audience, serializer);
+          sse_encode_list_prim_u_8_loose(nonce, serializer);
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 138,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtPresentConstMeta,
+        argValues: [token, holder, audience, nonce, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtPresentConstMeta => const TaskConstMeta(
+    debugName: "cwt_present",
+    argNames: ["token", "holder", "audience", "nonce", "domain"],
+  );
+
+  @override
+  Uint8List crateApiCwtCwtPresentSd({
+    required List<int> token,
+    required List<Uint8List> disclosures,
+    required Int64List reveal,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(token, serializer);
+          sse_encode_list_list_prim_u_8_strict(disclosures, serializer);
+          sse_encode_list_prim_i_64_loose(reveal, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 139,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtPresentSdConstMeta,
+        argValues: [token, disclosures, reveal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtPresentSdConstMeta => const TaskConstMeta(
+    debugName: "cwt_present_sd",
+    argNames: ["token", "disclosures", "reveal"],
+  );
+
+  @override
+  XdsaFingerprint crateApiCwtCwtSigner({required List<int> token}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(token, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 140,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtSignerConstMeta,
+        argValues: [token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtSignerConstMeta =>
+      const TaskConstMeta(debugName: "cwt_signer", argNames: ["token"]);
+
+  @override
+  Uint8List crateApiCwtCwtVerify({
+    required List<int> token,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    required CwtValidation validation,
+    StatusList? statusList,
+    CddlSchema? schema,
+    String? rule,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(token, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            verifier,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_box_autoadd_cwt_validation(validation, serializer);
+          sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+            statusList,
+            serializer,
+          );
+          sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+            schema,
+            serializer,
+          );
+          sse_encode_opt_String(rule, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 141,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtVerifyConstMeta,
+        argValues: [
+          token,
+          verifier,
+          domain,
+          validation,
+          statusList,
+          schema,
+          rule,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtVerifyConstMeta => const TaskConstMeta(
+    debugName: "cwt_verify",
+    argNames: [
+      "token",
+      "verifier",
+      "domain",
+      "validation",
+      "statusList",
+      "schema",
+      "rule",
+    ],
+  );
+
+  @override
+  CwtClaims crateApiCwtCwtVerifyChain({
+    required List<Uint8List> tokens,
+    required XdsaPublicKey root,
+    required List<int> domain,
+    BigInt? now,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_list_prim_u_8_strict(tokens, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            root,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_opt_box_autoadd_u_64(now, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 142,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cwt_claims,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtVerifyChainConstMeta,
+        argValues: [tokens, root, domain, now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtVerifyChainConstMeta => const TaskConstMeta(
+    debugName: "cwt_verify_chain",
+    argNames: ["tokens", "root", "domain", "now"],
+  );
+
+  @override
+  CwtClaims crateApiCwtCwtVerifyClaims({
+    required List<int> token,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    required CwtValidation validation,
+    StatusList? statusList,
+    CddlSchema? schema,
+    String? rule,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(token, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            verifier,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_box_autoadd_cwt_validation(validation, serializer);
+          sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+            statusList,
+            serializer,
+          );
+          sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+            schema,
+            serializer,
+          );
+          sse_encode_opt_String(rule, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 143,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cwt_claims,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtVerifyClaimsConstMeta,
+        argValues: [
+          token,
+          verifier,
+          domain,
+          validation,
+          statusList,
+          schema,
+          rule,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtVerifyClaimsConstMeta => const TaskConstMeta(
+    debugName: "cwt_verify_claims",
+    argNames: [
+      "token",
+      "verifier",
+      "domain",
+      "validation",
+      "statusList",
+      "schema",
+      "rule",
+    ],
+  );
+
+  @override
+  CwtClaims crateApiCwtCwtVerifyPresentation({
+    required List<int> presentation,
+    required XdsaPublicKey issuer,
+    required String audience,
+    required List<int> nonce,
+    required List<int> domain,
+    required CwtValidation validation,
+    BigInt? maxDriftSecs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(presentation, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            issuer,
+            serializer,
+          );
+          sse_encode_String(audience, serializer);
+          sse_encode_list_prim_u_8_loose(nonce, serializer);
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_box_autoadd_cwt_validation(validation, serializer);
+          sse_encode_opt_box_autoadd_u_64(maxDriftSecs, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 144,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cwt_claims,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtVerifyPresentationConstMeta,
+        argValues: [
+          presentation,
+          issuer,
+          audience,
+          nonce,
+          domain,
+          validation,
+          maxDriftSecs,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtVerifyPresentationConstMeta =>
+      const TaskConstMeta(
+        debugName: "cwt_verify_presentation",
+        argNames: [
+          "presentation",
+          "issuer",
+          "audience",
+          "nonce",
+          "domain",
+          "validation",
+          "maxDriftSecs",
+        ],
+      );
+
+  @override
+  CwtClaims crateApiCwtCwtVerifySd({
+    required List<int> presentation,
+    required XdsaPublicKey verifier,
+    required List<int> domain,
+    required CwtValidation validation,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(presentation, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            verifier,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          sse_encode_box_autoadd_cwt_validation(validation, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 145,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cwt_claims,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiCwtCwtVerifySdConstMeta,
+        argValues: [presentation, verifier, domain, validation],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCwtCwtVerifySdConstMeta => const TaskConstMeta(
+    debugName: "cwt_verify_sd",
+    argNames: ["presentation", "verifier", "domain", "validation"],
+  );
+
+  @override
+  Uint8List crateApiHkdfHkdfExpand({
+    required List<int> prk,
+    required List<int> info,
+    required BigInt length,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(prk, serializer);
+          sse_encode_list_prim_u_8_loose(info, serializer);
+          sse_encode_usize(length, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 146,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiHkdfHkdfExpandConstMeta,
+        argValues: [prk, info, length],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHkdfHkdfExpandConstMeta => const TaskConstMeta(
+    debugName: "hkdf_expand",
+    argNames: ["prk", "info", "length"],
+  );
+
+  @override
+  Uint8List crateApiHkdfHkdfExtract({
+    required List<int> secret,
+    required List<int> salt,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(secret, serializer);
+          sse_encode_list_prim_u_8_loose(salt, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 147,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHkdfHkdfExtractConstMeta,
+        argValues: [secret, salt],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHkdfHkdfExtractConstMeta => const TaskConstMeta(
+    debugName: "hkdf_extract",
+    argNames: ["secret", "salt"],
+  );
+
+  @override
+  Uint8List crateApiHkdfHkdfKey({
+    required List<int> secret,
+    required List<int> salt,
+    required List<int> info,
+    required BigInt length,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(secret, serializer);
+          sse_encode_list_prim_u_8_loose(salt, serializer);
+          sse_encode_list_prim_u_8_loose(info, serializer);
+          sse_encode_usize(length, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 148,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHkdfHkdfKeyConstMeta,
+        argValues: [secret, salt, info, length],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHkdfHkdfKeyConstMeta => const TaskConstMeta(
+    debugName: "hkdf_key",
+    argNames: ["secret", "salt", "info", "length"],
+  );
+
+  @override
+  Future<void> crateApiInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 149,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Uint8List crateApiRandRandomBytes({required BigInt length}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_usize(length, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 150,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRandRandomBytesConstMeta,
+        argValues: [length],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRandRandomBytesConstMeta =>
+      const TaskConstMeta(debugName: "random_bytes", argNames: ["length"]);
+
+  @override
+  Uint8List crateApiStreamStreamDecrypt({
+    required List<int> key,
+    required List<int> ciphertext,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(key, serializer);
+          sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 151,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiStreamStreamDecryptConstMeta,
+        argValues: [key, ciphertext],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStreamStreamDecryptConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_decrypt",
+        argNames: ["key", "ciphertext"],
+      );
+
+  @override
+  Stream<BigInt> crateApiStreamStreamDecryptFile({
+    required List<int> key,
+    required String inPath,
+    required String outPath,
+  }) {
+    final progress = RustStreamSink<BigInt>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_prim_u_8_loose(key, serializer);
+            sse_encode_String(inPath, serializer);
+            sse_encode_String(outPath, serializer);
+            sse_encode_StreamSink_u_64_Sse(progress, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 152,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_crypto_error,
+          ),
+          constMeta: kCrateApiStreamStreamDecryptFileConstMeta,
+          argValues: [key, inPath, outPath, progress],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return progress.stream;
+  }
+
+  TaskConstMeta get kCrateApiStreamStreamDecryptFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_decrypt_file",
+        argNames: ["key", "inPath", "outPath", "progress"],
+      );
+
+  @override
+  Uint8List crateApiStreamStreamEncrypt({
+    required List<int> key,
+    required List<int> plaintext,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(key, serializer);
+          sse_encode_list_prim_u_8_loose(plaintext, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 153,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiStreamStreamEncryptConstMeta,
+        argValues: [key, plaintext],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStreamStreamEncryptConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_encrypt",
+        argNames: ["key", "plaintext"],
+      );
+
+  @override
+  Stream<BigInt> crateApiStreamStreamEncryptFile({
+    required List<int> key,
+    required String inPath,
+    required String outPath,
+  }) {
+    final progress = RustStreamSink<BigInt>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_prim_u_8_loose(key, serializer);
+            sse_encode_String(inPath, serializer);
+            sse_encode_String(outPath, serializer);
+            sse_encode_StreamSink_u_64_Sse(progress, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 154,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_crypto_error,
+          ),
+          constMeta: kCrateApiStreamStreamEncryptFileConstMeta,
+          argValues: [key, inPath, outPath, progress],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return progress.stream;
+  }
+
+  TaskConstMeta get kCrateApiStreamStreamEncryptFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_encrypt_file",
+        argNames: ["key", "inPath", "outPath", "progress"],
+      );
+
+  @override
+  Uint8List crateApiStreamStreamOpenWithPassword({
+    required List<int> password,
+    required List<int> sealed,
+    required Argon2Params minParams,
+    required Argon2Params maxParams,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(password, serializer);
+          sse_encode_list_prim_u_8_loose(sealed, serializer);
+          sse_encode_box_autoadd_argon2_params(minParams, serializer);
+          sse_encode_box_autoadd_argon2_params(maxParams, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 155,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiStreamStreamOpenWithPasswordConstMeta,
+        argValues: [password, sealed, minParams, maxParams],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStreamStreamOpenWithPasswordConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_open_with_password",
+        argNames: ["password", "sealed", "minParams", "maxParams"],
+      );
+
+  @override
+  Uint8List crateApiStreamStreamSealWithPassword({
+    required List<int> password,
+    required List<int> plaintext,
+    required Argon2Params params,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(password, serializer);
+          sse_encode_list_prim_u_8_loose(plaintext, serializer);
+          sse_encode_box_autoadd_argon2_params(params, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 156,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiStreamStreamSealWithPasswordConstMeta,
+        argValues: [password, plaintext, params],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStreamStreamSealWithPasswordConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_seal_with_password",
+        argNames: ["password", "plaintext", "params"],
+      );
+
+  @override
+  CertInfo crateApiX509X509Parse({required List<int> cert}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(cert, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 157,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cert_info,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiX509X509ParseConstMeta,
+        argValues: [cert],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiX509X509ParseConstMeta =>
+      const TaskConstMeta(debugName: "x509_parse", argNames: ["cert"]);
+
+  @override
+  CertChain crateApiX509X509VerifyChain({
+    required List<int> leaf,
+    required List<Uint8List> intermediates,
+    required List<Uint8List> roots,
+    BigInt? now,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(leaf, serializer);
+          sse_encode_list_list_prim_u_8_strict(intermediates, serializer);
+          sse_encode_list_list_prim_u_8_strict(roots, serializer);
+          sse_encode_opt_box_autoadd_u_64(now, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 158,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cert_chain,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiX509X509VerifyChainConstMeta,
+        argValues: [leaf, intermediates, roots, now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiX509X509VerifyChainConstMeta =>
+      const TaskConstMeta(
+        debugName: "x509_verify_chain",
+        argNames: ["leaf", "intermediates", "roots", "now"],
+      );
+
+  @override
+  StreamDecryptor crateApiXhpkeXhpkeNewStreamDecryptor({
+    required XhpkeSecretKey secretKey,
+    required List<int> header,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            secretKey,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(header, serializer);
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 159,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiXhpkeXhpkeNewStreamDecryptorConstMeta,
+        argValues: [secretKey, header, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhpkeXhpkeNewStreamDecryptorConstMeta =>
+      const TaskConstMeta(
+        debugName: "xhpke_new_stream_decryptor",
+        argNames: ["secretKey", "header", "domain"],
+      );
+
+  @override
+  (StreamEncryptor, Uint8List) crateApiXhpkeXhpkeNewStreamEncryptor({
+    required XhpkePublicKey publicKey,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            publicKey,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 160,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_stream_encryptor_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiXhpkeXhpkeNewStreamEncryptorConstMeta,
+        argValues: [publicKey, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhpkeXhpkeNewStreamEncryptorConstMeta =>
+      const TaskConstMeta(
+        debugName: "xhpke_new_stream_encryptor",
+        argNames: ["publicKey", "domain"],
+      );
+
+  @override
+  Uint8List crateApiXhpkeXhpkeOpenStream({
+    required XhpkeSecretKey secretKey,
+    required List<int> sealed,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+            secretKey,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(sealed, serializer);
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 161,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiXhpkeXhpkeOpenStreamConstMeta,
+        argValues: [secretKey, sealed, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhpkeXhpkeOpenStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "xhpke_open_stream",
+        argNames: ["secretKey", "sealed", "domain"],
+      );
+
+  @override
+  Stream<BigInt> crateApiXhpkeXhpkeOpenStreamFile({
+    required XhpkeSecretKey secretKey,
+    required String inPath,
+    required String outPath,
+    required List<int> domain,
+  }) {
+    final progress = RustStreamSink<BigInt>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+              secretKey,
+              serializer,
+            );
+            sse_encode_String(inPath, serializer);
+            sse_encode_String(outPath, serializer);
+            sse_encode_list_prim_u_8_loose(domain, serializer);
+            sse_encode_StreamSink_u_64_Sse(progress, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 162,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_crypto_error,
+          ),
+          constMeta: kCrateApiXhpkeXhpkeOpenStreamFileConstMeta,
+          argValues: [secretKey, inPath, outPath, domain, progress],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return progress.stream;
+  }
+
+  TaskConstMeta get kCrateApiXhpkeXhpkeOpenStreamFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "xhpke_open_stream_file",
+        argNames: ["secretKey", "inPath", "outPath", "domain", "progress"],
+      );
+
+  @override
+  Uint8List crateApiXhpkeXhpkeSealStream({
+    required XhpkePublicKey publicKey,
+    required List<int> plaintext,
+    required List<int> domain,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            publicKey,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(plaintext, serializer);
+          sse_encode_list_prim_u_8_loose(domain, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 163,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_crypto_error,
+        ),
+        constMeta: kCrateApiXhpkeXhpkeSealStreamConstMeta,
+        argValues: [publicKey, plaintext, domain],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhpkeXhpkeSealStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "xhpke_seal_stream",
+        argNames: ["publicKey", "plaintext", "domain"],
+      );
+
+  @override
+  Stream<BigInt> crateApiXhpkeXhpkeSealStreamFile({
+    required XhpkePublicKey publicKey,
+    required String inPath,
+    required String outPath,
+    required List<int> domain,
+  }) {
+    final progress = RustStreamSink<BigInt>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+              publicKey,
+              serializer,
+            );
+            sse_encode_String(inPath, serializer);
+            sse_encode_String(outPath, serializer);
+            sse_encode_list_prim_u_8_loose(domain, serializer);
+            sse_encode_StreamSink_u_64_Sse(progress, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 164,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_crypto_error,
+          ),
+          constMeta: kCrateApiXhpkeXhpkeSealStreamFileConstMeta,
+          argValues: [publicKey, inPath, outPath, domain, progress],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return progress.stream;
+  }
+
+  TaskConstMeta get kCrateApiXhpkeXhpkeSealStreamFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "xhpke_seal_stream_file",
+        argNames: ["publicKey", "inPath", "outPath", "domain", "progress"],
+      );
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_CddlSchema => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_CddlSchema => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_RsaFingerprint => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_RsaFingerprint => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_RsaPublicKey => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_RsaPublicKey => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_RsaSecretKey => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_RsaSecretKey => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_RsaSignature => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_RsaSignature => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_StatusList => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_StatusList => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_StreamDecryptor => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_StreamDecryptor => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_StreamEncryptor => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_StreamEncryptor => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_StreamReader => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_StreamReader => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_XdsaFingerprint => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_XdsaFingerprint => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_XdsaPublicKey => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_XdsaPublicKey => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_XdsaSecretKey => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_XdsaSecretKey => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey;
+
+  RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_XdsaSignature => wire
       .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature;
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_XdsaSignature => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature;
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_XdsaSignature => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_XhpkeFingerprint => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_XhpkeFingerprint => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_XhpkePublicKey => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_XhpkePublicKey => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_XhpkeReceiver => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_XhpkeReceiver => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_XhpkeSecretKey => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_XhpkeSecretKey => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_XhpkeSender => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_XhpkeSender => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  CddlSchema
+  dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(raw);
+  }
+
+  @protected
+  StatusList
+  dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(raw);
+  }
+
+  @protected
+  XdsaPublicKey
+  dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(raw);
+  }
+
+  @protected
+  XdsaSecretKey
+  dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(raw);
+  }
+
+  @protected
+  XhpkePublicKey
+  dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(raw);
+  }
+
+  @protected
+  CddlSchema
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CddlSchemaImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaFingerprint
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaPublicKey
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaSecretKey
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaSignature
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StatusList
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StatusListImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StreamDecryptor
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamDecryptorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StreamEncryptor
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamEncryptorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StreamReader
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamReaderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaFingerprint
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaPublicKey
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaSecretKey
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaSignature
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeFingerprint
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkePublicKey
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkePublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeReceiver
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeReceiverImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeSecretKey
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeSender
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StatusList
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StatusListImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StreamDecryptor
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamDecryptorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StreamEncryptor
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamEncryptorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StreamReader
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamReaderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeReceiver
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeReceiverImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeSender
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  CddlSchema
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CddlSchemaImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaFingerprint
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaPublicKey
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaSecretKey
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaSignature
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StatusList
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StatusListImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StreamReader
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamReaderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaFingerprint
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaPublicKey
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaSecretKey
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaSignature
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeFingerprint
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkePublicKey
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkePublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeSecretKey
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  CddlSchema
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CddlSchemaImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaFingerprint
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaPublicKey
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaSecretKey
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsaSignature
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StatusList
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StatusListImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StreamDecryptor
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamDecryptorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StreamEncryptor
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamEncryptorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  StreamReader
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamReaderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaFingerprint
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaPublicKey
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaSecretKey
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XdsaSignature
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XdsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeFingerprint
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkePublicKey
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkePublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeReceiver
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeReceiverImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeSecretKey
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  XhpkeSender
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhpkeSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<BigInt> dco_decode_StreamSink_u_64_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  Argon2Params dco_decode_argon2_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Argon2Params(
+      time: dco_decode_u_32(arr[0]),
+      memory: dco_decode_u_32(arr[1]),
+      threads: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  Argon2Params dco_decode_box_autoadd_argon2_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_argon2_params(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CborValue dco_decode_box_autoadd_cbor_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cbor_value(raw);
+  }
+
+  @protected
+  CertName dco_decode_box_autoadd_cert_name(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cert_name(raw);
+  }
+
+  @protected
+  CertTemplate dco_decode_box_autoadd_cert_template(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cert_template(raw);
+  }
+
+  @protected
+  CwtClaims dco_decode_box_autoadd_cwt_claims(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cwt_claims(raw);
+  }
+
+  @protected
+  CwtConfirmKey dco_decode_box_autoadd_cwt_confirm_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cwt_confirm_key(raw);
+  }
+
+  @protected
+  CwtConfirmKeyType dco_decode_box_autoadd_cwt_confirm_key_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cwt_confirm_key_type(raw);
+  }
+
+  @protected
+  CwtStatus dco_decode_box_autoadd_cwt_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cwt_status(raw);
+  }
+
+  @protected
+  CwtValidation dco_decode_box_autoadd_cwt_validation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cwt_validation(raw);
+  }
+
+  @protected
+  EatDebugState dco_decode_box_autoadd_eat_debug_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_eat_debug_state(raw);
+  }
+
+  @protected
+  EatOemid dco_decode_box_autoadd_eat_oemid(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_eat_oemid(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  CwtClaims dco_decode_box_cwt_claims(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cwt_claims(raw);
+  }
+
+  @protected
+  CborMapEntry dco_decode_cbor_map_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CborMapEntry(
+      key: dco_decode_i_64(arr[0]),
+      value: dco_decode_cbor_value(arr[1]),
+    );
+  }
+
+  @protected
+  CborValue dco_decode_cbor_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CborValue_Null();
+      case 1:
+        return CborValue_Bool(dco_decode_bool(raw[1]));
+      case 2:
+        return CborValue_Int(dco_decode_i_64(raw[1]));
+      case 3:
+        return CborValue_Uint(dco_decode_u_64(raw[1]));
+      case 4:
+        return CborValue_Text(dco_decode_String(raw[1]));
+      case 5:
+        return CborValue_Bytes(dco_decode_list_prim_u_8_strict(raw[1]));
+      case 6:
+        return CborValue_Array(dco_decode_list_cbor_value(raw[1]));
+      case 7:
+        return CborValue_Map(dco_decode_list_cbor_map_entry(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CertChain dco_decode_cert_chain(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CertChain(
+      leafKey: dco_decode_cert_public_key(arr[0]),
+      path: dco_decode_list_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  CertExtension dco_decode_cert_extension(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CertExtension(
+      oid: dco_decode_String(arr[0]),
+      critical: dco_decode_bool(arr[1]),
+      value: dco_decode_list_prim_u_8_strict(arr[2]),
+    );
+  }
+
+  @protected
+  CertInfo dco_decode_cert_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17)
+      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return CertInfo(
+      subject: dco_decode_cert_name(arr[0]),
+      issuer: dco_decode_cert_name(arr[1]),
+      serial: dco_decode_list_prim_u_8_strict(arr[2]),
+      notBefore: dco_decode_u_64(arr[3]),
+      notAfter: dco_decode_u_64(arr[4]),
+      isCa: dco_decode_bool(arr[5]),
+      pathLen: dco_decode_opt_box_autoadd_u_8(arr[6]),
+      dnsNames: dco_decode_list_String(arr[7]),
+      uris: dco_decode_list_String(arr[8]),
+      emails: dco_decode_list_String(arr[9]),
+      keyUsage: dco_decode_list_cert_key_usage(arr[10]),
+      extendedKeyUsage: dco_decode_list_String(arr[11]),
+      extensions: dco_decode_list_cert_extension(arr[12]),
+      keyType: dco_decode_cert_key_type(arr[13]),
+      fingerprint: dco_decode_list_prim_u_8_strict(arr[14]),
+      subjectKeyId: dco_decode_opt_list_prim_u_8_strict(arr[15]),
+      issuerKeyId: dco_decode_opt_list_prim_u_8_strict(arr[16]),
+    );
+  }
+
+  @protected
+  CertKeyType dco_decode_cert_key_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CertKeyType.values[raw as int];
+  }
+
+  @protected
+  CertKeyUsage dco_decode_cert_key_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CertKeyUsage.values[raw as int];
+  }
+
+  @protected
+  CertName dco_decode_cert_name(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return CertName(
+      commonName: dco_decode_opt_String(arr[0]),
+      organization: dco_decode_opt_String(arr[1]),
+      organizationalUnit: dco_decode_opt_String(arr[2]),
+      country: dco_decode_opt_String(arr[3]),
+      province: dco_decode_opt_String(arr[4]),
+      locality: dco_decode_opt_String(arr[5]),
+      serialNumber: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  CertPublicKey dco_decode_cert_public_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CertPublicKey_Xdsa(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            raw[1],
+          ),
+        );
+      case 1:
+        return CertPublicKey_Xhpke(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            raw[1],
+          ),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CertTemplate dco_decode_cert_template(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return CertTemplate(
+      subject: dco_decode_cert_name(arr[0]),
+      issuer: dco_decode_cert_name(arr[1]),
+      notBefore: dco_decode_u_64(arr[2]),
+      notAfter: dco_decode_u_64(arr[3]),
+      isCa: dco_decode_bool(arr[4]),
+      pathLen: dco_decode_opt_box_autoadd_u_8(arr[5]),
+      dnsNames: dco_decode_list_String(arr[6]),
+      uris: dco_decode_list_String(arr[7]),
+      emails: dco_decode_list_String(arr[8]),
+      keyUsage: dco_decode_list_cert_key_usage(arr[9]),
+      extendedKeyUsage: dco_decode_list_String(arr[10]),
+      extensions: dco_decode_list_cert_extension(arr[11]),
+    );
+  }
+
+  @protected
+  CoseSign1Info dco_decode_cose_sign1_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CoseSign1Info(
+      payload: dco_decode_list_prim_u_8_strict(arr[0]),
+      signer: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+        arr[1],
+      ),
+      timestamp: dco_decode_i_64(arr[2]),
+      algorithm: dco_decode_i_64(arr[3]),
+      domain: dco_decode_opt_list_prim_u_8_strict(arr[4]),
+    );
+  }
+
+  @protected
+  CryptoError dco_decode_crypto_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CryptoError_InvalidLength(
+          expected: dco_decode_usize(raw[1]),
+          got: dco_decode_usize(raw[2]),
+        );
+      case 1:
+        return CryptoError_InvalidEncoding(details: dco_decode_String(raw[1]));
+      case 2:
+        return CryptoError_InvalidArgument(details: dco_decode_String(raw[1]));
+      case 3:
+        return CryptoError_CborInvalid(details: dco_decode_String(raw[1]));
+      case 4:
+        return CryptoError_SignatureMismatch(
+          details: dco_decode_String(raw[1]),
+        );
+      case 5:
+        return CryptoError_KeyMismatch(details: dco_decode_String(raw[1]));
+      case 6:
+        return CryptoError_EncryptionFailed(details: dco_decode_String(raw[1]));
+      case 7:
+        return CryptoError_DecryptionFailed(details: dco_decode_String(raw[1]));
+      case 8:
+        return CryptoError_Expired(
+          exp: dco_decode_u_64(raw[1]),
+          now: dco_decode_u_64(raw[2]),
+        );
+      case 9:
+        return CryptoError_NotYetValid(
+          nbf: dco_decode_u_64(raw[1]),
+          now: dco_decode_u_64(raw[2]),
+        );
+      case 10:
+        return CryptoError_StaleSignature(
+          drift: dco_decode_u_64(raw[1]),
+          maxDrift: dco_decode_u_64(raw[2]),
+        );
+      case 11:
+        return CryptoError_InvalidClaims(details: dco_decode_String(raw[1]));
+      case 12:
+        return CryptoError_InvalidCertificate(
+          details: dco_decode_String(raw[1]),
+        );
+      case 13:
+        return CryptoError_Io(details: dco_decode_String(raw[1]));
+      case 14:
+        return CryptoError_MissingClaim(key: dco_decode_i_64(raw[1]));
+      case 15:
+        return CryptoError_IssuerMismatch(
+          expected: dco_decode_String(raw[1]),
+          got: dco_decode_String(raw[2]),
+        );
+      case 16:
+        return CryptoError_AudienceMismatch(got: dco_decode_String(raw[1]));
+      case 17:
+        return CryptoError_TokenTooOld(
+          iat: dco_decode_u_64(raw[1]),
+          maxAge: dco_decode_u_64(raw[2]),
+          now: dco_decode_u_64(raw[3]),
+        );
+      case 18:
+        return CryptoError_ConfirmKeyMismatch(
+          details: dco_decode_String(raw[1]),
+        );
+      case 19:
+        return CryptoError_NonceMismatch(details: dco_decode_String(raw[1]));
+      case 20:
+        return CryptoError_Revoked(
+          idx: dco_decode_u_64(raw[1]),
+          status: dco_decode_u_8(raw[2]),
+        );
+      case 21:
+        return CryptoError_InvalidSchema(details: dco_decode_String(raw[1]));
+      case 22:
+        return CryptoError_SchemaMismatch(
+          path: dco_decode_String(raw[1]),
+          details: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CwtClaims dco_decode_cwt_claims(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 24)
+      throw Exception('unexpected arr length: expect 24 but see ${arr.length}');
+    return CwtClaims(
+      iss: dco_decode_opt_String(arr[0]),
+      sub: dco_decode_opt_String(arr[1]),
+      aud: dco_decode_opt_String(arr[2]),
+      exp: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      nbf: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      iat: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      cti: dco_decode_opt_list_prim_u_8_strict(arr[6]),
+      cnf: dco_decode_opt_box_autoadd_cwt_confirm_key(arr[7]),
+      eatNonce: dco_decode_opt_list_prim_u_8_strict(arr[8]),
+      ueid: dco_decode_opt_list_prim_u_8_strict(arr[9]),
+      oemid: dco_decode_opt_box_autoadd_eat_oemid(arr[10]),
+      hwModel: dco_decode_opt_list_prim_u_8_strict(arr[11]),
+      hwVersion: dco_decode_opt_String(arr[12]),
+      uptime: dco_decode_opt_box_autoadd_u_64(arr[13]),
+      oemBoot: dco_decode_opt_box_autoadd_bool(arr[14]),
+      dbgstat: dco_decode_opt_box_autoadd_eat_debug_state(arr[15]),
+      submods: dco_decode_list_eat_submodule(arr[16]),
+      bootCount: dco_decode_opt_box_autoadd_u_64(arr[17]),
+      bootSeed: dco_decode_opt_list_prim_u_8_strict(arr[18]),
+      swName: dco_decode_opt_String(arr[19]),
+      swVersion: dco_decode_opt_String(arr[20]),
+      measurements: dco_decode_list_eat_measurement(arr[21]),
+      status: dco_decode_opt_box_autoadd_cwt_status(arr[22]),
+      custom: dco_decode_list_cwt_custom_claim(arr[23]),
+    );
+  }
+
+  @protected
+  CwtConfirmKey dco_decode_cwt_confirm_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CwtConfirmKey_Xdsa(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+            raw[1],
+          ),
+        );
+      case 1:
+        return CwtConfirmKey_Xhpke(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+            raw[1],
+          ),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CwtConfirmKeyType dco_decode_cwt_confirm_key_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CwtConfirmKeyType.values[raw as int];
+  }
+
+  @protected
+  CwtCustomClaim dco_decode_cwt_custom_claim(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CwtCustomClaim(
+      key: dco_decode_i_64(arr[0]),
+      value: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  CwtStatus dco_decode_cwt_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CwtStatus(
+      idx: dco_decode_u_64(arr[0]),
+      uri: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  CwtValidation dco_decode_cwt_validation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return CwtValidation(
+      now: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      leewaySecs: dco_decode_u_64(arr[1]),
+      maxAgeSecs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      issuer: dco_decode_opt_String(arr[3]),
+      audiences: dco_decode_list_String(arr[4]),
+      requiredClaims: dco_decode_list_prim_i_64_strict(arr[5]),
+      confirmKey: dco_decode_opt_box_autoadd_cwt_confirm_key_type(arr[6]),
+      eatNonce: dco_decode_opt_list_prim_u_8_strict(arr[7]),
+    );
+  }
+
+  @protected
+  EatDebugState dco_decode_eat_debug_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EatDebugState.values[raw as int];
+  }
+
+  @protected
+  EatMeasurement dco_decode_eat_measurement(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EatMeasurement(
+      contentType: dco_decode_u_64(arr[0]),
+      content: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  EatOemid dco_decode_eat_oemid(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return EatOemid_Random(dco_decode_list_prim_u_8_strict(raw[1]));
+      case 1:
+        return EatOemid_Ieee(dco_decode_list_prim_u_8_strict(raw[1]));
+      case 2:
+        return EatOemid_Pen(dco_decode_u_64(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  EatSubmodule dco_decode_eat_submodule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EatSubmodule(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_eat_submodule_value(arr[1]),
+    );
+  }
+
+  @protected
+  EatSubmoduleValue dco_decode_eat_submodule_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return EatSubmoduleValue_Claims(dco_decode_box_cwt_claims(raw[1]));
+      case 1:
+        return EatSubmoduleValue_Embedded(
+          dco_decode_list_prim_u_8_strict(raw[1]),
+        );
+      case 2:
+        return EatSubmoduleValue_Detached(
+          alg: dco_decode_i_64(raw[1]),
+          digest: dco_decode_list_prim_u_8_strict(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  List<XdsaPublicKey>
+  dco_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey).toList();
+  }
+
+  @protected
+  List<XdsaSecretKey>
+  dco_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey).toList();
+  }
+
+  @protected
+  List<XhpkePublicKey>
+  dco_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey).toList();
+  }
+
+  @protected
+  List<XdsaFingerprint>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint).toList();
+  }
+
+  @protected
+  List<XhpkeFingerprint>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint).toList();
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<CborMapEntry> dco_decode_list_cbor_map_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cbor_map_entry).toList();
+  }
+
+  @protected
+  List<CborValue> dco_decode_list_cbor_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cbor_value).toList();
+  }
+
+  @protected
+  List<CertExtension> dco_decode_list_cert_extension(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cert_extension).toList();
+  }
+
+  @protected
+  List<CertKeyUsage> dco_decode_list_cert_key_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cert_key_usage).toList();
+  }
+
+  @protected
+  List<CwtCustomClaim> dco_decode_list_cwt_custom_claim(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cwt_custom_claim).toList();
+  }
+
+  @protected
+  List<EatMeasurement> dco_decode_list_eat_measurement(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_eat_measurement).toList();
+  }
+
+  @protected
+  List<EatSubmodule> dco_decode_list_eat_submodule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_eat_submodule).toList();
+  }
+
+  @protected
+  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
+  }
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Int64List;
+  }
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Int64List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  CddlSchema?
+  dco_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(raw);
+  }
+
+  @protected
+  StatusList?
+  dco_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(raw);
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  CwtConfirmKey? dco_decode_opt_box_autoadd_cwt_confirm_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cwt_confirm_key(raw);
+  }
+
+  @protected
+  CwtConfirmKeyType? dco_decode_opt_box_autoadd_cwt_confirm_key_type(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cwt_confirm_key_type(raw);
+  }
+
+  @protected
+  CwtStatus? dco_decode_opt_box_autoadd_cwt_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cwt_status(raw);
+  }
+
+  @protected
+  EatDebugState? dco_decode_opt_box_autoadd_eat_debug_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_eat_debug_state(raw);
+  }
+
+  @protected
+  EatOemid? dco_decode_opt_box_autoadd_eat_oemid(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_eat_oemid(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  (StreamEncryptor, Uint8List)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_stream_encryptor_list_prim_u_8_strict(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+        arr[0],
+      ),
+      dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  (XdsaPublicKey, BigInt, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) {
+      throw Exception('Expected 3 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+        arr[0],
+      ),
+      dco_decode_u_64(arr[1]),
+      dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  (XhpkePublicKey, BigInt, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) {
+      throw Exception('Expected 3 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+        arr[0],
+      ),
+      dco_decode_u_64(arr[1]),
+      dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  (XhpkeSender, Uint8List)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_sender_list_prim_u_8_strict(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+        arr[0],
+      ),
+      dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  (Uint8List, Uint8List)
+  dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_list_prim_u_8_strict(arr[0]),
+      dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  SdCwt dco_decode_sd_cwt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SdCwt(
+      token: dco_decode_list_prim_u_8_strict(arr[0]),
+      disclosures: dco_decode_list_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  CddlSchema
+  sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+          deserializer,
+        );
+    return inner;
+  }
+
+  @protected
+  StatusList
+  sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+          deserializer,
+        );
+    return inner;
+  }
+
+  @protected
+  XdsaPublicKey
+  sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+          deserializer,
+        );
+    return inner;
+  }
+
+  @protected
+  XdsaSecretKey
+  sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+          deserializer,
+        );
+    return inner;
+  }
+
+  @protected
+  XhpkePublicKey
+  sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+          deserializer,
+        );
+    return inner;
+  }
+
+  @protected
+  CddlSchema
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CddlSchemaImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaFingerprint
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaFingerprintImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaPublicKey
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaPublicKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaSecretKey
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaSecretKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaSignature
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaSignatureImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StatusList
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StatusListImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StreamDecryptor
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StreamDecryptorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StreamEncryptor
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StreamEncryptorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StreamReader
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StreamReaderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaFingerprint
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaFingerprintImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaPublicKey
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaPublicKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaSecretKey
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaSecretKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaSignature
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaSignatureImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeFingerprint
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeFingerprintImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkePublicKey
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkePublicKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeReceiver
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeReceiverImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeSecretKey
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeSecretKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeSender
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeSenderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StatusList
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StatusListImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StreamDecryptor
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StreamDecryptorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StreamEncryptor
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StreamEncryptorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StreamReader
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StreamReaderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeReceiver
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeReceiverImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeSender
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeSenderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  CddlSchema
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CddlSchemaImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaFingerprint
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaFingerprintImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaPublicKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaPublicKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaSecretKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaSecretKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaSignature
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaSignatureImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StatusList
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StatusListImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StreamReader
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StreamReaderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaFingerprint
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaFingerprintImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaPublicKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaPublicKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaSecretKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaSecretKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaSignature
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaSignatureImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeFingerprint
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeFingerprintImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkePublicKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkePublicKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeSecretKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeSecretKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  CddlSchema
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CddlSchemaImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaFingerprint
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaFingerprintImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaPublicKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaPublicKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaSecretKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaSecretKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RsaSignature
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsaSignatureImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StatusList
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StatusListImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StreamDecryptor
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StreamDecryptorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StreamEncryptor
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StreamEncryptorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  StreamReader
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StreamReaderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaFingerprint
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaFingerprintImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaPublicKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaPublicKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaSecretKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaSecretKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XdsaSignature
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XdsaSignatureImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeFingerprint
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeFingerprintImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkePublicKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkePublicKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeReceiver
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeReceiverImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeSecretKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeSecretKeyImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  XhpkeSender
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return XhpkeSenderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<BigInt> sse_decode_StreamSink_u_64_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  Argon2Params sse_decode_argon2_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_time = sse_decode_u_32(deserializer);
+    var var_memory = sse_decode_u_32(deserializer);
+    var var_threads = sse_decode_u_32(deserializer);
+    return Argon2Params(
+      time: var_time,
+      memory: var_memory,
+      threads: var_threads,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  Argon2Params sse_decode_box_autoadd_argon2_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_argon2_params(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  CborValue sse_decode_box_autoadd_cbor_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cbor_value(deserializer));
+  }
+
+  @protected
+  CertName sse_decode_box_autoadd_cert_name(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cert_name(deserializer));
+  }
+
+  @protected
+  CertTemplate sse_decode_box_autoadd_cert_template(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cert_template(deserializer));
+  }
+
+  @protected
+  CwtClaims sse_decode_box_autoadd_cwt_claims(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cwt_claims(deserializer));
+  }
+
+  @protected
+  CwtConfirmKey sse_decode_box_autoadd_cwt_confirm_key(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cwt_confirm_key(deserializer));
+  }
+
+  @protected
+  CwtConfirmKeyType sse_decode_box_autoadd_cwt_confirm_key_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cwt_confirm_key_type(deserializer));
+  }
+
+  @protected
+  CwtStatus sse_decode_box_autoadd_cwt_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cwt_status(deserializer));
+  }
+
+  @protected
+  CwtValidation sse_decode_box_autoadd_cwt_validation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cwt_validation(deserializer));
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_XhpkeFingerprint => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint;
+  @protected
+  EatDebugState sse_decode_box_autoadd_eat_debug_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_eat_debug_state(deserializer));
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_XhpkeFingerprint => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint;
+  @protected
+  EatOemid sse_decode_box_autoadd_eat_oemid(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_eat_oemid(deserializer));
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_XhpkePublicKey => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey;
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_XhpkePublicKey => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey;
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_8(deserializer));
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_XhpkeReceiver => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver;
+  @protected
+  CwtClaims sse_decode_box_cwt_claims(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cwt_claims(deserializer));
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_XhpkeReceiver => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver;
+  @protected
+  CborMapEntry sse_decode_cbor_map_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_i_64(deserializer);
+    var var_value = sse_decode_cbor_value(deserializer);
+    return CborMapEntry(key: var_key, value: var_value);
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_XhpkeSecretKey => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey;
+  @protected
+  CborValue sse_decode_cbor_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return CborValue_Null();
+      case 1:
+        var var_field0 = sse_decode_bool(deserializer);
+        return CborValue_Bool(var_field0);
+      case 2:
+        var var_field0 = sse_decode_i_64(deserializer);
+        return CborValue_Int(var_field0);
+      case 3:
+        var var_field0 = sse_decode_u_64(deserializer);
+        return CborValue_Uint(var_field0);
+      case 4:
+        var var_field0 = sse_decode_String(deserializer);
+        return CborValue_Text(var_field0);
+      case 5:
+        var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+        return CborValue_Bytes(var_field0);
+      case 6:
+        var var_field0 = sse_decode_list_cbor_value(deserializer);
+        return CborValue_Array(var_field0);
+      case 7:
+        var var_field0 = sse_decode_list_cbor_map_entry(deserializer);
+        return CborValue_Map(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_XhpkeSecretKey => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey;
+  @protected
+  CertChain sse_decode_cert_chain(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_leafKey = sse_decode_cert_public_key(deserializer);
+    var var_path = sse_decode_list_list_prim_u_8_strict(deserializer);
+    return CertChain(leafKey: var_leafKey, path: var_path);
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_XhpkeSender => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender;
+  @protected
+  CertExtension sse_decode_cert_extension(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_oid = sse_decode_String(deserializer);
+    var var_critical = sse_decode_bool(deserializer);
+    var var_value = sse_decode_list_prim_u_8_strict(deserializer);
+    return CertExtension(
+      oid: var_oid,
+      critical: var_critical,
+      value: var_value,
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_XhpkeSender => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender;
+  @protected
+  CertInfo sse_decode_cert_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_subject = sse_decode_cert_name(deserializer);
+    var var_issuer = sse_decode_cert_name(deserializer);
+    var var_serial = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_notBefore = sse_decode_u_64(deserializer);
+    var var_notAfter = sse_decode_u_64(deserializer);
+    var var_isCa = sse_decode_bool(deserializer);
+    var var_pathLen = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_dnsNames = sse_decode_list_String(deserializer);
+    var var_uris = sse_decode_list_String(deserializer);
+    var var_emails = sse_decode_list_String(deserializer);
+    var var_keyUsage = sse_decode_list_cert_key_usage(deserializer);
+    var var_extendedKeyUsage = sse_decode_list_String(deserializer);
+    var var_extensions = sse_decode_list_cert_extension(deserializer);
+    var var_keyType = sse_decode_cert_key_type(deserializer);
+    var var_fingerprint = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_subjectKeyId = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_issuerKeyId = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return CertInfo(
+      subject: var_subject,
+      issuer: var_issuer,
+      serial: var_serial,
+      notBefore: var_notBefore,
+      notAfter: var_notAfter,
+      isCa: var_isCa,
+      pathLen: var_pathLen,
+      dnsNames: var_dnsNames,
+      uris: var_uris,
+      emails: var_emails,
+      keyUsage: var_keyUsage,
+      extendedKeyUsage: var_extendedKeyUsage,
+      extensions: var_extensions,
+      keyType: var_keyType,
+      fingerprint: var_fingerprint,
+      subjectKeyId: var_subjectKeyId,
+      issuerKeyId: var_issuerKeyId,
+    );
+  }
+
+  @protected
+  CertKeyType sse_decode_cert_key_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CertKeyType.values[inner];
+  }
 
   @protected
-  RsaFingerprint
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    dynamic raw,
+  CertKeyUsage sse_decode_cert_key_usage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CertKeyUsage.values[inner];
+  }
+
+  @protected
+  CertName sse_decode_cert_name(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_commonName = sse_decode_opt_String(deserializer);
+    var var_organization = sse_decode_opt_String(deserializer);
+    var var_organizationalUnit = sse_decode_opt_String(deserializer);
+    var var_country = sse_decode_opt_String(deserializer);
+    var var_province = sse_decode_opt_String(deserializer);
+    var var_locality = sse_decode_opt_String(deserializer);
+    var var_serialNumber = sse_decode_opt_String(deserializer);
+    return CertName(
+      commonName: var_commonName,
+      organization: var_organization,
+      organizationalUnit: var_organizationalUnit,
+      country: var_country,
+      province: var_province,
+      locality: var_locality,
+      serialNumber: var_serialNumber,
+    );
+  }
+
+  @protected
+  CertPublicKey sse_decode_cert_public_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 =
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+              deserializer,
+            );
+        return CertPublicKey_Xdsa(var_field0);
+      case 1:
+        var var_field0 =
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+              deserializer,
+            );
+        return CertPublicKey_Xhpke(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CertTemplate sse_decode_cert_template(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_subject = sse_decode_cert_name(deserializer);
+    var var_issuer = sse_decode_cert_name(deserializer);
+    var var_notBefore = sse_decode_u_64(deserializer);
+    var var_notAfter = sse_decode_u_64(deserializer);
+    var var_isCa = sse_decode_bool(deserializer);
+    var var_pathLen = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_dnsNames = sse_decode_list_String(deserializer);
+    var var_uris = sse_decode_list_String(deserializer);
+    var var_emails = sse_decode_list_String(deserializer);
+    var var_keyUsage = sse_decode_list_cert_key_usage(deserializer);
+    var var_extendedKeyUsage = sse_decode_list_String(deserializer);
+    var var_extensions = sse_decode_list_cert_extension(deserializer);
+    return CertTemplate(
+      subject: var_subject,
+      issuer: var_issuer,
+      notBefore: var_notBefore,
+      notAfter: var_notAfter,
+      isCa: var_isCa,
+      pathLen: var_pathLen,
+      dnsNames: var_dnsNames,
+      uris: var_uris,
+      emails: var_emails,
+      keyUsage: var_keyUsage,
+      extendedKeyUsage: var_extendedKeyUsage,
+      extensions: var_extensions,
+    );
+  }
+
+  @protected
+  CoseSign1Info sse_decode_cose_sign1_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_signer =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+          deserializer,
+        );
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_algorithm = sse_decode_i_64(deserializer);
+    var var_domain = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return CoseSign1Info(
+      payload: var_payload,
+      signer: var_signer,
+      timestamp: var_timestamp,
+      algorithm: var_algorithm,
+      domain: var_domain,
+    );
+  }
+
+  @protected
+  CryptoError sse_decode_crypto_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_expected = sse_decode_usize(deserializer);
+        var var_got = sse_decode_usize(deserializer);
+        return CryptoError_InvalidLength(expected: var_expected, got: var_got);
+      case 1:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_InvalidEncoding(details: var_details);
+      case 2:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_InvalidArgument(details: var_details);
+      case 3:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_CborInvalid(details: var_details);
+      case 4:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_SignatureMismatch(details: var_details);
+      case 5:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_KeyMismatch(details: var_details);
+      case 6:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_EncryptionFailed(details: var_details);
+      case 7:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_DecryptionFailed(details: var_details);
+      case 8:
+        var var_exp = sse_decode_u_64(deserializer);
+        var var_now = sse_decode_u_64(deserializer);
+        return CryptoError_Expired(exp: var_exp, now: var_now);
+      case 9:
+        var var_nbf = sse_decode_u_64(deserializer);
+        var var_now = sse_decode_u_64(deserializer);
+        return CryptoError_NotYetValid(nbf: var_nbf, now: var_now);
+      case 10:
+        var var_drift = sse_decode_u_64(deserializer);
+        var var_maxDrift = sse_decode_u_64(deserializer);
+        return CryptoError_StaleSignature(
+          drift: var_drift,
+          maxDrift: var_maxDrift,
+        );
+      case 11:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_InvalidClaims(details: var_details);
+      case 12:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_InvalidCertificate(details: var_details);
+      case 13:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_Io(details: var_details);
+      case 14:
+        var var_key = sse_decode_i_64(deserializer);
+        return CryptoError_MissingClaim(key: var_key);
+      case 15:
+        var var_expected = sse_decode_String(deserializer);
+        var var_got = sse_decode_String(deserializer);
+        return CryptoError_IssuerMismatch(expected: var_expected, got: var_got);
+      case 16:
+        var var_got = sse_decode_String(deserializer);
+        return CryptoError_AudienceMismatch(got: var_got);
+      case 17:
+        var var_iat = sse_decode_u_64(deserializer);
+        var var_maxAge = sse_decode_u_64(deserializer);
+        var var_now = sse_decode_u_64(deserializer);
+        return CryptoError_TokenTooOld(
+          iat: var_iat,
+          maxAge: var_maxAge,
+          now: var_now,
+        );
+      case 18:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_ConfirmKeyMismatch(details: var_details);
+      case 19:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_NonceMismatch(details: var_details);
+      case 20:
+        var var_idx = sse_decode_u_64(deserializer);
+        var var_status = sse_decode_u_8(deserializer);
+        return CryptoError_Revoked(idx: var_idx, status: var_status);
+      case 21:
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_InvalidSchema(details: var_details);
+      case 22:
+        var var_path = sse_decode_String(deserializer);
+        var var_details = sse_decode_String(deserializer);
+        return CryptoError_SchemaMismatch(path: var_path, details: var_details);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CwtClaims sse_decode_cwt_claims(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_iss = sse_decode_opt_String(deserializer);
+    var var_sub = sse_decode_opt_String(deserializer);
+    var var_aud = sse_decode_opt_String(deserializer);
+    var var_exp = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_nbf = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_iat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_cti = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_cnf = sse_decode_opt_box_autoadd_cwt_confirm_key(deserializer);
+    var var_eatNonce = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_ueid = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_oemid = sse_decode_opt_box_autoadd_eat_oemid(deserializer);
+    var var_hwModel = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_hwVersion = sse_decode_opt_String(deserializer);
+    var var_uptime = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_oemBoot = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_dbgstat = sse_decode_opt_box_autoadd_eat_debug_state(deserializer);
+    var var_submods = sse_decode_list_eat_submodule(deserializer);
+    var var_bootCount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_bootSeed = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_swName = sse_decode_opt_String(deserializer);
+    var var_swVersion = sse_decode_opt_String(deserializer);
+    var var_measurements = sse_decode_list_eat_measurement(deserializer);
+    var var_status = sse_decode_opt_box_autoadd_cwt_status(deserializer);
+    var var_custom = sse_decode_list_cwt_custom_claim(deserializer);
+    return CwtClaims(
+      iss: var_iss,
+      sub: var_sub,
+      aud: var_aud,
+      exp: var_exp,
+      nbf: var_nbf,
+      iat: var_iat,
+      cti: var_cti,
+      cnf: var_cnf,
+      eatNonce: var_eatNonce,
+      ueid: var_ueid,
+      oemid: var_oemid,
+      hwModel: var_hwModel,
+      hwVersion: var_hwVersion,
+      uptime: var_uptime,
+      oemBoot: var_oemBoot,
+      dbgstat: var_dbgstat,
+      submods: var_submods,
+      bootCount: var_bootCount,
+      bootSeed: var_bootSeed,
+      swName: var_swName,
+      swVersion: var_swVersion,
+      measurements: var_measurements,
+      status: var_status,
+      custom: var_custom,
+    );
+  }
+
+  @protected
+  CwtConfirmKey sse_decode_cwt_confirm_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 =
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+              deserializer,
+            );
+        return CwtConfirmKey_Xdsa(var_field0);
+      case 1:
+        var var_field0 =
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+              deserializer,
+            );
+        return CwtConfirmKey_Xhpke(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CwtConfirmKeyType sse_decode_cwt_confirm_key_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CwtConfirmKeyType.values[inner];
+  }
+
+  @protected
+  CwtCustomClaim sse_decode_cwt_custom_claim(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_i_64(deserializer);
+    var var_value = sse_decode_list_prim_u_8_strict(deserializer);
+    return CwtCustomClaim(key: var_key, value: var_value);
+  }
+
+  @protected
+  CwtStatus sse_decode_cwt_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_idx = sse_decode_u_64(deserializer);
+    var var_uri = sse_decode_String(deserializer);
+    return CwtStatus(idx: var_idx, uri: var_uri);
+  }
+
+  @protected
+  CwtValidation sse_decode_cwt_validation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_now = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_leewaySecs = sse_decode_u_64(deserializer);
+    var var_maxAgeSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_issuer = sse_decode_opt_String(deserializer);
+    var var_audiences = sse_decode_list_String(deserializer);
+    var var_requiredClaims = sse_decode_list_prim_i_64_strict(deserializer);
+    var var_confirmKey = sse_decode_opt_box_autoadd_cwt_confirm_key_type(
+      deserializer,
+    );
+    var var_eatNonce = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return CwtValidation(
+      now: var_now,
+      leewaySecs: var_leewaySecs,
+      maxAgeSecs: var_maxAgeSecs,
+      issuer: var_issuer,
+      audiences: var_audiences,
+      requiredClaims: var_requiredClaims,
+      confirmKey: var_confirmKey,
+      eatNonce: var_eatNonce,
+    );
+  }
+
+  @protected
+  EatDebugState sse_decode_eat_debug_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EatDebugState.values[inner];
+  }
+
+  @protected
+  EatMeasurement sse_decode_eat_measurement(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_contentType = sse_decode_u_64(deserializer);
+    var var_content = sse_decode_list_prim_u_8_strict(deserializer);
+    return EatMeasurement(contentType: var_contentType, content: var_content);
+  }
+
+  @protected
+  EatOemid sse_decode_eat_oemid(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+        return EatOemid_Random(var_field0);
+      case 1:
+        var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+        return EatOemid_Ieee(var_field0);
+      case 2:
+        var var_field0 = sse_decode_u_64(deserializer);
+        return EatOemid_Pen(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  EatSubmodule sse_decode_eat_submodule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_eat_submodule_value(deserializer);
+    return EatSubmodule(name: var_name, value: var_value);
+  }
+
+  @protected
+  EatSubmoduleValue sse_decode_eat_submodule_value(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_cwt_claims(deserializer);
+        return EatSubmoduleValue_Claims(var_field0);
+      case 1:
+        var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+        return EatSubmoduleValue_Embedded(var_field0);
+      case 2:
+        var var_alg = sse_decode_i_64(deserializer);
+        var var_digest = sse_decode_list_prim_u_8_strict(deserializer);
+        return EatSubmoduleValue_Detached(alg: var_alg, digest: var_digest);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  List<XdsaPublicKey>
+  sse_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <XdsaPublicKey>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
+  @protected
+  List<XdsaSecretKey>
+  sse_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <XdsaSecretKey>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
+  @protected
+  List<XhpkePublicKey>
+  sse_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <XhpkePublicKey>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
+  @protected
+  List<XdsaFingerprint>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <XdsaFingerprint>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
+  @protected
+  List<XhpkeFingerprint>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <XhpkeFingerprint>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CborMapEntry> sse_decode_list_cbor_map_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CborMapEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cbor_map_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CborValue> sse_decode_list_cbor_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CborValue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cbor_value(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CertExtension> sse_decode_list_cert_extension(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CertExtension>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cert_extension(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CertKeyUsage> sse_decode_list_cert_key_usage(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CertKeyUsage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cert_key_usage(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CwtCustomClaim> sse_decode_list_cwt_custom_claim(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CwtCustomClaim>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cwt_custom_claim(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EatMeasurement> sse_decode_list_eat_measurement(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EatMeasurement>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_eat_measurement(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  RsaPublicKey
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    dynamic raw,
+  List<EatSubmodule> sse_decode_list_eat_submodule(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EatSubmodule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_eat_submodule(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  RsaSecretKey
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    dynamic raw,
+  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Uint8List>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  RsaSignature
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  Int64List sse_decode_list_prim_i_64_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
   }
 
   @protected
-  XdsaFingerprint
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
   }
 
   @protected
-  XdsaPublicKey
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
   }
 
   @protected
-  XdsaSecretKey
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
   }
 
   @protected
-  XdsaSignature
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    dynamic raw,
+  CddlSchema?
+  sse_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (
+        sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+          deserializer,
+        ),
+      );
+    } else {
+      return null;
+    }
   }
 
   @protected
-  XhpkeFingerprint
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    dynamic raw,
+  StatusList?
+  sse_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (
+        sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+          deserializer,
+        ),
+      );
+    } else {
+      return null;
+    }
   }
 
   @protected
-  XhpkePublicKey
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkePublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  XhpkeReceiver
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeReceiverImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  XhpkeSecretKey
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    dynamic raw,
+  CwtConfirmKey? sse_decode_opt_box_autoadd_cwt_confirm_key(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cwt_confirm_key(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  XhpkeSender
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    dynamic raw,
+  CwtConfirmKeyType? sse_decode_opt_box_autoadd_cwt_confirm_key_type(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cwt_confirm_key_type(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  XhpkeReceiver
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    dynamic raw,
+  CwtStatus? sse_decode_opt_box_autoadd_cwt_status(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeReceiverImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cwt_status(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  XhpkeSender
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    dynamic raw,
+  EatDebugState? sse_decode_opt_box_autoadd_eat_debug_state(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_eat_debug_state(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RsaFingerprint
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  EatOemid? sse_decode_opt_box_autoadd_eat_oemid(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_eat_oemid(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RsaPublicKey
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RsaSecretKey
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_8(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RsaSignature
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  XdsaFingerprint
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    dynamic raw,
+  (StreamEncryptor, Uint8List)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_stream_encryptor_list_prim_u_8_strict(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+          deserializer,
+        );
+    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  XdsaPublicKey
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    dynamic raw,
+  (XdsaPublicKey, BigInt, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+          deserializer,
+        );
+    var var_field1 = sse_decode_u_64(deserializer);
+    var var_field2 = sse_decode_u_64(deserializer);
+    return (var_field0, var_field1, var_field2);
   }
 
   @protected
-  XdsaSecretKey
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    dynamic raw,
+  (XhpkePublicKey, BigInt, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+          deserializer,
+        );
+    var var_field1 = sse_decode_u_64(deserializer);
+    var var_field2 = sse_decode_u_64(deserializer);
+    return (var_field0, var_field1, var_field2);
   }
 
   @protected
-  XdsaSignature
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    dynamic raw,
+  (XhpkeSender, Uint8List)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_sender_list_prim_u_8_strict(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+          deserializer,
+        );
+    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  XhpkeFingerprint
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    dynamic raw,
+  (Uint8List, Uint8List)
+  sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  XhpkePublicKey
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkePublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  SdCwt sse_decode_sd_cwt(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_token = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_disclosures = sse_decode_list_list_prim_u_8_strict(deserializer);
+    return SdCwt(token: var_token, disclosures: var_disclosures);
   }
 
   @protected
-  XhpkeSecretKey
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  RsaFingerprint
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  RsaPublicKey
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  RsaSecretKey
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  RsaSignature
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  XdsaFingerprint
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
   }
 
   @protected
-  XdsaPublicKey
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    dynamic raw,
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaPublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
   }
 
   @protected
-  XdsaSecretKey
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    dynamic raw,
+  void
+  sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    CddlSchema self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+      self,
+      serializer,
+    );
   }
 
   @protected
-  XdsaSignature
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    dynamic raw,
+  void
+  sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XdsaSignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+      self,
+      serializer,
+    );
   }
 
   @protected
-  XhpkeFingerprint
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    dynamic raw,
+  void
+  sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    XdsaPublicKey self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeFingerprintImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+      self,
+      serializer,
+    );
   }
 
   @protected
-  XhpkePublicKey
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    dynamic raw,
+  void
+  sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    XdsaSecretKey self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkePublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+      self,
+      serializer,
+    );
   }
 
   @protected
-  XhpkeReceiver
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    dynamic raw,
+  void
+  sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    XhpkePublicKey self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeReceiverImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+      self,
+      serializer,
+    );
   }
 
   @protected
-  XhpkeSecretKey
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    CddlSchema self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeSecretKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as CddlSchemaImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  XhpkeSender
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    RsaFingerprint self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return XhpkeSenderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as RsaFingerprintImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    RsaPublicKey self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as RsaPublicKeyImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    RsaSecretKey self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as RsaSecretKeyImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    RsaSignature self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as RsaSignatureImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  int dco_decode_box_autoadd_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as StatusListImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    StreamDecryptor self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as StreamDecryptorImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    StreamEncryptor self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as StreamEncryptorImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    StreamReader self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as StreamReaderImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
-  @protected
-  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    XdsaFingerprint self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as XdsaFingerprintImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  (XdsaPublicKey, BigInt, BigInt)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    XdsaPublicKey self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3) {
-      throw Exception('Expected 3 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-        arr[0],
-      ),
-      dco_decode_u_64(arr[1]),
-      dco_decode_u_64(arr[2]),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as XdsaPublicKeyImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  (XhpkePublicKey, BigInt, BigInt)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    XdsaSecretKey self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3) {
-      throw Exception('Expected 3 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-        arr[0],
-      ),
-      dco_decode_u_64(arr[1]),
-      dco_decode_u_64(arr[2]),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as XdsaSecretKeyImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  (XhpkeSender, Uint8List)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_sender_list_prim_u_8_strict(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    XdsaSignature self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-        arr[0],
-      ),
-      dco_decode_list_prim_u_8_strict(arr[1]),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as XdsaSignatureImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  (Uint8List, Uint8List)
-  dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_list_prim_u_8_strict(arr[0]),
-      dco_decode_list_prim_u_8_strict(arr[1]),
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    XhpkeFingerprint self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as XhpkeFingerprintImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    XhpkePublicKey self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as XhpkePublicKeyImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    XhpkeReceiver self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as XhpkeReceiverImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    XhpkeSecretKey self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as XhpkeSecretKeyImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    XhpkeSender self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as XhpkeSenderImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as StatusListImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  RsaFingerprint
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    StreamDecryptor self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaFingerprintImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as StreamDecryptorImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  RsaPublicKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    StreamEncryptor self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaPublicKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as StreamEncryptorImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  RsaSecretKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    StreamReader self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaSecretKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as StreamReaderImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  RsaSignature
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    XhpkeReceiver self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaSignatureImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XhpkeReceiverImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XdsaFingerprint
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    XhpkeSender self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaFingerprintImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XhpkeSenderImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XdsaPublicKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    CddlSchema self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaPublicKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as CddlSchemaImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XdsaSecretKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    RsaFingerprint self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaSecretKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as RsaFingerprintImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XdsaSignature
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    RsaPublicKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaSignatureImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as RsaPublicKeyImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XhpkeFingerprint
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    RsaSecretKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeFingerprintImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as RsaSecretKeyImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XhpkePublicKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    RsaSignature self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkePublicKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as RsaSignatureImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XhpkeReceiver
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeReceiverImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as StatusListImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XhpkeSecretKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    StreamReader self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeSecretKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as StreamReaderImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XhpkeSender
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    XdsaFingerprint self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeSenderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XdsaFingerprintImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XhpkeReceiver
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    XdsaPublicKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeReceiverImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XdsaPublicKeyImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XhpkeSender
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    XdsaSecretKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeSenderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XdsaSecretKeyImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  RsaFingerprint
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    XdsaSignature self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaFingerprintImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XdsaSignatureImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  RsaPublicKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    XhpkeFingerprint self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaPublicKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XhpkeFingerprintImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  RsaSecretKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    XhpkePublicKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaSecretKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XhpkePublicKeyImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  RsaSignature
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    XhpkeSecretKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaSignatureImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XhpkeSecretKeyImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  XdsaFingerprint
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    CddlSchema self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaFingerprintImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as CddlSchemaImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XdsaPublicKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    RsaFingerprint self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaPublicKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as RsaFingerprintImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XdsaSecretKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    RsaPublicKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaSecretKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as RsaPublicKeyImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XdsaSignature
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    RsaSecretKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaSignatureImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as RsaSecretKeyImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XhpkeFingerprint
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    RsaSignature self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeFingerprintImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as RsaSignatureImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XhpkePublicKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkePublicKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as StatusListImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XhpkeSecretKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    StreamDecryptor self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeSecretKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as StreamDecryptorImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  RsaFingerprint
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    StreamEncryptor self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaFingerprintImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as StreamEncryptorImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  RsaPublicKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    StreamReader self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaPublicKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as StreamReaderImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  RsaSecretKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    XdsaFingerprint self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaSecretKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XdsaFingerprintImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  RsaSignature
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    XdsaPublicKey self,
+    SseSerializer serializer,
   ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return RsaSignatureImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as XdsaPublicKeyImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XdsaFingerprint
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    XdsaSecretKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaFingerprintImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XdsaSecretKeyImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XdsaPublicKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    XdsaSignature self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaPublicKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XdsaSignatureImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XdsaSecretKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    XhpkeFingerprint self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaSecretKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XhpkeFingerprintImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XdsaSignature
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    XhpkePublicKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XdsaSignatureImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XhpkePublicKeyImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XhpkeFingerprint
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    XhpkeReceiver self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeFingerprintImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XhpkeReceiverImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XhpkePublicKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    XhpkeSecretKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkePublicKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XhpkeSecretKeyImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XhpkeReceiver
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    XhpkeSender self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeReceiverImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as XhpkeSenderImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  XhpkeSecretKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    SseDeserializer deserializer,
+  void sse_encode_StreamSink_u_64_Sse(
+    RustStreamSink<BigInt> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeSecretKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  XhpkeSender
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return XhpkeSenderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_argon2_params(Argon2Params self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_u_32(self.time, serializer);
+    sse_encode_u_32(self.memory, serializer);
+    sse_encode_u_32(self.threads, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_argon2_params(
+    Argon2Params self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
+    sse_encode_argon2_params(self, serializer);
   }
 
   @protected
-  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_8(deserializer));
+    sse_encode_bool(self, serializer);
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_cbor_value(
+    CborValue self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_cbor_value(self, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_cert_name(
+    CertName self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_cert_name(self, serializer);
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_cert_template(
+    CertTemplate self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_cert_template(self, serializer);
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_cwt_claims(
+    CwtClaims self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cwt_claims(self, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_8(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_box_autoadd_cwt_confirm_key(
+    CwtConfirmKey self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cwt_confirm_key(self, serializer);
   }
 
   @protected
-  (XdsaPublicKey, BigInt, BigInt)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_cwt_confirm_key_type(
+    CwtConfirmKeyType self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-          deserializer,
-        );
-    var var_field1 = sse_decode_u_64(deserializer);
-    var var_field2 = sse_decode_u_64(deserializer);
-    return (var_field0, var_field1, var_field2);
+    sse_encode_cwt_confirm_key_type(self, serializer);
   }
 
   @protected
-  (XhpkePublicKey, BigInt, BigInt)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_cwt_status(
+    CwtStatus self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-          deserializer,
-        );
-    var var_field1 = sse_decode_u_64(deserializer);
-    var var_field2 = sse_decode_u_64(deserializer);
-    return (var_field0, var_field1, var_field2);
+    sse_encode_cwt_status(self, serializer);
   }
 
   @protected
-  (XhpkeSender, Uint8List)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_sender_list_prim_u_8_strict(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_cwt_validation(
+    CwtValidation self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-          deserializer,
-        );
-    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_cwt_validation(self, serializer);
   }
 
   @protected
-  (Uint8List, Uint8List)
-  sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_eat_debug_state(
+    EatDebugState self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_eat_debug_state(self, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_eat_oemid(
+    EatOemid self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_eat_oemid(self, serializer);
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_u_8(self, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_box_cwt_claims(CwtClaims self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cwt_claims(self, serializer);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_cbor_map_entry(CborMapEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_64(self.key, serializer);
+    sse_encode_cbor_value(self.value, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_cbor_value(CborValue self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    switch (self) {
+      case CborValue_Null():
+        sse_encode_i_32(0, serializer);
+      case CborValue_Bool(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_bool(field0, serializer);
+      case CborValue_Int(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_i_64(field0, serializer);
+      case CborValue_Uint(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_u_64(field0, serializer);
+      case CborValue_Text(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(field0, serializer);
+      case CborValue_Bytes(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_list_prim_u_8_strict(field0, serializer);
+      case CborValue_Array(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_list_cbor_value(field0, serializer);
+      case CborValue_Map(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_list_cbor_map_entry(field0, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    RsaFingerprint self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_cert_chain(CertChain self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaFingerprintImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_cert_public_key(self.leafKey, serializer);
+    sse_encode_list_list_prim_u_8_strict(self.path, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    RsaPublicKey self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_cert_extension(CertExtension self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaPublicKeyImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_String(self.oid, serializer);
+    sse_encode_bool(self.critical, serializer);
+    sse_encode_list_prim_u_8_strict(self.value, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    RsaSecretKey self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_cert_info(CertInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaSecretKeyImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_cert_name(self.subject, serializer);
+    sse_encode_cert_name(self.issuer, serializer);
+    sse_encode_list_prim_u_8_strict(self.serial, serializer);
+    sse_encode_u_64(self.notBefore, serializer);
+    sse_encode_u_64(self.notAfter, serializer);
+    sse_encode_bool(self.isCa, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.pathLen, serializer);
+    sse_encode_list_String(self.dnsNames, serializer);
+    sse_encode_list_String(self.uris, serializer);
+    sse_encode_list_String(self.emails, serializer);
+    sse_encode_list_cert_key_usage(self.keyUsage, serializer);
+    sse_encode_list_String(self.extendedKeyUsage, serializer);
+    sse_encode_list_cert_extension(self.extensions, serializer);
+    sse_encode_cert_key_type(self.keyType, serializer);
+    sse_encode_list_prim_u_8_strict(self.fingerprint, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.subjectKeyId, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.issuerKeyId, serializer);
+  }
+
+  @protected
+  void sse_encode_cert_key_type(CertKeyType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    RsaSignature self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_cert_key_usage(CertKeyUsage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaSignatureImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_cert_name(CertName self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.commonName, serializer);
+    sse_encode_opt_String(self.organization, serializer);
+    sse_encode_opt_String(self.organizationalUnit, serializer);
+    sse_encode_opt_String(self.country, serializer);
+    sse_encode_opt_String(self.province, serializer);
+    sse_encode_opt_String(self.locality, serializer);
+    sse_encode_opt_String(self.serialNumber, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    XdsaFingerprint self,
+  void sse_encode_cert_public_key(
+    CertPublicKey self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaFingerprintImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    switch (self) {
+      case CertPublicKey_Xdsa(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+          field0,
+          serializer,
+        );
+      case CertPublicKey_Xhpke(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+          field0,
+          serializer,
+        );
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    XdsaPublicKey self,
+  void sse_encode_cert_template(CertTemplate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cert_name(self.subject, serializer);
+    sse_encode_cert_name(self.issuer, serializer);
+    sse_encode_u_64(self.notBefore, serializer);
+    sse_encode_u_64(self.notAfter, serializer);
+    sse_encode_bool(self.isCa, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.pathLen, serializer);
+    sse_encode_list_String(self.dnsNames, serializer);
+    sse_encode_list_String(self.uris, serializer);
+    sse_encode_list_String(self.emails, serializer);
+    sse_encode_list_cert_key_usage(self.keyUsage, serializer);
+    sse_encode_list_String(self.extendedKeyUsage, serializer);
+    sse_encode_list_cert_extension(self.extensions, serializer);
+  }
+
+  @protected
+  void sse_encode_cose_sign1_info(
+    CoseSign1Info self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaPublicKeyImpl).frbInternalSseEncode(move: true),
+    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+      self.signer,
       serializer,
     );
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_i_64(self.algorithm, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.domain, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    XdsaSecretKey self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_crypto_error(CryptoError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaSecretKeyImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    switch (self) {
+      case CryptoError_InvalidLength(expected: final expected, got: final got):
+        sse_encode_i_32(0, serializer);
+        sse_encode_usize(expected, serializer);
+        sse_encode_usize(got, serializer);
+      case CryptoError_InvalidEncoding(details: final details):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_InvalidArgument(details: final details):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_CborInvalid(details: final details):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_SignatureMismatch(details: final details):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_KeyMismatch(details: final details):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_EncryptionFailed(details: final details):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_DecryptionFailed(details: final details):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_Expired(exp: final exp, now: final now):
+        sse_encode_i_32(8, serializer);
+        sse_encode_u_64(exp, serializer);
+        sse_encode_u_64(now, serializer);
+      case CryptoError_NotYetValid(nbf: final nbf, now: final now):
+        sse_encode_i_32(9, serializer);
+        sse_encode_u_64(nbf, serializer);
+        sse_encode_u_64(now, serializer);
+      case CryptoError_StaleSignature(drift: final drift, maxDrift: final maxDrift):
+        sse_encode_i_32(10, serializer);
+        sse_encode_u_64(drift, serializer);
+        sse_encode_u_64(maxDrift, serializer);
+      case CryptoError_InvalidClaims(details: final details):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_InvalidCertificate(details: final details):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_Io(details: final details):
+        sse_encode_i_32(13, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_MissingClaim(key: final key):
+        sse_encode_i_32(14, serializer);
+        sse_encode_i_64(key, serializer);
+      case CryptoError_IssuerMismatch(expected: final expected, got: final got):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(expected, serializer);
+        sse_encode_String(got, serializer);
+      case CryptoError_AudienceMismatch(got: final got):
+        sse_encode_i_32(16, serializer);
+        sse_encode_String(got, serializer);
+      case CryptoError_TokenTooOld(iat: final iat, maxAge: final maxAge, now: final now):
+        sse_encode_i_32(17, serializer);
+        sse_encode_u_64(iat, serializer);
+        sse_encode_u_64(maxAge, serializer);
+        sse_encode_u_64(now, serializer);
+      case CryptoError_ConfirmKeyMismatch(details: final details):
+        sse_encode_i_32(18, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_NonceMismatch(details: final details):
+        sse_encode_i_32(19, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_Revoked(idx: final idx, status: final status):
+        sse_encode_i_32(20, serializer);
+        sse_encode_u_64(idx, serializer);
+        sse_encode_u_8(status, serializer);
+      case CryptoError_InvalidSchema(details: final details):
+        sse_encode_i_32(21, serializer);
+        sse_encode_String(details, serializer);
+      case CryptoError_SchemaMismatch(path: final path, details: final details):
+        sse_encode_i_32(22, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_String(details, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    XdsaSignature self,
+  void sse_encode_cwt_claims(CwtClaims self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.iss, serializer);
+    sse_encode_opt_String(self.sub, serializer);
+    sse_encode_opt_String(self.aud, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.exp, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.nbf, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.iat, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.cti, serializer);
+    sse_encode_opt_box_autoadd_cwt_confirm_key(self.cnf, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.eatNonce, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.ueid, serializer);
+    sse_encode_opt_box_autoadd_eat_oemid(self.oemid, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.hwModel, serializer);
+    sse_encode_opt_String(self.hwVersion, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.uptime, serializer);
+    sse_encode_opt_box_autoadd_bool(self.oemBoot, serializer);
+    sse_encode_opt_box_autoadd_eat_debug_state(self.dbgstat, serializer);
+    sse_encode_list_eat_submodule(self.submods, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.bootCount, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.bootSeed, serializer);
+    sse_encode_opt_String(self.swName, serializer);
+    sse_encode_opt_String(self.swVersion, serializer);
+    sse_encode_list_eat_measurement(self.measurements, serializer);
+    sse_encode_opt_box_autoadd_cwt_status(self.status, serializer);
+    sse_encode_list_cwt_custom_claim(self.custom, serializer);
+  }
+
+  @protected
+  void sse_encode_cwt_confirm_key(
+    CwtConfirmKey self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaSignatureImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    switch (self) {
+      case CwtConfirmKey_Xdsa(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+          field0,
+          serializer,
+        );
+      case CwtConfirmKey_Xhpke(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+          field0,
+          serializer,
+        );
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    XhpkeFingerprint self,
+  void sse_encode_cwt_confirm_key_type(
+    CwtConfirmKeyType self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeFingerprintImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    XhpkePublicKey self,
+  void sse_encode_cwt_custom_claim(
+    CwtCustomClaim self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkePublicKeyImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_64(self.key, serializer);
+    sse_encode_list_prim_u_8_strict(self.value, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    XhpkeReceiver self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_cwt_status(CwtStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeReceiverImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_u_64(self.idx, serializer);
+    sse_encode_String(self.uri, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    XhpkeSecretKey self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_cwt_validation(CwtValidation self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeSecretKeyImpl).frbInternalSseEncode(move: true),
+    sse_encode_opt_box_autoadd_u_64(self.now, serializer);
+    sse_encode_u_64(self.leewaySecs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxAgeSecs, serializer);
+    sse_encode_opt_String(self.issuer, serializer);
+    sse_encode_list_String(self.audiences, serializer);
+    sse_encode_list_prim_i_64_strict(self.requiredClaims, serializer);
+    sse_encode_opt_box_autoadd_cwt_confirm_key_type(
+      self.confirmKey,
       serializer,
     );
+    sse_encode_opt_list_prim_u_8_strict(self.eatNonce, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    XhpkeSender self,
+  void sse_encode_eat_debug_state(
+    EatDebugState self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeSenderImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    XhpkeReceiver self,
+  void sse_encode_eat_measurement(
+    EatMeasurement self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeReceiverImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_u_64(self.contentType, serializer);
+    sse_encode_list_prim_u_8_strict(self.content, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    XhpkeSender self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_eat_oemid(EatOemid self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeSenderImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    switch (self) {
+      case EatOemid_Random(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_list_prim_u_8_strict(field0, serializer);
+      case EatOemid_Ieee(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_prim_u_8_strict(field0, serializer);
+      case EatOemid_Pen(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_64(field0, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    RsaFingerprint self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_eat_submodule(EatSubmodule self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaFingerprintImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_String(self.name, serializer);
+    sse_encode_eat_submodule_value(self.value, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    RsaPublicKey self,
+  void sse_encode_eat_submodule_value(
+    EatSubmoduleValue self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaPublicKeyImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    switch (self) {
+      case EatSubmoduleValue_Claims(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_cwt_claims(field0, serializer);
+      case EatSubmoduleValue_Embedded(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_prim_u_8_strict(field0, serializer);
+      case EatSubmoduleValue_Detached(alg: final alg, digest: final digest):
+        sse_encode_i_32(2, serializer);
+        sse_encode_i_64(alg, serializer);
+        sse_encode_list_prim_u_8_strict(digest, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    RsaSecretKey self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaSecretKeyImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
   void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    RsaSignature self,
+  sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    List<XdsaPublicKey> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaSignatureImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+        item,
+        serializer,
+      );
+    }
   }
 
   @protected
   void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    XdsaFingerprint self,
+  sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    List<XdsaSecretKey> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaFingerprintImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+        item,
+        serializer,
+      );
+    }
   }
 
   @protected
   void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    XdsaPublicKey self,
+  sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    List<XhpkePublicKey> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaPublicKeyImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+        item,
+        serializer,
+      );
+    }
   }
 
   @protected
   void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    XdsaSecretKey self,
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    List<XdsaFingerprint> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaSecretKeyImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+        item,
+        serializer,
+      );
+    }
   }
 
   @protected
   void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    XdsaSignature self,
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    List<XhpkeFingerprint> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaSignatureImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+        item,
+        serializer,
+      );
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    XhpkeFingerprint self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeFingerprintImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    XhpkePublicKey self,
+  void sse_encode_list_cbor_map_entry(
+    List<CborMapEntry> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkePublicKeyImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cbor_map_entry(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    XhpkeSecretKey self,
+  void sse_encode_list_cbor_value(
+    List<CborValue> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeSecretKeyImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cbor_value(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    RsaFingerprint self,
+  void sse_encode_list_cert_extension(
+    List<CertExtension> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaFingerprintImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cert_extension(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    RsaPublicKey self,
+  void sse_encode_list_cert_key_usage(
+    List<CertKeyUsage> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaPublicKeyImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cert_key_usage(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    RsaSecretKey self,
+  void sse_encode_list_cwt_custom_claim(
+    List<CwtCustomClaim> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaSecretKeyImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cwt_custom_claim(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    RsaSignature self,
+  void sse_encode_list_eat_measurement(
+    List<EatMeasurement> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as RsaSignatureImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_eat_measurement(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    XdsaFingerprint self,
+  void sse_encode_list_eat_submodule(
+    List<EatSubmodule> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaFingerprintImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_eat_submodule(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    XdsaPublicKey self,
+  void sse_encode_list_list_prim_u_8_strict(
+    List<Uint8List> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaPublicKeyImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_prim_u_8_strict(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    XdsaSecretKey self,
+  void sse_encode_list_prim_i_64_loose(
+    Int64List self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaSecretKeyImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    XdsaSignature self,
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XdsaSignatureImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    XhpkeFingerprint self,
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeFingerprintImpl).frbInternalSseEncode(move: null),
-      serializer,
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
     );
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    XhpkePublicKey self,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkePublicKeyImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
   void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    XhpkeReceiver self,
+  sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    CddlSchema? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeReceiverImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+        self,
+        serializer,
+      );
+    }
   }
 
   @protected
   void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    XhpkeSecretKey self,
+  sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeSecretKeyImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+        self,
+        serializer,
+      );
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    XhpkeSender self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as XhpkeSenderImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_cwt_confirm_key(
+    CwtConfirmKey? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cwt_confirm_key(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_cwt_confirm_key_type(
+    CwtConfirmKeyType? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cwt_confirm_key_type(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_cwt_status(
+    CwtStatus? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_8(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cwt_status(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
+  void sse_encode_opt_box_autoadd_eat_debug_state(
+    EatDebugState? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_eat_debug_state(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_eat_oemid(
+    EatOemid? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_eat_oemid(self, serializer);
+    }
   }
 
   @protected
@@ -5632,6 +11753,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_stream_encryptor_list_prim_u_8_strict(
+    (StreamEncryptor, Uint8List) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+      self.$1,
+      serializer,
+    );
+    sse_encode_list_prim_u_8_strict(self.$2, serializer);
+  }
+
   @protected
   void
   sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64(
@@ -5686,6 +11834,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_sd_cwt(SdCwt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.token, serializer);
+    sse_encode_list_list_prim_u_8_strict(self.disclosures, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5722,6 +11877,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 }
 
+@sealed
+class CddlSchemaImpl extends RustOpaque implements CddlSchema {
+  // Not to be used by end users
+  CddlSchemaImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  CddlSchemaImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_CddlSchema,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_CddlSchema,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_CddlSchemaPtr,
+  );
+
+  /// Validates CBOR data against a rule of the schema, reporting the path of
+  /// the first mismatch.
+  ///
+  /// - `data`: The CBOR data to validate
+  /// - `rule`: Name of the rule the data must match
+  void validate({required List<int> data, required String rule}) => RustLib
+      .instance
+      .api
+      .crateApiCddlCddlSchemaValidate(that: this, data: data, rule: rule);
+}
+
 @sealed
 class RsaFingerprintImpl extends RustOpaque implements RsaFingerprint {
   // Not to be used by end users
@@ -5858,6 +12043,166 @@ class RsaSignatureImpl extends RustOpaque implements RsaSignature {
       RustLib.instance.api.crateApiRsaRsaSignatureToBytes(that: this);
 }
 
+@sealed
+class StatusListImpl extends RustOpaque implements StatusList {
+  // Not to be used by end users
+  StatusListImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  StatusListImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_StatusList,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_StatusList,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_StatusListPtr,
+  );
+
+  /// Returns the number of bits per entry.
+  int bits() => RustLib.instance.api.crateApiCwtStatusListBits(that: this);
+
+  /// Returns the status at `idx`.
+  int get({required BigInt idx}) =>
+      RustLib.instance.api.crateApiCwtStatusListGet(that: this, idx: idx);
+
+  /// Returns whether the list has no entries.
+  bool isEmpty() =>
+      RustLib.instance.api.crateApiCwtStatusListIsEmpty(that: this);
+
+  /// Signs the status list as a CWT, with the list compressed using zlib.
+  ///
+  /// - `signer`: The xDSA secret key to sign with
+  /// - `domain`: Application-specific domain separator
+  /// - `iat`: Issuance time as Unix timestamp
+  /// - `exp`: Time after which the list must not be used (None for no expiry)
+  /// - `ttl`: Seconds the list may be cached before refetching (None to omit)
+  Uint8List issue({
+    required XdsaSecretKey signer,
+    required List<int> domain,
+    required BigInt iat,
+    BigInt? exp,
+    BigInt? ttl,
+  }) => RustLib.instance.api.crateApiCwtStatusListIssue(
+    that: this,
+    signer: signer,
+    domain: domain,
+    iat: iat,
+    exp: exp,
+    ttl: ttl,
+  );
+
+  /// Returns the number of entries in the list.
+  BigInt len() => RustLib.instance.api.crateApiCwtStatusListLen(that: this);
+
+  /// Sets the status at `idx`, which must fit in `bits` bits.
+  void set({required BigInt idx, required int status}) => RustLib.instance.api
+      .crateApiCwtStatusListSet(that: this, idx: idx, status: status);
+
+  /// Returns the URI the status list is published at.
+  String uri() => RustLib.instance.api.crateApiCwtStatusListUri(that: this);
+}
+
+@sealed
+class StreamDecryptorImpl extends RustOpaque implements StreamDecryptor {
+  // Not to be used by end users
+  StreamDecryptorImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  StreamDecryptorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_StreamDecryptor,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_StreamDecryptor,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_StreamDecryptorPtr,
+  );
+
+  /// Terminates the stream, authenticating and returning the plaintext of
+  /// the final chunk. Fails if the stream was truncated.
+  Uint8List finish() =>
+      RustLib.instance.api.crateApiStreamStreamDecryptorFinish(that: this);
+
+  /// Feeds more ciphertext into the stream, returning the plaintext of all
+  /// chunks that were authenticated. The returned data may be empty.
+  Uint8List push({required List<int> chunk}) => RustLib.instance.api
+      .crateApiStreamStreamDecryptorPush(that: this, chunk: chunk);
+}
+
+@sealed
+class StreamEncryptorImpl extends RustOpaque implements StreamEncryptor {
+  // Not to be used by end users
+  StreamEncryptorImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  StreamEncryptorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_StreamEncryptor,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_StreamEncryptor,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_StreamEncryptorPtr,
+  );
+
+  /// Terminates the stream, returning the final ciphertext chunk. No more
+  /// data can be pushed afterwards.
+  Uint8List finish() =>
+      RustLib.instance.api.crateApiStreamStreamEncryptorFinish(that: this);
+
+  /// Feeds more plaintext into the stream, returning any ciphertext that
+  /// became available. The returned data may be empty.
+  Uint8List push({required List<int> chunk}) => RustLib.instance.api
+      .crateApiStreamStreamEncryptorPush(that: this, chunk: chunk);
+}
+
+@sealed
+class StreamReaderImpl extends RustOpaque implements StreamReader {
+  // Not to be used by end users
+  StreamReaderImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  StreamReaderImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_StreamReader,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_StreamReader,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_StreamReaderPtr,
+  );
+
+  /// Returns whether the plaintext is empty.
+  bool isEmpty() =>
+      RustLib.instance.api.crateApiStreamStreamReaderIsEmpty(that: this);
+
+  /// Returns the total length of the plaintext.
+  BigInt len() =>
+      RustLib.instance.api.crateApiStreamStreamReaderLen(that: this);
+
+  /// Decrypts up to `len` bytes of plaintext starting at `offset`. The result
+  /// is shorter than `len` if the range extends past the end of the plaintext.
+  Future<Uint8List> readAt({required BigInt offset, required BigInt len}) =>
+      RustLib.instance.api.crateApiStreamStreamReaderReadAt(
+        that: this,
+        offset: offset,
+        len: len,
+      );
+}
+
 @sealed
 class XdsaFingerprintImpl extends RustOpaque implements XdsaFingerprint {
   // Not to be used by end users
@@ -5910,61 +12255,31 @@ class XdsaPublicKeyImpl extends RustOpaque implements XdsaPublicKey {
       RustLib.instance.api.crateApiXdsaXdsaPublicKeyToBytes(that: this);
 
   /// Generates a DER-encoded X.509 certificate for this public key,
-  /// signed by the given xDSA secret key with the specified validity period.
+  /// signed by the given xDSA secret key.
   ///
   /// - `signer`: The xDSA secret key to sign the certificate
-  /// - `subject_name`: The subject's common name (CN)
-  /// - `issuer_name`: The issuer's common name (CN)
-  /// - `not_before`: Certificate validity start time (Unix timestamp)
-  /// - `not_after`: Certificate validity end time (Unix timestamp)
-  /// - `is_ca`: Whether this is a CA certificate
-  /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+  /// - `template`: The certificate's names, validity and extensions
   Uint8List toCertDer({
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   }) => RustLib.instance.api.crateApiXdsaXdsaPublicKeyToCertDer(
     that: this,
     signer: signer,
-    subjectName: subjectName,
-    issuerName: issuerName,
-    notBefore: notBefore,
-    notAfter: notAfter,
-    isCa: isCa,
-    pathLen: pathLen,
+    template: template,
   );
 
   /// Generates a PEM-encoded X.509 certificate for this public key,
-  /// signed by the given xDSA secret key with the specified validity period.
+  /// signed by the given xDSA secret key.
   ///
   /// - `signer`: The xDSA secret key to sign the certificate
-  /// - `subject_name`: The subject's common name (CN)
-  /// - `issuer_name`: The issuer's common name (CN)
-  /// - `not_before`: Certificate validity start time (Unix timestamp)
-  /// - `not_after`: Certificate validity end time (Unix timestamp)
-  /// - `is_ca`: Whether this is a CA certificate
-  /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+  /// - `template`: The certificate's names, validity and extensions
   String toCertPem({
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   }) => RustLib.instance.api.crateApiXdsaXdsaPublicKeyToCertPem(
     that: this,
     signer: signer,
-    subjectName: subjectName,
-    issuerName: issuerName,
-    notBefore: notBefore,
-    notAfter: notAfter,
-    isCa: isCa,
-    pathLen: pathLen,
+    template: template,
   );
 
   /// Serializes the public key to DER format.
@@ -6137,61 +12452,35 @@ class XhpkePublicKeyImpl extends RustOpaque implements XhpkePublicKey {
       RustLib.instance.api.crateApiXhpkeXhpkePublicKeyToBytes(that: this);
 
   /// Generates a DER-encoded X.509 certificate for this public key,
-  /// signed by the given xDSA secret key with the specified validity period.
+  /// signed by the given xDSA secret key.
+  ///
+  /// xHPKE certificates are always end-entity certificates.
   ///
   /// - `signer`: The xDSA secret key to sign the certificate
-  /// - `subject_name`: The subject's common name (CN)
-  /// - `issuer_name`: The issuer's common name (CN)
-  /// - `not_before`: Certificate validity start time (Unix timestamp)
-  /// - `not_after`: Certificate validity end time (Unix timestamp)
-  /// - `is_ca`: Whether this is a CA certificate
-  /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+  /// - `template`: The certificate's names, validity and extensions
   Uint8List toCertDer({
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   }) => RustLib.instance.api.crateApiXhpkeXhpkePublicKeyToCertDer(
     that: this,
     signer: signer,
-    subjectName: subjectName,
-    issuerName: issuerName,
-    notBefore: notBefore,
-    notAfter: notAfter,
-    isCa: isCa,
-    pathLen: pathLen,
+    template: template,
   );
 
   /// Generates a PEM-encoded X.509 certificate for this public key,
-  /// signed by the given xDSA secret key with the specified validity period.
+  /// signed by the given xDSA secret key.
+  ///
+  /// xHPKE certificates are always end-entity certificates.
   ///
   /// - `signer`: The xDSA secret key to sign the certificate
-  /// - `subject_name`: The subject's common name (CN)
-  /// - `issuer_name`: The issuer's common name (CN)
-  /// - `not_before`: Certificate validity start time (Unix timestamp)
-  /// - `not_after`: Certificate validity end time (Unix timestamp)
-  /// - `is_ca`: Whether this is a CA certificate
-  /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+  /// - `template`: The certificate's names, validity and extensions
   String toCertPem({
     required XdsaSecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
+    required CertTemplate template,
   }) => RustLib.instance.api.crateApiXhpkeXhpkePublicKeyToCertPem(
     that: this,
     signer: signer,
-    subjectName: subjectName,
-    issuerName: issuerName,
-    notBefore: notBefore,
-    notAfter: notAfter,
-    isCa: isCa,
-    pathLen: pathLen,
+    template: template,
   );
 
   /// Serializes the public key to DER format.
@@ -6287,6 +12576,23 @@ class XhpkeSecretKeyImpl extends RustOpaque implements XhpkeSecretKey {
     domain: domain,
   );
 
+  /// Decrypts a message produced by `xhpke_seal_multi`, locating the content
+  /// key wrapped for this key by its fingerprint.
+  ///
+  /// - `sealed`: The multi-recipient message to decrypt
+  /// - `msg_to_auth`: Additional authenticated data (not encrypted)
+  /// - `domain`: Application-specific domain separator
+  Uint8List openMulti({
+    required List<int> sealed,
+    required List<int> msgToAuth,
+    required List<int> domain,
+  }) => RustLib.instance.api.crateApiXhpkeXhpkeSecretKeyOpenMulti(
+    that: this,
+    sealed: sealed,
+    msgToAuth: msgToAuth,
+    domain: domain,
+  );
+
   /// Returns the public key corresponding to this private key.
   XhpkePublicKey publicKey() =>
       RustLib.instance.api.crateApiXhpkeXhpkeSecretKeyPublicKey(that: this);
@@ -8,12 +8,15 @@
 
 import 'api/argon2.dart';
 import 'api/cbor.dart';
+import 'api/cddl.dart';
 import 'api/cose.dart';
 import 'api/cwt.dart';
+import 'api/error.dart';
 import 'api/hkdf.dart';
 import 'api/rand.dart';
 import 'api/rsa.dart';
 import 'api/stream.dart';
+import 'api/x509.dart';
 import 'api/xdsa.dart';
 import 'api/xhpke.dart';
 import 'dart:async';
@@ -29,6 +32,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CddlSchemaPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_RsaFingerprintPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint;
@@ -45,6 +51,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_RsaSignaturePtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature;
 
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_StatusListPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_StreamDecryptorPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_StreamEncryptorPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_StreamReaderPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_XdsaFingerprintPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint;
@@ -81,6 +102,45 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_XhpkeSenderPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender;
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  CddlSchema
+  dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    dynamic raw,
+  );
+
+  @protected
+  StatusList
+  dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  );
+
+  @protected
+  XdsaPublicKey
+  dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    dynamic raw,
+  );
+
+  @protected
+  XdsaSecretKey
+  dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    dynamic raw,
+  );
+
+  @protected
+  XhpkePublicKey
+  dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    dynamic raw,
+  );
+
+  @protected
+  CddlSchema
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    dynamic raw,
+  );
+
   @protected
   RsaFingerprint
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
@@ -105,6 +165,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  StatusList
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  );
+
+  @protected
+  StreamDecryptor
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    dynamic raw,
+  );
+
+  @protected
+  StreamEncryptor
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    dynamic raw,
+  );
+
+  @protected
+  StreamReader
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    dynamic raw,
+  );
+
   @protected
   XdsaFingerprint
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
@@ -159,6 +243,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  StatusList
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  );
+
+  @protected
+  StreamDecryptor
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    dynamic raw,
+  );
+
+  @protected
+  StreamEncryptor
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    dynamic raw,
+  );
+
+  @protected
+  StreamReader
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    dynamic raw,
+  );
+
   @protected
   XhpkeReceiver
   dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
@@ -171,6 +279,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  CddlSchema
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    dynamic raw,
+  );
+
   @protected
   RsaFingerprint
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
@@ -195,6 +309,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  StatusList
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  );
+
+  @protected
+  StreamReader
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    dynamic raw,
+  );
+
   @protected
   XdsaFingerprint
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
@@ -237,6 +363,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  CddlSchema
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    dynamic raw,
+  );
+
   @protected
   RsaFingerprint
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
@@ -261,6 +393,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  StatusList
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  );
+
+  @protected
+  StreamDecryptor
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    dynamic raw,
+  );
+
+  @protected
+  StreamEncryptor
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    dynamic raw,
+  );
+
+  @protected
+  StreamReader
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    dynamic raw,
+  );
+
   @protected
   XdsaFingerprint
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
@@ -315,666 +471,1779 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<BigInt> dco_decode_StreamSink_u_64_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  Argon2Params dco_decode_argon2_params(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+  Argon2Params dco_decode_box_autoadd_argon2_params(dynamic raw);
 
   @protected
-  int dco_decode_box_autoadd_u_8(dynamic raw);
+  bool dco_decode_box_autoadd_bool(dynamic raw);
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  CborValue dco_decode_box_autoadd_cbor_value(dynamic raw);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  CertName dco_decode_box_autoadd_cert_name(dynamic raw);
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+  CertTemplate dco_decode_box_autoadd_cert_template(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+  CwtClaims dco_decode_box_autoadd_cwt_claims(dynamic raw);
 
   @protected
-  (XdsaPublicKey, BigInt, BigInt)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64(
-    dynamic raw,
-  );
+  CwtConfirmKey dco_decode_box_autoadd_cwt_confirm_key(dynamic raw);
 
   @protected
-  (XhpkePublicKey, BigInt, BigInt)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64(
-    dynamic raw,
-  );
+  CwtConfirmKeyType dco_decode_box_autoadd_cwt_confirm_key_type(dynamic raw);
 
   @protected
-  (XhpkeSender, Uint8List)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_sender_list_prim_u_8_strict(
-    dynamic raw,
-  );
+  CwtStatus dco_decode_box_autoadd_cwt_status(dynamic raw);
 
   @protected
-  (Uint8List, Uint8List)
-  dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw);
+  CwtValidation dco_decode_box_autoadd_cwt_validation(dynamic raw);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  EatDebugState dco_decode_box_autoadd_eat_debug_state(dynamic raw);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  EatOemid dco_decode_box_autoadd_eat_oemid(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  int dco_decode_box_autoadd_u_8(dynamic raw);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  CwtClaims dco_decode_box_cwt_claims(dynamic raw);
 
   @protected
-  RsaFingerprint
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    SseDeserializer deserializer,
-  );
+  CborMapEntry dco_decode_cbor_map_entry(dynamic raw);
 
   @protected
-  RsaPublicKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    SseDeserializer deserializer,
-  );
+  CborValue dco_decode_cbor_value(dynamic raw);
 
   @protected
-  RsaSecretKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    SseDeserializer deserializer,
-  );
+  CertChain dco_decode_cert_chain(dynamic raw);
 
   @protected
-  RsaSignature
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    SseDeserializer deserializer,
-  );
+  CertExtension dco_decode_cert_extension(dynamic raw);
 
   @protected
-  XdsaFingerprint
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    SseDeserializer deserializer,
-  );
+  CertInfo dco_decode_cert_info(dynamic raw);
 
   @protected
-  XdsaPublicKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    SseDeserializer deserializer,
-  );
+  CertKeyType dco_decode_cert_key_type(dynamic raw);
 
   @protected
-  XdsaSecretKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    SseDeserializer deserializer,
-  );
+  CertKeyUsage dco_decode_cert_key_usage(dynamic raw);
 
   @protected
-  XdsaSignature
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    SseDeserializer deserializer,
-  );
+  CertName dco_decode_cert_name(dynamic raw);
 
   @protected
-  XhpkeFingerprint
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    SseDeserializer deserializer,
-  );
+  CertPublicKey dco_decode_cert_public_key(dynamic raw);
 
   @protected
-  XhpkePublicKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    SseDeserializer deserializer,
-  );
+  CertTemplate dco_decode_cert_template(dynamic raw);
 
   @protected
-  XhpkeReceiver
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    SseDeserializer deserializer,
-  );
+  CoseSign1Info dco_decode_cose_sign1_info(dynamic raw);
 
   @protected
-  XhpkeSecretKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    SseDeserializer deserializer,
-  );
+  CryptoError dco_decode_crypto_error(dynamic raw);
 
   @protected
-  XhpkeSender
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    SseDeserializer deserializer,
-  );
+  CwtClaims dco_decode_cwt_claims(dynamic raw);
 
   @protected
-  XhpkeReceiver
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    SseDeserializer deserializer,
-  );
+  CwtConfirmKey dco_decode_cwt_confirm_key(dynamic raw);
 
   @protected
-  XhpkeSender
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    SseDeserializer deserializer,
-  );
+  CwtConfirmKeyType dco_decode_cwt_confirm_key_type(dynamic raw);
 
   @protected
-  RsaFingerprint
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    SseDeserializer deserializer,
-  );
+  CwtCustomClaim dco_decode_cwt_custom_claim(dynamic raw);
 
   @protected
-  RsaPublicKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    SseDeserializer deserializer,
-  );
+  CwtStatus dco_decode_cwt_status(dynamic raw);
 
   @protected
-  RsaSecretKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    SseDeserializer deserializer,
-  );
+  CwtValidation dco_decode_cwt_validation(dynamic raw);
 
   @protected
-  RsaSignature
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    SseDeserializer deserializer,
-  );
+  EatDebugState dco_decode_eat_debug_state(dynamic raw);
 
   @protected
-  XdsaFingerprint
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    SseDeserializer deserializer,
-  );
+  EatMeasurement dco_decode_eat_measurement(dynamic raw);
 
   @protected
-  XdsaPublicKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    SseDeserializer deserializer,
-  );
+  EatOemid dco_decode_eat_oemid(dynamic raw);
 
   @protected
-  XdsaSecretKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    SseDeserializer deserializer,
-  );
+  EatSubmodule dco_decode_eat_submodule(dynamic raw);
 
   @protected
-  XdsaSignature
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    SseDeserializer deserializer,
-  );
+  EatSubmoduleValue dco_decode_eat_submodule_value(dynamic raw);
 
   @protected
-  XhpkeFingerprint
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    SseDeserializer deserializer,
-  );
+  PlatformInt64 dco_decode_i_64(dynamic raw);
 
   @protected
-  XhpkePublicKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    SseDeserializer deserializer,
+  List<XdsaPublicKey>
+  dco_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    dynamic raw,
   );
 
   @protected
-  XhpkeSecretKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    SseDeserializer deserializer,
+  List<XdsaSecretKey>
+  dco_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    dynamic raw,
   );
 
   @protected
-  RsaFingerprint
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    SseDeserializer deserializer,
+  List<XhpkePublicKey>
+  dco_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    dynamic raw,
   );
 
   @protected
-  RsaPublicKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    SseDeserializer deserializer,
+  List<XdsaFingerprint>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    dynamic raw,
   );
 
   @protected
-  RsaSecretKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    SseDeserializer deserializer,
+  List<XhpkeFingerprint>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    dynamic raw,
   );
 
   @protected
-  RsaSignature
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    SseDeserializer deserializer,
-  );
+  List<String> dco_decode_list_String(dynamic raw);
 
   @protected
-  XdsaFingerprint
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    SseDeserializer deserializer,
-  );
+  List<CborMapEntry> dco_decode_list_cbor_map_entry(dynamic raw);
+
+  @protected
+  List<CborValue> dco_decode_list_cbor_value(dynamic raw);
+
+  @protected
+  List<CertExtension> dco_decode_list_cert_extension(dynamic raw);
+
+  @protected
+  List<CertKeyUsage> dco_decode_list_cert_key_usage(dynamic raw);
+
+  @protected
+  List<CwtCustomClaim> dco_decode_list_cwt_custom_claim(dynamic raw);
+
+  @protected
+  List<EatMeasurement> dco_decode_list_eat_measurement(dynamic raw);
+
+  @protected
+  List<EatSubmodule> dco_decode_list_eat_submodule(dynamic raw);
+
+  @protected
+  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_loose(dynamic raw);
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  CddlSchema?
+  dco_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    dynamic raw,
+  );
+
+  @protected
+  StatusList?
+  dco_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    dynamic raw,
+  );
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CwtConfirmKey? dco_decode_opt_box_autoadd_cwt_confirm_key(dynamic raw);
+
+  @protected
+  CwtConfirmKeyType? dco_decode_opt_box_autoadd_cwt_confirm_key_type(
+    dynamic raw,
+  );
+
+  @protected
+  CwtStatus? dco_decode_opt_box_autoadd_cwt_status(dynamic raw);
+
+  @protected
+  EatDebugState? dco_decode_opt_box_autoadd_eat_debug_state(dynamic raw);
+
+  @protected
+  EatOemid? dco_decode_opt_box_autoadd_eat_oemid(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  (StreamEncryptor, Uint8List)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_stream_encryptor_list_prim_u_8_strict(
+    dynamic raw,
+  );
+
+  @protected
+  (XdsaPublicKey, BigInt, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64(
+    dynamic raw,
+  );
+
+  @protected
+  (XhpkePublicKey, BigInt, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64(
+    dynamic raw,
+  );
+
+  @protected
+  (XhpkeSender, Uint8List)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_sender_list_prim_u_8_strict(
+    dynamic raw,
+  );
+
+  @protected
+  (Uint8List, Uint8List)
+  dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  SdCwt dco_decode_sd_cwt(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  CddlSchema
+  sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StatusList
+  sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  );
 
   @protected
   XdsaPublicKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+  sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
     SseDeserializer deserializer,
   );
 
   @protected
   XdsaSecretKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+  sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhpkePublicKey
+  sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CddlSchema
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RsaFingerprint
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RsaPublicKey
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RsaSecretKey
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RsaSignature
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StatusList
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StreamDecryptor
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StreamEncryptor
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StreamReader
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XdsaFingerprint
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XdsaPublicKey
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XdsaSecretKey
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
     SseDeserializer deserializer,
   );
 
   @protected
   XdsaSignature
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
     SseDeserializer deserializer,
   );
 
   @protected
   XhpkeFingerprint
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
     SseDeserializer deserializer,
   );
 
   @protected
   XhpkePublicKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
     SseDeserializer deserializer,
   );
 
   @protected
   XhpkeReceiver
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
     SseDeserializer deserializer,
   );
 
   @protected
   XhpkeSecretKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
     SseDeserializer deserializer,
   );
 
   @protected
   XhpkeSender
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
     SseDeserializer deserializer,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  StatusList
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+  StreamDecryptor
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+  StreamEncryptor
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  StreamReader
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  XhpkeReceiver
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+  XhpkeSender
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+  CddlSchema
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  (XdsaPublicKey, BigInt, BigInt)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64(
+  RsaFingerprint
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
     SseDeserializer deserializer,
   );
 
   @protected
-  (XhpkePublicKey, BigInt, BigInt)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64(
+  RsaPublicKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
     SseDeserializer deserializer,
   );
 
   @protected
-  (XhpkeSender, Uint8List)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_sender_list_prim_u_8_strict(
+  RsaSecretKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
     SseDeserializer deserializer,
   );
 
   @protected
-  (Uint8List, Uint8List)
-  sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(
+  RsaSignature
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
     SseDeserializer deserializer,
   );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  StatusList
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  StreamReader
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  XdsaFingerprint
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  XdsaPublicKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  XdsaSecretKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  XdsaSignature
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhpkeFingerprint
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhpkePublicKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhpkeSecretKey
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CddlSchema
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RsaFingerprint
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RsaPublicKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RsaSecretKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RsaSignature
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StatusList
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StreamDecryptor
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StreamEncryptor
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StreamReader
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XdsaFingerprint
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XdsaPublicKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XdsaSecretKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XdsaSignature
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhpkeFingerprint
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhpkePublicKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhpkeReceiver
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhpkeSecretKey
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhpkeSender
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<BigInt> sse_decode_StreamSink_u_64_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  Argon2Params sse_decode_argon2_params(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  Argon2Params sse_decode_box_autoadd_argon2_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CborValue sse_decode_box_autoadd_cbor_value(SseDeserializer deserializer);
+
+  @protected
+  CertName sse_decode_box_autoadd_cert_name(SseDeserializer deserializer);
+
+  @protected
+  CertTemplate sse_decode_box_autoadd_cert_template(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CwtClaims sse_decode_box_autoadd_cwt_claims(SseDeserializer deserializer);
+
+  @protected
+  CwtConfirmKey sse_decode_box_autoadd_cwt_confirm_key(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CwtConfirmKeyType sse_decode_box_autoadd_cwt_confirm_key_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CwtStatus sse_decode_box_autoadd_cwt_status(SseDeserializer deserializer);
+
+  @protected
+  CwtValidation sse_decode_box_autoadd_cwt_validation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EatDebugState sse_decode_box_autoadd_eat_debug_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EatOemid sse_decode_box_autoadd_eat_oemid(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  CwtClaims sse_decode_box_cwt_claims(SseDeserializer deserializer);
+
+  @protected
+  CborMapEntry sse_decode_cbor_map_entry(SseDeserializer deserializer);
+
+  @protected
+  CborValue sse_decode_cbor_value(SseDeserializer deserializer);
+
+  @protected
+  CertChain sse_decode_cert_chain(SseDeserializer deserializer);
+
+  @protected
+  CertExtension sse_decode_cert_extension(SseDeserializer deserializer);
+
+  @protected
+  CertInfo sse_decode_cert_info(SseDeserializer deserializer);
+
+  @protected
+  CertKeyType sse_decode_cert_key_type(SseDeserializer deserializer);
+
+  @protected
+  CertKeyUsage sse_decode_cert_key_usage(SseDeserializer deserializer);
+
+  @protected
+  CertName sse_decode_cert_name(SseDeserializer deserializer);
+
+  @protected
+  CertPublicKey sse_decode_cert_public_key(SseDeserializer deserializer);
+
+  @protected
+  CertTemplate sse_decode_cert_template(SseDeserializer deserializer);
+
+  @protected
+  CoseSign1Info sse_decode_cose_sign1_info(SseDeserializer deserializer);
+
+  @protected
+  CryptoError sse_decode_crypto_error(SseDeserializer deserializer);
+
+  @protected
+  CwtClaims sse_decode_cwt_claims(SseDeserializer deserializer);
+
+  @protected
+  CwtConfirmKey sse_decode_cwt_confirm_key(SseDeserializer deserializer);
+
+  @protected
+  CwtConfirmKeyType sse_decode_cwt_confirm_key_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CwtCustomClaim sse_decode_cwt_custom_claim(SseDeserializer deserializer);
+
+  @protected
+  CwtStatus sse_decode_cwt_status(SseDeserializer deserializer);
+
+  @protected
+  CwtValidation sse_decode_cwt_validation(SseDeserializer deserializer);
+
+  @protected
+  EatDebugState sse_decode_eat_debug_state(SseDeserializer deserializer);
+
+  @protected
+  EatMeasurement sse_decode_eat_measurement(SseDeserializer deserializer);
+
+  @protected
+  EatOemid sse_decode_eat_oemid(SseDeserializer deserializer);
+
+  @protected
+  EatSubmodule sse_decode_eat_submodule(SseDeserializer deserializer);
+
+  @protected
+  EatSubmoduleValue sse_decode_eat_submodule_value(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  List<XdsaPublicKey>
+  sse_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<XdsaSecretKey>
+  sse_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<XhpkePublicKey>
+  sse_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<XdsaFingerprint>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<XhpkeFingerprint>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<CborMapEntry> sse_decode_list_cbor_map_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CborValue> sse_decode_list_cbor_value(SseDeserializer deserializer);
+
+  @protected
+  List<CertExtension> sse_decode_list_cert_extension(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CertKeyUsage> sse_decode_list_cert_key_usage(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CwtCustomClaim> sse_decode_list_cwt_custom_claim(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EatMeasurement> sse_decode_list_eat_measurement(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EatSubmodule> sse_decode_list_eat_submodule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_loose(SseDeserializer deserializer);
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  CddlSchema?
+  sse_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StatusList?
+  sse_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CwtConfirmKey? sse_decode_opt_box_autoadd_cwt_confirm_key(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CwtConfirmKeyType? sse_decode_opt_box_autoadd_cwt_confirm_key_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CwtStatus? sse_decode_opt_box_autoadd_cwt_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EatDebugState? sse_decode_opt_box_autoadd_eat_debug_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EatOemid? sse_decode_opt_box_autoadd_eat_oemid(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  (StreamEncryptor, Uint8List)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_stream_encryptor_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (XdsaPublicKey, BigInt, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xdsa_public_key_u_64_u_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (XhpkePublicKey, BigInt, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_public_key_u_64_u_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (XhpkeSender, Uint8List)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_xhpke_sender_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (Uint8List, Uint8List)
+  sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SdCwt sse_decode_sd_cwt(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    CddlSchema self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    XdsaPublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    XdsaSecretKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    XhpkePublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    CddlSchema self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    RsaFingerprint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    RsaPublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    RsaSecretKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    RsaSignature self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    StreamDecryptor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    StreamEncryptor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    StreamReader self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    XdsaFingerprint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    XdsaPublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    XdsaSecretKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    XdsaSignature self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    XhpkeFingerprint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    XhpkePublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    XhpkeReceiver self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    XhpkeSecretKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    XhpkeSender self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    StreamDecryptor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    StreamEncryptor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    StreamReader self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    XhpkeReceiver self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    XhpkeSender self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    CddlSchema self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+    RsaFingerprint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    RsaPublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    RsaSecretKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    RsaSignature self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    StreamReader self,
+    SseSerializer serializer,
+  );
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    XdsaFingerprint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    XdsaPublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    XdsaSecretKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    XdsaSignature self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    XhpkeFingerprint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    XhpkePublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    XhpkeSecretKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    CddlSchema self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
     RsaFingerprint self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    RsaPublicKey self,
-    SseSerializer serializer,
-  );
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
+    RsaPublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
+    RsaSecretKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
+    RsaSignature self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    StreamDecryptor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    StreamEncryptor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    StreamReader self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    XdsaFingerprint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    XdsaPublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    XdsaSecretKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
+    XdsaSignature self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    XhpkeFingerprint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    XhpkePublicKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
+    XhpkeReceiver self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
+    XhpkeSecretKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
+    XhpkeSender self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_u_64_Sse(
+    RustStreamSink<BigInt> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_argon2_params(Argon2Params self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_argon2_params(
+    Argon2Params self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_cbor_value(
+    CborValue self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cert_name(
+    CertName self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cert_template(
+    CertTemplate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cwt_claims(
+    CwtClaims self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cwt_confirm_key(
+    CwtConfirmKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cwt_confirm_key_type(
+    CwtConfirmKeyType self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cwt_status(
+    CwtStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cwt_validation(
+    CwtValidation self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_eat_debug_state(
+    EatDebugState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_eat_oemid(
+    EatOemid self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_cwt_claims(CwtClaims self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cbor_map_entry(CborMapEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cbor_value(CborValue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cert_chain(CertChain self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cert_extension(CertExtension self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cert_info(CertInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cert_key_type(CertKeyType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cert_key_usage(CertKeyUsage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cert_name(CertName self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cert_public_key(CertPublicKey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cert_template(CertTemplate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cose_sign1_info(CoseSign1Info self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_crypto_error(CryptoError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cwt_claims(CwtClaims self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cwt_confirm_key(CwtConfirmKey self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    RsaSecretKey self,
+  void sse_encode_cwt_confirm_key_type(
+    CwtConfirmKeyType self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    RsaSignature self,
+  void sse_encode_cwt_custom_claim(
+    CwtCustomClaim self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    XdsaFingerprint self,
-    SseSerializer serializer,
-  );
+  void sse_encode_cwt_status(CwtStatus self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    XdsaPublicKey self,
-    SseSerializer serializer,
-  );
+  void sse_encode_cwt_validation(CwtValidation self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    XdsaSecretKey self,
-    SseSerializer serializer,
-  );
+  void sse_encode_eat_debug_state(EatDebugState self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    XdsaSignature self,
+  void sse_encode_eat_measurement(
+    EatMeasurement self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    XhpkeFingerprint self,
-    SseSerializer serializer,
-  );
+  void sse_encode_eat_oemid(EatOemid self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    XhpkePublicKey self,
-    SseSerializer serializer,
-  );
+  void sse_encode_eat_submodule(EatSubmodule self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    XhpkeReceiver self,
+  void sse_encode_eat_submodule_value(
+    EatSubmoduleValue self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    XhpkeSecretKey self,
-    SseSerializer serializer,
-  );
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    XhpkeSender self,
+  sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
+    List<XdsaPublicKey> self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    XhpkeReceiver self,
+  sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
+    List<XdsaSecretKey> self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    XhpkeSender self,
+  sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
+    List<XhpkePublicKey> self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    RsaFingerprint self,
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
+    List<XdsaFingerprint> self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    RsaPublicKey self,
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
+    List<XhpkeFingerprint> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    RsaSecretKey self,
-    SseSerializer serializer,
-  );
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    RsaSignature self,
+  void sse_encode_list_cbor_map_entry(
+    List<CborMapEntry> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    XdsaFingerprint self,
+  void sse_encode_list_cbor_value(
+    List<CborValue> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    XdsaPublicKey self,
+  void sse_encode_list_cert_extension(
+    List<CertExtension> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    XdsaSecretKey self,
+  void sse_encode_list_cert_key_usage(
+    List<CertKeyUsage> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    XdsaSignature self,
+  void sse_encode_list_cwt_custom_claim(
+    List<CwtCustomClaim> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    XhpkeFingerprint self,
+  void sse_encode_list_eat_measurement(
+    List<EatMeasurement> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    XhpkePublicKey self,
+  void sse_encode_list_eat_submodule(
+    List<EatSubmodule> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    XhpkeSecretKey self,
+  void sse_encode_list_list_prim_u_8_strict(
+    List<Uint8List> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
-    RsaFingerprint self,
+  void sse_encode_list_prim_i_64_loose(
+    Int64List self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaPublicKey(
-    RsaPublicKey self,
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSecretKey(
-    RsaSecretKey self,
-    SseSerializer serializer,
-  );
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaSignature(
-    RsaSignature self,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
-    XdsaFingerprint self,
+  sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    CddlSchema? self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaPublicKey(
-    XdsaPublicKey self,
+  sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    StatusList? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSecretKey(
-    XdsaSecretKey self,
-    SseSerializer serializer,
-  );
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaSignature(
-    XdsaSignature self,
-    SseSerializer serializer,
-  );
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeFingerprint(
-    XhpkeFingerprint self,
+  void sse_encode_opt_box_autoadd_cwt_confirm_key(
+    CwtConfirmKey? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkePublicKey(
-    XhpkePublicKey self,
+  void sse_encode_opt_box_autoadd_cwt_confirm_key_type(
+    CwtConfirmKeyType? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeReceiver(
-    XhpkeReceiver self,
+  void sse_encode_opt_box_autoadd_cwt_status(
+    CwtStatus? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSecretKey(
-    XhpkeSecretKey self,
+  void sse_encode_opt_box_autoadd_eat_debug_state(
+    EatDebugState? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXhpkeSender(
-    XhpkeSender self,
+  void sse_encode_opt_box_autoadd_eat_oemid(
+    EatOemid? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_stream_encryptor_list_prim_u_8_strict(
+    (StreamEncryptor, Uint8List) self,
+    SseSerializer serializer,
+  );
 
   @protected
   void
@@ -1003,6 +2272,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_sd_cwt(SdCwt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -1027,6 +2299,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
     int ptr,
@@ -1091,6 +2379,70 @@ class RustLibWire implements BaseWire {
         ptr,
       );
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
     int ptr,
@@ -1242,6 +2594,16 @@ external RustLibWasmModule get wasmModule;
 @JS()
 @anonymous
 extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
     int ptr,
@@ -1282,6 +2644,46 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
     int ptr,
@@ -27,3 +27,52 @@ Uint8List encrypt({required Uint8List key, required Uint8List plaintext}) =>
 /// [key] must **never** be repeated across multiple streams.
 Uint8List decrypt({required Uint8List key, required Uint8List ciphertext}) =>
     ffi.streamDecrypt(key: key, ciphertext: ciphertext);
+
+/// Encrypts a [plaintext] byte stream under the given [key], emitting
+/// ciphertext as soon as each 64KiB chunk fills up.
+///
+/// The output is identical to [encrypt] over the concatenated input.
+///
+/// [key] must **never** be repeated across multiple streams.
+Stream<Uint8List> encryptStream({
+  required Uint8List key,
+  required Stream<List<int>> plaintext,
+}) async* {
+  final encryptor = ffi.StreamEncryptor(key: key);
+  try {
+    await for (final chunk in plaintext) {
+      final ciphertext = encryptor.push(chunk: chunk);
+      if (ciphertext.isNotEmpty) {
+        yield ciphertext;
+      }
+    }
+    yield encryptor.finish();
+  } finally {
+    encryptor.dispose();
+  }
+}
+
+/// Decrypts a [ciphertext] byte stream under the given [key], emitting the
+/// plaintext of each 64KiB chunk once it has been authenticated.
+///
+/// The final chunk is only emitted after the input stream closes; if it was
+/// truncated, the returned stream ends with an error instead.
+///
+/// [key] must **never** be repeated across multiple streams.
+Stream<Uint8List> decryptStream({
+  required Uint8List key,
+  required Stream<List<int>> ciphertext,
+}) async* {
+  final decryptor = ffi.StreamDecryptor(key: key);
+  try {
+    await for (final chunk in ciphertext) {
+      final plaintext = decryptor.push(chunk: chunk);
+      if (plaintext.isNotEmpty) {
+        yield plaintext;
+      }
+    }
+    yield decryptor.finish();
+  } finally {
+    decryptor.dispose();
+  }
+}
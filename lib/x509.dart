@@ -0,0 +1,91 @@
+// crypto-fl: cryptography primitives and wrappers
+// Copyright 2026 Dark Bio AG. All rights reserved.
+//
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+/// X.509 certificate templates for issuing xDSA and xHPKE certificates,
+/// inspection, and chain verification against trusted roots.
+///
+/// https://datatracker.ietf.org/doc/html/rfc5280
+library;
+
+import 'dart:typed_data';
+
+import 'src/generated/api/x509.dart' as ffi;
+import 'xdsa.dart' as xdsa show PublicKey, PublicKeyInternal;
+import 'xhpke.dart' as xhpke show PublicKey, PublicKeyInternal;
+
+export 'src/generated/api/x509.dart'
+    show
+        CertTemplate,
+        CertName,
+        CertKeyUsage,
+        CertExtension,
+        CertInfo,
+        CertKeyType;
+
+/// VerifiedChain is a certificate path validated up to a trusted root, typed
+/// by the kind of key the leaf certifies.
+sealed class VerifiedChain {
+  /// DER-encoded certificates of the path, from the leaf to the root.
+  final List<Uint8List> path;
+
+  const VerifiedChain._(this.path);
+}
+
+/// XdsaChain is a verified chain whose leaf certifies an xDSA signing key.
+final class XdsaChain extends VerifiedChain {
+  /// The leaf certificate's subject key.
+  final xdsa.PublicKey leafKey;
+
+  const XdsaChain._(this.leafKey, super.path) : super._();
+}
+
+/// XhpkeChain is a verified chain whose leaf certifies an xHPKE encryption
+/// key.
+final class XhpkeChain extends VerifiedChain {
+  /// The leaf certificate's subject key.
+  final xhpke.PublicKey leafKey;
+
+  const XhpkeChain._(this.leafKey, super.path) : super._();
+}
+
+/// Verifies a certificate chain from a leaf up to one of the trusted roots.
+///
+/// Returns an [XdsaChain] or an [XhpkeChain] depending on the leaf key, along
+/// with the DER-encoded path from the leaf to the root.
+///
+/// - [leaf]: The DER-encoded end-entity certificate
+/// - [intermediates]: DER-encoded intermediate CA certificates, in any order
+/// - [roots]: DER-encoded self-signed xDSA root certificates to trust
+/// - [now]: Unix timestamp to check validity at (defaults to the current time)
+VerifiedChain verifyChain({
+  required Uint8List leaf,
+  List<Uint8List> intermediates = const [],
+  required List<Uint8List> roots,
+  int? now,
+}) {
+  final chain = ffi.x509VerifyChain(
+    leaf: leaf,
+    intermediates: intermediates,
+    roots: roots,
+    now: now != null ? BigInt.from(now) : null,
+  );
+  return switch (chain.leafKey) {
+    ffi.CertPublicKey_Xdsa(:final field0) => XdsaChain._(
+      xdsa.PublicKeyInternal.wrap(field0),
+      chain.path,
+    ),
+    ffi.CertPublicKey_Xhpke(:final field0) => XhpkeChain._(
+      xhpke.PublicKeyInternal.wrap(field0),
+      chain.path,
+    ),
+  };
+}
+
+/// Parses a DER or PEM encoded certificate without verifying its signature.
+///
+/// **Warning**: The returned content is unauthenticated and should not be
+/// trusted until the certificate is verified with [verifyChain].
+ffi.CertInfo parse(Uint8List cert) => ffi.x509Parse(cert: cert);
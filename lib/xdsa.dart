@@ -12,6 +12,7 @@ library;
 import 'dart:typed_data';
 
 import 'src/generated/api/xdsa.dart' as ffi;
+import 'x509.dart' as x509;
 
 /// An ML-DSA-65 private key paired with an Ed25519 private key for creating
 /// and verifying quantum resistant digital signatures.
@@ -76,13 +77,20 @@ class PublicKey {
   ///
   /// Returns a tuple of (key, notBefore, notAfter) where notBefore and notAfter
   /// are Unix timestamps in seconds defining the certificate validity period.
+  ///
+  /// If [now] is given, the certificate must be valid at that Unix timestamp,
+  /// tolerating [leeway] seconds of clock skew at both ends.
   static (PublicKey, BigInt, BigInt) fromCertDer(
     Uint8List der, {
     required PublicKey signer,
+    int? now,
+    int leeway = 0,
   }) {
     final (key, notBefore, notAfter) = ffi.XdsaPublicKey.fromCertDer(
       der: der,
       signer: signer._inner,
+      now: now != null ? BigInt.from(now) : null,
+      leewaySecs: BigInt.from(leeway),
     );
     return (PublicKey._(key), notBefore, notAfter);
   }
@@ -91,13 +99,20 @@ class PublicKey {
   ///
   /// Returns a tuple of (key, notBefore, notAfter) where notBefore and notAfter
   /// are Unix timestamps in seconds defining the certificate validity period.
+  ///
+  /// If [now] is given, the certificate must be valid at that Unix timestamp,
+  /// tolerating [leeway] seconds of clock skew at both ends.
   static (PublicKey, BigInt, BigInt) fromCertPem(
     String pem, {
     required PublicKey signer,
+    int? now,
+    int leeway = 0,
   }) {
     final (key, notBefore, notAfter) = ffi.XdsaPublicKey.fromCertPem(
       pem: pem,
       signer: signer._inner,
+      now: now != null ? BigInt.from(now) : null,
+      leewaySecs: BigInt.from(leeway),
     );
     return (PublicKey._(key), notBefore, notAfter);
   }
@@ -119,60 +134,24 @@ class PublicKey {
   String toPem() => _inner.toPem();
 
   /// Generates a DER-encoded X.509 certificate for this public key, signed by
-  /// the given xDSA secret key with the specified validity period.
+  /// the given xDSA secret key.
   ///
   /// - [signer]: The xDSA secret key to sign the certificate
-  /// - [subjectName]: The subject's common name (CN)
-  /// - [issuerName]: The issuer's common name (CN)
-  /// - [notBefore]: Certificate validity start time (Unix timestamp)
-  /// - [notAfter]: Certificate validity end time (Unix timestamp)
-  /// - [isCa]: Whether this is a CA certificate
-  /// - [pathLen]: Maximum intermediate CAs allowed (only if isCa is true)
+  /// - [template]: The certificate's names, validity and extensions
   Uint8List toCertDer({
     required SecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
-  }) => _inner.toCertDer(
-    signer: signer._inner,
-    subjectName: subjectName,
-    issuerName: issuerName,
-    notBefore: notBefore,
-    notAfter: notAfter,
-    isCa: isCa,
-    pathLen: pathLen,
-  );
+    required x509.CertTemplate template,
+  }) => _inner.toCertDer(signer: signer._inner, template: template);
 
   /// Generates a PEM-encoded X.509 certificate for this public key, signed by
-  /// the given xDSA secret key with the specified validity period.
+  /// the given xDSA secret key.
   ///
   /// - [signer]: The xDSA secret key to sign the certificate
-  /// - [subjectName]: The subject's common name (CN)
-  /// - [issuerName]: The issuer's common name (CN)
-  /// - [notBefore]: Certificate validity start time (Unix timestamp)
-  /// - [notAfter]: Certificate validity end time (Unix timestamp)
-  /// - [isCa]: Whether this is a CA certificate
-  /// - [pathLen]: Maximum intermediate CAs allowed (only if isCa is true)
+  /// - [template]: The certificate's names, validity and extensions
   String toCertPem({
     required SecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
-  }) => _inner.toCertPem(
-    signer: signer._inner,
-    subjectName: subjectName,
-    issuerName: issuerName,
-    notBefore: notBefore,
-    notAfter: notAfter,
-    isCa: isCa,
-    pathLen: pathLen,
-  );
+    required x509.CertTemplate template,
+  }) => _inner.toCertPem(signer: signer._inner, template: template);
 }
 
 /// A composite ML-DSA-65 + Ed25519 digital signature (3373 bytes).
@@ -208,6 +187,7 @@ extension SecretKeyInternal on SecretKey {
 
 extension PublicKeyInternal on PublicKey {
   ffi.XdsaPublicKey get inner => _inner;
+  static PublicKey wrap(ffi.XdsaPublicKey inner) => PublicKey._(inner);
 }
 
 extension FingerprintInternal on Fingerprint {
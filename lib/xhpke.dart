@@ -16,6 +16,7 @@ import 'dart:typed_data';
 
 import 'src/generated/api/xhpke.dart' as ffi;
 import 'xdsa.dart' as xdsa;
+import 'x509.dart' as x509;
 
 /// A private key of the X-Wing hybrid KEM (X25519 + ML-KEM-768).
 class SecretKey {
@@ -112,13 +113,20 @@ class PublicKey {
   ///
   /// Returns a tuple of (key, notBefore, notAfter) where notBefore and notAfter
   /// are Unix timestamps in seconds defining the certificate validity period.
+  ///
+  /// If [now] is given, the certificate must be valid at that Unix timestamp,
+  /// tolerating [leeway] seconds of clock skew at both ends.
   static (PublicKey, BigInt, BigInt) fromCertDer(
     Uint8List der, {
     required xdsa.PublicKey signer,
+    int? now,
+    int leeway = 0,
   }) {
     final (key, notBefore, notAfter) = ffi.XhpkePublicKey.fromCertDer(
       der: der,
       signer: signer.inner,
+      now: now != null ? BigInt.from(now) : null,
+      leewaySecs: BigInt.from(leeway),
     );
     return (PublicKey._(key), notBefore, notAfter);
   }
@@ -128,13 +136,20 @@ class PublicKey {
   ///
   /// Returns a tuple of (key, notBefore, notAfter) where notBefore and notAfter
   /// are Unix timestamps in seconds defining the certificate validity period.
+  ///
+  /// If [now] is given, the certificate must be valid at that Unix timestamp,
+  /// tolerating [leeway] seconds of clock skew at both ends.
   static (PublicKey, BigInt, BigInt) fromCertPem(
     String pem, {
     required xdsa.PublicKey signer,
+    int? now,
+    int leeway = 0,
   }) {
     final (key, notBefore, notAfter) = ffi.XhpkePublicKey.fromCertPem(
       pem: pem,
       signer: signer.inner,
+      now: now != null ? BigInt.from(now) : null,
+      leewaySecs: BigInt.from(leeway),
     );
     return (PublicKey._(key), notBefore, notAfter);
   }
@@ -182,60 +197,28 @@ class PublicKey {
   String toPem() => _inner.toPem();
 
   /// Generates a DER-encoded X.509 certificate for this public key, signed by
-  /// the given xDSA secret key with the specified validity period.
+  /// the given xDSA secret key.
+  ///
+  /// xHPKE certificates are always end-entity certificates.
   ///
   /// - [signer]: The xDSA secret key to sign the certificate
-  /// - [subjectName]: The subject's common name (CN)
-  /// - [issuerName]: The issuer's common name (CN)
-  /// - [notBefore]: Certificate validity start time (Unix timestamp)
-  /// - [notAfter]: Certificate validity end time (Unix timestamp)
-  /// - [isCa]: Whether this is a CA certificate
-  /// - [pathLen]: Maximum intermediate CAs allowed (only if isCa is true)
+  /// - [template]: The certificate's names, validity and extensions
   Uint8List toCertDer({
     required xdsa.SecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
-  }) => _inner.toCertDer(
-    signer: signer.inner,
-    subjectName: subjectName,
-    issuerName: issuerName,
-    notBefore: notBefore,
-    notAfter: notAfter,
-    isCa: isCa,
-    pathLen: pathLen,
-  );
+    required x509.CertTemplate template,
+  }) => _inner.toCertDer(signer: signer.inner, template: template);
 
   /// Generates a PEM-encoded X.509 certificate for this public key, signed by
-  /// the given xDSA secret key with the specified validity period.
+  /// the given xDSA secret key.
+  ///
+  /// xHPKE certificates are always end-entity certificates.
   ///
   /// - [signer]: The xDSA secret key to sign the certificate
-  /// - [subjectName]: The subject's common name (CN)
-  /// - [issuerName]: The issuer's common name (CN)
-  /// - [notBefore]: Certificate validity start time (Unix timestamp)
-  /// - [notAfter]: Certificate validity end time (Unix timestamp)
-  /// - [isCa]: Whether this is a CA certificate
-  /// - [pathLen]: Maximum intermediate CAs allowed (only if isCa is true)
+  /// - [template]: The certificate's names, validity and extensions
   String toCertPem({
     required xdsa.SecretKey signer,
-    required String subjectName,
-    required String issuerName,
-    required BigInt notBefore,
-    required BigInt notAfter,
-    required bool isCa,
-    int? pathLen,
-  }) => _inner.toCertPem(
-    signer: signer.inner,
-    subjectName: subjectName,
-    issuerName: issuerName,
-    notBefore: notBefore,
-    notAfter: notAfter,
-    isCa: isCa,
-    pathLen: pathLen,
-  );
+    required x509.CertTemplate template,
+  }) => _inner.toCertPem(signer: signer.inner, template: template);
 }
 
 /// A 32-byte unique identifier for an xHPKE key.
@@ -292,6 +275,7 @@ extension SecretKeyInternal on SecretKey {
 
 extension PublicKeyInternal on PublicKey {
   ffi.XhpkePublicKey get inner => _inner;
+  static PublicKey wrap(ffi.XhpkePublicKey inner) => PublicKey._(inner);
 }
 
 extension FingerprintInternal on Fingerprint {
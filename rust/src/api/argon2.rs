@@ -27,3 +27,44 @@ pub fn argon2_key(
 ) -> Vec<u8> {
     darkbio_crypto::argon2::key_with_len(&password, &salt, time, memory, threads, key_length)
 }
+
+/// Argon2Params are the Argon2id cost parameters used for password hashing.
+pub struct Argon2Params {
+    /// Number of iterations
+    pub time: u32,
+    /// Memory size in KiB
+    pub memory: u32,
+    /// Degree of parallelism
+    pub threads: u32,
+}
+
+impl Argon2Params {
+    /// Returns the RFC 9106 second recommended option (t=3, m=64MiB, p=4),
+    /// suitable for memory constrained environments such as mobile devices.
+    #[frb(sync)]
+    pub fn recommended() -> Self {
+        Self {
+            time: 3,
+            memory: 64 * 1024,
+            threads: 4,
+        }
+    }
+}
+
+/// Checks that the parameters are accepted by Argon2id, returning the reason
+/// if they are not.
+pub(crate) fn check_params(params: &Argon2Params) -> Result<(), String> {
+    if params.time < 1 {
+        return Err("argon2 time must be at least 1".to_string());
+    }
+    if params.threads < 1 || params.threads > 0xFFFFFF {
+        return Err(format!("argon2 threads {} out of range", params.threads));
+    }
+    if (params.memory as u64) < 8 * params.threads as u64 {
+        return Err(format!(
+            "argon2 memory {}KiB below 8KiB per thread",
+            params.memory
+        ));
+    }
+    Ok(())
+}
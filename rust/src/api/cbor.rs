@@ -4,8 +4,12 @@
 // Use of this source code is governed by a BSD-style
 // license that can be found in the LICENSE file.
 
+use darkbio_crypto::cbor::{Decode, Decoder, Encoder, Error, Raw, cbor_key_cmp};
 use flutter_rust_bridge::frb;
 
+use super::cddl::CddlSchema;
+use super::error::CryptoError;
+
 /// Validates that the given bytes are well-formed CBOR.
 ///
 /// This performs strict validation including:
@@ -15,6 +19,539 @@ use flutter_rust_bridge::frb;
 ///
 /// Returns an error if the bytes are not valid CBOR.
 #[frb(sync)]
-pub fn cbor_verify(data: Vec<u8>) -> Result<(), String> {
-    darkbio_crypto::cbor::verify(&data).map_err(|e| e.to_string())
+pub fn cbor_verify(data: Vec<u8>) -> Result<(), CryptoError> {
+    darkbio_crypto::cbor::verify(&data).map_err(CryptoError::from)
+}
+
+/// CborValue is a CBOR data item within the restricted deterministic profile:
+/// booleans, null, integers, text and byte strings, arrays, and maps with
+/// integer keys.
+pub enum CborValue {
+    /// Null
+    Null,
+    /// Boolean
+    Bool(bool),
+    /// Integer in the i64 range
+    Int(i64),
+    /// Unsigned integer above the i64 range
+    Uint(u64),
+    /// UTF-8 text string
+    Text(String),
+    /// Byte string
+    Bytes(Vec<u8>),
+    /// Array of items
+    Array(Vec<CborValue>),
+    /// Map with integer keys
+    Map(Vec<CborMapEntry>),
+}
+
+/// CborMapEntry is a key-value pair of a CBOR map.
+pub struct CborMapEntry {
+    /// Integer key
+    pub key: i64,
+    /// Value of the entry
+    pub value: CborValue,
+}
+
+/// Encodes a value into deterministic CBOR, sorting map entries by key.
+///
+/// Returns an error on duplicate map keys or nesting beyond the profile's
+/// depth limit.
+#[frb(sync)]
+pub fn cbor_encode(value: CborValue) -> Result<Vec<u8>, CryptoError> {
+    let mut enc = Encoder::new();
+    encode_value(&mut enc, &value)?;
+
+    let data = enc.finish();
+    darkbio_crypto::cbor::verify(&data)?;
+    Ok(data)
+}
+
+/// Decodes deterministic CBOR into a value, rejecting anything outside the
+/// profile enforced by `cbor_verify`.
+#[frb(sync)]
+pub fn cbor_decode(data: Vec<u8>) -> Result<CborValue, CryptoError> {
+    darkbio_crypto::cbor::verify(&data)?;
+
+    let mut dec = Decoder::new(&data);
+    let value = decode_value(&mut dec)?;
+    dec.finish()?;
+    Ok(value)
+}
+
+/// Encodes a single value, recursing into arrays and maps.
+fn encode_value(enc: &mut Encoder, value: &CborValue) -> Result<(), CryptoError> {
+    match value {
+        CborValue::Null => enc.encode_null(),
+        CborValue::Bool(value) => enc.encode_bool(*value),
+        CborValue::Int(value) => enc.encode_int(*value),
+        CborValue::Uint(value) => enc.encode_uint(*value),
+        CborValue::Text(value) => enc.encode_text(value),
+        CborValue::Bytes(value) => enc.encode_bytes(value),
+        CborValue::Array(items) => {
+            enc.encode_array_header(items.len());
+            for item in items {
+                encode_value(enc, item)?;
+            }
+        }
+        CborValue::Map(entries) => {
+            let mut sorted: Vec<_> = entries.iter().collect();
+            sorted.sort_by(|a, b| cbor_key_cmp(a.key, b.key));
+            if let Some(pair) = sorted.windows(2).find(|pair| pair[0].key == pair[1].key) {
+                return Err(Error::DuplicateMapKey(pair[0].key).into());
+            }
+            enc.encode_map_header(sorted.len());
+            for entry in sorted {
+                enc.encode_int(entry.key);
+                encode_value(enc, &entry.value)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Decodes a single value, telling the types apart by the major type in the
+/// leading byte of the item.
+fn decode_value(dec: &mut Decoder<'_>) -> Result<CborValue, CryptoError> {
+    let raw = Raw::decode_cbor_notrail(dec)?;
+    let mut item = Decoder::new(&raw);
+
+    let value = match raw[0] >> 5 {
+        0 => match item.decode_uint()? {
+            value if value > i64::MAX as u64 => CborValue::Uint(value),
+            value => CborValue::Int(value as i64),
+        },
+        1 => CborValue::Int(item.decode_int()?),
+        2 => CborValue::Bytes(item.decode_bytes()?),
+        3 => CborValue::Text(item.decode_text()?),
+        4 => {
+            let len = item.decode_array_header()?;
+            let mut items = Vec::new();
+            for _ in 0..len {
+                items.push(decode_value(&mut item)?);
+            }
+            CborValue::Array(items)
+        }
+        5 => {
+            let len = item.decode_map_header()?;
+            let mut entries = Vec::new();
+            for _ in 0..len {
+                let key = item.decode_int()?;
+                let value = decode_value(&mut item)?;
+                entries.push(CborMapEntry { key, value });
+            }
+            CborValue::Map(entries)
+        }
+        _ if item.peek_null() => {
+            item.decode_null()?;
+            CborValue::Null
+        }
+        _ => CborValue::Bool(item.decode_bool()?),
+    };
+    item.finish()?;
+    Ok(value)
+}
+
+/// Validates CBOR data against a rule of a CDDL (RFC 8610) schema, reporting
+/// the path of the first mismatch, e.g. `$[3][0]`.
+///
+/// Compiles the schema on every call; use `CddlSchema` to reuse it.
+///
+/// - `data`: The CBOR data to validate
+/// - `cddl`: CDDL rule definitions
+/// - `rule`: Name of the rule the data must match
+#[frb(sync)]
+pub fn cbor_validate(data: Vec<u8>, cddl: String, rule: String) -> Result<(), CryptoError> {
+    CddlSchema::compile(cddl)?.validate(data, rule)
+}
+
+/// Maximum nesting depth accepted when parsing diagnostic notation, matching
+/// the limit of the CBOR profile.
+const MAX_DIAGNOSTIC_DEPTH: usize = 32;
+
+/// Renders CBOR as RFC 8949 §8 diagnostic notation, e.g. `{1: "abc", 2: h'0102'}`.
+///
+/// Returns an error if the bytes are not valid CBOR within the profile.
+#[frb(sync)]
+pub fn cbor_to_diagnostic(data: Vec<u8>) -> Result<String, CryptoError> {
+    let value = cbor_decode(data)?;
+
+    let mut out = String::new();
+    render_value(&mut out, &value);
+    Ok(out)
+}
+
+/// Parses RFC 8949 §8 diagnostic notation into deterministic CBOR.
+///
+/// Supports `null`, `true`, `false`, integers, `"text"` with JSON escapes,
+/// `h'hex'` byte strings, arrays and maps with integer keys. Map entries are
+/// sorted into deterministic order.
+#[frb(sync)]
+pub fn cbor_from_diagnostic(text: String) -> Result<Vec<u8>, CryptoError> {
+    let mut parser = DiagnosticParser {
+        input: text.as_bytes(),
+        pos: 0,
+    };
+    let value = parser.parse_value(MAX_DIAGNOSTIC_DEPTH)?;
+    parser.skip_whitespace();
+    if parser.pos != parser.input.len() {
+        return Err(parser.error("unexpected trailing characters"));
+    }
+    cbor_encode(value)
+}
+
+/// Renders a single value into diagnostic notation.
+fn render_value(out: &mut String, value: &CborValue) {
+    match value {
+        CborValue::Null => out.push_str("null"),
+        CborValue::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+        CborValue::Int(value) => out.push_str(&value.to_string()),
+        CborValue::Uint(value) => out.push_str(&value.to_string()),
+        CborValue::Text(value) => {
+            out.push('"');
+            for c in value.chars() {
+                match c {
+                    '"' => out.push_str("\\\""),
+                    '\\' => out.push_str("\\\\"),
+                    '\n' => out.push_str("\\n"),
+                    '\r' => out.push_str("\\r"),
+                    '\t' => out.push_str("\\t"),
+                    c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+                    c => out.push(c),
+                }
+            }
+            out.push('"');
+        }
+        CborValue::Bytes(value) => {
+            out.push_str("h'");
+            out.push_str(&hex::encode(value));
+            out.push('\'');
+        }
+        CborValue::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                render_value(out, item);
+            }
+            out.push(']');
+        }
+        CborValue::Map(entries) => {
+            out.push('{');
+            for (i, entry) in entries.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                out.push_str(&entry.key.to_string());
+                out.push_str(": ");
+                render_value(out, &entry.value);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// DiagnosticParser is a recursive descent parser for the subset of
+/// diagnostic notation covering the CBOR profile.
+struct DiagnosticParser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl DiagnosticParser<'_> {
+    /// Parses a single value, with `depth` bounding the remaining nesting.
+    fn parse_value(&mut self, depth: usize) -> Result<CborValue, CryptoError> {
+        if depth == 0 {
+            return Err(self.error("nesting too deep"));
+        }
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                if !self.consume(b']') {
+                    loop {
+                        items.push(self.parse_value(depth - 1)?);
+                        if self.consume(b']') {
+                            break;
+                        }
+                        self.expect(b',')?;
+                    }
+                }
+                Ok(CborValue::Array(items))
+            }
+            Some(b'{') => {
+                self.pos += 1;
+                let mut entries = Vec::new();
+                if !self.consume(b'}') {
+                    loop {
+                        self.skip_whitespace();
+                        let key = match self.parse_integer()? {
+                            CborValue::Int(key) => key,
+                            _ => return Err(self.error("map key out of range")),
+                        };
+                        self.expect(b':')?;
+                        let value = self.parse_value(depth - 1)?;
+                        entries.push(CborMapEntry { key, value });
+                        if self.consume(b'}') {
+                            break;
+                        }
+                        self.expect(b',')?;
+                    }
+                }
+                Ok(CborValue::Map(entries))
+            }
+            Some(b'"') => self.parse_text(),
+            Some(b'h') if self.input[self.pos..].starts_with(b"h'") => self.parse_bytes(),
+            Some(b'-' | b'0'..=b'9') => self.parse_integer(),
+            _ => {
+                for (word, value) in [
+                    ("null", CborValue::Null),
+                    ("true", CborValue::Bool(true)),
+                    ("false", CborValue::Bool(false)),
+                ] {
+                    if self.input[self.pos..].starts_with(word.as_bytes()) {
+                        self.pos += word.len();
+                        return Ok(value);
+                    }
+                }
+                Err(self.error("unexpected character"))
+            }
+        }
+    }
+
+    /// Parses a decimal integer, picking the value variant by its range.
+    fn parse_integer(&mut self) -> Result<CborValue, CryptoError> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits = std::str::from_utf8(&self.input[start..self.pos]).unwrap();
+        if let Ok(value) = digits.parse::<i64>() {
+            return Ok(CborValue::Int(value));
+        }
+        match digits.parse::<u64>() {
+            Ok(value) => Ok(CborValue::Uint(value)),
+            Err(_) => Err(self.error("invalid or out of range integer")),
+        }
+    }
+
+    /// Parses a double quoted text string with JSON style escapes.
+    fn parse_text(&mut self) -> Result<CborValue, CryptoError> {
+        self.pos += 1;
+
+        let mut text = Vec::new();
+        loop {
+            let c = self.next().ok_or_else(|| self.error("unterminated text"))?;
+            match c {
+                b'"' => break,
+                b'\\' => {
+                    let escape = self.next().ok_or_else(|| self.error("unterminated text"))?;
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.parse_unicode_escape()?,
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    text.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                c => text.push(c),
+            }
+        }
+        // The input is a str and escapes are pushed as UTF-8, so this holds
+        Ok(CborValue::Text(String::from_utf8(text).unwrap()))
+    }
+
+    /// Parses the hex digits of a `\u` escape, joining surrogate pairs.
+    fn parse_unicode_escape(&mut self) -> Result<char, CryptoError> {
+        let high = self.parse_hex4()?;
+        let code = if (0xd800..0xdc00).contains(&high) {
+            if !self.input[self.pos..].starts_with(b"\\u") {
+                return Err(self.error("unpaired surrogate"));
+            }
+            self.pos += 2;
+            let low = self.parse_hex4()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(self.error("unpaired surrogate"));
+            }
+            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    /// Parses four hex digits into a code unit.
+    fn parse_hex4(&mut self) -> Result<u32, CryptoError> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(digits)
+    }
+
+    /// Parses an `h'...'` byte string, ignoring whitespace between digits.
+    fn parse_bytes(&mut self) -> Result<CborValue, CryptoError> {
+        self.pos += 2;
+
+        let start = self.pos;
+        let end = self.input[start..]
+            .iter()
+            .position(|&c| c == b'\'')
+            .map(|i| start + i)
+            .ok_or_else(|| self.error("unterminated byte string"))?;
+
+        let digits: Vec<u8> = self.input[start..end]
+            .iter()
+            .copied()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect();
+        let bytes = hex::decode(digits).map_err(|_| self.error("invalid hex in byte string"))?;
+
+        self.pos = end + 1;
+        Ok(CborValue::Bytes(bytes))
+    }
+
+    /// Skips whitespace, and consumes `c` if it is the next character.
+    fn consume(&mut self, c: u8) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    /// Skips whitespace, and consumes `c` or fails if something else follows.
+    fn expect(&mut self, c: u8) -> Result<(), CryptoError> {
+        if !self.consume(c) {
+            return Err(self.error(&format!("expected '{}'", c as char)));
+        }
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    /// Creates a parse error pointing at the current position.
+    fn error(&self, details: &str) -> CryptoError {
+        CryptoError::CborInvalid {
+            details: format!("diagnostic notation: {details} at offset {}", self.pos),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_roundtrip() {
+        let cases = [
+            "null",
+            "true",
+            "false",
+            "0",
+            "-1",
+            "9223372036854775807",
+            "18446744073709551615",
+            "-9223372036854775808",
+            "\"\"",
+            "\"a\\\"b\\\\c\\nd\\u0001é\"",
+            "h''",
+            "h'00ff10'",
+            "[]",
+            "[1, [2, h'03'], \"x\"]",
+            "{}",
+            "{1: \"abc\", 2: {3: [true]}, -1: null}",
+        ];
+        for text in cases {
+            let data = cbor_from_diagnostic(text.to_string()).unwrap();
+            cbor_verify(data.clone()).unwrap();
+            assert_eq!(cbor_to_diagnostic(data).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn test_diagnostic_normalization() {
+        let cases = [
+            // Whitespace is dropped and map keys are sorted bytewise
+            (" { 2 : 1 ,-1:[ 1 ,2 ] } ", "{2: 1, -1: [1, 2]}"),
+            ("h'00 ff\n10'", "h'00ff10'"),
+            // Escapes are decoded and re-rendered canonically
+            ("\"\\/\\t\\u00e9\"", "\"/\\t\u{e9}\""),
+            ("\"\\ud83d\\ude00\"", "\"\u{1f600}\""),
+            ("\"\\b\"", "\"\\u0008\""),
+        ];
+        for (text, want) in cases {
+            let data = cbor_from_diagnostic(text.to_string()).unwrap();
+            assert_eq!(cbor_to_diagnostic(data).unwrap(), want);
+        }
+    }
+
+    #[test]
+    fn test_diagnostic_invalid() {
+        let cases = [
+            "",
+            "nul",
+            "1.5",
+            "-",
+            "18446744073709551616",
+            "[1, 2",
+            "[1 2]",
+            "{1: 2",
+            "{\"a\": 1}",
+            "{18446744073709551615: 1}",
+            "{1: 1, 1: 2}",
+            "\"abc",
+            "\"\\x\"",
+            "\"\\ud83d\"",
+            "\"\\ude00\"",
+            "h'0'",
+            "h'zz'",
+            "h'00",
+            "null null",
+        ];
+        for text in cases {
+            assert!(
+                matches!(
+                    cbor_from_diagnostic(text.to_string()),
+                    Err(CryptoError::CborInvalid { .. })
+                ),
+                "{text:?} accepted"
+            );
+        }
+        let deep = "[".repeat(MAX_DIAGNOSTIC_DEPTH + 1) + &"]".repeat(MAX_DIAGNOSTIC_DEPTH + 1);
+        assert!(cbor_from_diagnostic(deep).is_err());
+
+        // Rendering rejects data outside the profile
+        assert!(cbor_to_diagnostic(vec![0xf9, 0x3c, 0x00]).is_err());
+        assert!(cbor_to_diagnostic(vec![0x01, 0x02]).is_err());
+    }
 }
@@ -0,0 +1,1431 @@
+// crypto-fl: cryptography primitives and wrappers
+// Copyright 2026 Dark Bio AG. All rights reserved.
+//
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+use std::collections::{HashMap, HashSet};
+
+use flutter_rust_bridge::frb;
+
+use super::cbor::{CborMapEntry, CborValue, cbor_decode};
+use super::error::CryptoError;
+use crate::frb_generated::RustAutoOpaque;
+
+/// Maximum number of nested rule references followed while validating a
+/// single item, guarding against schemas that recurse without consuming data.
+const MAX_RULE_DEPTH: usize = 64;
+
+/// Maximum nesting of arrays, maps and parentheses in schema source, guarding
+/// the recursive descent parser against stack exhaustion.
+const MAX_NESTING_DEPTH: usize = 64;
+
+/// CddlSchema is a compiled CDDL (RFC 8610) schema for validating CBOR data
+/// within the restricted deterministic profile.
+///
+/// Supports the prelude types (`any`, `bool`, `int`, `uint`, `nint`, `tstr`,
+/// `bstr`, `nil` and aliases), literals, integer ranges, type choices, arrays,
+/// maps with integer keys, group rules and choices, occurrence indicators and
+/// the `.size` control. Generics, sockets, unwrapping and other controls are
+/// rejected at compile time.
+#[frb(opaque)]
+pub struct CddlSchema {
+    rules: HashMap<String, Rule>,
+}
+
+impl CddlSchema {
+    /// Compiles CDDL source into a schema.
+    ///
+    /// - `source`: CDDL rule definitions
+    #[frb(sync)]
+    pub fn compile(source: String) -> Result<Self, CryptoError> {
+        let mut parser = Parser {
+            tokens: tokenize(&source)?,
+            pos: 0,
+            depth: 0,
+        };
+        let mut rules = HashMap::new();
+        while parser.peek().is_some() {
+            let (name, rule) = parser.parse_rule()?;
+            if rules.insert(name.clone(), rule).is_some() {
+                return Err(schema_error(format!("duplicate rule {name}")));
+            }
+        }
+        let schema = Self { rules };
+        for rule in schema.rules.values() {
+            schema.check_refs(rule)?;
+        }
+        Ok(schema)
+    }
+
+    /// Validates CBOR data against a rule of the schema, reporting the path of
+    /// the first mismatch.
+    ///
+    /// - `data`: The CBOR data to validate
+    /// - `rule`: Name of the rule the data must match
+    #[frb(sync)]
+    pub fn validate(&self, data: Vec<u8>, rule: String) -> Result<(), CryptoError> {
+        let value = cbor_decode(data)?;
+        let ty = match self.rules.get(&rule) {
+            Some(Rule::Type(ty)) => ty,
+            Some(Rule::Group(_)) => {
+                return Err(schema_error(format!("rule {rule} is a group, not a type")));
+            }
+            None => return Err(schema_error(format!("undefined rule {rule}"))),
+        };
+        self.match_type(ty, &value, "$", 0).map_err(|m| match m {
+            Mismatch::Data { path, details } => CryptoError::SchemaMismatch { path, details },
+            Mismatch::Schema(e) => e,
+        })
+    }
+}
+
+/// Validates data against a schema rule for the verify functions taking an
+/// optional schema. The schema and rule must be given together.
+pub(crate) fn check_schema(
+    data: &[u8],
+    schema: Option<RustAutoOpaque<CddlSchema>>,
+    rule: Option<String>,
+) -> Result<(), CryptoError> {
+    match (schema, rule) {
+        (None, None) => Ok(()),
+        (Some(schema), Some(rule)) => schema.blocking_read().validate(data.to_vec(), rule),
+        _ => Err(schema_error("schema and rule must be given together")),
+    }
+}
+
+/// Rule is the definition of a named CDDL rule.
+enum Rule {
+    /// Type rule, `name = type`
+    Type(Type),
+    /// Group rule, `name = (group)`
+    Group(Group),
+}
+
+/// Type is a choice between alternative types, `a / b / c`.
+struct Type(Vec<Type1>);
+
+/// Type1 is a single alternative of a type choice.
+enum Type1 {
+    Any,
+    Bool,
+    Nil,
+    Int,
+    Uint,
+    Nint,
+    Tstr,
+    Bstr,
+    LiteralInt(i128),
+    LiteralText(String),
+    LiteralBool(bool),
+    Range { min: i128, max: i128 },
+    Array(Group),
+    Map(Group),
+    Ref(String),
+    Sized { ty: Box<Type1>, min: u64, max: u64 },
+}
+
+/// Group is a choice between alternative entry sequences, `a // b`.
+struct Group(Vec<Vec<Entry>>);
+
+/// Entry is a single group entry along with its occurrence bounds.
+struct Entry {
+    min: usize,
+    max: usize,
+    kind: EntryKind,
+}
+
+enum EntryKind {
+    /// Positional type, or a group reference if it names a group rule
+    Type(Type),
+    /// Map member with an exact key, `1: type`
+    Member { key: i128, value: Type },
+    /// Map member with a key type, `int => type`
+    Members { key: Type, value: Type },
+    /// Member with a text key, `name: type`, only usable as an array label
+    Named { name: String, value: Type },
+    /// Inline group, `(a, b)`
+    Group(Group),
+}
+
+/// SequenceState is a backtracking match of array items against a flattened
+/// entry sequence.
+struct SequenceState<'a> {
+    entries: &'a [(usize, usize, &'a Type)],
+    items: &'a [CborValue],
+    path: &'a str,
+    depth: usize,
+    /// Entry index and item position pairs already tried, which can only fail
+    /// when reached again
+    failed: HashSet<(usize, usize)>,
+}
+
+/// Mismatch is the reason a value failed to match, either a data mismatch at a
+/// path or an error in the schema itself.
+enum Mismatch {
+    Data { path: String, details: String },
+    Schema(CryptoError),
+}
+
+impl Mismatch {
+    /// Returns the nesting depth of a data mismatch, used to pick the most
+    /// specific failure among alternatives.
+    fn depth(&self) -> usize {
+        match self {
+            Self::Data { path, .. } => path.matches('[').count(),
+            Self::Schema(_) => usize::MAX,
+        }
+    }
+}
+
+fn mismatch(path: &str, details: impl Into<String>) -> Mismatch {
+    Mismatch::Data {
+        path: path.to_string(),
+        details: details.into(),
+    }
+}
+
+fn schema_error(details: impl Into<String>) -> CryptoError {
+    CryptoError::InvalidSchema {
+        details: details.into(),
+    }
+}
+
+/// Picks the most specific of a set of alternative failures.
+fn deepest(mut failures: Vec<Mismatch>, path: &str, details: &str) -> Mismatch {
+    match failures.len() {
+        0 => mismatch(path, details),
+        1 => failures.remove(0),
+        _ => {
+            let best = failures.iter().map(Mismatch::depth).max().unwrap();
+            let shallow = path.matches('[').count();
+            if best <= shallow {
+                return mismatch(path, details);
+            }
+            let index = failures.iter().position(|f| f.depth() == best).unwrap();
+            failures.swap_remove(index)
+        }
+    }
+}
+
+impl CddlSchema {
+    /// Checks that every rule referenced from a rule is defined.
+    fn check_refs(&self, rule: &Rule) -> Result<(), CryptoError> {
+        match rule {
+            Rule::Type(ty) => self.check_type_refs(ty),
+            Rule::Group(group) => self.check_group_refs(group),
+        }
+    }
+
+    fn check_type_refs(&self, ty: &Type) -> Result<(), CryptoError> {
+        for ty1 in &ty.0 {
+            self.check_type1_refs(ty1)?;
+        }
+        Ok(())
+    }
+
+    fn check_type1_refs(&self, ty1: &Type1) -> Result<(), CryptoError> {
+        match ty1 {
+            Type1::Ref(name) if !self.rules.contains_key(name) => {
+                Err(schema_error(format!("undefined rule {name}")))
+            }
+            Type1::Array(group) | Type1::Map(group) => self.check_group_refs(group),
+            Type1::Sized { ty, .. } => self.check_type1_refs(ty),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_group_refs(&self, group: &Group) -> Result<(), CryptoError> {
+        for entry in group.0.iter().flatten() {
+            match &entry.kind {
+                EntryKind::Type(ty) => self.check_type_refs(ty)?,
+                EntryKind::Member { value, .. } | EntryKind::Named { value, .. } => {
+                    self.check_type_refs(value)?
+                }
+                EntryKind::Members { key, value } => {
+                    self.check_type_refs(key)?;
+                    self.check_type_refs(value)?;
+                }
+                EntryKind::Group(group) => self.check_group_refs(group)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Matches a value against a type choice.
+    fn match_type(
+        &self,
+        ty: &Type,
+        value: &CborValue,
+        path: &str,
+        depth: usize,
+    ) -> Result<(), Mismatch> {
+        let mut failures = Vec::new();
+        for ty1 in &ty.0 {
+            match self.match_type1(ty1, value, path, depth) {
+                Ok(()) => return Ok(()),
+                Err(Mismatch::Schema(e)) => return Err(Mismatch::Schema(e)),
+                Err(failure) => failures.push(failure),
+            }
+        }
+        Err(deepest(failures, path, "no type choice matched"))
+    }
+
+    /// Matches a value against a single type alternative.
+    fn match_type1(
+        &self,
+        ty: &Type1,
+        value: &CborValue,
+        path: &str,
+        depth: usize,
+    ) -> Result<(), Mismatch> {
+        let ok = match (ty, value) {
+            (Type1::Any, _) => true,
+            (Type1::Bool, CborValue::Bool(_)) => true,
+            (Type1::Nil, CborValue::Null) => true,
+            (Type1::Int, CborValue::Int(_) | CborValue::Uint(_)) => true,
+            (Type1::Uint, CborValue::Int(v)) => *v >= 0,
+            (Type1::Uint, CborValue::Uint(_)) => true,
+            (Type1::Nint, CborValue::Int(v)) => *v < 0,
+            (Type1::Tstr, CborValue::Text(_)) => true,
+            (Type1::Bstr, CborValue::Bytes(_)) => true,
+            (Type1::LiteralBool(b), CborValue::Bool(v)) => b == v,
+            (Type1::LiteralText(t), CborValue::Text(v)) => t == v,
+            (Type1::LiteralInt(i), _) => int_value(value) == Some(*i),
+            (Type1::Range { min, max }, _) => {
+                int_value(value).is_some_and(|v| *min <= v && v <= *max)
+            }
+            (Type1::Sized { ty, min, max }, _) => {
+                self.match_type1(ty, value, path, depth)?;
+                // The size of an unsigned integer only bounds it from above
+                let (size, min) = match value {
+                    CborValue::Text(v) => (v.len() as u64, *min),
+                    CborValue::Bytes(v) => (v.len() as u64, *min),
+                    CborValue::Int(v) if *v >= 0 => (uint_size(*v as u64), 0),
+                    CborValue::Uint(v) => (uint_size(*v), 0),
+                    _ => return Err(mismatch(path, ".size applied to unsupported type")),
+                };
+                if size < min || size > *max {
+                    return Err(mismatch(
+                        path,
+                        format!("size {size} not within {min}..{max}"),
+                    ));
+                }
+                true
+            }
+            (Type1::Array(group), CborValue::Array(items)) => {
+                return self.match_array(group, items, path, depth);
+            }
+            (Type1::Map(group), CborValue::Map(entries)) => {
+                return self.match_map(group, entries, path, depth);
+            }
+            (Type1::Ref(name), _) => {
+                if depth >= MAX_RULE_DEPTH {
+                    return Err(Mismatch::Schema(schema_error(format!(
+                        "rule {name} recurses too deep"
+                    ))));
+                }
+                return match self.rules.get(name) {
+                    Some(Rule::Type(ty)) => self.match_type(ty, value, path, depth + 1),
+                    Some(Rule::Group(_)) => Err(Mismatch::Schema(schema_error(format!(
+                        "group rule {name} used as a type"
+                    )))),
+                    None => Err(Mismatch::Schema(schema_error(format!(
+                        "undefined rule {name}"
+                    )))),
+                };
+            }
+            _ => false,
+        };
+        match ok {
+            true => Ok(()),
+            false => Err(mismatch(path, format!("expected {}", describe(ty)))),
+        }
+    }
+
+    /// Matches array items against the entries of a group choice.
+    fn match_array(
+        &self,
+        group: &Group,
+        items: &[CborValue],
+        path: &str,
+        depth: usize,
+    ) -> Result<(), Mismatch> {
+        let mut failures = Vec::new();
+        for choice in &group.0 {
+            let mut entries = Vec::new();
+            if let Err(e) = self.flatten(choice, false, &mut entries, depth) {
+                return Err(Mismatch::Schema(e));
+            }
+            match self.match_sequence(&entries, items, path, depth) {
+                Ok(()) => return Ok(()),
+                Err(Mismatch::Schema(e)) => return Err(Mismatch::Schema(e)),
+                Err(failure) => failures.push(failure),
+            }
+        }
+        Err(deepest(failures, path, "no group choice matched"))
+    }
+
+    /// Matches array items against a flattened entry sequence, backtracking
+    /// over how many items each repeated entry consumes.
+    fn match_sequence(
+        &self,
+        entries: &[(usize, usize, &Type)],
+        items: &[CborValue],
+        path: &str,
+        depth: usize,
+    ) -> Result<(), Mismatch> {
+        let mut state = SequenceState {
+            entries,
+            items,
+            path,
+            depth,
+            failed: HashSet::new(),
+        };
+        self.match_sequence_from(&mut state, 0, 0)
+    }
+
+    /// Matches the items from `pos` onward against the entries from `index`
+    /// onward. Each entry first takes as many items as it can, then gives them
+    /// back one at a time until the remaining entries match too.
+    fn match_sequence_from(
+        &self,
+        state: &mut SequenceState<'_>,
+        index: usize,
+        pos: usize,
+    ) -> Result<(), Mismatch> {
+        let path = state.path;
+        let Some(&(min, max, ty)) = state.entries.get(index) else {
+            if pos < state.items.len() {
+                return Err(mismatch(&format!("{path}[{pos}]"), "unexpected array item"));
+            }
+            return Ok(());
+        };
+        let mut count = 0;
+        let mut skipped = None;
+        while count < max && pos + count < state.items.len() {
+            let item_path = format!("{path}[{}]", pos + count);
+            match self.match_type(ty, &state.items[pos + count], &item_path, state.depth) {
+                Ok(()) => count += 1,
+                Err(Mismatch::Schema(e)) => return Err(Mismatch::Schema(e)),
+                Err(failure) => {
+                    skipped = Some(failure);
+                    break;
+                }
+            }
+        }
+        if count < min {
+            return Err(skipped.unwrap_or_else(|| {
+                mismatch(&format!("{path}[{}]", pos + count), "missing array item")
+            }));
+        }
+        // Failures of later entries come first so that ties between equally
+        // deep failures report the furthest progress
+        let mut failures = Vec::new();
+        for taken in (min..=count).rev() {
+            if !state.failed.insert((index + 1, pos + taken)) {
+                continue;
+            }
+            match self.match_sequence_from(state, index + 1, pos + taken) {
+                Ok(()) => return Ok(()),
+                Err(Mismatch::Schema(e)) => return Err(Mismatch::Schema(e)),
+                Err(failure) => failures.push(failure),
+            }
+        }
+        failures.extend(skipped);
+        Err(deepest(failures, path, "no array entry matched"))
+    }
+
+    /// Flattens the positional entries of a group choice, inlining group
+    /// references and nested groups. Entries of optional groups become
+    /// individually optional.
+    fn flatten<'a>(
+        &'a self,
+        choice: &'a [Entry],
+        optional: bool,
+        out: &mut Vec<(usize, usize, &'a Type)>,
+        depth: usize,
+    ) -> Result<(), CryptoError> {
+        if depth >= MAX_RULE_DEPTH {
+            return Err(schema_error("group references recurse too deep"));
+        }
+        for entry in choice {
+            let inner = match &entry.kind {
+                EntryKind::Type(ty) => match self.group_ref(ty) {
+                    Some(group) => group,
+                    None => {
+                        let min = if optional { 0 } else { entry.min };
+                        out.push((min, entry.max, ty));
+                        continue;
+                    }
+                },
+                EntryKind::Group(group) => group,
+                EntryKind::Member { value, .. } | EntryKind::Named { value, .. } => {
+                    let min = if optional { 0 } else { entry.min };
+                    out.push((min, entry.max, value));
+                    continue;
+                }
+                EntryKind::Members { .. } => {
+                    return Err(schema_error("map member in array"));
+                }
+            };
+            let optional = match (entry.min, entry.max) {
+                (1, 1) => optional,
+                (0, 1) => true,
+                _ => return Err(schema_error("repeated groups are not supported")),
+            };
+            match inner.0.as_slice() {
+                [choice] => self.flatten(choice, optional, out, depth + 1)?,
+                _ => return Err(schema_error("nested group choices are not supported")),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the group a type names, if it is a bare reference to a group rule.
+    fn group_ref(&self, ty: &Type) -> Option<&Group> {
+        match ty.0.as_slice() {
+            // Undefined rules are reported when matched as a type
+            [Type1::Ref(name)] => match self.rules.get(name) {
+                Some(Rule::Group(group)) => Some(group),
+                Some(Rule::Type(_)) | None => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Matches map entries against the members of a group choice.
+    fn match_map(
+        &self,
+        group: &Group,
+        entries: &[CborMapEntry],
+        path: &str,
+        depth: usize,
+    ) -> Result<(), Mismatch> {
+        let mut failures = Vec::new();
+        for choice in &group.0 {
+            let mut members = Vec::new();
+            if let Err(e) = self.flatten_members(choice, false, &mut members, depth) {
+                return Err(Mismatch::Schema(e));
+            }
+            match self.match_members(&members, entries, path, depth) {
+                Ok(()) => return Ok(()),
+                Err(Mismatch::Schema(e)) => return Err(Mismatch::Schema(e)),
+                Err(failure) => failures.push(failure),
+            }
+        }
+        Err(deepest(failures, path, "no group choice matched"))
+    }
+
+    /// Matches map entries against flattened members, rejecting leftovers.
+    fn match_members(
+        &self,
+        members: &[(usize, usize, &EntryKind)],
+        entries: &[CborMapEntry],
+        path: &str,
+        depth: usize,
+    ) -> Result<(), Mismatch> {
+        let mut used = vec![false; entries.len()];
+        for &(min, max, kind) in members {
+            let mut count = 0;
+            match kind {
+                EntryKind::Member { key, value } => {
+                    if let Some(i) = entries.iter().position(|e| e.key as i128 == *key) {
+                        let entry_path = format!("{path}[{key}]");
+                        self.match_type(value, &entries[i].value, &entry_path, depth)?;
+                        used[i] = true;
+                        count = 1;
+                    }
+                    if count < min {
+                        return Err(mismatch(path, format!("missing key {key}")));
+                    }
+                }
+                EntryKind::Members { key, value } => {
+                    for (i, entry) in entries.iter().enumerate() {
+                        if used[i]
+                            || self
+                                .match_type(key, &CborValue::Int(entry.key), path, depth)
+                                .is_err()
+                        {
+                            continue;
+                        }
+                        let entry_path = format!("{path}[{}]", entry.key);
+                        self.match_type(value, &entry.value, &entry_path, depth)?;
+                        used[i] = true;
+                        count += 1;
+                    }
+                    if count < min {
+                        return Err(mismatch(
+                            path,
+                            format!(
+                                "{count} entries match {}, expected at least {min}",
+                                describe_type(key)
+                            ),
+                        ));
+                    }
+                    if count > max {
+                        return Err(mismatch(
+                            path,
+                            format!(
+                                "{count} entries match {}, expected at most {max}",
+                                describe_type(key)
+                            ),
+                        ));
+                    }
+                }
+                EntryKind::Type(_) | EntryKind::Group(_) | EntryKind::Named { .. } => {
+                    unreachable!()
+                }
+            }
+        }
+        if let Some(i) = used.iter().position(|used| !used) {
+            return Err(mismatch(
+                &format!("{path}[{}]", entries[i].key),
+                "unexpected map key",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Flattens the members of a group choice, inlining group references and
+    /// nested groups. Members of optional groups become individually optional.
+    fn flatten_members<'a>(
+        &'a self,
+        choice: &'a [Entry],
+        optional: bool,
+        out: &mut Vec<(usize, usize, &'a EntryKind)>,
+        depth: usize,
+    ) -> Result<(), CryptoError> {
+        if depth >= MAX_RULE_DEPTH {
+            return Err(schema_error("group references recurse too deep"));
+        }
+        for entry in choice {
+            let inner = match &entry.kind {
+                EntryKind::Member { .. } | EntryKind::Members { .. } => {
+                    let min = if optional { 0 } else { entry.min };
+                    out.push((min, entry.max, &entry.kind));
+                    continue;
+                }
+                EntryKind::Named { name, .. } => {
+                    return Err(schema_error(format!(
+                        "text key {name} is outside the CBOR profile"
+                    )));
+                }
+                EntryKind::Type(ty) => self
+                    .group_ref(ty)
+                    .ok_or_else(|| schema_error("map entry without a key"))?,
+                EntryKind::Group(group) => group,
+            };
+            let optional = match (entry.min, entry.max) {
+                (1, 1) => optional,
+                (0, 1) => true,
+                _ => return Err(schema_error("repeated groups are not supported")),
+            };
+            match inner.0.as_slice() {
+                [choice] => self.flatten_members(choice, optional, out, depth + 1)?,
+                _ => return Err(schema_error("nested group choices are not supported")),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the integer held by a value, if any.
+fn int_value(value: &CborValue) -> Option<i128> {
+    match value {
+        CborValue::Int(v) => Some(*v as i128),
+        CborValue::Uint(v) => Some(*v as i128),
+        _ => None,
+    }
+}
+
+/// Returns the number of bytes needed to hold an unsigned integer.
+fn uint_size(value: u64) -> u64 {
+    (64 - value.leading_zeros() as u64).div_ceil(8)
+}
+
+/// Describes a type alternative for mismatch messages.
+fn describe(ty: &Type1) -> String {
+    match ty {
+        Type1::Any => "any".to_string(),
+        Type1::Bool => "bool".to_string(),
+        Type1::Nil => "nil".to_string(),
+        Type1::Int => "int".to_string(),
+        Type1::Uint => "uint".to_string(),
+        Type1::Nint => "nint".to_string(),
+        Type1::Tstr => "tstr".to_string(),
+        Type1::Bstr => "bstr".to_string(),
+        Type1::LiteralInt(v) => v.to_string(),
+        Type1::LiteralText(v) => format!("{v:?}"),
+        Type1::LiteralBool(v) => v.to_string(),
+        Type1::Range { min, max } => format!("{min}..{max}"),
+        Type1::Array(_) => "array".to_string(),
+        Type1::Map(_) => "map".to_string(),
+        Type1::Ref(name) => name.clone(),
+        Type1::Sized { ty, .. } => describe(ty),
+    }
+}
+
+/// Describes a type choice for mismatch messages.
+fn describe_type(ty: &Type) -> String {
+    ty.0.iter().map(describe).collect::<Vec<_>>().join(" / ")
+}
+
+/// Token is a lexical element of CDDL source.
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i128),
+    Text(String),
+    Control(String),
+    Punct(&'static str),
+}
+
+/// Splits CDDL source into tokens, dropping whitespace and comments. Each
+/// token records whether whitespace preceded it, which tells occurrence
+/// bounds (`1*2`) apart from adjacent values.
+fn tokenize(source: &str) -> Result<Vec<(Token, bool)>, CryptoError> {
+    const PUNCTS: [&str; 17] = [
+        "...", "//", "=>", "..", "=", "/", "(", ")", "[", "]", "{", "}", ",", ":", "?", "*", "+",
+    ];
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    let mut spaced = true;
+    while pos < bytes.len() {
+        let c = bytes[pos];
+        if c.is_ascii_whitespace() {
+            pos += 1;
+            spaced = true;
+            continue;
+        }
+        if c == b';' {
+            while pos < bytes.len() && bytes[pos] != b'\n' {
+                pos += 1;
+            }
+            spaced = true;
+            continue;
+        }
+        let token = if c == b'"' {
+            let end = source[pos + 1..]
+                .find('"')
+                .ok_or_else(|| schema_error("unterminated text literal"))?;
+            let text = source[pos + 1..pos + 1 + end].to_string();
+            pos += end + 2;
+            Token::Text(text)
+        } else if c.is_ascii_digit()
+            || (c == b'-' && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit))
+        {
+            let start = pos;
+            pos += 1;
+            while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric()) {
+                pos += 1;
+            }
+            let literal = &source[start..pos];
+            let (negative, digits) = match literal.strip_prefix('-') {
+                Some(digits) => (true, digits),
+                None => (false, literal),
+            };
+            let value = match digits.strip_prefix("0x") {
+                Some(hex) => i128::from_str_radix(hex, 16),
+                None => digits.parse::<i128>(),
+            }
+            .map_err(|_| schema_error(format!("invalid integer {literal}")))?;
+            Token::Int(if negative { -value } else { value })
+        } else if c == b'.' && bytes.get(pos + 1).is_some_and(u8::is_ascii_alphabetic) {
+            let start = pos + 1;
+            pos = start;
+            while pos < bytes.len() && bytes[pos].is_ascii_alphanumeric() {
+                pos += 1;
+            }
+            Token::Control(source[start..pos].to_string())
+        } else if is_ident_start(c) {
+            let start = pos;
+            pos += 1;
+            while pos < bytes.len() {
+                let c = bytes[pos];
+                if is_ident_start(c) || c.is_ascii_digit() {
+                    pos += 1;
+                } else if (c == b'-' || c == b'.')
+                    && bytes
+                        .get(pos + 1)
+                        .is_some_and(|&n| is_ident_start(n) || n.is_ascii_digit())
+                {
+                    pos += 2;
+                } else {
+                    break;
+                }
+            }
+            Token::Ident(source[start..pos].to_string())
+        } else {
+            let punct = PUNCTS
+                .iter()
+                .find(|p| source[pos..].starts_with(*p))
+                .ok_or_else(|| {
+                    schema_error(format!(
+                        "unsupported character {:?}",
+                        source[pos..].chars().next().unwrap()
+                    ))
+                })?;
+            pos += punct.len();
+            Token::Punct(punct)
+        };
+        tokens.push((token, spaced));
+        spaced = false;
+    }
+    Ok(tokens)
+}
+
+fn is_ident_start(c: u8) -> bool {
+    c.is_ascii_alphabetic() || c == b'_' || c == b'@' || c == b'$'
+}
+
+/// Parser is a recursive descent parser over CDDL tokens.
+struct Parser {
+    tokens: Vec<(Token, bool)>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset).map(|(token, _)| token)
+    }
+
+    /// Returns whether the token at `offset` directly follows the previous one.
+    fn adjacent(&self, offset: usize) -> bool {
+        self.tokens
+            .get(self.pos + offset)
+            .is_some_and(|(_, spaced)| !spaced)
+    }
+
+    fn next(&mut self) -> Result<Token, CryptoError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .map(|(token, _)| token.clone())
+            .ok_or_else(|| schema_error("unexpected end of schema"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn consume(&mut self, punct: &'static str) -> bool {
+        if self.peek() == Some(&Token::Punct(punct)) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, punct: &'static str) -> Result<(), CryptoError> {
+        if !self.consume(punct) {
+            return Err(schema_error(format!(
+                "expected '{punct}', found {}",
+                self.peek()
+                    .map(|t| format!("{t:?}"))
+                    .unwrap_or_else(|| "end of schema".to_string())
+            )));
+        }
+        Ok(())
+    }
+
+    /// Runs a parser for a nested construct, bounding the nesting depth.
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, CryptoError>,
+    ) -> Result<T, CryptoError> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(schema_error("schema nests too deep"));
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Parses `name = type` or `name = (group)`.
+    fn parse_rule(&mut self) -> Result<(String, Rule), CryptoError> {
+        let name = match self.next()? {
+            Token::Ident(name) => name,
+            token => return Err(schema_error(format!("expected rule name, found {token:?}"))),
+        };
+        if self.peek() == Some(&Token::Punct("/")) || self.peek() == Some(&Token::Punct("//")) {
+            return Err(schema_error("choice extensions are not supported"));
+        }
+        self.expect("=")?;
+        if self.consume("(") {
+            let group = self.nested(Self::parse_group)?;
+            self.expect(")")?;
+            return Ok((name, Rule::Group(group)));
+        }
+        Ok((name, Rule::Type(self.parse_type()?)))
+    }
+
+    /// Parses a type choice, `type1 / type1 / ...`.
+    fn parse_type(&mut self) -> Result<Type, CryptoError> {
+        let mut choices = vec![self.parse_type1()?];
+        while self.consume("/") {
+            choices.push(self.parse_type1()?);
+        }
+        Ok(Type(choices))
+    }
+
+    /// Parses a type alternative with an optional range or control operator.
+    fn parse_type1(&mut self) -> Result<Type1, CryptoError> {
+        let ty = self.parse_type2()?;
+        let ty = self.parse_range(ty)?;
+        if let Some(Token::Control(control)) = self.peek() {
+            if control != "size" {
+                return Err(schema_error(format!("unsupported control .{control}")));
+            }
+            self.pos += 1;
+            let size = self.parse_type2()?;
+            let (min, max) = match self.parse_range(size)? {
+                Type1::LiteralInt(size) => (size, size),
+                Type1::Range { min, max } => (min, max),
+                _ => return Err(schema_error(".size needs an integer or range")),
+            };
+            let (Ok(min), Ok(max)) = (u64::try_from(min), u64::try_from(max)) else {
+                return Err(schema_error(".size bounds must be non-negative"));
+            };
+            return Ok(Type1::Sized {
+                ty: Box::new(ty),
+                min,
+                max,
+            });
+        }
+        Ok(ty)
+    }
+
+    /// Parses the upper bound of a range if one follows the given type.
+    fn parse_range(&mut self, ty: Type1) -> Result<Type1, CryptoError> {
+        let Some(Token::Punct(op @ (".." | "..."))) = self.peek() else {
+            return Ok(ty);
+        };
+        let inclusive = *op == "..";
+        self.pos += 1;
+        let (Type1::LiteralInt(min), Type1::LiteralInt(max)) = (ty, self.parse_type2()?) else {
+            return Err(schema_error("ranges must have integer bounds"));
+        };
+        let max = match inclusive {
+            true => max,
+            false => max
+                .checked_sub(1)
+                .ok_or_else(|| schema_error("range bound out of range"))?,
+        };
+        Ok(Type1::Range { min, max })
+    }
+
+    /// Parses a primary type: literal, name, array, map or parenthesized range.
+    fn parse_type2(&mut self) -> Result<Type1, CryptoError> {
+        match self.next()? {
+            Token::Int(value) => Ok(Type1::LiteralInt(value)),
+            Token::Text(value) => Ok(Type1::LiteralText(value)),
+            Token::Ident(name) => Ok(match name.as_str() {
+                "any" => Type1::Any,
+                "bool" => Type1::Bool,
+                "true" => Type1::LiteralBool(true),
+                "false" => Type1::LiteralBool(false),
+                "nil" | "null" => Type1::Nil,
+                "int" | "integer" => Type1::Int,
+                "uint" | "unsigned" => Type1::Uint,
+                "nint" => Type1::Nint,
+                "tstr" | "text" => Type1::Tstr,
+                "bstr" | "bytes" => Type1::Bstr,
+                "float" | "float16" | "float32" | "float64" | "undefined" | "tdate" | "time"
+                | "number" | "biguint" | "bignint" | "bigint" => {
+                    return Err(schema_error(format!(
+                        "type {name} is outside the CBOR profile"
+                    )));
+                }
+                _ => Type1::Ref(name),
+            }),
+            Token::Punct("[") => {
+                let group = self.nested(Self::parse_group)?;
+                self.expect("]")?;
+                Ok(Type1::Array(group))
+            }
+            Token::Punct("{") => {
+                let group = self.nested(Self::parse_group)?;
+                self.expect("}")?;
+                if let Some(EntryKind::Named { name, .. }) = group
+                    .0
+                    .iter()
+                    .flatten()
+                    .map(|e| &e.kind)
+                    .find(|k| matches!(k, EntryKind::Named { .. }))
+                {
+                    return Err(schema_error(format!(
+                        "text key {name} is outside the CBOR profile"
+                    )));
+                }
+                Ok(Type1::Map(group))
+            }
+            Token::Punct("(") => {
+                let ty = self.nested(Self::parse_type1)?;
+                self.expect(")")?;
+                Ok(ty)
+            }
+            token => Err(schema_error(format!("unexpected {token:?} in type"))),
+        }
+    }
+
+    /// Parses a group choice, `entries // entries // ...`.
+    fn parse_group(&mut self) -> Result<Group, CryptoError> {
+        let mut choices = vec![self.parse_group_entries()?];
+        while self.consume("//") {
+            choices.push(self.parse_group_entries()?);
+        }
+        Ok(Group(choices))
+    }
+
+    /// Parses a comma separated sequence of group entries.
+    fn parse_group_entries(&mut self) -> Result<Vec<Entry>, CryptoError> {
+        let mut entries = Vec::new();
+        loop {
+            match self.peek() {
+                None | Some(Token::Punct(")" | "]" | "}" | "//")) => break,
+                _ => {}
+            }
+            entries.push(self.parse_entry()?);
+            self.consume(",");
+        }
+        Ok(entries)
+    }
+
+    /// Parses a single group entry with its occurrence indicator.
+    fn parse_entry(&mut self) -> Result<Entry, CryptoError> {
+        let (min, max) = self.parse_occurrence()?;
+        if self.consume("(") {
+            let group = self.nested(Self::parse_group)?;
+            self.expect(")")?;
+            return Ok(Entry {
+                min,
+                max,
+                kind: EntryKind::Group(group),
+            });
+        }
+        // Bare words followed by a colon are text keys, not rule names
+        if let (Some(Token::Ident(name)), Some(Token::Punct(":"))) = (self.peek(), self.peek_at(1))
+        {
+            let name = name.clone();
+            self.pos += 2;
+            return Ok(Entry {
+                min,
+                max,
+                kind: EntryKind::Named {
+                    name,
+                    value: self.parse_type()?,
+                },
+            });
+        }
+        let first = self.parse_type1()?;
+        if self.consume("=>") {
+            return Ok(Entry {
+                min,
+                max,
+                kind: EntryKind::Members {
+                    key: Type(vec![first]),
+                    value: self.parse_type()?,
+                },
+            });
+        }
+        if self.consume(":") {
+            let Type1::LiteralInt(key) = first else {
+                return Err(schema_error("map keys must be integers"));
+            };
+            return Ok(Entry {
+                min,
+                max,
+                kind: EntryKind::Member {
+                    key,
+                    value: self.parse_type()?,
+                },
+            });
+        }
+        let mut choices = vec![first];
+        while self.consume("/") {
+            choices.push(self.parse_type1()?);
+        }
+        Ok(Entry {
+            min,
+            max,
+            kind: EntryKind::Type(Type(choices)),
+        })
+    }
+
+    /// Parses an optional occurrence indicator: `?`, `*`, `+`, `n*m`.
+    fn parse_occurrence(&mut self) -> Result<(usize, usize), CryptoError> {
+        if self.consume("?") {
+            return Ok((0, 1));
+        }
+        if self.consume("+") {
+            return Ok((1, usize::MAX));
+        }
+        let min = match (self.peek(), self.peek_at(1)) {
+            (Some(Token::Int(n)), Some(Token::Punct("*"))) if self.adjacent(1) => {
+                let n = *n;
+                self.pos += 1;
+                Some(n)
+            }
+            _ => None,
+        };
+        if self.consume("*") {
+            let max = match self.peek() {
+                Some(Token::Int(n)) if self.adjacent(0) => {
+                    let n = *n;
+                    self.pos += 1;
+                    Some(n)
+                }
+                _ => None,
+            };
+            let to_usize =
+                |n: i128| usize::try_from(n).map_err(|_| schema_error("invalid occurrence bound"));
+            return Ok((
+                min.map(to_usize).transpose()?.unwrap_or(0),
+                max.map(to_usize).transpose()?.unwrap_or(usize::MAX),
+            ));
+        }
+        Ok((1, 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cbor::cbor_from_diagnostic;
+    use super::*;
+
+    /// Validates diagnostic notation against the `root` rule of a schema.
+    fn validate(cddl: &str, diag: &str) -> Result<(), CryptoError> {
+        CddlSchema::compile(cddl.to_string())?.validate(
+            cbor_from_diagnostic(diag.to_string()).unwrap(),
+            "root".to_string(),
+        )
+    }
+
+    /// Asserts that each value matches the schema and each other does not.
+    fn check(cddl: &str, valid: &[&str], invalid: &[&str]) {
+        for diag in valid {
+            if let Err(e) = validate(cddl, diag) {
+                panic!("{cddl}: {diag} rejected: {e:?}");
+            }
+        }
+        for diag in invalid {
+            match validate(cddl, diag) {
+                Err(CryptoError::SchemaMismatch { .. }) => {}
+                other => panic!("{cddl}: {diag} not mismatched: {other:?}"),
+            }
+        }
+    }
+
+    /// Asserts that the schema fails to compile.
+    fn check_invalid_schema(cddl: &str) {
+        assert!(
+            matches!(
+                CddlSchema::compile(cddl.to_string()),
+                Err(CryptoError::InvalidSchema { .. })
+            ),
+            "{cddl} compiled"
+        );
+    }
+
+    /// Asserts that the schema compiles but fails when validating a value.
+    fn check_unsupported(cddl: &str, diag: &str) {
+        assert!(
+            matches!(validate(cddl, diag), Err(CryptoError::InvalidSchema { .. })),
+            "{cddl}: {diag} validated"
+        );
+    }
+
+    /// Returns the path of the mismatch reported for a value.
+    fn mismatch_path(cddl: &str, diag: &str) -> String {
+        match validate(cddl, diag) {
+            Err(CryptoError::SchemaMismatch { path, .. }) => path,
+            other => panic!("{cddl}: {diag} not mismatched: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_prelude_types() {
+        check("root = any", &["null", "1", "[h'00']"], &[]);
+        check("root = bool", &["true", "false"], &["null", "0"]);
+        check("root = nil", &["null"], &["false", "0"]);
+        check(
+            "root = int",
+            &["0", "-1", "18446744073709551615"],
+            &["\"1\""],
+        );
+        check("root = uint", &["0", "18446744073709551615"], &["-1"]);
+        check("root = nint", &["-1"], &["0", "1"]);
+        check("root = tstr", &["\"\"", "\"abc\""], &["h'00'"]);
+        check("root = bstr", &["h''", "h'00'"], &["\"\""]);
+        check("root = text", &["\"abc\""], &["1"]);
+        check("root = bytes", &["h'00'"], &["1"]);
+        check_invalid_schema("root = float");
+        check_invalid_schema("root = tdate");
+    }
+
+    #[test]
+    fn test_literals() {
+        check("root = 7", &["7"], &["8", "\"7\""]);
+        check("root = -7", &["-7"], &["7"]);
+        check("root = 0x10", &["16"], &["10"]);
+        check("root = \"abc\"", &["\"abc\""], &["\"abd\"", "h'616263'"]);
+        check("root = true", &["true"], &["false"]);
+        check_invalid_schema("root = \"abc");
+        check_invalid_schema("root = 1x");
+    }
+
+    #[test]
+    fn test_ranges() {
+        check("root = 1..3", &["1", "2", "3"], &["0", "4", "\"2\""]);
+        check("root = 1...3", &["1", "2"], &["0", "3"]);
+        check("root = -2..-1", &["-2", "-1"], &["0", "-3"]);
+        check(
+            "root = 0..18446744073709551615",
+            &["0", "18446744073709551615"],
+            &["-1"],
+        );
+        check_invalid_schema("root = 1..tstr");
+        check_invalid_schema("root = \"a\"..\"b\"");
+        check(
+            "root = 0...-170141183460469231731687303715884105727",
+            &[],
+            &["0", "-1"],
+        );
+        check_invalid_schema("root = 0..340282366920938463463374607431768211456");
+    }
+
+    #[test]
+    fn test_type_choices() {
+        check(
+            "root = int / tstr / nil",
+            &["1", "\"a\"", "null"],
+            &["true", "h'00'"],
+        );
+        check("root = 0 / (1..2)", &["0", "1", "2"], &["3"]);
+        check_invalid_schema("root = int / ");
+    }
+
+    #[test]
+    fn test_arrays() {
+        check("root = []", &["[]"], &["[1]", "{}"]);
+        check(
+            "root = [int, tstr]",
+            &["[1, \"a\"]"],
+            &["[1]", "[1, \"a\", 2]", "[\"a\", 1]"],
+        );
+        check(
+            "root = [label: int, other: tstr]",
+            &["[1, \"a\"]"],
+            &["[\"a\", 1]"],
+        );
+        check(
+            "root = [int, [bool, bstr]]",
+            &["[1, [true, h'00']]"],
+            &["[1, [true, \"a\"]]"],
+        );
+        check_invalid_schema("root = [int");
+        check_unsupported("root = [int => int]", "[]");
+    }
+
+    #[test]
+    fn test_occurrences() {
+        check("root = [? int]", &["[]", "[1]"], &["[1, 2]", "[\"a\"]"]);
+        check("root = [* int]", &["[]", "[1, 2, 3]"], &["[1, \"a\"]"]);
+        check("root = [+ int]", &["[1]", "[1, 2]"], &["[]"]);
+        check(
+            "root = [2*3 int]",
+            &["[1, 2]", "[1, 2, 3]"],
+            &["[1]", "[1, 2, 3, 4]"],
+        );
+        check("root = [*2 int]", &["[]", "[1, 2]"], &["[1, 2, 3]"]);
+        check("root = [2* int]", &["[1, 2]", "[1, 2, 3]"], &["[1]"]);
+        check(
+            "root = [1*1 int, * tstr]",
+            &["[1]", "[1, \"a\", \"b\"]"],
+            &["[]", "[\"a\"]"],
+        );
+        check_invalid_schema("root = [-1*2 int]");
+    }
+
+    #[test]
+    fn test_backtracking() {
+        check(
+            "root = [* int, int]",
+            &["[1]", "[1, 2, 3]"],
+            &["[]", "[\"a\"]", "[1, \"a\"]"],
+        );
+        check(
+            "root = [* int, 2*2 int, tstr]",
+            &["[1, 2, \"a\"]", "[1, 2, 3, 4, \"a\"]"],
+            &["[1, \"a\"]", "[1, 2]"],
+        );
+        check("root = [? int, int]", &["[1]", "[1, 2]"], &["[1, 2, 3]"]);
+        check(
+            "root = [* any, tstr, * int]",
+            &["[\"a\"]", "[1, \"a\", 2]", "[\"a\", \"b\", 1, 2]"],
+            &["[1, 2]"],
+        );
+
+        // Ambiguous repetitions stay tractable on long inputs
+        let long = format!("[{}]", vec!["1"; 200].join(", "));
+        check(
+            "root = [* int, * int, * int, * int, tstr]",
+            &[],
+            &[long.as_str()],
+        );
+    }
+
+    #[test]
+    fn test_maps() {
+        check("root = {}", &["{}"], &["{1: 1}", "[]"]);
+        check(
+            "root = {1: int, ? 2: tstr}",
+            &["{1: 1}", "{1: 1, 2: \"a\"}"],
+            &["{}", "{2: \"a\"}", "{1: \"a\"}", "{1: 1, 3: 1}"],
+        );
+        check(
+            "root = {* int => tstr}",
+            &["{}", "{1: \"a\", -1: \"b\"}"],
+            &["{1: 1}"],
+        );
+        check(
+            "root = {1: int, 2*3 uint => bool}",
+            &["{1: 1, 2: true, 3: false}"],
+            &[
+                "{1: 1, 2: true}",
+                "{1: 1, 2: true, 3: true, 4: true, 5: true}",
+            ],
+        );
+        check(
+            "root = {-1: int, -2: bstr}",
+            &["{-1: 1, -2: h'00'}"],
+            &["{-1: 1}"],
+        );
+        check_invalid_schema("root = {name: int}");
+        check_invalid_schema("root = {\"a\": int}");
+        check_unsupported("root = {int}", "{}");
+    }
+
+    #[test]
+    fn test_groups() {
+        let cddl = "
+            root = [header, * entry]
+            header = (version: 1, tstr)
+            entry = int / bstr
+        ";
+        check(
+            cddl,
+            &["[1, \"a\"]", "[1, \"a\", 2, h'00']"],
+            &["[2, \"a\"]", "[1]", "[1, \"a\", \"b\"]"],
+        );
+        check(
+            "root = {common, ? (3: int, 4: int)} common = (1: int, ? 2: tstr)",
+            &["{1: 1}", "{1: 1, 2: \"a\", 3: 3, 4: 4}"],
+            &["{2: \"a\"}", "{1: 1, 5: 5}"],
+        );
+        check(
+            "root = [int, ? (tstr, bool)]",
+            &["[1]", "[1, \"a\", true]"],
+            &["[1, 2]"],
+        );
+        check_unsupported("root = [* (int, int)]", "[]");
+        check_unsupported("root = [(int // tstr)]", "[]");
+        check_unsupported("root = grp grp = (int)", "1");
+    }
+
+    #[test]
+    fn test_group_choices() {
+        check(
+            "root = [int, int // tstr]",
+            &["[1, 2]", "[\"a\"]"],
+            &["[1]", "[\"a\", 1]"],
+        );
+        check(
+            "root = {1: int // 2: tstr}",
+            &["{1: 1}", "{2: \"a\"}"],
+            &["{1: 1, 2: \"a\"}", "{}"],
+        );
+    }
+
+    #[test]
+    fn test_size() {
+        check("root = bstr .size 2", &["h'0000'"], &["h'00'", "h'000000'"]);
+        check(
+            "root = tstr .size (1..3)",
+            &["\"a\"", "\"abc\""],
+            &["\"\"", "\"abcd\""],
+        );
+        check("root = uint .size 1", &["0", "255"], &["256", "-1"]);
+        check("root = uint .size (1..2)", &["0", "65535"], &["65536"]);
+        check_invalid_schema("root = bstr .size tstr");
+        check_invalid_schema("root = bstr .size -1");
+        check_invalid_schema("root = bstr .regexp \"a\"");
+    }
+
+    #[test]
+    fn test_references() {
+        check(
+            "root = node node = [int, * node]",
+            &["[1]", "[1, [2], [3, [4]]]"],
+            &["[1, 2]"],
+        );
+        check_invalid_schema("root = missing");
+        check_invalid_schema("root = missing .size 2");
+        check_invalid_schema("root = [* missing .size 2]");
+        check_invalid_schema("root = int root = tstr");
+        check_invalid_schema("root /= int");
+
+        // Rules recursing without consuming data are cut off
+        assert!(matches!(
+            validate("root = other other = root", "1"),
+            Err(CryptoError::InvalidSchema { .. })
+        ));
+        assert!(matches!(
+            validate("root = int", "1").and(
+                CddlSchema::compile("root = int".to_string())
+                    .unwrap()
+                    .validate(vec![0x01], "missing".to_string())
+            ),
+            Err(CryptoError::InvalidSchema { .. })
+        ));
+    }
+
+    #[test]
+    fn test_nesting_limit() {
+        let depth = MAX_NESTING_DEPTH;
+        let ok = format!("root = {}int{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(CddlSchema::compile(ok).is_ok());
+        for (open, close) in [("[", "]"), ("{", "}"), ("(", ")")] {
+            let deep = format!(
+                "root = {}int{}",
+                open.repeat(depth + 1),
+                close.repeat(depth + 1)
+            );
+            check_invalid_schema(&deep);
+        }
+        let deep = format!("root = [{}int{}]", "(".repeat(10_000), ")".repeat(10_000));
+        check_invalid_schema(&deep);
+    }
+
+    #[test]
+    fn test_mismatch_paths() {
+        assert_eq!(
+            mismatch_path("root = [* [int]]", "[[1], [\"a\"]]"),
+            "$[1][0]"
+        );
+        assert_eq!(mismatch_path("root = [int, tstr]", "[1, 2]"), "$[1]");
+        assert_eq!(mismatch_path("root = [* int]", "[1, \"a\"]"), "$[1]");
+        assert_eq!(mismatch_path("root = [int, int]", "[1]"), "$[1]");
+        assert_eq!(
+            mismatch_path("root = {1: [int]}", "{1: [\"a\"]}"),
+            "$[1][0]"
+        );
+        assert_eq!(mismatch_path("root = {1: int}", "{1: 1, 2: 2}"), "$[2]");
+    }
+}
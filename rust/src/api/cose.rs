@@ -4,10 +4,16 @@
 // Use of this source code is governed by a BSD-style
 // license that can be found in the LICENSE file.
 
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use darkbio_crypto::cbor::Cbor;
 use flutter_rust_bridge::frb;
 
+use super::cddl::{CddlSchema, check_schema};
+use super::error::{CryptoError, to_array};
 use super::xdsa::{XdsaFingerprint, XdsaPublicKey, XdsaSecretKey};
 use super::xhpke::{XhpkeFingerprint, XhpkePublicKey, XhpkeSecretKey};
+use crate::frb_generated::RustAutoOpaque;
 
 /// Creates a COSE_Sign1 signature with an embedded payload.
 ///
@@ -21,9 +27,9 @@ pub fn cose_sign(
     msg_to_auth: Vec<u8>,
     signer: &XdsaSecretKey,
     domain: Vec<u8>,
-) -> Result<Vec<u8>, String> {
-    darkbio_crypto::cbor::verify(&msg_to_embed).map_err(|e| e.to_string())?;
-    darkbio_crypto::cbor::verify(&msg_to_auth).map_err(|e| e.to_string())?;
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_embed)?;
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
 
     darkbio_crypto::cose::sign(
         darkbio_crypto::cbor::Raw(msg_to_embed),
@@ -31,7 +37,7 @@ pub fn cose_sign(
         &signer.inner,
         &domain,
     )
-    .map_err(|e| e.to_string())
+    .map_err(CryptoError::from)
 }
 
 /// Creates a COSE_Sign1 signature without an embedded payload (detached mode).
@@ -44,15 +50,15 @@ pub fn cose_sign_detached(
     msg_to_auth: Vec<u8>,
     signer: &XdsaSecretKey,
     domain: Vec<u8>,
-) -> Result<Vec<u8>, String> {
-    darkbio_crypto::cbor::verify(&msg_to_auth).map_err(|e| e.to_string())?;
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
 
     darkbio_crypto::cose::sign_detached(
         darkbio_crypto::cbor::Raw(msg_to_auth),
         &signer.inner,
         &domain,
     )
-    .map_err(|e| e.to_string())
+    .map_err(CryptoError::from)
 }
 
 /// Verifies a COSE_Sign1 signature and returns the embedded payload.
@@ -62,6 +68,8 @@ pub fn cose_sign_detached(
 /// - `verifier`: The public key to verify against
 /// - `domain`: Application-specific domain separator
 /// - `max_drift_secs`: Maximum allowed clock drift (None for no time check)
+/// - `schema`: A compiled schema the payload must match (None to skip)
+/// - `rule`: Name of the schema rule the payload must match
 #[frb(sync)]
 pub fn cose_verify(
     msg_to_check: Vec<u8>,
@@ -69,8 +77,10 @@ pub fn cose_verify(
     verifier: &XdsaPublicKey,
     domain: Vec<u8>,
     max_drift_secs: Option<u64>,
-) -> Result<Vec<u8>, String> {
-    darkbio_crypto::cbor::verify(&msg_to_auth).map_err(|e| e.to_string())?;
+    schema: Option<RustAutoOpaque<CddlSchema>>,
+    rule: Option<String>,
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
 
     let raw: darkbio_crypto::cbor::Raw = darkbio_crypto::cose::verify(
         &msg_to_check,
@@ -78,11 +88,79 @@ pub fn cose_verify(
         &verifier.inner,
         &domain,
         max_drift_secs,
-    )
-    .map_err(|e| e.to_string())?;
+    )?;
+    check_schema(&raw.0, schema, rule)?;
     Ok(raw.0)
 }
 
+/// CoseSign1Info is the content of a COSE_Sign1 along with the fields of its
+/// protected header.
+pub struct CoseSign1Info {
+    /// The embedded payload
+    pub payload: Vec<u8>,
+    /// Fingerprint of the signing key
+    pub signer: XdsaFingerprint,
+    /// Unix timestamp of the signature in seconds
+    pub timestamp: i64,
+    /// COSE algorithm identifier of the signature
+    pub algorithm: i64,
+    /// Domain the signature was verified against (None if not verified, as
+    /// the domain is not carried in the message)
+    pub domain: Option<Vec<u8>>,
+}
+
+/// Verifies a COSE_Sign1 signature and returns the embedded payload together
+/// with the protected header fields.
+///
+/// - `msg_to_check`: The COSE_Sign1 structure to verify
+/// - `msg_to_auth`: Additional authenticated data (external AAD)
+/// - `verifier`: The public key to verify against
+/// - `domain`: Application-specific domain separator
+/// - `max_drift_secs`: Maximum allowed clock drift (None for no time check)
+#[frb(sync)]
+pub fn cose_verify_info(
+    msg_to_check: Vec<u8>,
+    msg_to_auth: Vec<u8>,
+    verifier: &XdsaPublicKey,
+    domain: Vec<u8>,
+    max_drift_secs: Option<u64>,
+) -> Result<CoseSign1Info, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
+
+    darkbio_crypto::cose::verify::<darkbio_crypto::cbor::Raw, _>(
+        &msg_to_check,
+        darkbio_crypto::cbor::Raw(msg_to_auth),
+        &verifier.inner,
+        &domain,
+        max_drift_secs,
+    )?;
+    let mut info = cose_inspect(msg_to_check)?;
+    info.domain = Some(domain);
+    Ok(info)
+}
+
+/// Extracts the embedded payload and protected header fields from a
+/// COSE_Sign1 without verifying.
+///
+/// Warning: This does NOT verify the signature. The returned data is
+/// unauthenticated and should not be trusted until verified with `verify`.
+#[frb(sync)]
+pub fn cose_inspect(signature: Vec<u8>) -> Result<CoseSign1Info, CryptoError> {
+    let sign1: darkbio_crypto::cose::CoseSign1 = darkbio_crypto::cbor::decode(&signature)?;
+    let header: darkbio_crypto::cose::SigProtectedHeader =
+        darkbio_crypto::cbor::decode(&sign1.protected)?;
+
+    Ok(CoseSign1Info {
+        payload: sign1
+            .payload
+            .ok_or(darkbio_crypto::cose::Error::MissingPayload)?,
+        signer: XdsaFingerprint { inner: header.kid },
+        timestamp: header.timestamp,
+        algorithm: header.algorithm,
+        domain: None,
+    })
+}
+
 /// Verifies a COSE_Sign1 signature with a detached payload.
 ///
 /// - `msg_to_check`: The COSE_Sign1 structure to verify
@@ -97,8 +175,8 @@ pub fn cose_verify_detached(
     verifier: &XdsaPublicKey,
     domain: Vec<u8>,
     max_drift_secs: Option<u64>,
-) -> Result<(), String> {
-    darkbio_crypto::cbor::verify(&msg_to_auth).map_err(|e| e.to_string())?;
+) -> Result<(), CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
 
     darkbio_crypto::cose::verify_detached(
         &msg_to_check,
@@ -107,13 +185,13 @@ pub fn cose_verify_detached(
         &domain,
         max_drift_secs,
     )
-    .map_err(|e| e.to_string())
+    .map_err(CryptoError::from)
 }
 
 /// Extracts the signer's fingerprint from a COSE_Sign1 without verifying.
 #[frb(sync)]
-pub fn cose_signer(signature: Vec<u8>) -> Result<XdsaFingerprint, String> {
-    let fp = darkbio_crypto::cose::signer(&signature).map_err(|e| e.to_string())?;
+pub fn cose_signer(signature: Vec<u8>) -> Result<XdsaFingerprint, CryptoError> {
+    let fp = darkbio_crypto::cose::signer(&signature)?;
     Ok(XdsaFingerprint { inner: fp })
 }
 
@@ -122,16 +200,15 @@ pub fn cose_signer(signature: Vec<u8>) -> Result<XdsaFingerprint, String> {
 /// Warning: This does NOT verify the signature. The returned payload is
 /// unauthenticated and should not be trusted until verified with `verify`.
 #[frb(sync)]
-pub fn cose_peek(signature: Vec<u8>) -> Result<Vec<u8>, String> {
-    let raw: darkbio_crypto::cbor::Raw =
-        darkbio_crypto::cose::peek(&signature).map_err(|e| e.to_string())?;
+pub fn cose_peek(signature: Vec<u8>) -> Result<Vec<u8>, CryptoError> {
+    let raw: darkbio_crypto::cbor::Raw = darkbio_crypto::cose::peek(&signature)?;
     Ok(raw.0)
 }
 
 /// Extracts the recipient's fingerprint from a COSE_Encrypt0 without decrypting.
 #[frb(sync)]
-pub fn cose_recipient(ciphertext: Vec<u8>) -> Result<XhpkeFingerprint, String> {
-    let fp = darkbio_crypto::cose::recipient(&ciphertext).map_err(|e| e.to_string())?;
+pub fn cose_recipient(ciphertext: Vec<u8>) -> Result<XhpkeFingerprint, CryptoError> {
+    let fp = darkbio_crypto::cose::recipient(&ciphertext)?;
     Ok(XhpkeFingerprint { inner: fp })
 }
 
@@ -151,8 +228,8 @@ pub fn cose_encrypt(
     msg_to_auth: Vec<u8>,
     recipient: &XhpkePublicKey,
     domain: Vec<u8>,
-) -> Result<Vec<u8>, String> {
-    darkbio_crypto::cbor::verify(&msg_to_auth).map_err(|e| e.to_string())?;
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
 
     darkbio_crypto::cose::encrypt(
         &sign1,
@@ -160,7 +237,7 @@ pub fn cose_encrypt(
         &recipient.inner,
         &domain,
     )
-    .map_err(|e| e.to_string())
+    .map_err(CryptoError::from)
 }
 
 /// Decrypts a sealed message without verifying the signature.
@@ -180,8 +257,8 @@ pub fn cose_decrypt(
     msg_to_auth: Vec<u8>,
     recipient: &XhpkeSecretKey,
     domain: Vec<u8>,
-) -> Result<Vec<u8>, String> {
-    darkbio_crypto::cbor::verify(&msg_to_auth).map_err(|e| e.to_string())?;
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
 
     darkbio_crypto::cose::decrypt(
         &msg_to_open,
@@ -189,7 +266,7 @@ pub fn cose_decrypt(
         &recipient.inner,
         &domain,
     )
-    .map_err(|e| e.to_string())
+    .map_err(CryptoError::from)
 }
 
 /// Signs a message then encrypts it to a recipient (sign-then-encrypt).
@@ -206,9 +283,9 @@ pub fn cose_seal(
     signer: &XdsaSecretKey,
     recipient: &XhpkePublicKey,
     domain: Vec<u8>,
-) -> Result<Vec<u8>, String> {
-    darkbio_crypto::cbor::verify(&msg_to_seal).map_err(|e| e.to_string())?;
-    darkbio_crypto::cbor::verify(&msg_to_auth).map_err(|e| e.to_string())?;
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_seal)?;
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
 
     darkbio_crypto::cose::seal(
         darkbio_crypto::cbor::Raw(msg_to_seal),
@@ -217,7 +294,7 @@ pub fn cose_seal(
         &recipient.inner,
         &domain,
     )
-    .map_err(|e| e.to_string())
+    .map_err(CryptoError::from)
 }
 
 /// Decrypts and verifies a sealed message.
@@ -236,8 +313,8 @@ pub fn cose_open(
     sender: &XdsaPublicKey,
     domain: Vec<u8>,
     max_drift_secs: Option<u64>,
-) -> Result<Vec<u8>, String> {
-    darkbio_crypto::cbor::verify(&msg_to_auth).map_err(|e| e.to_string())?;
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
 
     let raw: darkbio_crypto::cbor::Raw = darkbio_crypto::cose::open(
         &msg_to_open,
@@ -246,7 +323,764 @@ pub fn cose_open(
         &sender.inner,
         &domain,
         max_drift_secs,
-    )
-    .map_err(|e| e.to_string())?;
+    )?;
     Ok(raw.0)
 }
+
+/// Signs a message then encrypts it to multiple recipients at once, producing
+/// a COSE_Encrypt with one recipient structure per public key.
+///
+/// The signed message is encrypted a single time with ChaCha20-Poly1305 under a
+/// random content key, which is then sealed to each recipient with xHPKE.
+///
+/// - `msg_to_seal`: The payload to sign and encrypt
+/// - `msg_to_auth`: Additional authenticated data (external AAD)
+/// - `signer`: The private key to sign with
+/// - `recipients`: The public keys to encrypt to
+/// - `domain`: Application-specific domain separator
+#[frb(sync)]
+pub fn cose_seal_multi(
+    msg_to_seal: Vec<u8>,
+    msg_to_auth: Vec<u8>,
+    signer: &XdsaSecretKey,
+    recipients: Vec<RustAutoOpaque<XhpkePublicKey>>,
+    domain: Vec<u8>,
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_seal)?;
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
+
+    let sign1 = darkbio_crypto::cose::sign(
+        darkbio_crypto::cbor::Raw(msg_to_seal),
+        darkbio_crypto::cbor::Raw(msg_to_auth.clone()),
+        &signer.inner,
+        &domain,
+    )?;
+    let guards: Vec<_> = recipients.iter().map(|r| r.blocking_read()).collect();
+    let recipients: Vec<&XhpkePublicKey> = guards.iter().map(|g| &**g).collect();
+
+    encrypt_multi(&sign1, &msg_to_auth, &recipients, &domain)
+}
+
+/// Decrypts a COSE_Encrypt using this recipient's entry and verifies the
+/// signed message inside.
+///
+/// - `msg_to_open`: The COSE_Encrypt structure to decrypt and verify
+/// - `msg_to_auth`: Additional authenticated data (external AAD)
+/// - `recipient`: The private key to decrypt with
+/// - `sender`: The public key to verify the signature against
+/// - `domain`: Application-specific domain separator
+/// - `max_drift_secs`: Maximum allowed clock drift (None for no time check)
+#[frb(sync)]
+pub fn cose_open_multi(
+    msg_to_open: Vec<u8>,
+    msg_to_auth: Vec<u8>,
+    recipient: &XhpkeSecretKey,
+    sender: &XdsaPublicKey,
+    domain: Vec<u8>,
+    max_drift_secs: Option<u64>,
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
+
+    let sign1 = decrypt_multi(&msg_to_open, &msg_to_auth, recipient, &domain)?;
+
+    let raw: darkbio_crypto::cbor::Raw = darkbio_crypto::cose::verify(
+        &sign1,
+        darkbio_crypto::cbor::Raw(msg_to_auth),
+        &sender.inner,
+        &domain,
+        max_drift_secs,
+    )?;
+    Ok(raw.0)
+}
+
+/// Extracts the fingerprints of all recipients from a COSE_Encrypt without
+/// decrypting.
+#[frb(sync)]
+pub fn cose_recipients(ciphertext: Vec<u8>) -> Result<Vec<XhpkeFingerprint>, CryptoError> {
+    let encrypt: CoseEncrypt = darkbio_crypto::cbor::decode(&ciphertext)?;
+
+    let mut fingerprints = Vec::with_capacity(encrypt.recipients.len());
+    for recipient in encrypt.recipients.iter() {
+        let header: darkbio_crypto::cose::EncProtectedHeader =
+            darkbio_crypto::cbor::decode(&recipient.protected)?;
+        fingerprints.push(XhpkeFingerprint { inner: header.kid });
+    }
+    Ok(fingerprints)
+}
+
+/// COSE algorithm identifier for ChaCha20/Poly1305 (RFC 9053).
+const ALGORITHM_ID_CHACHA20_POLY1305: i64 = 24;
+
+/// Protected header for the content layer of a COSE_Encrypt.
+#[derive(Cbor)]
+struct ContentProtectedHeader {
+    /// Algorithm identifier (COSE header label 1)
+    #[cbor(key = 1)]
+    algorithm: i64,
+}
+
+/// Unprotected header for the content layer of a COSE_Encrypt.
+#[derive(Cbor)]
+struct IvHeader {
+    /// Initialization vector (COSE header label 5)
+    #[cbor(key = 5)]
+    iv: [u8; 12],
+}
+
+/// COSE_recipient structure per RFC 9052 Section 5.1, carrying the content
+/// key sealed to a single recipient.
+#[derive(Cbor)]
+#[cbor(array)]
+struct CoseRecipient {
+    /// Protected header (algorithm and recipient's fingerprint)
+    protected: Vec<u8>,
+    /// Unprotected header map (contains encapsulated key)
+    unprotected: darkbio_crypto::cose::EncapKeyHeader,
+    /// Sealed content key
+    ciphertext: Vec<u8>,
+}
+
+/// COSE_Encrypt structure per RFC 9052 Section 5.1.
+#[derive(Cbor)]
+#[cbor(array)]
+struct CoseEncrypt {
+    /// Protected header (content encryption algorithm)
+    protected: Vec<u8>,
+    /// Unprotected header map (contains the IV)
+    unprotected: IvHeader,
+    /// Ciphertext bytes
+    ciphertext: Vec<u8>,
+    /// Content key sealed to each recipient
+    recipients: darkbio_crypto::cbor::Array<CoseRecipient>,
+}
+
+/// Encrypts an already-signed COSE_Sign1 to multiple recipients.
+fn encrypt_multi(
+    sign1: &[u8],
+    msg_to_auth: &[u8],
+    recipients: &[&XhpkePublicKey],
+    domain: &[u8],
+) -> Result<Vec<u8>, CryptoError> {
+    if recipients.is_empty() {
+        return Err(CryptoError::EncryptionFailed {
+            details: "no recipients".to_string(),
+        });
+    }
+    let key = darkbio_crypto::rand::generate(32);
+
+    // Seal the content key to each recipient (domain prefixing is handled by xHPKE)
+    let mut wraps = Vec::with_capacity(recipients.len());
+    for recipient in recipients {
+        let protected = darkbio_crypto::cbor::encode(&darkbio_crypto::cose::EncProtectedHeader {
+            algorithm: darkbio_crypto::cose::ALGORITHM_ID_XHPKE,
+            kid: recipient.inner.fingerprint(),
+        })?;
+        let (encap_key, ciphertext) = recipient
+            .inner
+            .seal(
+                &key,
+                &darkbio_crypto::cbor::encode(darkbio_crypto::cose::EncStructure {
+                    context: "Enc_Recipient",
+                    protected: &protected,
+                    external_aad: msg_to_auth,
+                })?,
+                domain,
+            )
+            .map_err(|e| CryptoError::EncryptionFailed {
+                details: e.to_string(),
+            })?;
+        wraps.push(CoseRecipient {
+            protected,
+            unprotected: darkbio_crypto::cose::EncapKeyHeader {
+                encap_key: encap_key.to_vec(),
+            },
+            ciphertext,
+        });
+    }
+    // Encrypt the signed message once with the content key
+    let protected = darkbio_crypto::cbor::encode(&ContentProtectedHeader {
+        algorithm: ALGORITHM_ID_CHACHA20_POLY1305,
+    })?;
+    let iv: [u8; 12] = darkbio_crypto::rand::generate(12).try_into().unwrap();
+
+    let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&key))
+        .encrypt(
+            Nonce::from_slice(&iv),
+            Payload {
+                msg: sign1,
+                aad: &darkbio_crypto::cbor::encode(darkbio_crypto::cose::EncStructure {
+                    context: "Encrypt",
+                    protected: &protected,
+                    external_aad: msg_to_auth,
+                })?,
+            },
+        )
+        .map_err(|e| CryptoError::EncryptionFailed {
+            details: e.to_string(),
+        })?;
+
+    darkbio_crypto::cbor::encode(&CoseEncrypt {
+        protected,
+        unprotected: IvHeader { iv },
+        ciphertext,
+        recipients: wraps.into(),
+    })
+    .map_err(CryptoError::from)
+}
+
+/// Decrypts a COSE_Encrypt using the recipient structure matching the given
+/// secret key, returning the COSE_Sign1 inside (not yet verified).
+fn decrypt_multi(
+    msg_to_open: &[u8],
+    msg_to_auth: &[u8],
+    recipient: &XhpkeSecretKey,
+    domain: &[u8],
+) -> Result<Vec<u8>, CryptoError> {
+    let encrypt: CoseEncrypt = darkbio_crypto::cbor::decode(msg_to_open)?;
+
+    let header: ContentProtectedHeader = darkbio_crypto::cbor::decode(&encrypt.protected)?;
+    if header.algorithm != ALGORITHM_ID_CHACHA20_POLY1305 {
+        return Err(CryptoError::InvalidEncoding {
+            details: format!("unexpected algorithm {}", header.algorithm),
+        });
+    }
+    // Locate the recipient structure addressed to this key
+    let fingerprint = recipient.inner.fingerprint();
+
+    let mut wrap = None;
+    for entry in encrypt.recipients.iter() {
+        let header: darkbio_crypto::cose::EncProtectedHeader =
+            darkbio_crypto::cbor::decode(&entry.protected)?;
+        if header.kid == fingerprint {
+            if header.algorithm != darkbio_crypto::cose::ALGORITHM_ID_XHPKE {
+                return Err(CryptoError::InvalidEncoding {
+                    details: format!("unexpected algorithm {}", header.algorithm),
+                });
+            }
+            wrap = Some(entry);
+            break;
+        }
+    }
+    let wrap = wrap.ok_or_else(|| CryptoError::KeyMismatch {
+        details: "message not encrypted to this key".to_string(),
+    })?;
+
+    let encap_key: [u8; darkbio_crypto::xhpke::ENCAP_KEY_SIZE] =
+        to_array(wrap.unprotected.encap_key.clone())?;
+
+    // Open the content key (domain prefixing is handled by xHPKE)
+    let key = recipient
+        .inner
+        .open(
+            &encap_key,
+            &wrap.ciphertext,
+            &darkbio_crypto::cbor::encode(darkbio_crypto::cose::EncStructure {
+                context: "Enc_Recipient",
+                protected: &wrap.protected,
+                external_aad: msg_to_auth,
+            })?,
+            domain,
+        )
+        .map_err(|e| CryptoError::DecryptionFailed {
+            details: e.to_string(),
+        })?;
+    let key: [u8; 32] = to_array(key)?;
+
+    ChaCha20Poly1305::new(Key::from_slice(&key))
+        .decrypt(
+            Nonce::from_slice(&encrypt.unprotected.iv),
+            Payload {
+                msg: &encrypt.ciphertext,
+                aad: &darkbio_crypto::cbor::encode(darkbio_crypto::cose::EncStructure {
+                    context: "Encrypt",
+                    protected: &encrypt.protected,
+                    external_aad: msg_to_auth,
+                })?,
+            },
+        )
+        .map_err(|e| CryptoError::DecryptionFailed {
+            details: e.to_string(),
+        })
+}
+
+/// Creates a COSE_Sign with an embedded payload, co-signed by several keys.
+/// At least one signer is required.
+///
+/// - `msg_to_embed`: The payload to embed and sign
+/// - `msg_to_auth`: Additional authenticated data (external AAD)
+/// - `signers`: The private keys to sign with
+/// - `domain`: Application-specific domain separator
+#[frb(sync)]
+pub fn cose_sign_multi(
+    msg_to_embed: Vec<u8>,
+    msg_to_auth: Vec<u8>,
+    signers: Vec<RustAutoOpaque<XdsaSecretKey>>,
+    domain: Vec<u8>,
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_embed)?;
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
+
+    if signers.is_empty() {
+        return Err(CryptoError::InvalidArgument {
+            details: "at least one signer is required".to_string(),
+        });
+    }
+    let mut sign = CoseSign {
+        protected: Vec::new(),
+        unprotected: darkbio_crypto::cose::EmptyHeader {},
+        payload: msg_to_embed,
+        signatures: darkbio_crypto::cbor::Array(Vec::with_capacity(signers.len())),
+    };
+    for signer in &signers {
+        add_signature(&mut sign, &msg_to_auth, &signer.blocking_read(), &domain)?;
+    }
+    darkbio_crypto::cbor::encode(&sign).map_err(CryptoError::from)
+}
+
+/// Appends a signature to an existing COSE_Sign, failing if the key already
+/// signed it.
+///
+/// - `msg_to_sign`: The COSE_Sign structure to co-sign
+/// - `msg_to_auth`: The same additional authenticated data used by the other signers
+/// - `signer`: The private key to sign with
+/// - `domain`: Application-specific domain separator
+#[frb(sync)]
+pub fn cose_add_signature(
+    msg_to_sign: Vec<u8>,
+    msg_to_auth: Vec<u8>,
+    signer: &XdsaSecretKey,
+    domain: Vec<u8>,
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
+
+    let mut sign: CoseSign = darkbio_crypto::cbor::decode(&msg_to_sign)?;
+    add_signature(&mut sign, &msg_to_auth, signer, &domain)?;
+
+    darkbio_crypto::cbor::encode(&sign).map_err(CryptoError::from)
+}
+
+/// Extracts the fingerprints of all signers from a COSE_Sign without verifying.
+///
+/// The returned data is unauthenticated.
+#[frb(sync)]
+pub fn cose_signers(signature: Vec<u8>) -> Result<Vec<XdsaFingerprint>, CryptoError> {
+    let sign: CoseSign = darkbio_crypto::cbor::decode(&signature)?;
+
+    let mut fingerprints = Vec::with_capacity(sign.signatures.len());
+    for entry in sign.signatures.iter() {
+        let header: darkbio_crypto::cose::SigProtectedHeader =
+            darkbio_crypto::cbor::decode(&entry.protected)?;
+        fingerprints.push(XdsaFingerprint { inner: header.kid });
+    }
+    Ok(fingerprints)
+}
+
+/// Verifies that at least `threshold` of the given keys validly signed a
+/// COSE_Sign, returning the embedded payload. Signatures from other keys are
+/// ignored, and each key counts at most once.
+///
+/// - `msg_to_check`: The COSE_Sign structure to verify
+/// - `msg_to_auth`: Additional authenticated data (external AAD)
+/// - `verifiers`: The public keys whose signatures count towards the threshold
+/// - `threshold`: Minimum number of valid signatures required
+/// - `domain`: Application-specific domain separator
+/// - `max_drift_secs`: Maximum allowed clock drift (None for no time check)
+#[frb(sync)]
+pub fn cose_verify_threshold(
+    msg_to_check: Vec<u8>,
+    msg_to_auth: Vec<u8>,
+    verifiers: Vec<RustAutoOpaque<XdsaPublicKey>>,
+    threshold: usize,
+    domain: Vec<u8>,
+    max_drift_secs: Option<u64>,
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&msg_to_auth)?;
+
+    if threshold == 0 {
+        return Err(CryptoError::InvalidArgument {
+            details: "threshold must be at least 1".to_string(),
+        });
+    }
+    let sign: CoseSign = darkbio_crypto::cbor::decode(&msg_to_check)?;
+    let aad = sig_external_aad(&msg_to_auth, &domain)?;
+    let now = unix_now();
+
+    let mut seen = Vec::with_capacity(verifiers.len());
+    let mut valid = 0;
+    for verifier in &verifiers {
+        let verifier = verifier.blocking_read();
+        let fingerprint = verifier.inner.fingerprint();
+        if seen.contains(&fingerprint) {
+            continue;
+        }
+        seen.push(fingerprint.clone());
+
+        let verified =
+            sign.signatures.iter().any(|entry| {
+                let Ok(header) = darkbio_crypto::cbor::decode::<
+                    darkbio_crypto::cose::SigProtectedHeader,
+                >(&entry.protected) else {
+                    return false;
+                };
+                if header.kid != fingerprint
+                    || header.algorithm != darkbio_crypto::cose::ALGORITHM_ID_XDSA
+                    || header.crit.timestamp != darkbio_crypto::cose::HEADER_TIMESTAMP
+                {
+                    return false;
+                }
+                if let Some(max) = max_drift_secs
+                    && now.abs_diff(header.timestamp) > max
+                {
+                    return false;
+                }
+                let Ok(blob) = sig_structure(&sign, &entry.protected, &aad) else {
+                    return false;
+                };
+                verifier.inner.verify(&blob, &entry.signature).is_ok()
+            });
+        if verified {
+            valid += 1;
+        }
+    }
+    if valid < threshold {
+        return Err(CryptoError::SignatureMismatch {
+            details: format!("{valid} valid signatures, {threshold} required"),
+        });
+    }
+    Ok(sign.payload)
+}
+
+/// COSE_Signature structure per RFC 9052 Section 4.1.
+#[derive(Cbor)]
+#[cbor(array)]
+struct CoseSignature {
+    /// Protected header (algorithm, signer's fingerprint and timestamp)
+    protected: Vec<u8>,
+    /// Unprotected header map (empty)
+    unprotected: darkbio_crypto::cose::EmptyHeader,
+    /// Signature (fixed size for xDSA)
+    signature: darkbio_crypto::xdsa::Signature,
+}
+
+/// COSE_Sign structure per RFC 9052 Section 4.1.
+#[derive(Cbor)]
+#[cbor(array)]
+struct CoseSign {
+    /// Body protected header (empty)
+    protected: Vec<u8>,
+    /// Unprotected header map (empty)
+    unprotected: darkbio_crypto::cose::EmptyHeader,
+    /// Payload bytes
+    payload: Vec<u8>,
+    /// Signature of each signer
+    signatures: darkbio_crypto::cbor::Array<CoseSignature>,
+}
+
+/// Signs a COSE_Sign with one more key, rejecting keys that already signed.
+fn add_signature(
+    sign: &mut CoseSign,
+    msg_to_auth: &[u8],
+    signer: &XdsaSecretKey,
+    domain: &[u8],
+) -> Result<(), CryptoError> {
+    let fingerprint = signer.inner.fingerprint();
+    for entry in sign.signatures.iter() {
+        let header: darkbio_crypto::cose::SigProtectedHeader =
+            darkbio_crypto::cbor::decode(&entry.protected)?;
+        if header.kid == fingerprint {
+            return Err(CryptoError::InvalidEncoding {
+                details: "already signed by this key".to_string(),
+            });
+        }
+    }
+    let protected = darkbio_crypto::cbor::encode(&darkbio_crypto::cose::SigProtectedHeader {
+        algorithm: darkbio_crypto::cose::ALGORITHM_ID_XDSA,
+        crit: darkbio_crypto::cose::CritHeader {
+            timestamp: darkbio_crypto::cose::HEADER_TIMESTAMP,
+        },
+        kid: fingerprint,
+        timestamp: unix_now(),
+    })?;
+    let aad = sig_external_aad(msg_to_auth, domain)?;
+    let signature = signer.inner.sign(&sig_structure(sign, &protected, &aad)?);
+
+    sign.signatures.push(CoseSignature {
+        protected,
+        unprotected: darkbio_crypto::cose::EmptyHeader {},
+        signature,
+    });
+    Ok(())
+}
+
+/// Builds the external AAD the same way COSE_Sign1 does, restricting the
+/// user's domain to the context of this library.
+fn sig_external_aad(msg_to_auth: &[u8], domain: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    let info = [darkbio_crypto::cose::DOMAIN_PREFIX, domain].concat();
+    darkbio_crypto::cbor::encode(&(&info, darkbio_crypto::cbor::Raw(msg_to_auth.to_vec())))
+        .map_err(CryptoError::from)
+}
+
+/// Builds the Sig_structure signed by a single COSE_Sign signer per RFC 9052
+/// Section 4.4.
+fn sig_structure(sign: &CoseSign, protected: &[u8], aad: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::encode((
+        "Signature",
+        sign.protected.as_slice(),
+        protected,
+        aad,
+        sign.payload.as_slice(),
+    ))
+    .map_err(CryptoError::from)
+}
+
+/// Returns the current Unix timestamp in seconds.
+fn unix_now() -> i64 {
+    web_time::SystemTime::now()
+        .duration_since(web_time::UNIX_EPOCH)
+        .expect("system time before Unix epoch")
+        .as_secs() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cbor(text: &str) -> Vec<u8> {
+        darkbio_crypto::cbor::encode(text).unwrap()
+    }
+
+    #[test]
+    fn test_seal_multi_roundtrip() {
+        let signer = XdsaSecretKey::generate();
+        let alice = XhpkeSecretKey::generate();
+        let bob = XhpkeSecretKey::generate();
+
+        let sealed = cose_seal_multi(
+            cbor("message"),
+            cbor("aad"),
+            &signer,
+            vec![
+                RustAutoOpaque::new(alice.public_key()),
+                RustAutoOpaque::new(bob.public_key()),
+            ],
+            b"domain".to_vec(),
+        )
+        .unwrap();
+
+        let recipients = cose_recipients(sealed.clone()).unwrap();
+        assert_eq!(recipients.len(), 2);
+        assert_eq!(recipients[0].to_bytes(), alice.fingerprint().to_bytes());
+        assert_eq!(recipients[1].to_bytes(), bob.fingerprint().to_bytes());
+
+        for recipient in [&alice, &bob] {
+            let opened = cose_open_multi(
+                sealed.clone(),
+                cbor("aad"),
+                recipient,
+                &signer.public_key(),
+                b"domain".to_vec(),
+                None,
+            )
+            .unwrap();
+            assert_eq!(opened, cbor("message"));
+        }
+        let open = |aad: &str, recipient: &XhpkeSecretKey, sender: &XdsaPublicKey| {
+            cose_open_multi(
+                sealed.clone(),
+                cbor(aad),
+                recipient,
+                sender,
+                b"domain".to_vec(),
+                None,
+            )
+        };
+        assert!(matches!(
+            open("other", &alice, &signer.public_key()),
+            Err(CryptoError::DecryptionFailed { .. })
+        ));
+        assert!(matches!(
+            open("aad", &XhpkeSecretKey::generate(), &signer.public_key()),
+            Err(CryptoError::KeyMismatch { .. })
+        ));
+        assert!(matches!(
+            open("aad", &alice, &XdsaSecretKey::generate().public_key()),
+            Err(CryptoError::KeyMismatch { .. })
+        ));
+
+        assert!(matches!(
+            cose_seal_multi(cbor("message"), cbor("aad"), &signer, vec![], vec![]),
+            Err(CryptoError::EncryptionFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_sign_multi_threshold() {
+        let alice = XdsaSecretKey::generate();
+        let bob = XdsaSecretKey::generate();
+        let carol = XdsaSecretKey::generate();
+
+        let signed = cose_sign_multi(
+            cbor("message"),
+            cbor("aad"),
+            vec![
+                RustAutoOpaque::new(XdsaSecretKey::from_bytes(alice.to_bytes()).unwrap()),
+                RustAutoOpaque::new(XdsaSecretKey::from_bytes(bob.to_bytes()).unwrap()),
+            ],
+            b"domain".to_vec(),
+        )
+        .unwrap();
+        let signers: Vec<_> = cose_signers(signed.clone())
+            .unwrap()
+            .iter()
+            .map(|fp| fp.to_bytes())
+            .collect();
+        assert_eq!(
+            signers,
+            vec![alice.fingerprint().to_bytes(), bob.fingerprint().to_bytes()]
+        );
+
+        let verify = |signed: &[u8], keys: &[&XdsaSecretKey], threshold: usize| {
+            cose_verify_threshold(
+                signed.to_vec(),
+                cbor("aad"),
+                keys.iter()
+                    .map(|key| RustAutoOpaque::new(key.public_key()))
+                    .collect(),
+                threshold,
+                b"domain".to_vec(),
+                Some(60),
+            )
+        };
+        assert_eq!(
+            verify(&signed, &[&alice, &bob], 2).unwrap(),
+            cbor("message")
+        );
+        assert_eq!(
+            verify(&signed, &[&carol, &bob], 1).unwrap(),
+            cbor("message")
+        );
+
+        // Non-signers and repeated keys do not count towards the threshold
+        assert!(matches!(
+            verify(&signed, &[&alice, &carol], 2),
+            Err(CryptoError::SignatureMismatch { .. })
+        ));
+        assert!(matches!(
+            verify(&signed, &[&alice, &alice], 2),
+            Err(CryptoError::SignatureMismatch { .. })
+        ));
+
+        // Co-signing adds a countable signature, but only once per key
+        let signed = cose_add_signature(signed, cbor("aad"), &carol, b"domain".to_vec()).unwrap();
+        assert_eq!(
+            verify(&signed, &[&alice, &bob, &carol], 3).unwrap(),
+            cbor("message")
+        );
+        assert!(
+            cose_add_signature(signed.clone(), cbor("aad"), &carol, b"domain".to_vec()).is_err()
+        );
+
+        assert!(matches!(
+            cose_sign_multi(cbor("message"), cbor("aad"), vec![], b"domain".to_vec()),
+            Err(CryptoError::InvalidArgument { .. })
+        ));
+        assert!(matches!(
+            verify(&signed, &[&alice], 0),
+            Err(CryptoError::InvalidArgument { .. })
+        ));
+
+        // Signer controlled timestamps at the extremes of the range count as
+        // stale instead of overflowing the drift computation
+        let mut sign: CoseSign = darkbio_crypto::cbor::decode(&signed).unwrap();
+        for timestamp in [i64::MIN, i64::MAX] {
+            sign.signatures[0].protected =
+                darkbio_crypto::cbor::encode(&darkbio_crypto::cose::SigProtectedHeader {
+                    algorithm: darkbio_crypto::cose::ALGORITHM_ID_XDSA,
+                    crit: darkbio_crypto::cose::CritHeader {
+                        timestamp: darkbio_crypto::cose::HEADER_TIMESTAMP,
+                    },
+                    kid: alice.inner.fingerprint(),
+                    timestamp,
+                })
+                .unwrap();
+            let signed = darkbio_crypto::cbor::encode(&sign).unwrap();
+            assert!(matches!(
+                verify(&signed, &[&alice], 1),
+                Err(CryptoError::SignatureMismatch { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_inspect_info() {
+        let signer = XdsaSecretKey::generate();
+        let signed = cose_sign(cbor("message"), cbor("aad"), &signer, b"domain".to_vec()).unwrap();
+
+        let info = cose_inspect(signed.clone()).unwrap();
+        assert_eq!(info.payload, cbor("message"));
+        assert_eq!(info.signer.to_bytes(), signer.fingerprint().to_bytes());
+        assert_eq!(info.algorithm, darkbio_crypto::cose::ALGORITHM_ID_XDSA);
+        assert!((unix_now() - info.timestamp).abs() <= 1);
+        assert!(info.domain.is_none());
+
+        let info = cose_verify_info(
+            signed.clone(),
+            cbor("aad"),
+            &signer.public_key(),
+            b"domain".to_vec(),
+            Some(60),
+        )
+        .unwrap();
+        assert_eq!(info.payload, cbor("message"));
+        assert_eq!(info.domain, Some(b"domain".to_vec()));
+
+        assert!(
+            cose_verify_info(
+                signed,
+                cbor("aad"),
+                &signer.public_key(),
+                b"other".to_vec(),
+                None,
+            )
+            .is_err()
+        );
+
+        // Detached signatures carry no payload to inspect
+        let detached = cose_sign_detached(cbor("aad"), &signer, b"domain".to_vec()).unwrap();
+        assert!(cose_inspect(detached).is_err());
+    }
+
+    #[test]
+    fn test_verify_schema() {
+        let signer = XdsaSecretKey::generate();
+        let signature = cose_sign(
+            darkbio_crypto::cbor::encode((1u64, "two")).unwrap(),
+            cbor("aad"),
+            &signer,
+            b"domain".to_vec(),
+        )
+        .unwrap();
+        let verify = |cddl: &str, rule: Option<&str>| {
+            cose_verify(
+                signature.clone(),
+                cbor("aad"),
+                &signer.public_key(),
+                b"domain".to_vec(),
+                None,
+                Some(RustAutoOpaque::new(
+                    CddlSchema::compile(cddl.to_string()).unwrap(),
+                )),
+                rule.map(str::to_string),
+            )
+        };
+        assert!(verify("payload = [uint, tstr]", Some("payload")).is_ok());
+        assert!(matches!(
+            verify("payload = [uint, bstr]", Some("payload")),
+            Err(CryptoError::SchemaMismatch { path, .. }) if path == "$[1]"
+        ));
+        assert!(matches!(
+            verify("payload = [uint, tstr]", None),
+            Err(CryptoError::InvalidSchema { .. })
+        ));
+    }
+}
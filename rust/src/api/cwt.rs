@@ -4,9 +4,19 @@
 // Use of this source code is governed by a BSD-style
 // license that can be found in the LICENSE file.
 
+use darkbio_crypto::cbor::{
+    Array, Cbor, Decode, Decoder, Encoder, MapDecode, MapEncode, MapEncodeBuffer, MapEntries, Raw,
+    decode_map_entries_slices_notrail,
+};
+use darkbio_crypto::cwt::claims::{Confirm, eat};
 use flutter_rust_bridge::frb;
+use sha2::{Digest, Sha256};
 
+use super::cddl::{CddlSchema, check_schema};
+use super::error::{CryptoError, to_array};
 use super::xdsa::{XdsaFingerprint, XdsaPublicKey, XdsaSecretKey};
+use super::xhpke::{XhpkePublicKey, XhpkeSecretKey};
+use crate::frb_generated::RustAutoOpaque;
 
 /// Issues a CWT by signing pre-encoded CBOR claims with COSE Sign1.
 ///
@@ -18,38 +28,40 @@ pub fn cwt_issue(
     claims_cbor: Vec<u8>,
     signer: &XdsaSecretKey,
     domain: Vec<u8>,
-) -> Result<Vec<u8>, String> {
-    darkbio_crypto::cbor::verify(&claims_cbor).map_err(|e| e.to_string())?;
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cbor::verify(&claims_cbor)?;
 
     darkbio_crypto::cwt::issue(
         &darkbio_crypto::cbor::Raw(claims_cbor),
         &signer.inner,
         &domain,
     )
-    .map_err(|e| e.to_string())
+    .map_err(CryptoError::from)
 }
 
-/// Verifies a CWT's COSE signature and temporal validity, returning
-/// the raw CBOR-encoded claims.
-///
-/// When `now` is provided, temporal claims are validated: nbf must be
-/// present and `nbf <= now`, and if exp is present then `now < exp`.
-/// When `now` is `None`, temporal validation is skipped.
+/// Verifies a CWT's COSE signature and checks its claims against the
+/// validation policy, returning the raw CBOR-encoded claims.
 ///
 /// - `token`: The serialized CWT
 /// - `verifier`: The xDSA public key to verify against
 /// - `domain`: Application-specific domain separator
-/// - `now`: Current Unix timestamp for temporal validation (None to skip)
+/// - `validation`: The policy the claims must satisfy
+/// - `status_list`: A verified status list the token's status claim must be
+///   valid in (None to skip the status check)
+/// - `schema`: A compiled schema the claims must match (None to skip)
+/// - `rule`: Name of the schema rule the claims must match
 #[frb(sync)]
 pub fn cwt_verify(
     token: Vec<u8>,
     verifier: &XdsaPublicKey,
     domain: Vec<u8>,
-    now: Option<u64>,
-) -> Result<Vec<u8>, String> {
-    let raw: darkbio_crypto::cbor::Raw =
-        darkbio_crypto::cwt::verify(&token, &verifier.inner, &domain, now)
-            .map_err(|e| e.to_string())?;
+    validation: CwtValidation,
+    status_list: Option<RustAutoOpaque<StatusList>>,
+    schema: Option<RustAutoOpaque<CddlSchema>>,
+    rule: Option<String>,
+) -> Result<Vec<u8>, CryptoError> {
+    let raw = verify_checked(&token, verifier, &domain, &validation, status_list)?;
+    check_schema(&raw, schema, rule)?;
     Ok(raw.0)
 }
 
@@ -57,8 +69,8 @@ pub fn cwt_verify(
 ///
 /// The returned data is unauthenticated.
 #[frb(sync)]
-pub fn cwt_signer(token: Vec<u8>) -> Result<XdsaFingerprint, String> {
-    let fp = darkbio_crypto::cwt::signer(&token).map_err(|e| e.to_string())?;
+pub fn cwt_signer(token: Vec<u8>) -> Result<XdsaFingerprint, CryptoError> {
+    let fp = darkbio_crypto::cwt::signer(&token)?;
     Ok(XdsaFingerprint { inner: fp })
 }
 
@@ -67,8 +79,2083 @@ pub fn cwt_signer(token: Vec<u8>) -> Result<XdsaFingerprint, String> {
 /// **Warning**: The returned payload is unauthenticated and should not be
 /// trusted until verified with `cwt_verify`.
 #[frb(sync)]
-pub fn cwt_peek(token: Vec<u8>) -> Result<Vec<u8>, String> {
-    let raw: darkbio_crypto::cbor::Raw =
-        darkbio_crypto::cwt::peek(&token).map_err(|e| e.to_string())?;
+pub fn cwt_peek(token: Vec<u8>) -> Result<Vec<u8>, CryptoError> {
+    let raw: darkbio_crypto::cbor::Raw = darkbio_crypto::cwt::peek(&token)?;
     Ok(raw.0)
 }
+
+/// CwtClaims is a typed set of CWT claims (RFC 8392) with the common EAT
+/// claims (RFC 9711), encoded deterministically on the Rust side.
+pub struct CwtClaims {
+    /// Issuer (key 1)
+    pub iss: Option<String>,
+    /// Subject (key 2)
+    pub sub: Option<String>,
+    /// Audience (key 3)
+    pub aud: Option<String>,
+    /// Expiration time as Unix timestamp (key 4)
+    pub exp: Option<u64>,
+    /// Not-before time as Unix timestamp (key 5)
+    pub nbf: Option<u64>,
+    /// Issued-at time as Unix timestamp (key 6)
+    pub iat: Option<u64>,
+    /// Unique token identifier (key 7)
+    pub cti: Option<Vec<u8>>,
+    /// Confirmation key bound to the token (key 8)
+    pub cnf: Option<CwtConfirmKey>,
+    /// Attestation challenge echoed by the entity (key 10)
+    pub eat_nonce: Option<Vec<u8>>,
+    /// Universal entity identifier (key 256)
+    pub ueid: Option<Vec<u8>>,
+    /// Hardware manufacturer identifier (key 258)
+    pub oemid: Option<EatOemid>,
+    /// Hardware model identifier (key 259)
+    pub hw_model: Option<Vec<u8>>,
+    /// Hardware version (key 260)
+    pub hw_version: Option<String>,
+    /// Seconds since the last boot (key 261)
+    pub uptime: Option<u64>,
+    /// Whether the boot chain is OEM-authorized (key 262)
+    pub oem_boot: Option<bool>,
+    /// Debug port state (key 263)
+    pub dbgstat: Option<EatDebugState>,
+    /// Claims of the entity's submodules (key 266)
+    pub submods: Vec<EatSubmodule>,
+    /// Number of times the device has booted (key 267)
+    pub boot_count: Option<u64>,
+    /// Random value unique to the current boot cycle (key 268)
+    pub boot_seed: Option<Vec<u8>>,
+    /// Software name (key 270)
+    pub sw_name: Option<String>,
+    /// Software version (key 271)
+    pub sw_version: Option<String>,
+    /// Measurements of the entity's software components (key 273)
+    pub measurements: Vec<EatMeasurement>,
+    /// Entry of the token in a status list, for revocation (key 65535)
+    pub status: Option<CwtStatus>,
+    /// Application specific claims, which must not reuse the keys above
+    pub custom: Vec<CwtCustomClaim>,
+}
+
+/// CwtConfirmKey is a public key bound to a token by the cnf claim.
+#[allow(clippy::large_enum_variant)]
+pub enum CwtConfirmKey {
+    /// xDSA signing key, e.g. for proof-of-possession
+    Xdsa(XdsaPublicKey),
+    /// xHPKE encryption key
+    Xhpke(XhpkePublicKey),
+}
+
+/// EatOemid identifies the hardware manufacturer in one of the three RFC 9711
+/// formats.
+pub enum EatOemid {
+    /// 16-byte random manufacturer identifier
+    Random(Vec<u8>),
+    /// 3-byte IEEE OUI/MA-L
+    Ieee(Vec<u8>),
+    /// IANA Private Enterprise Number
+    Pen(u64),
+}
+
+/// EatDebugState is the state of the entity's debug facilities.
+pub enum EatDebugState {
+    /// Debug is currently enabled
+    Enabled,
+    /// Debug is currently disabled
+    Disabled,
+    /// Debug was disabled at boot and has not been enabled since
+    DisabledSinceBoot,
+    /// Debug is disabled and cannot be re-enabled
+    DisabledPermanently,
+    /// All debug, including DMA-based, is permanently disabled
+    DisabledFullyPermanently,
+}
+
+/// EatMeasurement is a measurement of a software component, tagged with the
+/// CoAP content format of its encoding.
+pub struct EatMeasurement {
+    /// CoAP content format of the measurement
+    pub content_type: u64,
+    /// Encoded measurement
+    pub content: Vec<u8>,
+}
+
+/// EatSubmodule is a named submodule of the attested entity.
+pub struct EatSubmodule {
+    /// Submodule name
+    pub name: String,
+    /// Submodule claims or token
+    pub value: EatSubmoduleValue,
+}
+
+/// EatSubmoduleValue is the attestation of a submodule, either inline or as
+/// a separate EAT.
+pub enum EatSubmoduleValue {
+    /// Claims set embedded in the parent token
+    Claims(Box<CwtClaims>),
+    /// Separately signed EAT embedded in the parent token
+    Embedded(Vec<u8>),
+    /// Digest of an EAT conveyed separately from the parent token
+    Detached {
+        /// COSE algorithm identifier of the digest
+        alg: i64,
+        /// Digest of the detached EAT
+        digest: Vec<u8>,
+    },
+}
+
+/// CwtStatus references the entry of a token in a status list.
+pub struct CwtStatus {
+    /// Index of the token's status in the list
+    pub idx: u64,
+    /// URI of the status list token, as found in its sub claim
+    pub uri: String,
+}
+
+impl CwtStatus {
+    /// Encodes the status reference as `{"status_list": {"idx": .., "uri": ..}}`.
+    fn to_cbor(&self) -> Result<Vec<u8>, CryptoError> {
+        let reference = encode_text_map(vec![
+            ("idx".to_string(), darkbio_crypto::cbor::encode(self.idx)?),
+            ("uri".to_string(), darkbio_crypto::cbor::encode(&self.uri)?),
+        ])?;
+        encode_text_map(vec![("status_list".to_string(), reference)])
+    }
+
+    /// Decodes the status reference, rejecting unknown mechanisms.
+    fn from_cbor(data: &[u8]) -> Result<Self, CryptoError> {
+        let mut entries = decode_text_map(data)?;
+        let reference: Raw = take_text_entry(&mut entries, "status_list")?;
+
+        let mut fields = decode_text_map(&reference)?;
+        let status = Self {
+            idx: take_text_entry(&mut fields, "idx")?,
+            uri: take_text_entry(&mut fields, "uri")?,
+        };
+        if let Some((key, _)) = entries.first().or(fields.first()) {
+            return Err(CryptoError::InvalidClaims {
+                details: format!("unknown status field {key}"),
+            });
+        }
+        Ok(status)
+    }
+}
+
+/// CwtCustomClaim is an application specific claim with an integer key.
+pub struct CwtCustomClaim {
+    /// Claim key
+    pub key: i64,
+    /// CBOR-encoded claim value
+    pub value: Vec<u8>,
+}
+
+impl CwtClaims {
+    /// Encodes the claims into a deterministic CBOR map.
+    pub(crate) fn to_cbor(&self) -> Result<Vec<u8>, CryptoError> {
+        let mut enc = MapEncodeBuffer::new(24 + self.custom.len());
+
+        enc.push_optional(1, &self.iss)?;
+        enc.push_optional(2, &self.sub)?;
+        enc.push_optional(3, &self.aud)?;
+        enc.push_optional(4, &self.exp)?;
+        enc.push_optional(5, &self.nbf)?;
+        enc.push_optional(6, &self.iat)?;
+        enc.push_optional(7, &self.cti)?;
+        match &self.cnf {
+            Some(CwtConfirmKey::Xdsa(key)) => {
+                Confirm::new((*key.inner).clone()).encode_map(&mut enc)?
+            }
+            Some(CwtConfirmKey::Xhpke(key)) => {
+                Confirm::new(key.inner.clone()).encode_map(&mut enc)?
+            }
+            None => {}
+        }
+        enc.push_optional(10, &self.eat_nonce)?;
+        enc.push_optional(256, &self.ueid)?;
+        match &self.oemid {
+            Some(EatOemid::Random(id)) => {
+                eat::Oemid::new_random(to_array(id.clone())?).encode_map(&mut enc)?
+            }
+            Some(EatOemid::Ieee(id)) => {
+                eat::Oemid::new_ieee(to_array(id.clone())?).encode_map(&mut enc)?
+            }
+            Some(EatOemid::Pen(pen)) => eat::Oemid::new_pen(*pen).encode_map(&mut enc)?,
+            None => {}
+        }
+        enc.push_optional(259, &self.hw_model)?;
+        if let Some(version) = &self.hw_version {
+            eat::HwVersion::new(version.clone()).encode_map(&mut enc)?;
+        }
+        enc.push_optional(261, &self.uptime)?;
+        enc.push_optional(262, &self.oem_boot)?;
+        if let Some(state) = &self.dbgstat {
+            enc.push(263, &state.to_inner())?;
+        }
+        if !self.submods.is_empty() {
+            enc.push(266, &Raw(encode_submods(&self.submods)?))?;
+        }
+        enc.push_optional(267, &self.boot_count)?;
+        enc.push_optional(268, &self.boot_seed)?;
+        enc.push_optional(270, &self.sw_name)?;
+        if let Some(version) = &self.sw_version {
+            eat::SwVersion::new(version.clone()).encode_map(&mut enc)?;
+        }
+        if !self.measurements.is_empty() {
+            let measurements: Vec<_> = self
+                .measurements
+                .iter()
+                .map(|m| (m.content_type, m.content.clone()))
+                .collect();
+            enc.push(273, &Array::from(measurements))?;
+        }
+        if let Some(status) = &self.status {
+            enc.push(65535, &Raw(status.to_cbor()?))?;
+        }
+        for claim in &self.custom {
+            darkbio_crypto::cbor::verify(&claim.value)?;
+            enc.push(claim.key, &Raw(claim.value.clone()))?;
+        }
+        let mut out = Vec::new();
+        enc.finish_to(&mut out)?;
+        Ok(out)
+    }
+
+    /// Decodes the claims from a CBOR map, collecting unknown keys as custom
+    /// claims.
+    pub(crate) fn from_cbor(data: &[u8]) -> Result<Self, CryptoError> {
+        let mut dec = Decoder::new(data);
+        let mut entries = MapEntries::new(decode_map_entries_slices_notrail(&mut dec)?);
+        dec.finish()?;
+
+        let cnf = match entries.take(8) {
+            Some(raw) => {
+                let mut cnf = MapEntries::new(vec![(8, raw)]);
+                Some(
+                    match Confirm::<darkbio_crypto::xdsa::PublicKey>::decode_map(&mut cnf.clone()) {
+                        Ok(confirm) => CwtConfirmKey::Xdsa(XdsaPublicKey {
+                            inner: Box::new(confirm.key().clone()),
+                        }),
+                        Err(_) => CwtConfirmKey::Xhpke(XhpkePublicKey {
+                            inner: Confirm::<darkbio_crypto::xhpke::PublicKey>::decode_map(
+                                &mut cnf,
+                            )?
+                            .key()
+                            .clone(),
+                        }),
+                    },
+                )
+            }
+            None => None,
+        };
+        let mut claims = Self {
+            iss: take_claim(&mut entries, 1)?,
+            sub: take_claim(&mut entries, 2)?,
+            aud: take_claim(&mut entries, 3)?,
+            exp: take_claim(&mut entries, 4)?,
+            nbf: take_claim(&mut entries, 5)?,
+            iat: take_claim(&mut entries, 6)?,
+            cti: take_claim(&mut entries, 7)?,
+            cnf,
+            eat_nonce: take_claim(&mut entries, 10)?,
+            ueid: take_claim(&mut entries, 256)?,
+            oemid: match entries.contains(258) {
+                true => {
+                    let oemid = eat::Oemid::decode_map(&mut entries)?;
+                    Some(match (oemid.random(), oemid.ieee(), oemid.pen()) {
+                        (Some(id), _, _) => EatOemid::Random(id.to_vec()),
+                        (_, Some(id), _) => EatOemid::Ieee(id.to_vec()),
+                        (_, _, Some(pen)) => EatOemid::Pen(pen),
+                        _ => {
+                            return Err(CryptoError::InvalidClaims {
+                                details: "unsupported oemid format".to_string(),
+                            });
+                        }
+                    })
+                }
+                false => None,
+            },
+            hw_model: take_claim(&mut entries, 259)?,
+            hw_version: match entries.contains(260) {
+                true => Some(
+                    eat::HwVersion::decode_map(&mut entries)?
+                        .version()
+                        .to_string(),
+                ),
+                false => None,
+            },
+            uptime: take_claim(&mut entries, 261)?,
+            oem_boot: take_claim(&mut entries, 262)?,
+            dbgstat: take_claim::<eat::DebugState>(&mut entries, 263)?
+                .map(EatDebugState::from_inner),
+            submods: match entries.take(266) {
+                Some(raw) => decode_submods(raw)?,
+                None => Vec::new(),
+            },
+            boot_count: take_claim(&mut entries, 267)?,
+            boot_seed: take_claim(&mut entries, 268)?,
+            sw_name: take_claim(&mut entries, 270)?,
+            sw_version: match entries.contains(271) {
+                true => Some(
+                    eat::SwVersion::decode_map(&mut entries)?
+                        .version()
+                        .to_string(),
+                ),
+                false => None,
+            },
+            measurements: take_claim::<Array<(u64, Vec<u8>)>>(&mut entries, 273)?
+                .map(|measurements| {
+                    measurements
+                        .0
+                        .into_iter()
+                        .map(|(content_type, content)| EatMeasurement {
+                            content_type,
+                            content,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            status: entries.take(65535).map(CwtStatus::from_cbor).transpose()?,
+            custom: Vec::new(),
+        };
+        for key in entries.remaining_keys() {
+            claims.custom.push(CwtCustomClaim {
+                key,
+                value: entries.take(key).unwrap().to_vec(),
+            });
+        }
+        Ok(claims)
+    }
+}
+
+impl EatDebugState {
+    /// Converts the state into its RFC 9711 representation.
+    fn to_inner(&self) -> eat::DebugState {
+        match self {
+            Self::Enabled => eat::DebugState::Enabled,
+            Self::Disabled => eat::DebugState::Disabled,
+            Self::DisabledSinceBoot => eat::DebugState::DisabledSinceBoot,
+            Self::DisabledPermanently => eat::DebugState::DisabledPermanently,
+            Self::DisabledFullyPermanently => eat::DebugState::DisabledFullyPermanently,
+        }
+    }
+
+    /// Converts the state from its RFC 9711 representation.
+    fn from_inner(state: eat::DebugState) -> Self {
+        match state {
+            eat::DebugState::Enabled => Self::Enabled,
+            eat::DebugState::Disabled => Self::Disabled,
+            eat::DebugState::DisabledSinceBoot => Self::DisabledSinceBoot,
+            eat::DebugState::DisabledPermanently => Self::DisabledPermanently,
+            eat::DebugState::DisabledFullyPermanently => Self::DisabledFullyPermanently,
+        }
+    }
+}
+
+/// Encodes submodules into a text keyed CBOR map.
+fn encode_submods(submods: &[EatSubmodule]) -> Result<Vec<u8>, CryptoError> {
+    let mut entries = Vec::with_capacity(submods.len());
+    for submod in submods {
+        let value = match &submod.value {
+            EatSubmoduleValue::Claims(claims) => claims.to_cbor()?,
+            EatSubmoduleValue::Embedded(token) => darkbio_crypto::cbor::encode(token)?,
+            EatSubmoduleValue::Detached { alg, digest } => {
+                darkbio_crypto::cbor::encode((*alg, digest.clone()))?
+            }
+        };
+        entries.push((submod.name.clone(), value));
+    }
+    encode_text_map(entries)
+}
+
+/// Decodes a text keyed CBOR map of submodules, telling the kinds apart by
+/// their CBOR major type (map, byte string or array).
+fn decode_submods(data: &[u8]) -> Result<Vec<EatSubmodule>, CryptoError> {
+    let mut submods = Vec::new();
+    for (name, raw) in decode_text_map(data)? {
+        let value = match raw.first().map(|b| b >> 5) {
+            Some(5) => EatSubmoduleValue::Claims(Box::new(CwtClaims::from_cbor(&raw)?)),
+            Some(2) => EatSubmoduleValue::Embedded(darkbio_crypto::cbor::decode(&raw)?),
+            Some(4) => {
+                let (alg, digest) = darkbio_crypto::cbor::decode(&raw)?;
+                EatSubmoduleValue::Detached { alg, digest }
+            }
+            _ => {
+                return Err(CryptoError::InvalidClaims {
+                    details: format!("submodule {name}: unsupported value type"),
+                });
+            }
+        };
+        submods.push(EatSubmodule { name, value });
+    }
+    Ok(submods)
+}
+
+/// Encodes a text keyed CBOR map from pre-encoded values, sorting the entries
+/// by their encoded keys for deterministic output.
+fn encode_text_map(entries: Vec<(String, Vec<u8>)>) -> Result<Vec<u8>, CryptoError> {
+    let mut encoded = Vec::with_capacity(entries.len());
+    for (key, value) in entries {
+        encoded.push((darkbio_crypto::cbor::encode(&key)?, key, value));
+    }
+    encoded.sort_by(|a, b| a.0.cmp(&b.0));
+    if let Some(pair) = encoded.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        return Err(CryptoError::InvalidClaims {
+            details: format!("duplicate map key {}", pair[0].1),
+        });
+    }
+    let mut enc = Encoder::new();
+    enc.encode_map_header(encoded.len());
+    for (key, _, value) in encoded {
+        enc.extend(&key);
+        enc.extend(&value);
+    }
+    Ok(enc.finish())
+}
+
+/// Decodes a text keyed CBOR map into its raw values, requiring the keys to be
+/// in deterministic order.
+fn decode_text_map(data: &[u8]) -> Result<Vec<(String, Raw)>, CryptoError> {
+    let mut dec = Decoder::new(data);
+    let n = dec.decode_map_header()?;
+
+    let mut entries = Vec::new();
+    let mut last: Option<Vec<u8>> = None;
+    for _ in 0..n {
+        let key = dec.decode_text()?;
+        let encoded = darkbio_crypto::cbor::encode(&key)?;
+        if last.as_ref().is_some_and(|last| *last >= encoded) {
+            return Err(CryptoError::InvalidClaims {
+                details: "map keys not in deterministic order".to_string(),
+            });
+        }
+        last = Some(encoded);
+
+        entries.push((key, Raw::decode_cbor_notrail(&mut dec)?));
+    }
+    dec.finish()?;
+    Ok(entries)
+}
+
+/// Takes and decodes a required entry from a decoded text keyed map.
+fn take_text_entry<T: Decode>(
+    entries: &mut Vec<(String, Raw)>,
+    key: &str,
+) -> Result<T, CryptoError> {
+    let index =
+        entries
+            .iter()
+            .position(|(k, _)| k == key)
+            .ok_or_else(|| CryptoError::InvalidClaims {
+                details: format!("missing {key}"),
+            })?;
+    let (_, raw) = entries.remove(index);
+    darkbio_crypto::cbor::decode(&raw).map_err(CryptoError::from)
+}
+
+/// Issues a CWT from typed claims, encoding them deterministically in Rust.
+///
+/// - `claims`: The claims to sign
+/// - `signer`: The xDSA secret key to sign with
+/// - `domain`: Application-specific domain separator
+#[frb(sync)]
+pub fn cwt_issue_claims(
+    claims: CwtClaims,
+    signer: &XdsaSecretKey,
+    domain: Vec<u8>,
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cwt::issue(&Raw(claims.to_cbor()?), &signer.inner, &domain)
+        .map_err(CryptoError::from)
+}
+
+/// Verifies a CWT's COSE signature and checks its claims against the
+/// validation policy, returning the decoded claims.
+///
+/// - `token`: The serialized CWT
+/// - `verifier`: The xDSA public key to verify against
+/// - `domain`: Application-specific domain separator
+/// - `validation`: The policy the claims must satisfy
+/// - `status_list`: A verified status list the token's status claim must be
+///   valid in (None to skip the status check)
+/// - `schema`: A compiled schema the claims must match (None to skip)
+/// - `rule`: Name of the schema rule the claims must match
+#[frb(sync)]
+pub fn cwt_verify_claims(
+    token: Vec<u8>,
+    verifier: &XdsaPublicKey,
+    domain: Vec<u8>,
+    validation: CwtValidation,
+    status_list: Option<RustAutoOpaque<StatusList>>,
+    schema: Option<RustAutoOpaque<CddlSchema>>,
+    rule: Option<String>,
+) -> Result<CwtClaims, CryptoError> {
+    let raw = verify_checked(&token, verifier, &domain, &validation, status_list)?;
+    check_schema(&raw, schema, rule)?;
+    CwtClaims::from_cbor(&raw)
+}
+
+/// Extracts and decodes typed claims from a CWT without verifying the
+/// signature.
+///
+/// **Warning**: The returned claims are unauthenticated and should not be
+/// trusted until verified with `cwt_verify_claims`.
+#[frb(sync)]
+pub fn cwt_peek_claims(token: Vec<u8>) -> Result<CwtClaims, CryptoError> {
+    let raw: Raw = darkbio_crypto::cwt::peek(&token)?;
+    CwtClaims::from_cbor(&raw)
+}
+
+/// Issues a CWT from typed claims that is signed by the issuer and then
+/// encrypted to the relying party, so the claims cannot be peeked at.
+///
+/// - `claims`: The claims to sign and encrypt
+/// - `signer`: The xDSA secret key to sign with
+/// - `recipient`: The xHPKE public key to encrypt to
+/// - `domain`: Application-specific domain separator
+#[frb(sync)]
+pub fn cwt_issue_sealed(
+    claims: CwtClaims,
+    signer: &XdsaSecretKey,
+    recipient: &XhpkePublicKey,
+    domain: Vec<u8>,
+) -> Result<Vec<u8>, CryptoError> {
+    darkbio_crypto::cose::seal(
+        Raw(claims.to_cbor()?),
+        darkbio_crypto::cbor::NULL,
+        &signer.inner,
+        &recipient.inner,
+        &domain,
+    )
+    .map_err(CryptoError::from)
+}
+
+/// Decrypts a CWT issued by `cwt_issue_sealed`, verifies the issuer's
+/// signature and checks its claims against the validation policy, returning
+/// the decoded claims.
+///
+/// - `token`: The encrypted CWT
+/// - `recipient`: The xHPKE secret key to decrypt with
+/// - `issuer`: The xDSA public key to verify against
+/// - `domain`: Application-specific domain separator
+/// - `validation`: The policy the claims must satisfy
+#[frb(sync)]
+pub fn cwt_open_sealed(
+    token: Vec<u8>,
+    recipient: &XhpkeSecretKey,
+    issuer: &XdsaPublicKey,
+    domain: Vec<u8>,
+    validation: CwtValidation,
+) -> Result<CwtClaims, CryptoError> {
+    let raw: Raw = darkbio_crypto::cose::open(
+        &token,
+        darkbio_crypto::cbor::NULL,
+        &recipient.inner,
+        &issuer.inner,
+        &domain,
+        None,
+    )?;
+    validation.check(&raw)?;
+    CwtClaims::from_cbor(&raw)
+}
+
+/// Verifies a delegation chain of CWTs, in which each token is signed by the
+/// xDSA key bound in the previous token's cnf claim, returning the leaf claims.
+///
+/// Every token's validity period must fall within that of its parent, and when
+/// `now` is provided each token must be valid at that time.
+///
+/// - `tokens`: The serialized CWTs, from the one signed by `root` to the leaf
+/// - `root`: The xDSA public key the first token must verify against
+/// - `domain`: Application-specific domain separator
+/// - `now`: Current Unix timestamp for temporal validation (None to skip)
+#[frb(sync)]
+pub fn cwt_verify_chain(
+    tokens: Vec<Vec<u8>>,
+    root: &XdsaPublicKey,
+    domain: Vec<u8>,
+    now: Option<u64>,
+) -> Result<CwtClaims, CryptoError> {
+    let validation = CwtValidation::at(now);
+
+    let mut verifier = root.inner.clone();
+    let mut parent: Option<CwtClaims> = None;
+    for (i, token) in tokens.iter().enumerate() {
+        let raw: Raw = darkbio_crypto::cwt::verify(token, &verifier, &domain, None)?;
+        validation.check(&raw)?;
+        let claims = CwtClaims::from_cbor(&raw)?;
+
+        if let Some(parent) = &parent {
+            check_narrowing(parent, &claims, i)?;
+        }
+        if i + 1 < tokens.len() {
+            verifier = match &claims.cnf {
+                Some(CwtConfirmKey::Xdsa(key)) => key.inner.clone(),
+                Some(CwtConfirmKey::Xhpke(_)) => {
+                    return Err(CryptoError::ConfirmKeyMismatch {
+                        details: format!("token {i} binds an xHPKE key, cannot delegate"),
+                    });
+                }
+                None => return Err(CryptoError::MissingClaim { key: 8 }),
+            };
+        }
+        parent = Some(claims);
+    }
+    parent.ok_or_else(|| CryptoError::InvalidClaims {
+        details: "empty token chain".to_string(),
+    })
+}
+
+/// Checks that a delegated token's validity period lies within its parent's.
+fn check_narrowing(parent: &CwtClaims, child: &CwtClaims, index: usize) -> Result<(), CryptoError> {
+    if let Some(nbf) = parent.nbf
+        && child.nbf.is_none_or(|child_nbf| child_nbf < nbf)
+    {
+        return Err(CryptoError::InvalidClaims {
+            details: format!("token {index} becomes valid before its parent"),
+        });
+    }
+    if let Some(exp) = parent.exp
+        && child.exp.is_none_or(|child_exp| child_exp > exp)
+    {
+        return Err(CryptoError::InvalidClaims {
+            details: format!("token {index} outlives its parent"),
+        });
+    }
+    Ok(())
+}
+
+/// CwtValidation is the policy a token's claims are checked against after
+/// its signature verifies. Each failed check maps to a distinct error.
+pub struct CwtValidation {
+    /// Current Unix timestamp for temporal validation (None to skip). When
+    /// set, nbf must be present and `nbf <= now`, and if exp is present then
+    /// `now < exp`, both relaxed by `leeway_secs`.
+    pub now: Option<u64>,
+    /// Allowed clock skew in seconds between the issuer and the verifier
+    pub leeway_secs: u64,
+    /// Maximum age in seconds since iat (None to skip). Setting it without
+    /// `now` fails every check.
+    pub max_age_secs: Option<u64>,
+    /// Required iss value (None to accept any)
+    pub issuer: Option<String>,
+    /// Accepted aud values, one of which must match (empty to accept any)
+    pub audiences: Vec<String>,
+    /// Claim keys that must be present
+    pub required_claims: Vec<i64>,
+    /// Required type of the cnf key (None to accept any or none)
+    pub confirm_key: Option<CwtConfirmKeyType>,
+    /// Expected eat_nonce challenge (None to skip)
+    pub eat_nonce: Option<Vec<u8>>,
+}
+
+/// CwtConfirmKeyType selects the kind of key a token's cnf claim must hold.
+pub enum CwtConfirmKeyType {
+    /// xDSA signing key
+    Xdsa,
+    /// xHPKE encryption key
+    Xhpke,
+}
+
+impl CwtValidation {
+    /// Returns a policy that only checks temporal validity at `now`, without
+    /// leeway or any other constraints.
+    #[frb(sync)]
+    pub fn at(now: Option<u64>) -> Self {
+        Self {
+            now,
+            leeway_secs: 0,
+            max_age_secs: None,
+            issuer: None,
+            audiences: Vec::new(),
+            required_claims: Vec::new(),
+            confirm_key: None,
+            eat_nonce: None,
+        }
+    }
+
+    /// Checks the CBOR-encoded claims against the policy.
+    pub(crate) fn check(&self, claims: &[u8]) -> Result<(), CryptoError> {
+        // A maximum age cannot be enforced without a clock, so refuse to
+        // silently accept tokens of any age
+        if self.max_age_secs.is_some() && self.now.is_none() {
+            return Err(CryptoError::InvalidClaims {
+                details: "max_age_secs requires now".to_string(),
+            });
+        }
+        let mut dec = Decoder::new(claims);
+        let mut entries = MapEntries::new(decode_map_entries_slices_notrail(&mut dec)?);
+        dec.finish()?;
+
+        for &key in &self.required_claims {
+            if !entries.contains(key) {
+                return Err(CryptoError::MissingClaim { key });
+            }
+        }
+        if let Some(now) = self.now {
+            let nbf: u64 =
+                take_claim(&mut entries, 5)?.ok_or(CryptoError::MissingClaim { key: 5 })?;
+            if nbf > now.saturating_add(self.leeway_secs) {
+                return Err(CryptoError::NotYetValid { nbf, now });
+            }
+            if let Some(exp) = take_claim::<u64>(&mut entries, 4)?
+                && now >= exp.saturating_add(self.leeway_secs)
+            {
+                return Err(CryptoError::Expired { exp, now });
+            }
+            if let Some(max_age) = self.max_age_secs {
+                let iat: u64 =
+                    take_claim(&mut entries, 6)?.ok_or(CryptoError::MissingClaim { key: 6 })?;
+                if now > iat.saturating_add(max_age).saturating_add(self.leeway_secs) {
+                    return Err(CryptoError::TokenTooOld { iat, max_age, now });
+                }
+            }
+        }
+        if let Some(expected) = &self.issuer {
+            let got: String =
+                take_claim(&mut entries, 1)?.ok_or(CryptoError::MissingClaim { key: 1 })?;
+            if got != *expected {
+                return Err(CryptoError::IssuerMismatch {
+                    expected: expected.clone(),
+                    got,
+                });
+            }
+        }
+        if !self.audiences.is_empty() {
+            let got: String =
+                take_claim(&mut entries, 3)?.ok_or(CryptoError::MissingClaim { key: 3 })?;
+            if !self.audiences.contains(&got) {
+                return Err(CryptoError::AudienceMismatch { got });
+            }
+        }
+        if let Some(kind) = &self.confirm_key {
+            let raw = entries
+                .take(8)
+                .ok_or(CryptoError::MissingClaim { key: 8 })?;
+            let mut cnf = MapEntries::new(vec![(8, raw)]);
+            let matches = match kind {
+                CwtConfirmKeyType::Xdsa => {
+                    Confirm::<darkbio_crypto::xdsa::PublicKey>::decode_map(&mut cnf).is_ok()
+                }
+                CwtConfirmKeyType::Xhpke => {
+                    Confirm::<darkbio_crypto::xhpke::PublicKey>::decode_map(&mut cnf).is_ok()
+                }
+            };
+            if !matches {
+                return Err(CryptoError::ConfirmKeyMismatch {
+                    details: "cnf key is not of the required type".to_string(),
+                });
+            }
+        }
+        if let Some(expected) = &self.eat_nonce {
+            let got: Vec<u8> =
+                take_claim(&mut entries, 10)?.ok_or(CryptoError::MissingClaim { key: 10 })?;
+            if got != *expected {
+                return Err(CryptoError::NonceMismatch {
+                    details: "eat_nonce does not match the challenge".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Creates a proof-of-possession presentation of a CWT that binds an xDSA
+/// holder key via its cnf claim.
+///
+/// The holder signs the verifier's audience and nonce with COSE Sign1, using
+/// the SHA-256 hash of the token as external AAD, so the proof is bound to the
+/// token and cannot be mistaken for a CWT issued by the holder.
+///
+/// - `token`: The serialized CWT binding the holder's key
+/// - `holder`: The xDSA secret key confirmed by the token
+/// - `audience`: The verifier the presentation is intended for
+/// - `nonce`: The verifier's challenge
+/// - `domain`: Application-specific domain separator
+#[frb(sync)]
+pub fn cwt_present(
+    token: Vec<u8>,
+    holder: &XdsaSecretKey,
+    audience: String,
+    nonce: Vec<u8>,
+    domain: Vec<u8>,
+) -> Result<Vec<u8>, CryptoError> {
+    let binding = darkbio_crypto::cose::sign(
+        KeyBinding {
+            aud: audience,
+            cnonce: nonce,
+        },
+        token_hash(&token),
+        &holder.inner,
+        &domain,
+    )?;
+    darkbio_crypto::cbor::encode(Presentation { token, binding }).map_err(CryptoError::from)
+}
+
+/// Verifies a presentation created by `cwt_present`, returning the token's
+/// claims.
+///
+/// Checks the issuer's signature and the validation policy on the token, then
+/// that the holder's proof is signed by the token's cnf key and carries the
+/// expected audience and nonce.
+///
+/// - `presentation`: The serialized presentation
+/// - `issuer`: The xDSA public key the token must verify against
+/// - `audience`: The audience the proof must be intended for
+/// - `nonce`: The challenge the proof must answer
+/// - `domain`: Application-specific domain separator
+/// - `validation`: The policy the token's claims must satisfy
+/// - `max_drift_secs`: Maximum allowed clock drift of the proof (None for no time check)
+#[frb(sync)]
+pub fn cwt_verify_presentation(
+    presentation: Vec<u8>,
+    issuer: &XdsaPublicKey,
+    audience: String,
+    nonce: Vec<u8>,
+    domain: Vec<u8>,
+    validation: CwtValidation,
+    max_drift_secs: Option<u64>,
+) -> Result<CwtClaims, CryptoError> {
+    let presentation: Presentation = darkbio_crypto::cbor::decode(&presentation)?;
+
+    let raw: Raw = darkbio_crypto::cwt::verify(&presentation.token, &issuer.inner, &domain, None)?;
+    validation.check(&raw)?;
+    let claims = CwtClaims::from_cbor(&raw)?;
+
+    let holder = match &claims.cnf {
+        Some(CwtConfirmKey::Xdsa(key)) => &key.inner,
+        Some(CwtConfirmKey::Xhpke(_)) => {
+            return Err(CryptoError::ConfirmKeyMismatch {
+                details: "token binds an xHPKE key, cannot prove possession".to_string(),
+            });
+        }
+        None => return Err(CryptoError::MissingClaim { key: 8 }),
+    };
+    let binding: KeyBinding = darkbio_crypto::cose::verify(
+        &presentation.binding,
+        token_hash(&presentation.token),
+        holder,
+        &domain,
+        max_drift_secs,
+    )?;
+    if binding.aud != audience {
+        return Err(CryptoError::AudienceMismatch { got: binding.aud });
+    }
+    if binding.cnonce != nonce {
+        return Err(CryptoError::NonceMismatch {
+            details: "presentation answers a different challenge".to_string(),
+        });
+    }
+    Ok(claims)
+}
+
+/// Presentation is a CWT along with the holder's proof of possession.
+#[derive(Cbor)]
+#[cbor(array)]
+struct Presentation {
+    /// The presented CWT
+    token: Vec<u8>,
+    /// COSE_Sign1 over the key binding claims by the token's cnf key
+    binding: Vec<u8>,
+}
+
+/// KeyBinding holds the claims signed by the holder when presenting a token.
+#[derive(Cbor)]
+struct KeyBinding {
+    /// Audience the presentation is intended for (key 3)
+    #[cbor(key = 3)]
+    aud: String,
+    /// Verifier supplied nonce (key 39)
+    #[cbor(key = 39)]
+    cnonce: Vec<u8>,
+}
+
+/// Hashes a token for binding a presentation proof to it.
+fn token_hash(token: &[u8]) -> [u8; 32] {
+    Sha256::digest(token).into()
+}
+
+/// Claim key holding the digests of the redacted claims of an SD-CWT. The
+/// draft uses the simple value 59 as map key, which the deterministic CBOR
+/// profile does not admit, so a private use claim key stands in for it.
+const SD_REDACTED_CLAIMS: i64 = -65538;
+
+/// Size of the random salt blinding each disclosure.
+const SD_SALT_SIZE: usize = 16;
+
+/// SdCwt is a selective disclosure CWT along with the disclosures of its
+/// redacted claims, which the issuer hands over to the holder.
+pub struct SdCwt {
+    /// The serialized CWT, carrying salted digests in place of redacted claims
+    pub token: Vec<u8>,
+    /// One disclosure per redacted claim
+    pub disclosures: Vec<Vec<u8>>,
+}
+
+/// Issues a selective disclosure CWT, replacing the disclosable claims with
+/// salted SHA-256 digests of their disclosures.
+///
+/// The claims a verifier relies on to process the token (keys 1 to 8 and the
+/// status at 65535) cannot be made disclosable.
+///
+/// - `claims`: The claims to sign
+/// - `disclosable`: Keys of the claims the holder may choose to reveal
+/// - `signer`: The xDSA secret key to sign with
+/// - `domain`: Application-specific domain separator
+#[frb(sync)]
+pub fn cwt_issue_sd(
+    claims: CwtClaims,
+    disclosable: Vec<i64>,
+    signer: &XdsaSecretKey,
+    domain: Vec<u8>,
+) -> Result<SdCwt, CryptoError> {
+    let encoded = claims.to_cbor()?;
+    let mut dec = Decoder::new(&encoded);
+    let mut entries = decode_map_entries_slices_notrail(&mut dec)?;
+    dec.finish()?;
+
+    let mut digests = Vec::with_capacity(disclosable.len());
+    let mut disclosures = Vec::with_capacity(disclosable.len());
+    for key in disclosable {
+        check_disclosable(key)?;
+        let index = entries
+            .iter()
+            .position(|(k, _)| *k == key)
+            .ok_or(CryptoError::MissingClaim { key })?;
+        let (_, value) = entries.remove(index);
+
+        let salt: [u8; SD_SALT_SIZE] = to_array(darkbio_crypto::rand::generate(SD_SALT_SIZE))?;
+        let disclosure = darkbio_crypto::cbor::encode((salt, Raw(value.to_vec()), key))?;
+
+        digests.push(disclosure_hash(&disclosure));
+        disclosures.push(disclosure);
+    }
+    // Sort the digests so their order leaks nothing about the redacted claims
+    digests.sort();
+
+    let mut enc = MapEncodeBuffer::new(entries.len() + 1);
+    for (key, value) in entries {
+        enc.push(key, &Raw(value.to_vec()))?;
+    }
+    if !digests.is_empty() {
+        enc.push(SD_REDACTED_CLAIMS, &Array::from(digests))?;
+    }
+    let mut redacted = Vec::new();
+    enc.finish_to(&mut redacted)?;
+
+    let token = darkbio_crypto::cwt::issue(&Raw(redacted), &signer.inner, &domain)?;
+    Ok(SdCwt { token, disclosures })
+}
+
+/// Creates a selective disclosure presentation revealing only the chosen
+/// claims of an SD-CWT.
+///
+/// - `token`: The serialized SD-CWT
+/// - `disclosures`: All disclosures received from the issuer
+/// - `reveal`: Keys of the claims to reveal
+#[frb(sync)]
+pub fn cwt_present_sd(
+    token: Vec<u8>,
+    disclosures: Vec<Vec<u8>>,
+    reveal: Vec<i64>,
+) -> Result<Vec<u8>, CryptoError> {
+    let mut revealed = Vec::with_capacity(reveal.len());
+    let mut missing = reveal;
+    for disclosure in disclosures {
+        let key = disclosure_key(&disclosure)?;
+        if let Some(index) = missing.iter().position(|k| *k == key) {
+            missing.swap_remove(index);
+            revealed.push(disclosure);
+        }
+    }
+    if let Some(&key) = missing.first() {
+        return Err(CryptoError::MissingClaim { key });
+    }
+    darkbio_crypto::cbor::encode(SdPresentation {
+        token,
+        disclosures: Array::from(revealed),
+    })
+    .map_err(CryptoError::from)
+}
+
+/// Verifies a selective disclosure presentation, checking each disclosure
+/// against the digests signed by the issuer, and returns the always visible
+/// claims along with the disclosed ones. Disclosures of the claims that
+/// `cwt_issue_sd` refuses to redact are rejected.
+///
+/// - `presentation`: The serialized presentation
+/// - `verifier`: The xDSA public key to verify against
+/// - `domain`: Application-specific domain separator
+/// - `validation`: The policy the disclosed claims must satisfy
+#[frb(sync)]
+pub fn cwt_verify_sd(
+    presentation: Vec<u8>,
+    verifier: &XdsaPublicKey,
+    domain: Vec<u8>,
+    validation: CwtValidation,
+) -> Result<CwtClaims, CryptoError> {
+    let presentation: SdPresentation = darkbio_crypto::cbor::decode(&presentation)?;
+
+    let raw: Raw =
+        darkbio_crypto::cwt::verify(&presentation.token, &verifier.inner, &domain, None)?;
+    let mut dec = Decoder::new(&raw);
+    let mut entries = MapEntries::new(decode_map_entries_slices_notrail(&mut dec)?);
+    dec.finish()?;
+
+    let mut digests: Vec<[u8; 32]> =
+        take_claim::<Array<[u8; 32]>>(&mut entries, SD_REDACTED_CLAIMS)?
+            .map(|digests| digests.0)
+            .unwrap_or_default();
+
+    let mut enc =
+        MapEncodeBuffer::new(entries.remaining_keys().len() + presentation.disclosures.len());
+    for key in entries.remaining_keys() {
+        enc.push(key, &Raw(entries.take(key).unwrap().to_vec()))?;
+    }
+    for disclosure in presentation.disclosures.iter() {
+        let digest = disclosure_hash(disclosure);
+        let index = digests.iter().position(|d| *d == digest).ok_or_else(|| {
+            CryptoError::InvalidClaims {
+                details: "disclosure not signed by the issuer".to_string(),
+            }
+        })?;
+        digests.swap_remove(index);
+
+        let (_, value, key): ([u8; SD_SALT_SIZE], Raw, i64) =
+            darkbio_crypto::cbor::decode(disclosure)?;
+        // Tokens from other issuers may redact claims this library never would
+        check_disclosable(key)?;
+        enc.push(key, &value)?;
+    }
+    let mut claims = Vec::new();
+    enc.finish_to(&mut claims)
+        .map_err(|e| CryptoError::InvalidClaims {
+            details: e.to_string(),
+        })?;
+
+    validation.check(&claims)?;
+    CwtClaims::from_cbor(&claims)
+}
+
+/// SdPresentation is an SD-CWT along with the disclosures the holder reveals.
+#[derive(Cbor)]
+#[cbor(array)]
+struct SdPresentation {
+    /// The presented SD-CWT
+    token: Vec<u8>,
+    /// Disclosures of the revealed claims
+    disclosures: Array<Vec<u8>>,
+}
+
+/// Hashes a disclosure into the digest that the issuer signs in its place.
+fn disclosure_hash(disclosure: &[u8]) -> [u8; 32] {
+    Sha256::digest(disclosure).into()
+}
+
+/// Rejects the claims a verifier relies on to process a token (keys 1 to 8, the
+/// status at 65535 and the redacted digests themselves) as disclosures.
+fn check_disclosable(key: i64) -> Result<(), CryptoError> {
+    if (1..=8).contains(&key) || key == 65535 || key == SD_REDACTED_CLAIMS {
+        return Err(CryptoError::InvalidClaims {
+            details: format!("claim {key} cannot be disclosable"),
+        });
+    }
+    Ok(())
+}
+
+/// Extracts the claim key from a disclosure.
+fn disclosure_key(disclosure: &[u8]) -> Result<i64, CryptoError> {
+    let (_, _, key): ([u8; SD_SALT_SIZE], Raw, i64) = darkbio_crypto::cbor::decode(disclosure)?;
+    Ok(key)
+}
+
+/// Upper bound on the decompressed size of a status list, guarding against
+/// decompression bombs.
+const STATUS_LIST_MAX_SIZE: usize = 16 * 1024 * 1024;
+
+/// StatusList is a Token Status List, a compressed bitstring holding the
+/// status of each referenced token in `bits` wide entries. Issuers build and
+/// sign it as a CWT, verifiers check tokens against a cached, verified copy.
+///
+/// Status 0 means valid, 1 invalid (revoked) and 2 suspended; other values
+/// are application specific.
+#[frb(opaque)]
+pub struct StatusList {
+    uri: String,
+    bits: u8,
+    statuses: Vec<u8>,
+}
+
+impl StatusList {
+    /// Creates a status list with every entry set to valid.
+    ///
+    /// - `uri`: URI the status list token will be published at
+    /// - `bits`: Number of bits per entry (1, 2, 4 or 8)
+    /// - `len`: Number of entries, rounded up to fill the last byte
+    #[frb(sync)]
+    pub fn new(uri: String, bits: u8, len: usize) -> Result<Self, CryptoError> {
+        check_status_bits(bits)?;
+
+        let size = len.saturating_mul(bits as usize).div_ceil(8);
+        if size > STATUS_LIST_MAX_SIZE {
+            return Err(CryptoError::InvalidClaims {
+                details: format!("status list of {len} entries too large"),
+            });
+        }
+        Ok(Self {
+            uri,
+            bits,
+            statuses: vec![0; size],
+        })
+    }
+
+    /// Verifies a status list token and extracts the list from it.
+    ///
+    /// - `token`: The serialized status list token
+    /// - `verifier`: The xDSA public key of the status list issuer
+    /// - `domain`: Application-specific domain separator
+    /// - `now`: Current Unix timestamp to check nbf, exp and ttl against (None
+    ///   to skip)
+    ///
+    /// A list is rejected once `now` reaches its exp or its iat plus ttl, as
+    /// well as before its nbf.
+    #[frb(sync)]
+    pub fn verify(
+        token: Vec<u8>,
+        verifier: &XdsaPublicKey,
+        domain: Vec<u8>,
+        now: Option<u64>,
+    ) -> Result<Self, CryptoError> {
+        let raw: Raw = darkbio_crypto::cwt::verify(&token, &verifier.inner, &domain, None)?;
+        let mut dec = Decoder::new(&raw);
+        let mut entries = MapEntries::new(decode_map_entries_slices_notrail(&mut dec)?);
+        dec.finish()?;
+
+        let uri: String =
+            take_claim(&mut entries, 2)?.ok_or(CryptoError::MissingClaim { key: 2 })?;
+        let exp: Option<u64> = take_claim(&mut entries, 4)?;
+        let nbf: Option<u64> = take_claim(&mut entries, 5)?;
+        let iat: Option<u64> = take_claim(&mut entries, 6)?;
+        let ttl: Option<u64> = take_claim(&mut entries, 65534)?;
+        if let Some(now) = now {
+            if let Some(nbf) = nbf
+                && now < nbf
+            {
+                return Err(CryptoError::NotYetValid { nbf, now });
+            }
+            if let Some(exp) = exp
+                && now >= exp
+            {
+                return Err(CryptoError::Expired { exp, now });
+            }
+            if let Some(ttl) = ttl {
+                let iat = iat.ok_or(CryptoError::MissingClaim { key: 6 })?;
+                let stale = iat.saturating_add(ttl);
+                if now >= stale {
+                    return Err(CryptoError::Expired { exp: stale, now });
+                }
+            }
+        }
+        let list = entries
+            .take(65533)
+            .ok_or(CryptoError::MissingClaim { key: 65533 })?;
+
+        let mut fields = decode_text_map(list)?;
+        let bits: u64 = take_text_entry(&mut fields, "bits")?;
+        let lst: Vec<u8> = take_text_entry(&mut fields, "lst")?;
+
+        let bits = u8::try_from(bits).map_err(|_| CryptoError::InvalidClaims {
+            details: format!("invalid status bits {bits}"),
+        })?;
+        check_status_bits(bits)?;
+
+        let statuses =
+            miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(&lst, STATUS_LIST_MAX_SIZE)
+                .map_err(|e| CryptoError::InvalidClaims {
+                    details: format!("status list decompression failed: {e}"),
+                })?;
+        Ok(Self {
+            uri,
+            bits,
+            statuses,
+        })
+    }
+
+    /// Signs the status list as a CWT, with the list compressed using zlib.
+    ///
+    /// - `signer`: The xDSA secret key to sign with
+    /// - `domain`: Application-specific domain separator
+    /// - `iat`: Issuance time as Unix timestamp
+    /// - `exp`: Time after which the list must not be used (None for no expiry)
+    /// - `ttl`: Seconds the list may be cached before refetching (None to omit)
+    #[frb(sync)]
+    pub fn issue(
+        &self,
+        signer: &XdsaSecretKey,
+        domain: Vec<u8>,
+        iat: u64,
+        exp: Option<u64>,
+        ttl: Option<u64>,
+    ) -> Result<Vec<u8>, CryptoError> {
+        let lst = miniz_oxide::deflate::compress_to_vec_zlib(&self.statuses, 9);
+        let list = encode_text_map(vec![
+            (
+                "bits".to_string(),
+                darkbio_crypto::cbor::encode(self.bits as u64)?,
+            ),
+            ("lst".to_string(), darkbio_crypto::cbor::encode(lst)?),
+        ])?;
+
+        let mut enc = MapEncodeBuffer::new(5);
+        enc.push(2, &self.uri)?;
+        enc.push_optional(4, &exp)?;
+        enc.push(6, &iat)?;
+        enc.push_optional(65534, &ttl)?;
+        enc.push(65533, &Raw(list))?;
+
+        let mut claims = Vec::new();
+        enc.finish_to(&mut claims)?;
+        darkbio_crypto::cwt::issue(&Raw(claims), &signer.inner, &domain).map_err(CryptoError::from)
+    }
+
+    /// Returns the URI the status list is published at.
+    #[frb(sync)]
+    pub fn uri(&self) -> String {
+        self.uri.clone()
+    }
+
+    /// Returns the number of bits per entry.
+    #[frb(sync)]
+    pub fn bits(&self) -> u8 {
+        self.bits
+    }
+
+    /// Returns the number of entries in the list.
+    #[frb(sync)]
+    pub fn len(&self) -> usize {
+        self.statuses.len() * 8 / self.bits as usize
+    }
+
+    /// Returns whether the list has no entries.
+    #[frb(sync)]
+    pub fn is_empty(&self) -> bool {
+        self.statuses.is_empty()
+    }
+
+    /// Returns the status at `idx`.
+    #[frb(sync)]
+    pub fn get(&self, idx: usize) -> Result<u8, CryptoError> {
+        let (byte, shift, mask) = self.locate(idx)?;
+        Ok((self.statuses[byte] >> shift) & mask)
+    }
+
+    /// Sets the status at `idx`, which must fit in `bits` bits.
+    #[frb(sync)]
+    pub fn set(&mut self, idx: usize, status: u8) -> Result<(), CryptoError> {
+        let (byte, shift, mask) = self.locate(idx)?;
+        if status > mask {
+            return Err(CryptoError::InvalidClaims {
+                details: format!("status {status} does not fit in {} bits", self.bits),
+            });
+        }
+        self.statuses[byte] = (self.statuses[byte] & !(mask << shift)) | (status << shift);
+        Ok(())
+    }
+
+    /// Returns the byte, bit offset and mask of the entry at `idx`, with
+    /// entries packed from the least significant bit.
+    fn locate(&self, idx: usize) -> Result<(usize, u32, u8), CryptoError> {
+        if idx >= self.len() {
+            return Err(CryptoError::InvalidClaims {
+                details: format!("status index {idx} out of range"),
+            });
+        }
+        let bit = idx * self.bits as usize;
+        Ok((bit / 8, (bit % 8) as u32, (((1u16 << self.bits) - 1) as u8)))
+    }
+}
+
+/// Checks that the entry size is one allowed by the Token Status List spec.
+fn check_status_bits(bits: u8) -> Result<(), CryptoError> {
+    match bits {
+        1 | 2 | 4 | 8 => Ok(()),
+        _ => Err(CryptoError::InvalidClaims {
+            details: format!("invalid status bits {bits}"),
+        }),
+    }
+}
+
+/// Verifies a CWT's signature, checks its claims against the validation
+/// policy and, if a status list is given, rejects it unless its entry in the
+/// list is valid.
+fn verify_checked(
+    token: &[u8],
+    verifier: &XdsaPublicKey,
+    domain: &[u8],
+    validation: &CwtValidation,
+    status_list: Option<RustAutoOpaque<StatusList>>,
+) -> Result<Raw, CryptoError> {
+    let raw: Raw = darkbio_crypto::cwt::verify(token, &verifier.inner, domain, None)?;
+    validation.check(&raw)?;
+
+    let Some(status_list) = status_list else {
+        return Ok(raw);
+    };
+    let status_list = status_list.blocking_read();
+
+    let mut dec = Decoder::new(&raw);
+    let mut entries = MapEntries::new(decode_map_entries_slices_notrail(&mut dec)?);
+    dec.finish()?;
+
+    let status = CwtStatus::from_cbor(
+        entries
+            .take(65535)
+            .ok_or(CryptoError::MissingClaim { key: 65535 })?,
+    )?;
+    if status.uri != status_list.uri {
+        return Err(CryptoError::InvalidClaims {
+            details: format!("token references status list {}", status.uri),
+        });
+    }
+    let idx = usize::try_from(status.idx).map_err(|_| CryptoError::InvalidClaims {
+        details: format!("status index {} out of range", status.idx),
+    })?;
+    match status_list.get(idx)? {
+        0 => Ok(raw),
+        state => Err(CryptoError::Revoked {
+            idx: status.idx,
+            status: state,
+        }),
+    }
+}
+
+/// Takes and decodes a single claim from the map entries, if present.
+fn take_claim<T: Decode>(entries: &mut MapEntries<'_>, key: i64) -> Result<Option<T>, CryptoError> {
+    entries
+        .take(key)
+        .map(darkbio_crypto::cbor::decode)
+        .transpose()
+        .map_err(CryptoError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns an empty claims set to fill in with struct update syntax.
+    fn empty_claims() -> CwtClaims {
+        CwtClaims {
+            iss: None,
+            sub: None,
+            aud: None,
+            exp: None,
+            nbf: None,
+            iat: None,
+            cti: None,
+            cnf: None,
+            eat_nonce: None,
+            ueid: None,
+            oemid: None,
+            hw_model: None,
+            hw_version: None,
+            uptime: None,
+            oem_boot: None,
+            dbgstat: None,
+            submods: Vec::new(),
+            boot_count: None,
+            boot_seed: None,
+            sw_name: None,
+            sw_version: None,
+            measurements: Vec::new(),
+            status: None,
+            custom: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_claims_roundtrip() {
+        let issuer = XdsaSecretKey::generate();
+        let holder = XdsaSecretKey::generate();
+
+        let claims = CwtClaims {
+            iss: Some("issuer".to_string()),
+            sub: Some("device-abc".to_string()),
+            nbf: Some(1000),
+            exp: Some(2000),
+            cti: Some(vec![1, 2, 3]),
+            cnf: Some(CwtConfirmKey::Xdsa(holder.public_key())),
+            hw_version: Some("1.2".to_string()),
+            dbgstat: Some(EatDebugState::DisabledPermanently),
+            custom: vec![CwtCustomClaim {
+                key: -70000,
+                value: darkbio_crypto::cbor::encode("custom").unwrap(),
+            }],
+            ..empty_claims()
+        };
+        let token = cwt_issue_claims(claims, &issuer, b"domain".to_vec()).unwrap();
+
+        let check = |claims: CwtClaims| {
+            assert_eq!(claims.iss.as_deref(), Some("issuer"));
+            assert_eq!(claims.sub.as_deref(), Some("device-abc"));
+            assert_eq!((claims.nbf, claims.exp), (Some(1000), Some(2000)));
+            assert_eq!(claims.cti, Some(vec![1, 2, 3]));
+            assert!(matches!(
+                claims.cnf,
+                Some(CwtConfirmKey::Xdsa(key)) if key.to_bytes() == holder.public_key().to_bytes()
+            ));
+            assert_eq!(claims.hw_version.as_deref(), Some("1.2"));
+            assert!(matches!(
+                claims.dbgstat,
+                Some(EatDebugState::DisabledPermanently)
+            ));
+            assert_eq!(claims.custom.len(), 1);
+            assert_eq!(claims.custom[0].key, -70000);
+            assert_eq!(
+                claims.custom[0].value,
+                darkbio_crypto::cbor::encode("custom").unwrap()
+            );
+        };
+        check(cwt_peek_claims(token.clone()).unwrap());
+        check(
+            cwt_verify_claims(
+                token,
+                &issuer.public_key(),
+                b"domain".to_vec(),
+                CwtValidation::at(Some(1500)),
+                None,
+                None,
+                None,
+            )
+            .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_validation_policy() {
+        let signer = XdsaSecretKey::generate();
+        let token = cwt_issue_claims(
+            CwtClaims {
+                iss: Some("issuer".to_string()),
+                aud: Some("service".to_string()),
+                nbf: Some(1000),
+                iat: Some(1000),
+                exp: Some(2000),
+                ..empty_claims()
+            },
+            &signer,
+            b"domain".to_vec(),
+        )
+        .unwrap();
+        let verify = |validation: CwtValidation| {
+            cwt_verify_claims(
+                token.clone(),
+                &signer.public_key(),
+                b"domain".to_vec(),
+                validation,
+                None,
+                None,
+                None,
+            )
+        };
+        assert!(verify(CwtValidation::at(Some(1500))).is_ok());
+        assert!(matches!(
+            verify(CwtValidation::at(Some(999))),
+            Err(CryptoError::NotYetValid {
+                nbf: 1000,
+                now: 999
+            })
+        ));
+        assert!(matches!(
+            verify(CwtValidation::at(Some(2000))),
+            Err(CryptoError::Expired {
+                exp: 2000,
+                now: 2000
+            })
+        ));
+        assert!(
+            verify(CwtValidation {
+                leeway_secs: 10,
+                ..CwtValidation::at(Some(2005))
+            })
+            .is_ok()
+        );
+        assert!(matches!(
+            verify(CwtValidation {
+                max_age_secs: Some(100),
+                ..CwtValidation::at(Some(1200))
+            }),
+            Err(CryptoError::TokenTooOld { .. })
+        ));
+        assert!(matches!(
+            verify(CwtValidation {
+                max_age_secs: Some(100),
+                ..CwtValidation::at(None)
+            }),
+            Err(CryptoError::InvalidClaims { .. })
+        ));
+        assert!(matches!(
+            verify(CwtValidation {
+                issuer: Some("other".to_string()),
+                ..CwtValidation::at(None)
+            }),
+            Err(CryptoError::IssuerMismatch { .. })
+        ));
+        assert!(matches!(
+            verify(CwtValidation {
+                audiences: vec!["other".to_string()],
+                ..CwtValidation::at(None)
+            }),
+            Err(CryptoError::AudienceMismatch { .. })
+        ));
+        assert!(matches!(
+            verify(CwtValidation {
+                required_claims: vec![7],
+                ..CwtValidation::at(None)
+            }),
+            Err(CryptoError::MissingClaim { key: 7 })
+        ));
+        assert!(matches!(
+            verify(CwtValidation {
+                confirm_key: Some(CwtConfirmKeyType::Xdsa),
+                ..CwtValidation::at(None)
+            }),
+            Err(CryptoError::MissingClaim { key: 8 })
+        ));
+    }
+
+    #[test]
+    fn test_sealed_roundtrip() {
+        let issuer = XdsaSecretKey::generate();
+        let relying = XhpkeSecretKey::generate();
+
+        let token = cwt_issue_sealed(
+            CwtClaims {
+                sub: Some("secret".to_string()),
+                nbf: Some(1000),
+                ..empty_claims()
+            },
+            &issuer,
+            &relying.public_key(),
+            b"domain".to_vec(),
+        )
+        .unwrap();
+
+        // The claims are encrypted, so they cannot be peeked at
+        assert!(cwt_peek_claims(token.clone()).is_err());
+
+        let open = |recipient: &XhpkeSecretKey, signer: &XdsaPublicKey, now: u64| {
+            cwt_open_sealed(
+                token.clone(),
+                recipient,
+                signer,
+                b"domain".to_vec(),
+                CwtValidation::at(Some(now)),
+            )
+        };
+        let claims = open(&relying, &issuer.public_key(), 1000).unwrap();
+        assert_eq!(claims.sub.as_deref(), Some("secret"));
+
+        assert!(matches!(
+            open(&relying, &issuer.public_key(), 999),
+            Err(CryptoError::NotYetValid { .. })
+        ));
+        assert!(open(&XhpkeSecretKey::generate(), &issuer.public_key(), 1000).is_err());
+        assert!(open(&relying, &XdsaSecretKey::generate().public_key(), 1000).is_err());
+    }
+
+    #[test]
+    fn test_verify_chain() {
+        let root = XdsaSecretKey::generate();
+        let middle = XdsaSecretKey::generate();
+        let leaf = XdsaSecretKey::generate();
+
+        let issue = |signer: &XdsaSecretKey, cnf: Option<CwtConfirmKey>, nbf: u64, exp: u64| {
+            cwt_issue_claims(
+                CwtClaims {
+                    cnf,
+                    nbf: Some(nbf),
+                    exp: Some(exp),
+                    sub: Some(format!("{nbf}-{exp}")),
+                    ..empty_claims()
+                },
+                signer,
+                b"domain".to_vec(),
+            )
+            .unwrap()
+        };
+        let first = issue(
+            &root,
+            Some(CwtConfirmKey::Xdsa(middle.public_key())),
+            1000,
+            3000,
+        );
+        let second = issue(
+            &middle,
+            Some(CwtConfirmKey::Xdsa(leaf.public_key())),
+            1500,
+            2500,
+        );
+        let third = issue(&leaf, None, 1600, 2400);
+
+        let verify = |tokens: Vec<Vec<u8>>, now: Option<u64>| {
+            cwt_verify_chain(tokens, &root.public_key(), b"domain".to_vec(), now)
+        };
+        let claims = verify(
+            vec![first.clone(), second.clone(), third.clone()],
+            Some(2000),
+        )
+        .unwrap();
+        assert_eq!(claims.sub.as_deref(), Some("1600-2400"));
+
+        // Every token must be valid at `now`, not only the leaf
+        assert!(matches!(
+            verify(
+                vec![first.clone(), second.clone(), third.clone()],
+                Some(2450)
+            ),
+            Err(CryptoError::Expired { exp: 2400, .. })
+        ));
+        // Links must be signed by the key bound in their parent
+        assert!(verify(vec![first.clone(), third.clone()], None).is_err());
+        assert!(matches!(
+            verify(vec![], None),
+            Err(CryptoError::InvalidClaims { .. })
+        ));
+
+        // Delegated tokens may not outlive their parent
+        let outliving = issue(&middle, None, 1500, 3500);
+        assert!(matches!(
+            verify(vec![first.clone(), outliving], None),
+            Err(CryptoError::InvalidClaims { .. })
+        ));
+
+        // An xHPKE key cannot sign the next link
+        let sealing = issue(
+            &root,
+            Some(CwtConfirmKey::Xhpke(
+                XhpkeSecretKey::generate().public_key(),
+            )),
+            1000,
+            3000,
+        );
+        assert!(matches!(
+            verify(vec![sealing, second], None),
+            Err(CryptoError::ConfirmKeyMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_presentation() {
+        let issuer = XdsaSecretKey::generate();
+        let holder = XdsaSecretKey::generate();
+
+        let token = cwt_issue_claims(
+            CwtClaims {
+                sub: Some("holder".to_string()),
+                cnf: Some(CwtConfirmKey::Xdsa(holder.public_key())),
+                ..empty_claims()
+            },
+            &issuer,
+            b"domain".to_vec(),
+        )
+        .unwrap();
+        let presentation = cwt_present(
+            token.clone(),
+            &holder,
+            "verifier".to_string(),
+            b"nonce".to_vec(),
+            b"domain".to_vec(),
+        )
+        .unwrap();
+
+        let verify = |presentation: Vec<u8>, audience: &str, nonce: &[u8]| {
+            cwt_verify_presentation(
+                presentation,
+                &issuer.public_key(),
+                audience.to_string(),
+                nonce.to_vec(),
+                b"domain".to_vec(),
+                CwtValidation::at(None),
+                Some(60),
+            )
+        };
+        let claims = verify(presentation.clone(), "verifier", b"nonce").unwrap();
+        assert_eq!(claims.sub.as_deref(), Some("holder"));
+
+        assert!(matches!(
+            verify(presentation.clone(), "other", b"nonce"),
+            Err(CryptoError::AudienceMismatch { .. })
+        ));
+        assert!(matches!(
+            verify(presentation, "verifier", b"replayed"),
+            Err(CryptoError::NonceMismatch { .. })
+        ));
+
+        // Only the key bound in the token can prove possession
+        let stolen = cwt_present(
+            token,
+            &XdsaSecretKey::generate(),
+            "verifier".to_string(),
+            b"nonce".to_vec(),
+            b"domain".to_vec(),
+        )
+        .unwrap();
+        assert!(verify(stolen, "verifier", b"nonce").is_err());
+
+        // Tokens without a bound key cannot be presented
+        let unbound = cwt_issue_claims(empty_claims(), &issuer, b"domain".to_vec()).unwrap();
+        let presentation = cwt_present(
+            unbound,
+            &holder,
+            "verifier".to_string(),
+            b"nonce".to_vec(),
+            b"domain".to_vec(),
+        )
+        .unwrap();
+        assert!(matches!(
+            verify(presentation, "verifier", b"nonce"),
+            Err(CryptoError::MissingClaim { key: 8 })
+        ));
+    }
+
+    #[test]
+    fn test_eat_roundtrip() {
+        let signer = XdsaSecretKey::generate();
+
+        let roundtrip = |claims: CwtClaims| {
+            let token = cwt_issue_claims(claims, &signer, b"domain".to_vec()).unwrap();
+            cwt_peek_claims(token).unwrap()
+        };
+        for oemid in [
+            EatOemid::Random(vec![1; 16]),
+            EatOemid::Ieee(vec![2; 3]),
+            EatOemid::Pen(12345),
+        ] {
+            let claims = roundtrip(CwtClaims {
+                oemid: Some(oemid),
+                ..empty_claims()
+            });
+            match claims.oemid {
+                Some(EatOemid::Random(id)) => assert_eq!(id, vec![1; 16]),
+                Some(EatOemid::Ieee(id)) => assert_eq!(id, vec![2; 3]),
+                Some(EatOemid::Pen(pen)) => assert_eq!(pen, 12345),
+                None => panic!("oemid lost in round trip"),
+            }
+        }
+        // Identifiers of any other size are rejected when issuing
+        assert!(
+            cwt_issue_claims(
+                CwtClaims {
+                    oemid: Some(EatOemid::Ieee(vec![2; 4])),
+                    ..empty_claims()
+                },
+                &signer,
+                b"domain".to_vec(),
+            )
+            .is_err()
+        );
+
+        let nested = XdsaSecretKey::generate();
+        let embedded = cwt_issue_claims(
+            CwtClaims {
+                sub: Some("embedded".to_string()),
+                ..empty_claims()
+            },
+            &nested,
+            b"domain".to_vec(),
+        )
+        .unwrap();
+        let claims = roundtrip(CwtClaims {
+            submods: vec![
+                EatSubmodule {
+                    name: "radio".to_string(),
+                    value: EatSubmoduleValue::Claims(Box::new(CwtClaims {
+                        sw_name: Some("firmware".to_string()),
+                        measurements: vec![EatMeasurement {
+                            content_type: 60,
+                            content: vec![3; 32],
+                        }],
+                        ..empty_claims()
+                    })),
+                },
+                EatSubmodule {
+                    name: "secure-element".to_string(),
+                    value: EatSubmoduleValue::Embedded(embedded.clone()),
+                },
+                EatSubmodule {
+                    name: "tpm".to_string(),
+                    value: EatSubmoduleValue::Detached {
+                        alg: -16,
+                        digest: vec![4; 32],
+                    },
+                },
+            ],
+            ..empty_claims()
+        });
+        let mut submods = claims.submods;
+        submods.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(submods.len(), 3);
+
+        let EatSubmoduleValue::Claims(radio) = &submods[0].value else {
+            panic!("radio submodule not inline claims");
+        };
+        assert_eq!(radio.sw_name.as_deref(), Some("firmware"));
+        assert_eq!(radio.measurements[0].content_type, 60);
+        assert_eq!(radio.measurements[0].content, vec![3; 32]);
+
+        let EatSubmoduleValue::Embedded(token) = &submods[1].value else {
+            panic!("secure-element submodule not an embedded token");
+        };
+        assert_eq!(*token, embedded);
+
+        let EatSubmoduleValue::Detached { alg, digest } = &submods[2].value else {
+            panic!("tpm submodule not a detached digest");
+        };
+        assert_eq!((*alg, digest.as_slice()), (-16, [4u8; 32].as_slice()));
+    }
+
+    #[test]
+    fn test_selective_disclosure() {
+        let signer = XdsaSecretKey::generate();
+        let claims = || CwtClaims {
+            sub: Some("holder".to_string()),
+            sw_name: Some("firmware".to_string()),
+            ueid: Some(vec![1; 16]),
+            ..empty_claims()
+        };
+        let sd = cwt_issue_sd(claims(), vec![256, 270], &signer, b"domain".to_vec()).unwrap();
+        assert_eq!(sd.disclosures.len(), 2);
+
+        // Redacted claims are not visible without their disclosures
+        let peeked = cwt_peek_claims(sd.token.clone()).unwrap();
+        assert!(peeked.sw_name.is_none() && peeked.ueid.is_none());
+
+        let verify = |presentation: Vec<u8>| {
+            cwt_verify_sd(
+                presentation,
+                &signer.public_key(),
+                b"domain".to_vec(),
+                CwtValidation::at(None),
+            )
+        };
+        let presentation =
+            cwt_present_sd(sd.token.clone(), sd.disclosures.clone(), vec![270]).unwrap();
+        let disclosed = verify(presentation).unwrap();
+        assert_eq!(disclosed.sub.as_deref(), Some("holder"));
+        assert_eq!(disclosed.sw_name.as_deref(), Some("firmware"));
+        assert!(disclosed.ueid.is_none());
+
+        // Disclosures not signed by the issuer are rejected
+        let other = cwt_issue_sd(claims(), vec![270], &signer, b"domain".to_vec()).unwrap();
+        let forged = darkbio_crypto::cbor::encode(SdPresentation {
+            token: sd.token.clone(),
+            disclosures: Array::from(other.disclosures),
+        })
+        .unwrap();
+        assert!(matches!(
+            verify(forged),
+            Err(CryptoError::InvalidClaims { .. })
+        ));
+
+        for key in [1, 4, 5, 8, 65535] {
+            assert!(matches!(
+                cwt_issue_sd(claims(), vec![key], &signer, b"domain".to_vec()),
+                Err(CryptoError::InvalidClaims { .. })
+            ));
+        }
+        assert!(matches!(
+            cwt_issue_sd(claims(), vec![261], &signer, b"domain".to_vec()),
+            Err(CryptoError::MissingClaim { key: 261 })
+        ));
+
+        // Reserved claims disclosed by tokens from other issuers are rejected
+        for key in [1, 8, 65535, SD_REDACTED_CLAIMS] {
+            let disclosure =
+                darkbio_crypto::cbor::encode(([7u8; SD_SALT_SIZE], Raw(vec![0x40]), key)).unwrap();
+            let mut enc = MapEncodeBuffer::new(2);
+            enc.push(2, &"holder").unwrap();
+            enc.push(
+                SD_REDACTED_CLAIMS,
+                &Array::from(vec![disclosure_hash(&disclosure)]),
+            )
+            .unwrap();
+            let mut redacted = Vec::new();
+            enc.finish_to(&mut redacted).unwrap();
+
+            let token =
+                darkbio_crypto::cwt::issue(&Raw(redacted), &signer.inner, b"domain").unwrap();
+            let presentation = cwt_present_sd(token, vec![disclosure], vec![key]).unwrap();
+            assert!(matches!(
+                verify(presentation),
+                Err(CryptoError::InvalidClaims { details }) if details.contains("disclosable")
+            ));
+        }
+    }
+
+    #[test]
+    fn test_status_list() {
+        let issuer = XdsaSecretKey::generate();
+        let authority = XdsaSecretKey::generate();
+
+        let mut list = StatusList::new("https://example.com/status".to_string(), 2, 16).unwrap();
+        list.set(3, 1).unwrap();
+        let list_token = list
+            .issue(&authority, b"status".to_vec(), 1000, Some(3000), Some(600))
+            .unwrap();
+
+        let list_verify = |now| {
+            StatusList::verify(
+                list_token.clone(),
+                &authority.public_key(),
+                b"status".to_vec(),
+                now,
+            )
+        };
+        assert!(matches!(
+            list_verify(Some(1599)).map(|list| list.get(3)),
+            Ok(Ok(1))
+        ));
+        assert!(matches!(
+            list_verify(Some(1600)),
+            Err(CryptoError::Expired { exp: 1600, .. })
+        ));
+
+        let late = list
+            .issue(&authority, b"status".to_vec(), 1000, Some(3000), None)
+            .unwrap();
+        assert!(matches!(
+            StatusList::verify(
+                late,
+                &authority.public_key(),
+                b"status".to_vec(),
+                Some(3000)
+            ),
+            Err(CryptoError::Expired { exp: 3000, .. })
+        ));
+
+        // A list with a not-before time is rejected ahead of it
+        let mut enc = MapEncodeBuffer::new(3);
+        enc.push(2, &list.uri).unwrap();
+        enc.push(5, &2000u64).unwrap();
+        enc.push(
+            65533,
+            &Raw(encode_text_map(vec![
+                (
+                    "bits".to_string(),
+                    darkbio_crypto::cbor::encode(2u64).unwrap(),
+                ),
+                (
+                    "lst".to_string(),
+                    darkbio_crypto::cbor::encode(miniz_oxide::deflate::compress_to_vec_zlib(
+                        &list.statuses,
+                        9,
+                    ))
+                    .unwrap(),
+                ),
+            ])
+            .unwrap()),
+        )
+        .unwrap();
+        let mut claims = Vec::new();
+        enc.finish_to(&mut claims).unwrap();
+        let future = darkbio_crypto::cwt::issue(&Raw(claims), &authority.inner, b"status").unwrap();
+        assert!(matches!(
+            StatusList::verify(
+                future.clone(),
+                &authority.public_key(),
+                b"status".to_vec(),
+                Some(1999)
+            ),
+            Err(CryptoError::NotYetValid { nbf: 2000, .. })
+        ));
+        assert!(
+            StatusList::verify(
+                future,
+                &authority.public_key(),
+                b"status".to_vec(),
+                Some(2000)
+            )
+            .is_ok()
+        );
+
+        let list = RustAutoOpaque::new(list_verify(Some(1500)).unwrap());
+        let verify = |idx: u64, uri: &str| {
+            let token = cwt_issue_claims(
+                CwtClaims {
+                    status: Some(CwtStatus {
+                        idx,
+                        uri: uri.to_string(),
+                    }),
+                    ..empty_claims()
+                },
+                &issuer,
+                b"domain".to_vec(),
+            )
+            .unwrap();
+            cwt_verify(
+                token,
+                &issuer.public_key(),
+                b"domain".to_vec(),
+                CwtValidation::at(None),
+                Some(list.clone()),
+                None,
+                None,
+            )
+        };
+        assert!(verify(2, "https://example.com/status").is_ok());
+        assert!(matches!(
+            verify(3, "https://example.com/status"),
+            Err(CryptoError::Revoked { idx: 3, status: 1 })
+        ));
+        assert!(matches!(
+            verify(2, "https://example.com/other"),
+            Err(CryptoError::InvalidClaims { .. })
+        ));
+        assert!(matches!(
+            verify(16, "https://example.com/status"),
+            Err(CryptoError::InvalidClaims { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_schema() {
+        let signer = XdsaSecretKey::generate();
+        let token = cwt_issue_claims(
+            CwtClaims {
+                sub: Some("device".to_string()),
+                ..empty_claims()
+            },
+            &signer,
+            b"domain".to_vec(),
+        )
+        .unwrap();
+        let verify = |cddl: &str| {
+            cwt_verify(
+                token.clone(),
+                &signer.public_key(),
+                b"domain".to_vec(),
+                CwtValidation::at(None),
+                None,
+                Some(RustAutoOpaque::new(
+                    CddlSchema::compile(cddl.to_string()).unwrap(),
+                )),
+                Some("claims".to_string()),
+            )
+        };
+        assert!(verify("claims = {2: tstr}").is_ok());
+        assert!(matches!(
+            verify("claims = {2: tstr, 4: uint}"),
+            Err(CryptoError::SchemaMismatch { .. })
+        ));
+        assert!(matches!(
+            cwt_verify(
+                token.clone(),
+                &signer.public_key(),
+                b"domain".to_vec(),
+                CwtValidation::at(None),
+                None,
+                None,
+                Some("claims".to_string()),
+            ),
+            Err(CryptoError::InvalidSchema { .. })
+        ));
+    }
+}
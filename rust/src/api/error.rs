@@ -0,0 +1,210 @@
+// crypto-fl: cryptography primitives and wrappers
+// Copyright 2026 Dark Bio AG. All rights reserved.
+//
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+use std::fmt;
+
+/// CryptoError is the structured failure returned by every fallible call,
+/// surfaced in Dart as a sealed exception hierarchy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CryptoError {
+    /// A fixed-size input (key, seed, signature, fingerprint) had the wrong size.
+    InvalidLength { expected: usize, got: usize },
+    /// A key, signature or certificate could not be parsed.
+    InvalidEncoding { details: String },
+    /// A parameter was outside the values the call accepts, e.g. an empty key
+    /// list or a zero threshold.
+    InvalidArgument { details: String },
+    /// CBOR data was malformed or outside the restricted deterministic profile.
+    CborInvalid { details: String },
+    /// A signature did not verify against the given key and domain. Domains are
+    /// not carried in messages, so a wrong domain cannot be told apart from a
+    /// wrong key or tampered data.
+    SignatureMismatch { details: String },
+    /// A message was signed by or encrypted to a different key than provided.
+    KeyMismatch { details: String },
+    /// Encryption could not be performed.
+    EncryptionFailed { details: String },
+    /// Authenticated decryption failed (wrong key, domain or tampered data).
+    DecryptionFailed { details: String },
+    /// The validity period ended at `exp`, before `now`.
+    Expired { exp: u64, now: u64 },
+    /// The validity period starts at `nbf`, after `now`.
+    NotYetValid { nbf: u64, now: u64 },
+    /// The signature timestamp drifted further from the current time than allowed.
+    StaleSignature { drift: u64, max_drift: u64 },
+    /// A token's claims were missing or malformed.
+    InvalidClaims { details: String },
+    /// A certificate could not be issued or failed a policy check.
+    InvalidCertificate { details: String },
+    /// Reading or writing the underlying data failed.
+    Io { details: String },
+    /// A claim required by the validation policy was absent.
+    MissingClaim { key: i64 },
+    /// The token was issued by someone other than the expected issuer.
+    IssuerMismatch { expected: String, got: String },
+    /// The token's audience was not among the accepted ones.
+    AudienceMismatch { got: String },
+    /// The token was issued at `iat`, more than `max_age` seconds before `now`.
+    TokenTooOld { iat: u64, max_age: u64, now: u64 },
+    /// The token's confirmation key was not of the required type.
+    ConfirmKeyMismatch { details: String },
+    /// A proof answered a different challenge than the expected nonce.
+    NonceMismatch { details: String },
+    /// The token's entry in its status list is not valid (revoked or suspended).
+    Revoked { idx: u64, status: u8 },
+    /// A CDDL schema could not be compiled or evaluated.
+    InvalidSchema { details: String },
+    /// A CBOR value did not match its schema at `path`.
+    SchemaMismatch { path: String, details: String },
+}
+
+impl fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, got } => {
+                write!(f, "invalid length: {got} bytes, expected {expected}")
+            }
+            Self::InvalidEncoding { details } => write!(f, "invalid encoding: {details}"),
+            Self::InvalidArgument { details } => write!(f, "invalid argument: {details}"),
+            Self::CborInvalid { details } => write!(f, "invalid cbor: {details}"),
+            Self::SignatureMismatch { details } => write!(f, "signature mismatch: {details}"),
+            Self::KeyMismatch { details } => write!(f, "key mismatch: {details}"),
+            Self::EncryptionFailed { details } => write!(f, "encryption failed: {details}"),
+            Self::DecryptionFailed { details } => write!(f, "decryption failed: {details}"),
+            Self::Expired { exp, now } => write!(f, "expired: exp {exp} <= now {now}"),
+            Self::NotYetValid { nbf, now } => write!(f, "not yet valid: nbf {nbf} > now {now}"),
+            Self::StaleSignature { drift, max_drift } => {
+                write!(
+                    f,
+                    "stale signature: drift {drift}s exceeds max {max_drift}s"
+                )
+            }
+            Self::InvalidClaims { details } => write!(f, "invalid claims: {details}"),
+            Self::InvalidCertificate { details } => write!(f, "invalid certificate: {details}"),
+            Self::Io { details } => write!(f, "i/o error: {details}"),
+            Self::MissingClaim { key } => write!(f, "missing claim: {key}"),
+            Self::IssuerMismatch { expected, got } => {
+                write!(f, "issuer mismatch: {got}, expected {expected}")
+            }
+            Self::AudienceMismatch { got } => write!(f, "audience mismatch: {got}"),
+            Self::TokenTooOld { iat, max_age, now } => {
+                write!(f, "token too old: iat {iat} + {max_age}s < now {now}")
+            }
+            Self::ConfirmKeyMismatch { details } => write!(f, "confirm key mismatch: {details}"),
+            Self::NonceMismatch { details } => write!(f, "nonce mismatch: {details}"),
+            Self::Revoked { idx, status } => write!(f, "revoked: status {status} at index {idx}"),
+            Self::InvalidSchema { details } => write!(f, "invalid schema: {details}"),
+            Self::SchemaMismatch { path, details } => {
+                write!(f, "schema mismatch at {path}: {details}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CryptoError {}
+
+impl From<darkbio_crypto::cbor::Error> for CryptoError {
+    fn from(e: darkbio_crypto::cbor::Error) -> Self {
+        Self::CborInvalid {
+            details: e.to_string(),
+        }
+    }
+}
+
+impl From<darkbio_crypto::cose::Error> for CryptoError {
+    fn from(e: darkbio_crypto::cose::Error) -> Self {
+        use darkbio_crypto::cose::Error;
+
+        match e {
+            Error::CborError(e) => e.into(),
+            Error::UnexpectedSigningKey(..) | Error::UnexpectedEncryptionKey(..) => {
+                Self::KeyMismatch {
+                    details: e.to_string(),
+                }
+            }
+            Error::InvalidSignature(details) => Self::SignatureMismatch { details },
+            Error::StaleSignature(drift, max_drift) => Self::StaleSignature { drift, max_drift },
+            Error::InvalidEncapKeySize(got, expected) => Self::InvalidLength { expected, got },
+            Error::DecryptionFailed(details) => Self::DecryptionFailed { details },
+            Error::UnexpectedAlgorithm(..) | Error::UnexpectedPayload | Error::MissingPayload => {
+                Self::InvalidEncoding {
+                    details: e.to_string(),
+                }
+            }
+        }
+    }
+}
+
+impl From<darkbio_crypto::cwt::Error> for CryptoError {
+    fn from(e: darkbio_crypto::cwt::Error) -> Self {
+        use darkbio_crypto::cwt::Error;
+
+        match e {
+            Error::Cbor(e) => e.into(),
+            Error::Cose(e) => e.into(),
+            Error::NotYetValid { nbf, now } => Self::NotYetValid { nbf, now },
+            Error::AlreadyExpired { exp, now } => Self::Expired { exp, now },
+            Error::MissingNbf | Error::DuplicateKey(_) => Self::InvalidClaims {
+                details: e.to_string(),
+            },
+        }
+    }
+}
+
+impl From<darkbio_crypto::x509::Error> for CryptoError {
+    fn from(e: darkbio_crypto::x509::Error) -> Self {
+        use darkbio_crypto::x509::Error;
+
+        match e {
+            // The only boxed error is the issuer's signature check
+            Error::External(e) => Self::SignatureMismatch {
+                details: e.to_string(),
+            },
+            Error::X509Parse { details } => Self::InvalidEncoding { details },
+            Error::Der(_) | Error::Oid(_) => Self::InvalidEncoding {
+                details: e.to_string(),
+            },
+            _ => Self::InvalidCertificate {
+                details: e.to_string(),
+            },
+        }
+    }
+}
+
+impl From<std::io::Error> for CryptoError {
+    fn from(e: std::io::Error) -> Self {
+        // STREAM reports authentication failures and truncation as I/O errors
+        match e.kind() {
+            std::io::ErrorKind::InvalidData | std::io::ErrorKind::UnexpectedEof => {
+                Self::DecryptionFailed {
+                    details: e.to_string(),
+                }
+            }
+            _ => Self::Io {
+                details: e.to_string(),
+            },
+        }
+    }
+}
+
+impl CryptoError {
+    /// Wraps a key or PEM parsing failure, which upstream reports as a boxed
+    /// error.
+    pub(crate) fn encoding(e: Box<dyn std::error::Error>) -> Self {
+        Self::InvalidEncoding {
+            details: e.to_string(),
+        }
+    }
+}
+
+/// Converts a byte vector into a fixed-size array, reporting the size mismatch
+/// if the length is wrong.
+pub(crate) fn to_array<const N: usize>(bytes: Vec<u8>) -> Result<[u8; N], CryptoError> {
+    let got = bytes.len();
+    bytes
+        .try_into()
+        .map_err(|_| CryptoError::InvalidLength { expected: N, got })
+}
@@ -6,6 +6,8 @@
 
 use flutter_rust_bridge::frb;
 
+use super::error::{CryptoError, to_array};
+
 /// Derives a key of the specified length from the secret, salt, and info using HKDF-SHA256.
 #[frb(sync)]
 pub fn hkdf_key(secret: Vec<u8>, salt: Vec<u8>, info: Vec<u8>, length: usize) -> Vec<u8> {
@@ -22,10 +24,8 @@ pub fn hkdf_extract(secret: Vec<u8>, salt: Vec<u8>) -> Vec<u8> {
 /// Expands a 32-byte pseudorandom key into a key of the specified length using HKDF-SHA256.
 /// The prk should have been generated by hkdf_extract.
 #[frb(sync)]
-pub fn hkdf_expand(prk: Vec<u8>, info: Vec<u8>, length: usize) -> Result<Vec<u8>, String> {
-    let prk_array: [u8; 32] = prk
-        .try_into()
-        .map_err(|_| "Invalid PRK length, expected 32 bytes".to_string())?;
+pub fn hkdf_expand(prk: Vec<u8>, info: Vec<u8>, length: usize) -> Result<Vec<u8>, CryptoError> {
+    let prk_array: [u8; 32] = to_array(prk)?;
 
     Ok(darkbio_crypto::hkdf::expand_with_len(
         prk_array, &info, length,
@@ -6,12 +6,15 @@
 
 pub mod argon2;
 pub mod cbor;
+pub mod cddl;
 pub mod cose;
 pub mod cwt;
+pub mod error;
 pub mod hkdf;
 pub mod rand;
 pub mod rsa;
 pub mod stream;
+pub mod x509;
 pub mod xdsa;
 pub mod xhpke;
 
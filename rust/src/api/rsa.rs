@@ -6,6 +6,8 @@
 
 use flutter_rust_bridge::frb;
 
+use super::error::{CryptoError, to_array};
+
 /// RsaSecretKey is a 2048-bit RSA private key for creating digital signatures
 /// using SHA-256 as the underlying hash algorithm.
 #[frb(opaque)]
@@ -25,29 +27,30 @@ impl RsaSecretKey {
     /// Creates a private key from a 520-byte array.
     /// Format: p (128 bytes) || q (128 bytes) || d (256 bytes) || e (8 bytes).
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 520] = bytes
-            .try_into()
-            .map_err(|_| "Invalid key length, expected 520 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 520] = to_array(bytes)?;
         Ok(Self {
-            inner: darkbio_crypto::rsa::SecretKey::from_bytes(&bytes_array)
-                .map_err(|e| e.to_string())?,
+            inner: darkbio_crypto::rsa::SecretKey::from_bytes(&bytes_array).map_err(|e| {
+                CryptoError::InvalidEncoding {
+                    details: e.to_string(),
+                }
+            })?,
         })
     }
 
     /// Parses a DER-encoded private key.
     #[frb(sync)]
-    pub fn from_der(der: Vec<u8>) -> Result<Self, String> {
+    pub fn from_der(der: Vec<u8>) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::rsa::SecretKey::from_der(&der).map_err(|e| e.to_string())?,
+            inner: darkbio_crypto::rsa::SecretKey::from_der(&der).map_err(CryptoError::encoding)?,
         })
     }
 
     /// Parses a PEM-encoded private key.
     #[frb(sync)]
-    pub fn from_pem(pem: String) -> Result<Self, String> {
+    pub fn from_pem(pem: String) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::rsa::SecretKey::from_pem(&pem).map_err(|e| e.to_string())?,
+            inner: darkbio_crypto::rsa::SecretKey::from_pem(&pem).map_err(CryptoError::encoding)?,
         })
     }
 
@@ -104,29 +107,30 @@ impl RsaPublicKey {
     /// Creates a public key from a 264-byte array.
     /// Format: n (256 bytes) || e (8 bytes).
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 264] = bytes
-            .try_into()
-            .map_err(|_| "Invalid key length, expected 264 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 264] = to_array(bytes)?;
         Ok(Self {
-            inner: darkbio_crypto::rsa::PublicKey::from_bytes(&bytes_array)
-                .map_err(|e| e.to_string())?,
+            inner: darkbio_crypto::rsa::PublicKey::from_bytes(&bytes_array).map_err(|e| {
+                CryptoError::InvalidEncoding {
+                    details: e.to_string(),
+                }
+            })?,
         })
     }
 
     /// Parses a DER-encoded public key.
     #[frb(sync)]
-    pub fn from_der(der: Vec<u8>) -> Result<Self, String> {
+    pub fn from_der(der: Vec<u8>) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::rsa::PublicKey::from_der(&der).map_err(|e| e.to_string())?,
+            inner: darkbio_crypto::rsa::PublicKey::from_der(&der).map_err(CryptoError::encoding)?,
         })
     }
 
     /// Parses a PEM-encoded public key.
     #[frb(sync)]
-    pub fn from_pem(pem: String) -> Result<Self, String> {
+    pub fn from_pem(pem: String) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::rsa::PublicKey::from_pem(&pem).map_err(|e| e.to_string())?,
+            inner: darkbio_crypto::rsa::PublicKey::from_pem(&pem).map_err(CryptoError::encoding)?,
         })
     }
 
@@ -158,10 +162,12 @@ impl RsaPublicKey {
 
     /// Verifies a signature against a message.
     #[frb(sync)]
-    pub fn verify(&self, message: Vec<u8>, signature: &RsaSignature) -> Result<(), String> {
+    pub fn verify(&self, message: Vec<u8>, signature: &RsaSignature) -> Result<(), CryptoError> {
         self.inner
             .verify(&message, &signature.inner)
-            .map_err(|e| e.to_string())
+            .map_err(|e| CryptoError::SignatureMismatch {
+                details: e.to_string(),
+            })
     }
 }
 
@@ -174,10 +180,8 @@ pub struct RsaSignature {
 impl RsaSignature {
     /// Creates a signature from a 256-byte array.
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 256] = bytes
-            .try_into()
-            .map_err(|_| "Invalid signature length, expected 256 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 256] = to_array(bytes)?;
         Ok(Self {
             inner: darkbio_crypto::rsa::Signature::from_bytes(&bytes_array),
         })
@@ -199,10 +203,8 @@ pub struct RsaFingerprint {
 impl RsaFingerprint {
     /// Creates a fingerprint from a 32-byte array.
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 32] = bytes
-            .try_into()
-            .map_err(|_| "Invalid fingerprint length, expected 32 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 32] = to_array(bytes)?;
         Ok(Self {
             inner: darkbio_crypto::rsa::Fingerprint::from_bytes(&bytes_array),
         })
@@ -5,28 +5,32 @@
 // license that can be found in the LICENSE file.
 
 use flutter_rust_bridge::frb;
-use std::io::{Read, Write};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::VecDeque;
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, BufWriter, Cursor, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use super::argon2::{Argon2Params, check_params};
+use super::error::{CryptoError, to_array};
+use crate::frb_generated::StreamSink;
 
 /// Encrypts plaintext using the STREAM construction with ChaCha20-Poly1305.
 /// The key must be exactly 32 bytes and should never be reused across streams.
 #[frb(sync)]
-pub fn stream_encrypt(key: Vec<u8>, plaintext: Vec<u8>) -> Result<Vec<u8>, String> {
-    let key_array: [u8; 32] = key
-        .try_into()
-        .map_err(|_| "Invalid key length, expected 32 bytes".to_string())?;
+pub fn stream_encrypt(key: Vec<u8>, plaintext: Vec<u8>) -> Result<Vec<u8>, CryptoError> {
+    let key_array: [u8; 32] = to_array(key)?;
 
     let payload_key = darkbio_crypto::stream::PayloadKey(key_array.into());
 
     let mut ciphertext = Vec::new();
     let mut writer = darkbio_crypto::stream::Stream::encrypt(payload_key, &mut ciphertext);
 
-    writer
-        .write_all(&plaintext)
-        .map_err(|e| format!("Encryption write error: {}", e))?;
-
-    writer
-        .finish()
-        .map_err(|e| format!("Encryption finish error: {}", e))?;
+    writer.write_all(&plaintext)?;
+    writer.finish()?;
 
     Ok(ciphertext)
 }
@@ -34,19 +38,826 @@ pub fn stream_encrypt(key: Vec<u8>, plaintext: Vec<u8>) -> Result<Vec<u8>, Strin
 /// Decrypts ciphertext using the STREAM construction with ChaCha20-Poly1305.
 /// The key must be exactly 32 bytes and must match the key used for encryption.
 #[frb(sync)]
-pub fn stream_decrypt(key: Vec<u8>, ciphertext: Vec<u8>) -> Result<Vec<u8>, String> {
-    let key_array: [u8; 32] = key
-        .try_into()
-        .map_err(|_| "Invalid key length, expected 32 bytes".to_string())?;
+pub fn stream_decrypt(key: Vec<u8>, ciphertext: Vec<u8>) -> Result<Vec<u8>, CryptoError> {
+    let key_array: [u8; 32] = to_array(key)?;
 
     let payload_key = darkbio_crypto::stream::PayloadKey(key_array.into());
 
     let mut reader = darkbio_crypto::stream::Stream::decrypt(payload_key, ciphertext.as_slice());
 
     let mut plaintext = Vec::new();
-    reader
-        .read_to_end(&mut plaintext)
-        .map_err(|e| format!("Decryption error: {}", e))?;
+    reader.read_to_end(&mut plaintext)?;
+
+    Ok(plaintext)
+}
+
+/// Encrypts the file at `in_path` into `out_path` using the STREAM construction
+/// with ChaCha20-Poly1305, without moving any data across the FFI boundary.
+///
+/// The ciphertext is written to a temporary file next to `out_path` that is
+/// only renamed into place once complete, and removed on failure.
+///
+/// - `key`: 32-byte key that should never be reused across streams
+/// - `in_path`: Path of the plaintext file to encrypt
+/// - `out_path`: Path of the ciphertext file to create or overwrite
+/// - `progress`: Sink receiving the number of plaintext bytes processed so far
+pub fn stream_encrypt_file(
+    key: Vec<u8>,
+    in_path: String,
+    out_path: String,
+    progress: StreamSink<u64>,
+) -> Result<(), CryptoError> {
+    let key_array: [u8; 32] = to_array(key)?;
+
+    let payload_key = darkbio_crypto::stream::PayloadKey(key_array.into());
+
+    encrypt_file(payload_key, &[], &in_path, &out_path, |n| {
+        let _ = progress.add(n);
+    })
+}
+
+/// Decrypts the file at `in_path` into `out_path` using the STREAM construction
+/// with ChaCha20-Poly1305, without moving any data across the FFI boundary.
+///
+/// The plaintext is written to a temporary file next to `out_path` that is only
+/// renamed into place after the final chunk authenticates. On failure it gets
+/// removed, so truncated or tampered data never leaves partial plaintext behind.
+///
+/// - `key`: 32-byte key that must match the key used for encryption
+/// - `in_path`: Path of the ciphertext file to decrypt
+/// - `out_path`: Path of the plaintext file to create or overwrite
+/// - `progress`: Sink receiving the number of plaintext bytes processed so far
+pub fn stream_decrypt_file(
+    key: Vec<u8>,
+    in_path: String,
+    out_path: String,
+    progress: StreamSink<u64>,
+) -> Result<(), CryptoError> {
+    let key_array: [u8; 32] = to_array(key)?;
+
+    let payload_key = darkbio_crypto::stream::PayloadKey(key_array.into());
+    let input = BufReader::new(File::open(&in_path)?);
+
+    decrypt_file(payload_key, input, &out_path, |n| {
+        let _ = progress.add(n);
+    })
+}
+
+/// Encrypts the file at `in_path` into `out_path`, writing `header` verbatim
+/// before the STREAM ciphertext.
+pub(crate) fn encrypt_file(
+    payload_key: darkbio_crypto::stream::PayloadKey,
+    header: &[u8],
+    in_path: &str,
+    out_path: &str,
+    progress: impl Fn(u64),
+) -> Result<(), CryptoError> {
+    let mut input = File::open(in_path)?;
+
+    write_atomic(out_path, |output| {
+        output.write_all(header)?;
+        let mut writer = darkbio_crypto::stream::Stream::encrypt(payload_key, output);
+
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        let mut processed = 0u64;
+        loop {
+            let n = input.read(&mut buffer)?;
+            if n == 0 {
+                break;
+            }
+            writer.write_all(&buffer[..n])?;
+
+            processed += n as u64;
+            progress(processed);
+        }
+        writer.finish()?;
+        Ok(())
+    })
+}
+
+/// Decrypts the STREAM ciphertext in `input` into `out_path`, going through a
+/// temporary file that is only renamed into place once fully authenticated.
+pub(crate) fn decrypt_file(
+    payload_key: darkbio_crypto::stream::PayloadKey,
+    input: impl Read,
+    out_path: &str,
+    progress: impl Fn(u64),
+) -> Result<(), CryptoError> {
+    write_atomic(out_path, |output| {
+        let mut reader = darkbio_crypto::stream::Stream::decrypt(payload_key, input);
+
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        let mut processed = 0u64;
+        loop {
+            let n = reader.read(&mut buffer)?;
+            if n == 0 {
+                break;
+            }
+            output.write_all(&buffer[..n])?;
+
+            processed += n as u64;
+            progress(processed);
+        }
+        Ok(())
+    })
+}
+
+/// Writes `out_path` through a uniquely named temporary file in the same
+/// directory, which is renamed into place only if `write` succeeds and is
+/// removed otherwise, so a failure never leaves partial output behind.
+fn write_atomic(
+    out_path: &str,
+    write: impl FnOnce(&mut BufWriter<File>) -> Result<(), CryptoError>,
+) -> Result<(), CryptoError> {
+    let out_path = Path::new(out_path);
+    let name = out_path.file_name().ok_or_else(|| CryptoError::Io {
+        details: format!("invalid output path {}", out_path.display()),
+    })?;
+    let mut temp_name = OsString::from(".");
+    temp_name.push(name);
+    temp_name.push(format!(
+        ".{}.tmp",
+        hex::encode(darkbio_crypto::rand::generate(8))
+    ));
+    let temp_path = out_path.with_file_name(temp_name);
+
+    // Refuse to reuse an existing file, so nobody can plant one to be written
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temp_path)?;
+
+    let result = (|| {
+        let mut output = BufWriter::new(file);
+        write(&mut output)?;
+        output
+            .into_inner()
+            .map_err(|e| e.into_error())?
+            .sync_all()?;
+        fs::rename(&temp_path, out_path)?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// Version of the password protected STREAM envelope format.
+const PASSWORD_VERSION: u8 = 1;
+
+/// Size of the random Argon2id salt in the password envelope header.
+const PASSWORD_SALT_SIZE: usize = 16;
+
+/// Size of the password envelope header without the trailing HMAC-SHA256 tag:
+/// version (1) || time (4) || memory (4) || threads (4) || salt (16).
+const PASSWORD_HEADER_SIZE: usize = 1 + 4 + 4 + 4 + PASSWORD_SALT_SIZE;
+
+/// Encrypts plaintext with a password, producing a self-describing envelope
+/// of an authenticated header followed by the STREAM ciphertext.
+///
+/// The header carries the format version, the Argon2id parameters and salt,
+/// and an HMAC-SHA256 tag over them keyed from the password.
+///
+/// - `password`: The password to encrypt with
+/// - `plaintext`: The data to encrypt
+/// - `params`: Argon2id cost parameters for deriving the keys
+#[frb(sync)]
+pub fn stream_seal_with_password(
+    password: Vec<u8>,
+    plaintext: Vec<u8>,
+    params: Argon2Params,
+) -> Result<Vec<u8>, CryptoError> {
+    check_params(&params).map_err(|details| CryptoError::EncryptionFailed { details })?;
+
+    let salt = darkbio_crypto::rand::generate(PASSWORD_SALT_SIZE);
+
+    let mut sealed = Vec::with_capacity(PASSWORD_HEADER_SIZE + 32 + plaintext.len());
+    sealed.push(PASSWORD_VERSION);
+    sealed.extend_from_slice(&params.time.to_be_bytes());
+    sealed.extend_from_slice(&params.memory.to_be_bytes());
+    sealed.extend_from_slice(&params.threads.to_be_bytes());
+    sealed.extend_from_slice(&salt);
+
+    let (payload_key, mut mac) = derive_password_keys(&password, &salt, &params);
+    mac.update(&sealed);
+    sealed.extend_from_slice(&mac.finalize().into_bytes());
+
+    let mut writer = darkbio_crypto::stream::Stream::encrypt(payload_key, &mut sealed);
+    writer.write_all(&plaintext)?;
+    writer.finish()?;
+
+    Ok(sealed)
+}
+
+/// Decrypts an envelope produced by `stream_seal_with_password`.
+///
+/// The Argon2id parameters are read from the unauthenticated header, so they
+/// are rejected before any key derivation if any of them is below `min_params`
+/// or above `max_params`. This way a tampered header can neither downgrade the
+/// cost of guessing the password, nor exhaust the memory or time of the device.
+///
+/// - `password`: The password to decrypt with
+/// - `sealed`: The envelope to decrypt
+/// - `min_params`: Minimum Argon2id cost parameters to accept
+/// - `max_params`: Maximum Argon2id cost parameters to accept
+#[frb(sync)]
+pub fn stream_open_with_password(
+    password: Vec<u8>,
+    sealed: Vec<u8>,
+    min_params: Argon2Params,
+    max_params: Argon2Params,
+) -> Result<Vec<u8>, CryptoError> {
+    if sealed.len() < PASSWORD_HEADER_SIZE + 32 {
+        return Err(CryptoError::InvalidEncoding {
+            details: "password envelope too short".to_string(),
+        });
+    }
+    let (header, rest) = sealed.split_at(PASSWORD_HEADER_SIZE);
+    let (tag, ciphertext) = rest.split_at(32);
+
+    if header[0] != PASSWORD_VERSION {
+        return Err(CryptoError::InvalidEncoding {
+            details: format!("unsupported password envelope version {}", header[0]),
+        });
+    }
+    let params = Argon2Params {
+        time: u32::from_be_bytes(header[1..5].try_into().unwrap()),
+        memory: u32::from_be_bytes(header[5..9].try_into().unwrap()),
+        threads: u32::from_be_bytes(header[9..13].try_into().unwrap()),
+    };
+    let salt = &header[13..];
+
+    check_params(&params).map_err(|details| CryptoError::InvalidEncoding { details })?;
+    if params.time < min_params.time
+        || params.memory < min_params.memory
+        || params.threads < min_params.threads
+    {
+        return Err(CryptoError::InvalidEncoding {
+            details: format!(
+                "argon2 parameters t={} m={} p={} below minimum t={} m={} p={}",
+                params.time,
+                params.memory,
+                params.threads,
+                min_params.time,
+                min_params.memory,
+                min_params.threads
+            ),
+        });
+    }
+    if params.time > max_params.time
+        || params.memory > max_params.memory
+        || params.threads > max_params.threads
+    {
+        return Err(CryptoError::InvalidEncoding {
+            details: format!(
+                "argon2 parameters t={} m={} p={} above maximum t={} m={} p={}",
+                params.time,
+                params.memory,
+                params.threads,
+                max_params.time,
+                max_params.memory,
+                max_params.threads
+            ),
+        });
+    }
+    let (payload_key, mut mac) = derive_password_keys(&password, salt, &params);
+    mac.update(header);
+    mac.verify_slice(tag)
+        .map_err(|_| CryptoError::DecryptionFailed {
+            details: "wrong password or tampered header".to_string(),
+        })?;
+
+    let mut reader = darkbio_crypto::stream::Stream::decrypt(payload_key, ciphertext);
+
+    let mut plaintext = Vec::new();
+    reader.read_to_end(&mut plaintext)?;
 
     Ok(plaintext)
 }
+
+/// Derives the STREAM payload key and the header MAC from a password, using
+/// Argon2id to stretch it and HKDF-SHA256 to split it into independent keys.
+fn derive_password_keys(
+    password: &[u8],
+    salt: &[u8],
+    params: &Argon2Params,
+) -> (darkbio_crypto::stream::PayloadKey, Hmac<Sha256>) {
+    let secret: [u8; 32] =
+        darkbio_crypto::argon2::key(password, salt, params.time, params.memory, params.threads);
+    let payload_key: [u8; 32] =
+        darkbio_crypto::hkdf::key(&secret, &[], b"stream-password-payload-key");
+    let mac_key: [u8; 32] = darkbio_crypto::hkdf::key(&secret, &[], b"stream-password-header-key");
+
+    (
+        darkbio_crypto::stream::PayloadKey(payload_key.into()),
+        Hmac::<Sha256>::new_from_slice(&mac_key).unwrap(),
+    )
+}
+
+/// Plaintext size of a single STREAM chunk.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Ciphertext size of a single full STREAM chunk (plaintext + Poly1305 tag).
+const ENCRYPTED_CHUNK_SIZE: usize = CHUNK_SIZE + 16;
+
+/// StreamEncryptor incrementally encrypts a payload using the STREAM
+/// construction, emitting ciphertext as soon as each 64KiB chunk fills up.
+///
+/// The ciphertext is identical to what `stream_encrypt` produces for the
+/// concatenation of all pushed data.
+#[frb(opaque)]
+pub struct StreamEncryptor {
+    writer: Option<darkbio_crypto::stream::StreamWriter<SharedBuffer>>,
+    output: SharedBuffer,
+}
+
+impl StreamEncryptor {
+    /// Creates an encryptor with a 32-byte key that must never be reused
+    /// across streams.
+    #[frb(sync)]
+    pub fn new(key: Vec<u8>) -> Result<Self, CryptoError> {
+        let key_array: [u8; 32] = to_array(key)?;
+
+        Ok(Self::from_payload_key(darkbio_crypto::stream::PayloadKey(
+            key_array.into(),
+        )))
+    }
+
+    /// Creates an encryptor from an already constructed payload key.
+    pub(crate) fn from_payload_key(payload_key: darkbio_crypto::stream::PayloadKey) -> Self {
+        let output = SharedBuffer::default();
+
+        Self {
+            writer: Some(darkbio_crypto::stream::Stream::encrypt(
+                payload_key,
+                output.clone(),
+            )),
+            output,
+        }
+    }
+
+    /// Feeds more plaintext into the stream, returning any ciphertext that
+    /// became available. The returned data may be empty.
+    #[frb(sync)]
+    pub fn push(&mut self, chunk: Vec<u8>) -> Result<Vec<u8>, CryptoError> {
+        let writer = self
+            .writer
+            .as_mut()
+            .ok_or_else(|| CryptoError::EncryptionFailed {
+                details: "stream already finished".to_string(),
+            })?;
+        writer.write_all(&chunk)?;
+
+        Ok(self.output.drain())
+    }
+
+    /// Terminates the stream, returning the final ciphertext chunk. No more
+    /// data can be pushed afterwards.
+    #[frb(sync)]
+    pub fn finish(&mut self) -> Result<Vec<u8>, CryptoError> {
+        let writer = self
+            .writer
+            .take()
+            .ok_or_else(|| CryptoError::EncryptionFailed {
+                details: "stream already finished".to_string(),
+            })?;
+        writer.finish()?;
+
+        Ok(self.output.drain())
+    }
+}
+
+/// StreamDecryptor incrementally decrypts a STREAM ciphertext, releasing the
+/// plaintext of each 64KiB chunk as soon as it has been authenticated.
+///
+/// A chunk is only released once data beyond it has been pushed, since the
+/// final chunk can only be identified at the end of the stream. Callers must
+/// call `finish` to authenticate the final chunk; until then the stream may
+/// have been truncated.
+#[frb(opaque)]
+pub struct StreamDecryptor {
+    reader: Option<darkbio_crypto::stream::StreamReader<SharedBuffer>>,
+    input: SharedBuffer,
+}
+
+impl StreamDecryptor {
+    /// Creates a decryptor with the 32-byte key used for encryption.
+    #[frb(sync)]
+    pub fn new(key: Vec<u8>) -> Result<Self, CryptoError> {
+        let key_array: [u8; 32] = to_array(key)?;
+
+        Ok(Self::from_payload_key(darkbio_crypto::stream::PayloadKey(
+            key_array.into(),
+        )))
+    }
+
+    /// Creates a decryptor from an already constructed payload key.
+    pub(crate) fn from_payload_key(payload_key: darkbio_crypto::stream::PayloadKey) -> Self {
+        let input = SharedBuffer::default();
+
+        Self {
+            reader: Some(darkbio_crypto::stream::Stream::decrypt(
+                payload_key,
+                input.clone(),
+            )),
+            input,
+        }
+    }
+
+    /// Feeds more ciphertext into the stream, returning the plaintext of all
+    /// chunks that were authenticated. The returned data may be empty.
+    #[frb(sync)]
+    pub fn push(&mut self, chunk: Vec<u8>) -> Result<Vec<u8>, CryptoError> {
+        let reader = self
+            .reader
+            .as_mut()
+            .ok_or_else(|| CryptoError::DecryptionFailed {
+                details: "stream already finished".to_string(),
+            })?;
+        self.input.extend(&chunk);
+
+        // Only decrypt chunks that are certainly not the last one, otherwise
+        // the reader would mistake the end of the buffered data for the end
+        // of the stream.
+        let mut plaintext = Vec::new();
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        while self.input.len() > ENCRYPTED_CHUNK_SIZE {
+            let n = reader.read(&mut buffer)?;
+            if n == 0 {
+                break;
+            }
+            plaintext.extend_from_slice(&buffer[..n]);
+        }
+        Ok(plaintext)
+    }
+
+    /// Terminates the stream, authenticating and returning the plaintext of
+    /// the final chunk. Fails if the stream was truncated.
+    #[frb(sync)]
+    pub fn finish(&mut self) -> Result<Vec<u8>, CryptoError> {
+        let mut reader = self
+            .reader
+            .take()
+            .ok_or_else(|| CryptoError::DecryptionFailed {
+                details: "stream already finished".to_string(),
+            })?;
+
+        let mut plaintext = Vec::new();
+        reader.read_to_end(&mut plaintext)?;
+
+        Ok(plaintext)
+    }
+}
+
+/// SharedBuffer is a byte queue shared between a STREAM reader or writer and
+/// its incremental wrapper, used to move data in and out of the stream.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<VecDeque<u8>>>);
+
+impl SharedBuffer {
+    fn len(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+
+    fn extend(&self, data: &[u8]) {
+        self.0.lock().unwrap().extend(data);
+    }
+
+    fn drain(&self) -> Vec<u8> {
+        self.0.lock().unwrap().drain(..).collect()
+    }
+}
+
+impl Read for SharedBuffer {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().read(buf)
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.extend(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// StreamReader provides random access into a STREAM ciphertext, decrypting
+/// only the 64KiB chunks covering each requested range.
+///
+/// The final chunk is authenticated when the reader is opened, so truncated
+/// ciphertexts are rejected upfront rather than on the first read near the end.
+#[frb(opaque)]
+pub struct StreamReader {
+    reader: darkbio_crypto::stream::StreamReader<StreamSource>,
+    len: u64,
+}
+
+impl StreamReader {
+    /// Opens a random access reader over the ciphertext file at `path`.
+    ///
+    /// - `key`: 32-byte key that must match the key used for encryption
+    /// - `path`: Path of the ciphertext file to read from
+    #[frb(sync)]
+    pub fn open(key: Vec<u8>, path: String) -> Result<Self, CryptoError> {
+        Self::new(key, StreamSource::File(File::open(&path)?))
+    }
+
+    /// Creates a random access reader over an in-memory ciphertext.
+    ///
+    /// - `key`: 32-byte key that must match the key used for encryption
+    /// - `ciphertext`: Complete STREAM ciphertext to read from
+    #[frb(sync)]
+    pub fn from_bytes(key: Vec<u8>, ciphertext: Vec<u8>) -> Result<Self, CryptoError> {
+        Self::new(key, StreamSource::Bytes(Cursor::new(ciphertext)))
+    }
+
+    /// Returns the total length of the plaintext.
+    #[frb(sync)]
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns whether the plaintext is empty.
+    #[frb(sync)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decrypts up to `len` bytes of plaintext starting at `offset`. The result
+    /// is shorter than `len` if the range extends past the end of the plaintext.
+    pub fn read_at(&mut self, offset: u64, len: u64) -> Result<Vec<u8>, CryptoError> {
+        if offset >= self.len {
+            return Ok(Vec::new());
+        }
+        let len = len.min(self.len - offset);
+
+        self.reader.seek(SeekFrom::Start(offset))?;
+
+        let mut plaintext = vec![0u8; len as usize];
+        self.reader.read_exact(&mut plaintext)?;
+
+        Ok(plaintext)
+    }
+
+    fn new(key: Vec<u8>, mut source: StreamSource) -> Result<Self, CryptoError> {
+        let key_array: [u8; 32] = to_array(key)?;
+
+        // Even an empty stream carries the tag of its final chunk
+        let size = source.seek(SeekFrom::End(0))?;
+        if size < 16 {
+            return Err(CryptoError::InvalidLength {
+                expected: 16,
+                got: size as usize,
+            });
+        }
+        source.seek(SeekFrom::Start(0))?;
+
+        let payload_key = darkbio_crypto::stream::PayloadKey(key_array.into());
+        let mut reader = darkbio_crypto::stream::Stream::decrypt(payload_key, source);
+
+        // Seeking to the end authenticates the last chunk, and thus the length
+        let len = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(0))?;
+
+        Ok(Self { reader, len })
+    }
+}
+
+/// StreamSource is the seekable ciphertext backing a StreamReader.
+enum StreamSource {
+    File(File),
+    Bytes(Cursor<Vec<u8>>),
+}
+
+impl Read for StreamSource {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Self::File(file) => file.read(buf),
+            Self::Bytes(bytes) => bytes.read(buf),
+        }
+    }
+}
+
+impl Seek for StreamSource {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match self {
+            Self::File(file) => file.seek(pos),
+            Self::Bytes(bytes) => bytes.seek(pos),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Pushes data in uneven pieces so chunk boundaries never line up with them.
+    fn push_all(
+        data: &[u8],
+        mut push: impl FnMut(Vec<u8>) -> Result<Vec<u8>, CryptoError>,
+    ) -> Vec<u8> {
+        let mut out = Vec::new();
+        for piece in data.chunks(10_007) {
+            out.extend(push(piece.to_vec()).unwrap());
+        }
+        out
+    }
+
+    #[test]
+    fn test_incremental_roundtrip_chunk_multiple() {
+        let key = vec![7u8; 32];
+        let plaintext: Vec<u8> = (0..2 * CHUNK_SIZE).map(|i| i as u8).collect();
+
+        let mut encryptor = StreamEncryptor::new(key.clone()).unwrap();
+        let mut ciphertext = push_all(&plaintext, |c| encryptor.push(c));
+        ciphertext.extend(encryptor.finish().unwrap());
+        assert_eq!(ciphertext.len(), 2 * ENCRYPTED_CHUNK_SIZE);
+        assert_eq!(
+            ciphertext,
+            stream_encrypt(key.clone(), plaintext.clone()).unwrap()
+        );
+
+        let mut decryptor = StreamDecryptor::new(key).unwrap();
+        let mut decrypted = push_all(&ciphertext, |c| decryptor.push(c));
+        assert_eq!(decrypted.len(), CHUNK_SIZE);
+        decrypted.extend(decryptor.finish().unwrap());
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn test_incremental_truncated() {
+        let key = vec![7u8; 32];
+        let plaintext = vec![1u8; 3 * CHUNK_SIZE];
+        let ciphertext = stream_encrypt(key.clone(), plaintext).unwrap();
+
+        // Dropping the final chunk leaves a valid prefix ending on a chunk
+        // boundary, which must not be accepted as a complete stream.
+        let truncated = &ciphertext[..2 * ENCRYPTED_CHUNK_SIZE];
+
+        let mut decryptor = StreamDecryptor::new(key).unwrap();
+        push_all(truncated, |c| decryptor.push(c));
+        assert!(matches!(
+            decryptor.finish(),
+            Err(CryptoError::DecryptionFailed { .. })
+        ));
+    }
+
+    // Creates an empty scratch directory unique to the calling test.
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "darkbio-stream-{name}-{}",
+            hex::encode(darkbio_crypto::rand::generate(8))
+        ));
+        fs::create_dir(&dir).unwrap();
+        dir
+    }
+
+    fn payload_key() -> darkbio_crypto::stream::PayloadKey {
+        darkbio_crypto::stream::PayloadKey([7u8; 32].into())
+    }
+
+    #[test]
+    fn test_file_roundtrip() {
+        let dir = temp_dir("roundtrip");
+        let plain = dir.join("plain");
+        let sealed = dir.join("sealed");
+        let opened = dir.join("opened");
+
+        let plaintext: Vec<u8> = (0..CHUNK_SIZE + 123).map(|i| i as u8).collect();
+        fs::write(&plain, &plaintext).unwrap();
+
+        let sealed_path = sealed.to_str().unwrap();
+        encrypt_file(
+            payload_key(),
+            b"hdr",
+            plain.to_str().unwrap(),
+            sealed_path,
+            |_| {},
+        )
+        .unwrap();
+        let ciphertext = fs::read(&sealed).unwrap();
+        assert_eq!(&ciphertext[..3], b"hdr");
+
+        let input = Cursor::new(ciphertext[3..].to_vec());
+        decrypt_file(payload_key(), input, opened.to_str().unwrap(), |_| {}).unwrap();
+        assert_eq!(fs::read(&opened).unwrap(), plaintext);
+
+        // Only the three named files may remain, no temporaries
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 3);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_failure_keeps_output() {
+        let dir = temp_dir("failure");
+        let opened = dir.join("opened");
+        fs::write(&opened, b"previous").unwrap();
+
+        let ciphertext = stream_encrypt(vec![7u8; 32], vec![1u8; 2 * CHUNK_SIZE]).unwrap();
+        let truncated = Cursor::new(ciphertext[..ENCRYPTED_CHUNK_SIZE].to_vec());
+        let result = decrypt_file(payload_key(), truncated, opened.to_str().unwrap(), |_| {});
+        assert!(matches!(result, Err(CryptoError::DecryptionFailed { .. })));
+
+        let missing = dir.join("missing");
+        let result = encrypt_file(
+            payload_key(),
+            &[],
+            missing.to_str().unwrap(),
+            opened.to_str().unwrap(),
+            |_| {},
+        );
+        assert!(matches!(result, Err(CryptoError::Io { .. })));
+
+        // The existing output is untouched and no temporaries are left behind
+        assert_eq!(fs::read(&opened).unwrap(), b"previous");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_reader_short_input() {
+        for size in [0, 1, 15] {
+            assert!(matches!(
+                StreamReader::from_bytes(vec![7u8; 32], vec![0u8; size]),
+                Err(CryptoError::InvalidLength { expected: 16, got }) if got == size
+            ));
+        }
+    }
+
+    #[test]
+    fn test_reader_read_at() {
+        let key = vec![7u8; 32];
+        let plaintext: Vec<u8> = (0..2 * CHUNK_SIZE + 5).map(|i| i as u8).collect();
+        let ciphertext = stream_encrypt(key.clone(), plaintext.clone()).unwrap();
+
+        let mut reader = StreamReader::from_bytes(key.clone(), ciphertext).unwrap();
+        assert_eq!(reader.len(), plaintext.len() as u64);
+
+        let offset = CHUNK_SIZE - 3;
+        assert_eq!(
+            reader.read_at(offset as u64, 10).unwrap(),
+            &plaintext[offset..offset + 10]
+        );
+        assert_eq!(
+            reader.read_at(2 * CHUNK_SIZE as u64, 100).unwrap(),
+            &plaintext[2 * CHUNK_SIZE..]
+        );
+        assert!(
+            reader
+                .read_at(plaintext.len() as u64, 1)
+                .unwrap()
+                .is_empty()
+        );
+
+        let empty = stream_encrypt(key.clone(), Vec::new()).unwrap();
+        assert!(StreamReader::from_bytes(key, empty).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_password_params_bounds() {
+        // Cheapest valid parameters, so the test runs fast
+        let low = || Argon2Params {
+            time: 1,
+            memory: 8,
+            threads: 1,
+        };
+        let high = || Argon2Params {
+            time: 2,
+            memory: 16,
+            threads: 1,
+        };
+        let sealed = stream_seal_with_password(b"pw".to_vec(), b"data".to_vec(), low()).unwrap();
+        assert_eq!(
+            stream_open_with_password(b"pw".to_vec(), sealed.clone(), low(), high()).unwrap(),
+            b"data"
+        );
+
+        // Above the ceiling, e.g. a tampered header requesting 4TiB
+        let mut tampered = sealed.clone();
+        tampered[5..9].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(matches!(
+            stream_open_with_password(b"pw".to_vec(), tampered, low(), high()),
+            Err(CryptoError::InvalidEncoding { .. })
+        ));
+
+        // Below the floor
+        assert!(matches!(
+            stream_open_with_password(b"pw".to_vec(), sealed.clone(), high(), high()),
+            Err(CryptoError::InvalidEncoding { .. })
+        ));
+
+        // Not accepted by Argon2id at all, which would otherwise panic
+        let mut tampered = sealed;
+        tampered[1..5].copy_from_slice(&0u32.to_be_bytes());
+        assert!(matches!(
+            stream_open_with_password(b"pw".to_vec(), tampered, low(), high()),
+            Err(CryptoError::InvalidEncoding { .. })
+        ));
+    }
+}
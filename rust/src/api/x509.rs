@@ -0,0 +1,1513 @@
+// crypto-fl: cryptography primitives and wrappers
+// Copyright 2026 Dark Bio AG. All rights reserved.
+//
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use const_oid::{AssociatedOid, ObjectIdentifier};
+use der::asn1::{Any, BitString, Ia5String, OctetString, SetOfVec, UtcTime};
+use der::flagset::FlagSet;
+use der::{Decode, Encode, Tagged};
+use flutter_rust_bridge::frb;
+use sha1::{Digest, Sha1};
+use x509_cert::Certificate;
+use x509_cert::attr::AttributeTypeAndValue;
+use x509_cert::certificate::{CertificateInner, TbsCertificateInner, Version};
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::ext::pkix::{
+    AuthorityKeyIdentifier, BasicConstraints, ExtendedKeyUsage, KeyUsage, KeyUsages,
+    SubjectAltName, SubjectKeyIdentifier,
+};
+use x509_cert::ext::{AsExtension, Extension};
+use x509_cert::name::{RdnSequence, RelativeDistinguishedName};
+use x509_cert::serial_number::SerialNumber;
+use x509_cert::spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
+use x509_cert::time::{Time, Validity};
+
+use super::error::{CryptoError, to_array};
+use super::xdsa::XdsaPublicKey;
+use super::xhpke::XhpkePublicKey;
+
+/// CertName is an X.509 distinguished name. Attributes left as None are
+/// omitted; at least one must be set.
+pub struct CertName {
+    /// Common name (CN)
+    pub common_name: Option<String>,
+    /// Organization (O)
+    pub organization: Option<String>,
+    /// Organizational unit (OU)
+    pub organizational_unit: Option<String>,
+    /// Country (C)
+    pub country: Option<String>,
+    /// State or province (ST)
+    pub province: Option<String>,
+    /// Locality (L)
+    pub locality: Option<String>,
+    /// Serial number of the named entity (not of the certificate)
+    pub serial_number: Option<String>,
+}
+
+impl CertName {
+    /// Creates a name holding only a common name (CN).
+    #[frb(sync)]
+    pub fn cn(common_name: String) -> Self {
+        Self {
+            common_name: Some(common_name),
+            organization: None,
+            organizational_unit: None,
+            country: None,
+            province: None,
+            locality: None,
+            serial_number: None,
+        }
+    }
+
+    /// Converts the name into upstream form, ordered from the most general
+    /// attribute to the most specific.
+    fn to_name(&self) -> darkbio_crypto::x509::Name {
+        let attrs = [
+            ("2.5.4.6", &self.country),
+            ("2.5.4.8", &self.province),
+            ("2.5.4.7", &self.locality),
+            ("2.5.4.10", &self.organization),
+            ("2.5.4.11", &self.organizational_unit),
+            ("2.5.4.3", &self.common_name),
+            ("2.5.4.5", &self.serial_number),
+        ];
+        let mut name = darkbio_crypto::x509::Name::new();
+        for (oid, value) in attrs {
+            if let Some(value) = value {
+                name = name.push(ObjectIdentifier::new_unwrap(oid), value.clone());
+            }
+        }
+        name
+    }
+}
+
+/// CertKeyUsage is a bit of the X.509 keyUsage extension (RFC 5280 §4.2.1.3).
+#[derive(Clone, Copy)]
+pub enum CertKeyUsage {
+    DigitalSignature,
+    NonRepudiation,
+    KeyEncipherment,
+    DataEncipherment,
+    KeyAgreement,
+    KeyCertSign,
+    CrlSign,
+    EncipherOnly,
+    DecipherOnly,
+}
+
+impl CertKeyUsage {
+    const ALL: [Self; 9] = [
+        Self::DigitalSignature,
+        Self::NonRepudiation,
+        Self::KeyEncipherment,
+        Self::DataEncipherment,
+        Self::KeyAgreement,
+        Self::KeyCertSign,
+        Self::CrlSign,
+        Self::EncipherOnly,
+        Self::DecipherOnly,
+    ];
+
+    fn to_inner(self) -> KeyUsages {
+        match self {
+            Self::DigitalSignature => KeyUsages::DigitalSignature,
+            Self::NonRepudiation => KeyUsages::NonRepudiation,
+            Self::KeyEncipherment => KeyUsages::KeyEncipherment,
+            Self::DataEncipherment => KeyUsages::DataEncipherment,
+            Self::KeyAgreement => KeyUsages::KeyAgreement,
+            Self::KeyCertSign => KeyUsages::KeyCertSign,
+            Self::CrlSign => KeyUsages::CRLSign,
+            Self::EncipherOnly => KeyUsages::EncipherOnly,
+            Self::DecipherOnly => KeyUsages::DecipherOnly,
+        }
+    }
+}
+
+/// CertExtension is a custom certificate extension.
+pub struct CertExtension {
+    /// Dotted extension OID, outside the reserved 2.5.29 arc
+    pub oid: String,
+    /// Whether the extension is marked critical
+    pub critical: bool,
+    /// DER-encoded extension payload (inside the OCTET STRING)
+    pub value: Vec<u8>,
+}
+
+/// CertTemplate holds the parameters of a certificate to issue.
+pub struct CertTemplate {
+    /// Subject distinguished name
+    pub subject: CertName,
+    /// Issuer distinguished name, matching the signer's certificate subject
+    pub issuer: CertName,
+    /// Validity start time (Unix timestamp)
+    pub not_before: u64,
+    /// Validity end time (Unix timestamp)
+    pub not_after: u64,
+    /// Whether this is a CA certificate
+    pub is_ca: bool,
+    /// Maximum intermediate CAs allowed (only if is_ca is true)
+    pub path_len: Option<u8>,
+    /// subjectAltName DNS names
+    pub dns_names: Vec<String>,
+    /// subjectAltName URIs
+    pub uris: Vec<String>,
+    /// subjectAltName email addresses
+    pub emails: Vec<String>,
+    /// keyUsage bits; empty for the key type's default. Must include the
+    /// default bits, which verification requires.
+    pub key_usage: Vec<CertKeyUsage>,
+    /// Dotted extendedKeyUsage purpose OIDs, omitted if empty
+    pub extended_key_usage: Vec<String>,
+    /// Custom extensions
+    pub extensions: Vec<CertExtension>,
+}
+
+impl CertTemplate {
+    /// Creates a template for a leaf certificate with no extensions beyond the
+    /// defaults.
+    ///
+    /// - `subject`: Subject distinguished name
+    /// - `issuer`: Issuer distinguished name
+    /// - `not_before`: Validity start time (Unix timestamp)
+    /// - `not_after`: Validity end time (Unix timestamp)
+    #[frb(sync)]
+    pub fn leaf(subject: CertName, issuer: CertName, not_before: u64, not_after: u64) -> Self {
+        Self {
+            subject,
+            issuer,
+            not_before,
+            not_after,
+            is_ca: false,
+            path_len: None,
+            dns_names: Vec::new(),
+            uris: Vec::new(),
+            emails: Vec::new(),
+            key_usage: Vec::new(),
+            extended_key_usage: Vec::new(),
+            extensions: Vec::new(),
+        }
+    }
+}
+
+/// Issues a DER-encoded certificate for a raw subject key from a template.
+///
+/// Mirrors the upstream issuer (random serial, basicConstraints, keyUsage and
+/// key identifiers), adding the subjectAltName and extendedKeyUsage extensions
+/// and configurable keyUsage bits, which upstream reserves.
+pub(crate) fn issue_cert_der(
+    subject_key: &[u8],
+    algorithm: ObjectIdentifier,
+    default_usage: FlagSet<KeyUsages>,
+    signer: &darkbio_crypto::xdsa::SecretKey,
+    template: CertTemplate,
+) -> Result<Vec<u8>, CryptoError> {
+    use darkbio_crypto::x509::Error;
+
+    let subject = template.subject.to_name();
+    let issuer = template.issuer.to_name();
+    if subject.attrs.is_empty() {
+        return Err(Error::EmptyDistinguishedName { field: "subject" }.into());
+    }
+    if issuer.attrs.is_empty() {
+        return Err(Error::EmptyDistinguishedName { field: "issuer" }.into());
+    }
+    if template.not_before >= template.not_after {
+        return Err(Error::InvalidValidity.into());
+    }
+    let subject_name = to_x509_name(&subject)?;
+    let issuer_name = to_x509_name(&issuer)?;
+
+    // Generate a random positive serial number
+    let mut serial = [0u8; 16];
+    serial.copy_from_slice(&darkbio_crypto::rand::generate(16));
+    serial[0] &= 0x7f;
+    let serial_number: SerialNumber = SerialNumber::new(&serial).map_err(der_error)?;
+
+    // Assemble the standard extensions
+    let mut key_usage = default_usage;
+    if !template.key_usage.is_empty() {
+        key_usage = FlagSet::default();
+        for usage in &template.key_usage {
+            key_usage |= usage.to_inner();
+        }
+        if !key_usage.contains(default_usage) {
+            return Err(Error::InvalidKeyUsage {
+                details: "key usage lacks the bits required for the key type and role",
+            }
+            .into());
+        }
+    }
+    let mut extensions = Vec::<Extension>::new();
+    push_extension(
+        &mut extensions,
+        &subject_name,
+        BasicConstraints {
+            ca: template.is_ca,
+            path_len_constraint: template.path_len.filter(|_| template.is_ca),
+        },
+    )?;
+    push_extension(&mut extensions, &subject_name, KeyUsage(key_usage))?;
+    push_extension(
+        &mut extensions,
+        &subject_name,
+        SubjectKeyIdentifier(OctetString::new(key_identifier(subject_key)).map_err(der_error)?),
+    )?;
+    push_extension(
+        &mut extensions,
+        &subject_name,
+        AuthorityKeyIdentifier {
+            key_identifier: Some(
+                OctetString::new(key_identifier(&signer.public_key().to_bytes()))
+                    .map_err(der_error)?,
+            ),
+            authority_cert_issuer: None,
+            authority_cert_serial_number: None,
+        },
+    )?;
+
+    let mut alt_names = Vec::new();
+    for (names, kind) in [
+        (
+            &template.dns_names,
+            GeneralName::DnsName as fn(Ia5String) -> GeneralName,
+        ),
+        (&template.uris, GeneralName::UniformResourceIdentifier),
+        (&template.emails, GeneralName::Rfc822Name),
+    ] {
+        for name in names {
+            let name = Ia5String::new(name).map_err(|_| CryptoError::InvalidCertificate {
+                details: format!("subjectAltName {name:?} is not ASCII"),
+            })?;
+            alt_names.push(kind(name));
+        }
+    }
+    if !alt_names.is_empty() {
+        push_extension(&mut extensions, &subject_name, SubjectAltName(alt_names))?;
+    }
+    if !template.extended_key_usage.is_empty() {
+        let purposes = template
+            .extended_key_usage
+            .iter()
+            .map(|oid| parse_oid(oid))
+            .collect::<Result<Vec<_>, _>>()?;
+        push_extension(&mut extensions, &subject_name, ExtendedKeyUsage(purposes))?;
+    }
+
+    // Inject custom extensions, rejecting reserved or duplicate OIDs
+    let mut oids: HashSet<String> = extensions.iter().map(|e| e.extn_id.to_string()).collect();
+    for custom in template.extensions {
+        let oid = parse_oid(&custom.oid)?;
+        if oid.to_string().starts_with("2.5.29.") {
+            return Err(Error::ReservedExtensionOid.into());
+        }
+        if !oids.insert(oid.to_string()) {
+            return Err(Error::DuplicateExtensionOid {
+                oid: oid.to_string(),
+            }
+            .into());
+        }
+        extensions.push(Extension {
+            extn_id: oid,
+            critical: custom.critical,
+            extn_value: OctetString::new(custom.value).map_err(der_error)?,
+        });
+    }
+
+    // Assemble and sign the certificate content
+    let signature_alg = AlgorithmIdentifierOwned {
+        oid: darkbio_crypto::xdsa::OID,
+        parameters: None,
+    };
+    let tbs_certificate = TbsCertificateInner {
+        version: Version::V3,
+        serial_number,
+        signature: signature_alg.clone(),
+        issuer: issuer_name,
+        validity: Validity {
+            not_before: utc_time(template.not_before)?,
+            not_after: utc_time(template.not_after)?,
+        },
+        subject: subject_name,
+        subject_public_key_info: SubjectPublicKeyInfoOwned {
+            algorithm: AlgorithmIdentifierOwned {
+                oid: algorithm,
+                parameters: None,
+            },
+            subject_public_key: BitString::from_bytes(subject_key).map_err(der_error)?,
+        },
+        issuer_unique_id: None,
+        subject_unique_id: None,
+        extensions: Some(extensions),
+    };
+    let signature = signer.sign(&tbs_certificate.to_der().map_err(der_error)?);
+
+    CertificateInner {
+        tbs_certificate,
+        signature_algorithm: signature_alg,
+        signature: BitString::from_bytes(&signature.to_bytes()).map_err(der_error)?,
+    }
+    .to_der()
+    .map_err(der_error)
+}
+
+/// CertPublicKey is the subject key of a verified certificate.
+#[allow(clippy::large_enum_variant)]
+pub enum CertPublicKey {
+    /// xDSA signing key
+    Xdsa(XdsaPublicKey),
+    /// xHPKE encryption key
+    Xhpke(XhpkePublicKey),
+}
+
+/// CertChain is a certificate path validated up to a trusted root.
+pub struct CertChain {
+    /// The leaf certificate's subject key
+    pub leaf_key: CertPublicKey,
+    /// DER-encoded certificates of the path, from the leaf to the root
+    pub path: Vec<Vec<u8>>,
+}
+
+/// Maximum number of candidate issuers tried while building a chain, bounding
+/// the work spent on adversarial intermediates that share a name.
+const MAX_CHAIN_CANDIDATES: usize = 64;
+
+/// Maximum number of certificates in a chain, from the leaf to the root.
+const MAX_CHAIN_LENGTH: usize = 10;
+
+/// Verifies a certificate chain from a leaf up to one of the trusted roots,
+/// returning the leaf key and the validated path.
+///
+/// Issuers are located by distinguished name among the intermediates and
+/// roots, skipping those whose subject key identifier contradicts the child's
+/// authority key identifier. If several candidates match, each resulting path
+/// is tried until one verifies, up to 64 candidates and 10 certificates per
+/// path. Every link must be signed by its issuer's xDSA key, every issuer must
+/// be a CA whose path length constraint admits the CAs below it, every
+/// certificate must carry the key usage of its role, and all of them must be
+/// valid at `now`.
+///
+/// - `leaf`: The DER-encoded end-entity certificate (xDSA or xHPKE)
+/// - `intermediates`: DER-encoded intermediate CA certificates, in any order
+/// - `roots`: DER-encoded self-signed xDSA root certificates to trust
+/// - `now`: Unix timestamp to check validity at (None for the current time)
+#[frb(sync)]
+pub fn x509_verify_chain(
+    leaf: Vec<u8>,
+    intermediates: Vec<Vec<u8>>,
+    roots: Vec<Vec<u8>>,
+    now: Option<u64>,
+) -> Result<CertChain, CryptoError> {
+    let mut builder = ChainBuilder {
+        certs: vec![ChainCert::parse(leaf)?],
+        roots: 1 + intermediates.len(),
+        now: now.unwrap_or_else(unix_now),
+        attempts: 0,
+        error: None,
+    };
+    // Unparseable candidates can never be an issuer, keep their slots so the
+    // intermediates and roots stay partitioned
+    for der in intermediates.into_iter().chain(roots) {
+        builder
+            .certs
+            .push(ChainCert::parse(der).unwrap_or_default());
+    }
+    let mut path = vec![0];
+    if let Some(chain) = builder.extend(&mut path) {
+        return Ok(chain);
+    }
+    Err(builder
+        .error
+        .unwrap_or_else(|| CryptoError::InvalidCertificate {
+            details: "no issuer found for certificate 0".to_string(),
+        }))
+}
+
+/// ChainCert is a candidate certificate of a chain, parsed once up front.
+#[derive(Default)]
+struct ChainCert {
+    der: Vec<u8>,
+    cert: Option<Certificate>,
+    subject_key_id: Option<Vec<u8>>,
+    issuer_key_id: Option<Vec<u8>>,
+}
+
+impl ChainCert {
+    fn parse(der: Vec<u8>) -> Result<Self, CryptoError> {
+        let cert = parse_cert(&der)?;
+        let mut parsed = Self {
+            der,
+            cert: None,
+            subject_key_id: None,
+            issuer_key_id: None,
+        };
+        for ext in cert.tbs_certificate.extensions.iter().flatten() {
+            let value = ext.extn_value.as_bytes();
+            match ext.extn_id {
+                SubjectKeyIdentifier::OID => {
+                    let ski = SubjectKeyIdentifier::from_der(value).map_err(der_error)?;
+                    parsed.subject_key_id = Some(ski.0.as_bytes().to_vec());
+                }
+                AuthorityKeyIdentifier::OID => {
+                    let aki = AuthorityKeyIdentifier::from_der(value).map_err(der_error)?;
+                    parsed.issuer_key_id = aki.key_identifier.map(|id| id.as_bytes().to_vec());
+                }
+                _ => {}
+            }
+        }
+        parsed.cert = Some(cert);
+        Ok(parsed)
+    }
+
+    /// Returns whether this certificate may have issued the child, by name
+    /// and, if both sides carry one, by key identifier.
+    fn may_issue(&self, child: &ChainCert) -> bool {
+        let (Some(cert), Some(child_cert)) = (&self.cert, &child.cert) else {
+            return false;
+        };
+        if cert.tbs_certificate.subject != child_cert.tbs_certificate.issuer {
+            return false;
+        }
+        match (&self.subject_key_id, &child.issuer_key_id) {
+            (Some(ski), Some(aki)) => ski == aki,
+            _ => true,
+        }
+    }
+}
+
+/// ChainBuilder searches the candidate certificates for a path from the leaf
+/// to a trusted root that verifies.
+struct ChainBuilder {
+    /// The leaf, followed by the intermediates, followed by the roots
+    certs: Vec<ChainCert>,
+    /// Index of the first root in `certs`
+    roots: usize,
+    now: u64,
+    /// Number of candidate issuers tried so far
+    attempts: usize,
+    /// Failure of the most recent path, reported if none verifies
+    error: Option<CryptoError>,
+}
+
+impl ChainBuilder {
+    /// Extends the path with each candidate issuer of its last certificate,
+    /// roots first, verifying every path that reaches a root until one does.
+    fn extend(&mut self, path: &mut Vec<usize>) -> Option<CertChain> {
+        let child = &self.certs[*path.last().unwrap()];
+        let candidates: Vec<usize> = (self.roots..self.certs.len())
+            .chain(1..self.roots)
+            .filter(|i| !path.contains(i) && self.certs[*i].may_issue(child))
+            .collect();
+        if candidates.is_empty() && self.error.is_none() {
+            self.error = Some(CryptoError::InvalidCertificate {
+                details: format!("no issuer found for certificate {}", path.len() - 1),
+            });
+        }
+        for i in candidates {
+            if self.attempts >= MAX_CHAIN_CANDIDATES {
+                self.error.get_or_insert(CryptoError::InvalidCertificate {
+                    details: format!("no chain found within {MAX_CHAIN_CANDIDATES} candidates"),
+                });
+                return None;
+            }
+            self.attempts += 1;
+
+            // An intermediate is only worth trying if a root may still follow
+            if i < self.roots && path.len() + 2 > MAX_CHAIN_LENGTH {
+                self.error.get_or_insert(CryptoError::InvalidCertificate {
+                    details: format!("chain longer than {MAX_CHAIN_LENGTH} certificates"),
+                });
+                continue;
+            }
+            path.push(i);
+            let chain = if i >= self.roots {
+                let ders: Vec<&[u8]> = path.iter().map(|&i| self.certs[i].der.as_slice()).collect();
+                match verify_path(&ders, self.now) {
+                    Ok(leaf_key) => Some(CertChain {
+                        leaf_key,
+                        path: ders.iter().map(|der| der.to_vec()).collect(),
+                    }),
+                    Err(e) => {
+                        self.error = Some(e);
+                        None
+                    }
+                }
+            } else {
+                self.extend(path)
+            };
+            path.pop();
+            if chain.is_some() {
+                return chain;
+            }
+        }
+        None
+    }
+}
+
+/// Verifies a path of DER-encoded certificates from the leaf up to a
+/// self-signed root, returning the leaf key.
+fn verify_path(path: &[&[u8]], now: u64) -> Result<CertPublicKey, CryptoError> {
+    // Verify the path top-down, starting from the self-signed root
+    let root = path.last().unwrap();
+    let root_key = Box::new(
+        darkbio_crypto::xdsa::PublicKey::from_bytes(&subject_key(root)?).map_err(invalid_key)?,
+    );
+    let mut issuer = verify_issued(root, &root_key, now)?;
+
+    let mut max_path_len = match issuer.cert.role {
+        darkbio_crypto::x509::Role::Authority { path_len } => path_len,
+        darkbio_crypto::x509::Role::Leaf => {
+            return Err(darkbio_crypto::x509::Error::InvalidIssuer {
+                details: "not a CA",
+            }
+            .into());
+        }
+    };
+    for cert in path[1..path.len() - 1].iter().rev() {
+        if max_path_len == Some(0) {
+            return Err(CryptoError::InvalidCertificate {
+                details: "path length constraint exceeded".to_string(),
+            });
+        }
+        let verified = verify_issued_by(cert, &issuer, now)?;
+
+        max_path_len = max_path_len.map(|n| n - 1);
+        if let darkbio_crypto::x509::Role::Authority {
+            path_len: Some(path_len),
+        } = verified.cert.role
+        {
+            max_path_len = Some(max_path_len.map_or(path_len, |n| n.min(path_len)));
+        }
+        issuer = verified;
+    }
+
+    // Verify the leaf against its issuer according to its key type
+    let leaf = path[0];
+    let algorithm = parse_cert(leaf)?
+        .tbs_certificate
+        .subject_public_key_info
+        .algorithm
+        .oid;
+    if algorithm == darkbio_crypto::xhpke::OID {
+        let verified = darkbio_crypto::xhpke::verify_cert_der_with_issuer(
+            leaf,
+            &issuer,
+            darkbio_crypto::x509::ValidityCheck::Disabled,
+        )?;
+        check_validity(&verified.cert, now, 0)?;
+        Ok(CertPublicKey::Xhpke(XhpkePublicKey {
+            inner: verified.public_key,
+        }))
+    } else {
+        let verified = verify_issued_by(leaf, &issuer, now)?;
+        Ok(CertPublicKey::Xdsa(XdsaPublicKey {
+            inner: Box::new(verified.public_key),
+        }))
+    }
+}
+
+/// An xDSA certificate verified along a chain, boxed as ML-DSA keys are too
+/// large to keep several of on the stack.
+type VerifiedXdsa = Box<darkbio_crypto::x509::Verified<darkbio_crypto::xdsa::PublicKey>>;
+
+/// Verifies an xDSA certificate signed by the given key and checks that it is
+/// valid at `now`.
+fn verify_issued(
+    cert: &[u8],
+    signer: &darkbio_crypto::xdsa::PublicKey,
+    now: u64,
+) -> Result<VerifiedXdsa, CryptoError> {
+    let verified = Box::new(darkbio_crypto::xdsa::verify_cert_der(
+        cert,
+        signer,
+        darkbio_crypto::x509::ValidityCheck::Disabled,
+    )?);
+    check_validity(&verified.cert, now, 0)?;
+    Ok(verified)
+}
+
+/// Verifies an xDSA certificate against its issuer's certificate, including
+/// the name and key identifier chaining, and checks that it is valid at `now`.
+fn verify_issued_by(
+    cert: &[u8],
+    issuer: &darkbio_crypto::x509::Verified<darkbio_crypto::xdsa::PublicKey>,
+    now: u64,
+) -> Result<VerifiedXdsa, CryptoError> {
+    let verified = Box::new(darkbio_crypto::xdsa::verify_cert_der_with_issuer(
+        cert,
+        issuer,
+        darkbio_crypto::x509::ValidityCheck::Disabled,
+    )?);
+    check_validity(&verified.cert, now, 0)?;
+    Ok(verified)
+}
+
+/// CertKeyType is the kind of subject key a certificate holds.
+pub enum CertKeyType {
+    /// xDSA signing key
+    Xdsa,
+    /// xHPKE encryption key
+    Xhpke,
+}
+
+/// CertInfo is the content of a certificate, read without verifying it.
+pub struct CertInfo {
+    /// Subject distinguished name
+    pub subject: CertName,
+    /// Issuer distinguished name
+    pub issuer: CertName,
+    /// Serial number (big-endian)
+    pub serial: Vec<u8>,
+    /// Validity start time (Unix timestamp)
+    pub not_before: u64,
+    /// Validity end time (Unix timestamp)
+    pub not_after: u64,
+    /// Whether this is a CA certificate
+    pub is_ca: bool,
+    /// Maximum intermediate CAs allowed (only if is_ca is true)
+    pub path_len: Option<u8>,
+    /// subjectAltName DNS names
+    pub dns_names: Vec<String>,
+    /// subjectAltName URIs
+    pub uris: Vec<String>,
+    /// subjectAltName email addresses
+    pub emails: Vec<String>,
+    /// keyUsage bits
+    pub key_usage: Vec<CertKeyUsage>,
+    /// Dotted extendedKeyUsage purpose OIDs
+    pub extended_key_usage: Vec<String>,
+    /// Extensions other than the standard ones above
+    pub extensions: Vec<CertExtension>,
+    /// Kind of the subject key
+    pub key_type: CertKeyType,
+    /// 32-byte fingerprint of the subject key
+    pub fingerprint: Vec<u8>,
+    /// Subject key identifier (SHA-1 of the subject key)
+    pub subject_key_id: Option<Vec<u8>>,
+    /// Authority key identifier, matching the issuer's subject key identifier
+    pub issuer_key_id: Option<Vec<u8>>,
+}
+
+/// Parses a DER or PEM encoded xDSA or xHPKE certificate without verifying
+/// its signature.
+///
+/// **Warning**: The returned content is unauthenticated and should not be
+/// trusted until the certificate is verified, e.g. with `x509_verify_chain`.
+///
+/// - `cert`: The DER-encoded certificate, or its PEM encoding, which may be
+///   preceded by whitespace or a UTF-8 byte order mark
+#[frb(sync)]
+pub fn x509_parse(cert: Vec<u8>) -> Result<CertInfo, CryptoError> {
+    // Editors and platforms may prepend a byte order mark or blank lines
+    let text = cert.strip_prefix(b"\xef\xbb\xbf").unwrap_or(&cert);
+    let text = text.trim_ascii_start();
+    let der = if text.starts_with(b"-----BEGIN") {
+        let (tag, der) = darkbio_crypto::pem::decode(text).map_err(CryptoError::encoding)?;
+        if tag != "CERTIFICATE" {
+            return Err(CryptoError::InvalidCertificate {
+                details: format!("invalid PEM tag {tag}"),
+            });
+        }
+        der
+    } else {
+        cert
+    };
+    let tbs = parse_cert(&der)?.tbs_certificate;
+
+    // Identify the subject key
+    let spki = &tbs.subject_public_key_info;
+    let key = spki.subject_public_key.raw_bytes().to_vec();
+    let (key_type, fingerprint) = if spki.algorithm.oid == darkbio_crypto::xdsa::OID {
+        let key =
+            darkbio_crypto::xdsa::PublicKey::from_bytes(&to_array(key)?).map_err(invalid_key)?;
+        (CertKeyType::Xdsa, key.fingerprint().to_bytes().to_vec())
+    } else if spki.algorithm.oid == darkbio_crypto::xhpke::OID {
+        let key =
+            darkbio_crypto::xhpke::PublicKey::from_bytes(&to_array(key)?).map_err(invalid_key)?;
+        (CertKeyType::Xhpke, key.fingerprint().to_bytes().to_vec())
+    } else {
+        return Err(CryptoError::InvalidCertificate {
+            details: format!("unsupported key algorithm {}", spki.algorithm.oid),
+        });
+    };
+
+    let mut info = CertInfo {
+        subject: parse_name(&tbs.subject)?,
+        issuer: parse_name(&tbs.issuer)?,
+        serial: tbs.serial_number.as_bytes().to_vec(),
+        not_before: tbs.validity.not_before.to_unix_duration().as_secs(),
+        not_after: tbs.validity.not_after.to_unix_duration().as_secs(),
+        is_ca: false,
+        path_len: None,
+        dns_names: Vec::new(),
+        uris: Vec::new(),
+        emails: Vec::new(),
+        key_usage: Vec::new(),
+        extended_key_usage: Vec::new(),
+        extensions: Vec::new(),
+        key_type,
+        fingerprint,
+        subject_key_id: None,
+        issuer_key_id: None,
+    };
+
+    // Split the standard extensions out of the custom ones
+    for ext in tbs.extensions.unwrap_or_default() {
+        let value = ext.extn_value.as_bytes();
+        match ext.extn_id {
+            BasicConstraints::OID => {
+                let bc = BasicConstraints::from_der(value).map_err(der_error)?;
+                info.is_ca = bc.ca;
+                info.path_len = bc.path_len_constraint;
+            }
+            KeyUsage::OID => {
+                let usage = KeyUsage::from_der(value).map_err(der_error)?;
+                info.key_usage = CertKeyUsage::ALL
+                    .into_iter()
+                    .filter(|u| usage.0.contains(u.to_inner()))
+                    .collect();
+            }
+            SubjectAltName::OID => {
+                for name in SubjectAltName::from_der(value).map_err(der_error)?.0 {
+                    match name {
+                        GeneralName::DnsName(name) => info.dns_names.push(name.to_string()),
+                        GeneralName::UniformResourceIdentifier(name) => {
+                            info.uris.push(name.to_string())
+                        }
+                        GeneralName::Rfc822Name(name) => info.emails.push(name.to_string()),
+                        _ => {}
+                    }
+                }
+            }
+            ExtendedKeyUsage::OID => {
+                info.extended_key_usage = ExtendedKeyUsage::from_der(value)
+                    .map_err(der_error)?
+                    .0
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+            }
+            SubjectKeyIdentifier::OID => {
+                let ski = SubjectKeyIdentifier::from_der(value).map_err(der_error)?;
+                info.subject_key_id = Some(ski.0.as_bytes().to_vec());
+            }
+            AuthorityKeyIdentifier::OID => {
+                let aki = AuthorityKeyIdentifier::from_der(value).map_err(der_error)?;
+                info.issuer_key_id = aki.key_identifier.map(|id| id.as_bytes().to_vec());
+            }
+            oid => info.extensions.push(CertExtension {
+                oid: oid.to_string(),
+                critical: ext.critical,
+                value: value.to_vec(),
+            }),
+        }
+    }
+    Ok(info)
+}
+
+/// Checks that a certificate is valid at the given time, with the validity
+/// period relaxed by `leeway_secs` on both ends.
+pub(crate) fn check_validity(
+    cert: &darkbio_crypto::x509::Certificate,
+    now: u64,
+    leeway_secs: u64,
+) -> Result<(), CryptoError> {
+    if cert.not_before > now.saturating_add(leeway_secs) {
+        return Err(CryptoError::NotYetValid {
+            nbf: cert.not_before,
+            now,
+        });
+    }
+    if now > cert.not_after.saturating_add(leeway_secs) {
+        return Err(CryptoError::Expired {
+            exp: cert.not_after,
+            now,
+        });
+    }
+    Ok(())
+}
+
+/// Reads the supported attributes of a distinguished name, skipping others.
+fn parse_name(name: &RdnSequence) -> Result<CertName, CryptoError> {
+    let mut parsed = CertName {
+        common_name: None,
+        organization: None,
+        organizational_unit: None,
+        country: None,
+        province: None,
+        locality: None,
+        serial_number: None,
+    };
+    for attr in name.0.iter().flat_map(|rdn| rdn.0.iter()) {
+        let slot = match attr.oid.to_string().as_str() {
+            "2.5.4.3" => &mut parsed.common_name,
+            "2.5.4.10" => &mut parsed.organization,
+            "2.5.4.11" => &mut parsed.organizational_unit,
+            "2.5.4.6" => &mut parsed.country,
+            "2.5.4.8" => &mut parsed.province,
+            "2.5.4.7" => &mut parsed.locality,
+            "2.5.4.5" => &mut parsed.serial_number,
+            _ => continue,
+        };
+        *slot = Some(decode_dn_string(&attr.value)?);
+    }
+    Ok(parsed)
+}
+
+/// Decodes a DirectoryString or other string typed attribute value into text.
+fn decode_dn_string(value: &Any) -> Result<String, CryptoError> {
+    let bytes = value.value();
+    let invalid = || CryptoError::InvalidCertificate {
+        details: format!("DN attribute value is not a valid {}", value.tag()),
+    };
+    match value.tag() {
+        der::Tag::Utf8String => String::from_utf8(bytes.to_vec()).map_err(|_| invalid()),
+        der::Tag::PrintableString
+        | der::Tag::NumericString
+        | der::Tag::Ia5String
+        | der::Tag::VisibleString => match bytes.is_ascii() {
+            true => String::from_utf8(bytes.to_vec()).map_err(|_| invalid()),
+            false => Err(invalid()),
+        },
+        // T.61 is in practice written as Latin-1
+        der::Tag::TeletexString => Ok(bytes.iter().map(|&b| b as char).collect()),
+        der::Tag::BmpString => {
+            if !bytes.len().is_multiple_of(2) {
+                return Err(invalid());
+            }
+            let units = bytes
+                .chunks_exact(2)
+                .map(|unit| u16::from_be_bytes([unit[0], unit[1]]));
+            char::decode_utf16(units)
+                .collect::<Result<String, _>>()
+                .map_err(|_| invalid())
+        }
+        tag => Err(CryptoError::InvalidCertificate {
+            details: format!("unsupported DN attribute type {tag}"),
+        }),
+    }
+}
+
+/// Parses a DER-encoded certificate without verifying it.
+fn parse_cert(der: &[u8]) -> Result<Certificate, CryptoError> {
+    Certificate::from_der(der).map_err(der_error)
+}
+
+/// Extracts the raw xDSA subject key of a DER-encoded certificate, without
+/// verifying it.
+fn subject_key(der: &[u8]) -> Result<[u8; darkbio_crypto::xdsa::PUBLIC_KEY_SIZE], CryptoError> {
+    let spki = parse_cert(der)?.tbs_certificate.subject_public_key_info;
+    if spki.algorithm.oid != darkbio_crypto::xdsa::OID {
+        return Err(CryptoError::InvalidCertificate {
+            details: "root certificate key is not xDSA".to_string(),
+        });
+    }
+    to_array(spki.subject_public_key.raw_bytes().to_vec())
+}
+
+/// Returns the current Unix timestamp in seconds.
+fn unix_now() -> u64 {
+    web_time::SystemTime::now()
+        .duration_since(web_time::UNIX_EPOCH)
+        .expect("system time before Unix epoch")
+        .as_secs()
+}
+
+/// Appends a standard extension, encoded against the subject name.
+fn push_extension<E: AsExtension>(
+    extensions: &mut Vec<Extension>,
+    subject: &x509_cert::name::Name,
+    extension: E,
+) -> Result<(), CryptoError> {
+    let encoded = extension
+        .to_extension(subject, extensions)
+        .map_err(der_error)?;
+    extensions.push(encoded);
+    Ok(())
+}
+
+/// Converts a distinguished name into an RDN sequence, one UTF-8 attribute
+/// per RDN.
+fn to_x509_name(name: &darkbio_crypto::x509::Name) -> Result<RdnSequence, CryptoError> {
+    let mut rdns = Vec::with_capacity(name.attrs.len());
+    for attr in &name.attrs {
+        let mut set = SetOfVec::new();
+        set.insert(AttributeTypeAndValue {
+            oid: attr.oid,
+            value: Any::new(der::Tag::Utf8String, attr.value.as_bytes()).map_err(der_error)?,
+        })
+        .map_err(der_error)?;
+        rdns.push(RelativeDistinguishedName::from(set));
+    }
+    Ok(RdnSequence(rdns))
+}
+
+/// Computes the SHA-1 key identifier of a public key (RFC 5280 §4.2.1.2).
+fn key_identifier(public_key: &[u8]) -> Vec<u8> {
+    Sha1::digest(public_key).to_vec()
+}
+
+fn utc_time(secs: u64) -> Result<Time, CryptoError> {
+    Ok(Time::UtcTime(
+        UtcTime::from_unix_duration(Duration::from_secs(secs)).map_err(der_error)?,
+    ))
+}
+
+fn parse_oid(oid: &str) -> Result<ObjectIdentifier, CryptoError> {
+    ObjectIdentifier::new(oid).map_err(|e| CryptoError::InvalidCertificate {
+        details: format!("invalid OID {oid}: {e}"),
+    })
+}
+
+fn der_error(e: der::Error) -> CryptoError {
+    darkbio_crypto::x509::Error::from(e).into()
+}
+
+fn invalid_key(e: Box<dyn std::error::Error>) -> CryptoError {
+    CryptoError::InvalidCertificate {
+        details: format!("invalid subject key: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::xdsa::XdsaSecretKey;
+    use crate::api::xhpke::XhpkeSecretKey;
+
+    #[test]
+    fn test_template_roundtrip() {
+        let issuer = XdsaSecretKey::generate();
+        let subject = XdsaSecretKey::generate();
+
+        let template = || CertTemplate {
+            subject: CertName {
+                organization: Some("Dark Bio".to_string()),
+                country: Some("CH".to_string()),
+                ..CertName::cn("device".to_string())
+            },
+            dns_names: vec!["example.com".to_string(), "*.example.com".to_string()],
+            uris: vec!["https://example.com/device".to_string()],
+            emails: vec!["device@example.com".to_string()],
+            key_usage: vec![CertKeyUsage::DigitalSignature, CertKeyUsage::NonRepudiation],
+            extended_key_usage: vec![
+                "1.3.6.1.5.5.7.3.1".to_string(),
+                "1.3.6.1.5.5.7.3.2".to_string(),
+            ],
+            extensions: vec![
+                CertExtension {
+                    oid: "1.3.6.1.4.1.99999.1".to_string(),
+                    critical: false,
+                    value: vec![0x04, 0x02, 0xca, 0xfe],
+                },
+                CertExtension {
+                    oid: "1.3.6.1.4.1.99999.2".to_string(),
+                    critical: true,
+                    value: vec![0x05, 0x00],
+                },
+            ],
+            ..CertTemplate::leaf(
+                CertName::cn("device".to_string()),
+                CertName::cn("issuer".to_string()),
+                1_000_000,
+                2_000_000,
+            )
+        };
+        let der = subject
+            .public_key()
+            .to_cert_der(&issuer, template())
+            .unwrap();
+
+        let info = x509_parse(der.clone()).unwrap();
+        assert_eq!(info.subject.common_name.as_deref(), Some("device"));
+        assert_eq!(info.subject.organization.as_deref(), Some("Dark Bio"));
+        assert_eq!(info.subject.country.as_deref(), Some("CH"));
+        assert_eq!(info.issuer.common_name.as_deref(), Some("issuer"));
+        assert_eq!((info.not_before, info.not_after), (1_000_000, 2_000_000));
+        assert!(!info.is_ca && info.path_len.is_none());
+        assert_eq!(info.dns_names, template().dns_names);
+        assert_eq!(info.uris, template().uris);
+        assert_eq!(info.emails, template().emails);
+        assert!(matches!(
+            info.key_usage.as_slice(),
+            [CertKeyUsage::DigitalSignature, CertKeyUsage::NonRepudiation]
+        ));
+        assert_eq!(info.extended_key_usage, template().extended_key_usage);
+        assert_eq!(info.extensions.len(), 2);
+        for (got, want) in info.extensions.iter().zip(template().extensions) {
+            assert_eq!(got.oid, want.oid);
+            assert_eq!(got.critical, want.critical);
+            assert_eq!(got.value, want.value);
+        }
+        assert!(matches!(info.key_type, CertKeyType::Xdsa));
+        assert_eq!(
+            info.fingerprint,
+            subject.public_key().fingerprint().to_bytes()
+        );
+        assert_eq!(
+            info.subject_key_id,
+            Some(key_identifier(&subject.public_key().to_bytes()))
+        );
+        assert_eq!(
+            info.issuer_key_id,
+            Some(key_identifier(&issuer.public_key().to_bytes()))
+        );
+
+        // The extended certificate still verifies upstream
+        let verified = darkbio_crypto::xdsa::verify_cert_der(
+            &der,
+            &issuer.public_key().inner,
+            darkbio_crypto::x509::ValidityCheck::Disabled,
+        )
+        .unwrap();
+        assert_eq!(
+            verified.public_key.to_bytes(),
+            subject.public_key().inner.to_bytes()
+        );
+
+        // So does an xHPKE certificate with extensions
+        let encryption = XhpkeSecretKey::generate();
+        let der = encryption
+            .public_key()
+            .to_cert_der(
+                &issuer,
+                CertTemplate {
+                    key_usage: Vec::new(),
+                    ..template()
+                },
+            )
+            .unwrap();
+        let info = x509_parse(der.clone()).unwrap();
+        assert!(matches!(info.key_type, CertKeyType::Xhpke));
+        assert_eq!(info.dns_names, template().dns_names);
+        assert_eq!(info.extensions.len(), 2);
+        darkbio_crypto::xhpke::verify_cert_der(
+            &der,
+            &issuer.public_key().inner,
+            darkbio_crypto::x509::ValidityCheck::Disabled,
+        )
+        .unwrap();
+
+        // Reserved and duplicate extension OIDs are rejected
+        for oids in [
+            vec!["2.5.29.99"],
+            vec!["1.3.6.1.4.1.99999.1", "1.3.6.1.4.1.99999.1"],
+        ] {
+            let extensions = oids
+                .into_iter()
+                .map(|oid| CertExtension {
+                    oid: oid.to_string(),
+                    critical: false,
+                    value: vec![0x05, 0x00],
+                })
+                .collect();
+            assert!(
+                subject
+                    .public_key()
+                    .to_cert_der(
+                        &issuer,
+                        CertTemplate {
+                            extensions,
+                            ..template()
+                        }
+                    )
+                    .is_err()
+            );
+        }
+    }
+
+    /// Returns a CA template for the given names and path length constraint.
+    fn ca(subject: &str, issuer: &str, path_len: Option<u8>) -> CertTemplate {
+        CertTemplate {
+            is_ca: true,
+            path_len,
+            ..CertTemplate::leaf(
+                CertName::cn(subject.to_string()),
+                CertName::cn(issuer.to_string()),
+                1_000_000,
+                2_000_000,
+            )
+        }
+    }
+
+    /// Returns a leaf template for the given names.
+    fn leaf(subject: &str, issuer: &str) -> CertTemplate {
+        CertTemplate::leaf(
+            CertName::cn(subject.to_string()),
+            CertName::cn(issuer.to_string()),
+            1_000_000,
+            2_000_000,
+        )
+    }
+
+    #[test]
+    fn test_verify_chain() {
+        let root = XdsaSecretKey::generate();
+        let inter = XdsaSecretKey::generate();
+        let device = XdsaSecretKey::generate();
+
+        let root_der = root
+            .public_key()
+            .to_cert_der(&root, ca("root", "root", None))
+            .unwrap();
+        let inter_der = inter
+            .public_key()
+            .to_cert_der(&root, ca("inter", "root", Some(0)))
+            .unwrap();
+        let leaf_der = device
+            .public_key()
+            .to_cert_der(&inter, leaf("device", "inter"))
+            .unwrap();
+
+        let verify = |leaf: &[u8], intermediates: Vec<Vec<u8>>, now| {
+            x509_verify_chain(
+                leaf.to_vec(),
+                intermediates,
+                vec![root_der.clone()],
+                Some(now),
+            )
+        };
+        let chain = verify(&leaf_der, vec![inter_der.clone()], 1_500_000).unwrap();
+        assert!(matches!(
+            &chain.leaf_key,
+            CertPublicKey::Xdsa(key) if key.to_bytes() == device.public_key().to_bytes()
+        ));
+        assert_eq!(
+            chain.path,
+            vec![leaf_der.clone(), inter_der.clone(), root_der.clone()]
+        );
+
+        // A missing intermediate leaves the leaf without an issuer
+        assert!(matches!(
+            verify(&leaf_der, vec![], 1_500_000),
+            Err(CryptoError::InvalidCertificate { .. })
+        ));
+
+        // Every certificate of the path must be valid at the given time
+        assert!(matches!(
+            verify(&leaf_der, vec![inter_der.clone()], 2_500_000),
+            Err(CryptoError::Expired { .. })
+        ));
+        let expired_inter = inter
+            .public_key()
+            .to_cert_der(
+                &root,
+                CertTemplate {
+                    not_after: 1_200_000,
+                    ..ca("inter", "root", None)
+                },
+            )
+            .unwrap();
+        assert!(matches!(
+            verify(&leaf_der, vec![expired_inter], 1_500_000),
+            Err(CryptoError::Expired { exp: 1_200_000, .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_chain_constraints() {
+        let root = XdsaSecretKey::generate();
+        let inter = XdsaSecretKey::generate();
+        let sub = XdsaSecretKey::generate();
+        let device = XdsaSecretKey::generate();
+
+        let root_der = root
+            .public_key()
+            .to_cert_der(&root, ca("root", "root", Some(1)))
+            .unwrap();
+        let verify = |leaf: &[u8], intermediates: Vec<Vec<u8>>| {
+            x509_verify_chain(
+                leaf.to_vec(),
+                intermediates,
+                vec![root_der.clone()],
+                Some(1_500_000),
+            )
+        };
+
+        // The root admits a single intermediate CA below it
+        let inter_der = inter
+            .public_key()
+            .to_cert_der(&root, ca("inter", "root", None))
+            .unwrap();
+        let sub_der = sub
+            .public_key()
+            .to_cert_der(&inter, ca("sub", "inter", None))
+            .unwrap();
+        let leaf_der = device
+            .public_key()
+            .to_cert_der(&sub, leaf("device", "sub"))
+            .unwrap();
+        let direct_der = device
+            .public_key()
+            .to_cert_der(&inter, leaf("device", "inter"))
+            .unwrap();
+        assert!(verify(&direct_der, vec![inter_der.clone()]).is_ok());
+        assert!(matches!(
+            verify(&leaf_der, vec![inter_der.clone(), sub_der]),
+            Err(CryptoError::InvalidCertificate { details }) if details.contains("path length")
+        ));
+
+        // A leaf certificate cannot issue others
+        let non_ca_der = inter
+            .public_key()
+            .to_cert_der(&root, leaf("inter", "root"))
+            .unwrap();
+        assert!(matches!(
+            verify(&direct_der, vec![non_ca_der]),
+            Err(CryptoError::InvalidCertificate { details }) if details.contains("not a CA")
+        ));
+    }
+
+    #[test]
+    fn test_verify_chain_xhpke_leaf() {
+        let root = XdsaSecretKey::generate();
+        let device = XhpkeSecretKey::generate();
+
+        let root_der = root
+            .public_key()
+            .to_cert_der(&root, ca("root", "root", None))
+            .unwrap();
+        let leaf_der = device
+            .public_key()
+            .to_cert_der(&root, leaf("device", "root"))
+            .unwrap();
+        let chain = x509_verify_chain(
+            leaf_der.clone(),
+            vec![],
+            vec![root_der.clone()],
+            Some(1_500_000),
+        )
+        .unwrap();
+        assert!(matches!(
+            &chain.leaf_key,
+            CertPublicKey::Xhpke(key) if key.to_bytes() == device.public_key().to_bytes()
+        ));
+        assert_eq!(chain.path, vec![leaf_der, root_der]);
+    }
+
+    #[test]
+    fn test_verify_chain_candidates() {
+        let root = XdsaSecretKey::generate();
+        let inter = XdsaSecretKey::generate();
+        let impostor = XdsaSecretKey::generate();
+        let device = XdsaSecretKey::generate();
+
+        let root_der = root
+            .public_key()
+            .to_cert_der(&root, ca("root", "root", None))
+            .unwrap();
+        let inter_der = inter
+            .public_key()
+            .to_cert_der(&root, ca("inter", "root", None))
+            .unwrap();
+        let leaf_der = device
+            .public_key()
+            .to_cert_der(&inter, leaf("device", "inter"))
+            .unwrap();
+
+        // A same-named CA with another key is skipped by its key identifier
+        let impostor_der = impostor
+            .public_key()
+            .to_cert_der(&root, ca("inter", "root", None))
+            .unwrap();
+        let chain = x509_verify_chain(
+            leaf_der.clone(),
+            vec![impostor_der, inter_der.clone()],
+            vec![root_der.clone()],
+            Some(1_500_000),
+        )
+        .unwrap();
+        assert_eq!(chain.path[1], inter_der);
+
+        // A same-named CA with the right key but failing verification is
+        // passed over for the next candidate
+        let stale_der = inter
+            .public_key()
+            .to_cert_der(
+                &root,
+                CertTemplate {
+                    not_after: 1_200_000,
+                    ..ca("inter", "root", None)
+                },
+            )
+            .unwrap();
+        let chain = x509_verify_chain(
+            leaf_der.clone(),
+            vec![stale_der.clone(), inter_der.clone()],
+            vec![root_der.clone()],
+            Some(1_500_000),
+        )
+        .unwrap();
+        assert_eq!(chain.path[1], inter_der);
+        assert!(matches!(
+            x509_verify_chain(leaf_der, vec![stale_der], vec![root_der], Some(1_500_000)),
+            Err(CryptoError::Expired { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_chain_limits() {
+        // Intermediates sharing a name and key may each issue the others, so
+        // without a matching root every ordering of them is a candidate path
+        let inter = XdsaSecretKey::generate();
+        let loops: Vec<Vec<u8>> = (0..12)
+            .map(|_| {
+                inter
+                    .public_key()
+                    .to_cert_der(&inter, ca("inter", "inter", None))
+                    .unwrap()
+            })
+            .collect();
+        let leaf_der = XdsaSecretKey::generate()
+            .public_key()
+            .to_cert_der(&inter, leaf("device", "inter"))
+            .unwrap();
+        let root = XdsaSecretKey::generate();
+        let root_der = root
+            .public_key()
+            .to_cert_der(&root, ca("root", "root", None))
+            .unwrap();
+        assert!(matches!(
+            x509_verify_chain(leaf_der, loops, vec![root_der.clone()], Some(1_500_000)),
+            Err(CryptoError::InvalidCertificate { .. })
+        ));
+
+        // Chains longer than the limit are rejected even if they verify
+        let keys: Vec<XdsaSecretKey> = (0..MAX_CHAIN_LENGTH - 1)
+            .map(|_| XdsaSecretKey::generate())
+            .collect();
+        let mut issuer = (&root, "root".to_string());
+        let mut intermediates = Vec::new();
+        for (n, key) in keys.iter().enumerate() {
+            let name = format!("inter{n}");
+            intermediates.push(
+                key.public_key()
+                    .to_cert_der(issuer.0, ca(&name, &issuer.1, None))
+                    .unwrap(),
+            );
+            issuer = (key, name);
+        }
+        let device = XdsaSecretKey::generate();
+        let verify = |intermediates: &[Vec<u8>], issuer: (&XdsaSecretKey, &str)| {
+            let leaf_der = device
+                .public_key()
+                .to_cert_der(issuer.0, leaf("device", issuer.1))
+                .unwrap();
+            x509_verify_chain(
+                leaf_der,
+                intermediates.to_vec(),
+                vec![root_der.clone()],
+                Some(1_500_000),
+            )
+        };
+        let last = intermediates.len() - 1;
+        let name = format!("inter{}", last - 1);
+        let chain = verify(&intermediates[..last], (&keys[last - 1], &name)).unwrap();
+        assert_eq!(chain.path.len(), MAX_CHAIN_LENGTH);
+        assert!(matches!(
+            verify(&intermediates, (issuer.0, &issuer.1)),
+            Err(CryptoError::InvalidCertificate { details }) if details.contains("longer")
+        ));
+    }
+
+    #[test]
+    fn test_parse_pem_prefix() {
+        let issuer = XdsaSecretKey::generate();
+        let pem = issuer
+            .public_key()
+            .to_cert_pem(&issuer, leaf("device", "issuer"))
+            .unwrap();
+
+        for prefix in ["", "\n", " \r\n\t", "\u{feff}", "\u{feff}\r\n"] {
+            let info = x509_parse(format!("{prefix}{pem}").into_bytes()).unwrap();
+            assert_eq!(info.subject.common_name.as_deref(), Some("device"));
+        }
+        // A byte order mark is only stripped once, and only at the start
+        assert!(x509_parse(format!("\u{feff}\u{feff}{pem}").into_bytes()).is_err());
+        assert!(x509_parse(format!("\n\u{feff}{pem}").into_bytes()).is_err());
+
+        let key = darkbio_crypto::pem::encode("PUBLIC KEY", &[0x30, 0x00]);
+        assert!(matches!(
+            x509_parse(format!("\u{feff}\n{key}").into_bytes()),
+            Err(CryptoError::InvalidCertificate { details }) if details.contains("PEM tag")
+        ));
+    }
+
+    /// Reissues a certificate with the subject rewritten to the given string
+    /// typed attributes, leaving the signature stale.
+    fn with_subject(der: &[u8], attrs: &[(&str, der::Tag, &[u8])]) -> Vec<u8> {
+        let mut cert = Certificate::from_der(der).unwrap();
+        let rdns = attrs
+            .iter()
+            .map(|(oid, tag, value)| {
+                let attr = AttributeTypeAndValue {
+                    oid: ObjectIdentifier::new_unwrap(oid),
+                    value: Any::new(*tag, *value).unwrap(),
+                };
+                RelativeDistinguishedName(SetOfVec::try_from(vec![attr]).unwrap())
+            })
+            .collect();
+        cert.tbs_certificate.subject = RdnSequence(rdns);
+        cert.to_der().unwrap()
+    }
+
+    #[test]
+    fn test_parse_name_string_types() {
+        let issuer = XdsaSecretKey::generate();
+        let der = issuer
+            .public_key()
+            .to_cert_der(&issuer, leaf("device", "issuer"))
+            .unwrap();
+
+        let bmp: Vec<u8> = "Zürich 🔑"
+            .encode_utf16()
+            .flat_map(|unit| unit.to_be_bytes())
+            .collect();
+        let cert = with_subject(
+            &der,
+            &[
+                ("2.5.4.3", der::Tag::BmpString, &bmp),
+                ("2.5.4.10", der::Tag::PrintableString, b"Dark Bio"),
+                ("2.5.4.11", der::Tag::TeletexString, b"Gen\xe8ve"),
+                ("2.5.4.6", der::Tag::PrintableString, b"CH"),
+                ("2.5.4.8", der::Tag::Utf8String, "Zürich".as_bytes()),
+                ("2.5.4.7", der::Tag::Ia5String, b"Zurich"),
+                ("2.5.4.5", der::Tag::NumericString, b"0042"),
+            ],
+        );
+        let info = x509_parse(cert).unwrap();
+        assert_eq!(info.subject.common_name.as_deref(), Some("Zürich 🔑"));
+        assert_eq!(info.subject.organization.as_deref(), Some("Dark Bio"));
+        assert_eq!(info.subject.organizational_unit.as_deref(), Some("Genève"));
+        assert_eq!(info.subject.country.as_deref(), Some("CH"));
+        assert_eq!(info.subject.province.as_deref(), Some("Zürich"));
+        assert_eq!(info.subject.locality.as_deref(), Some("Zurich"));
+        assert_eq!(info.subject.serial_number.as_deref(), Some("0042"));
+        assert_eq!(info.issuer.common_name.as_deref(), Some("issuer"));
+
+        // Malformed values and unsupported string types are rejected
+        let cases: [(der::Tag, &[u8]); 6] = [
+            (der::Tag::BmpString, &[0x00, 0x41, 0x00]),
+            (der::Tag::BmpString, &[0xd8, 0x00, 0x00, 0x41]),
+            (der::Tag::Utf8String, &[0xff]),
+            (der::Tag::Ia5String, b"\xe9"),
+            (der::Tag::OctetString, b"device"),
+            (der::Tag::Integer, &[0x01]),
+        ];
+        for (tag, value) in cases {
+            let cert = with_subject(&der, &[("2.5.4.3", tag, value)]);
+            assert!(matches!(
+                x509_parse(cert),
+                Err(CryptoError::InvalidCertificate { .. })
+            ));
+        }
+        // Values of attributes not surfaced are not decoded
+        let cert = with_subject(&der, &[("2.5.4.42", der::Tag::OctetString, b"x")]);
+        assert_eq!(x509_parse(cert).unwrap().subject.common_name, None);
+    }
+}
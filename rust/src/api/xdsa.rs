@@ -5,12 +5,16 @@
 // license that can be found in the LICENSE file.
 
 use flutter_rust_bridge::frb;
+use x509_cert::ext::pkix::KeyUsages;
+
+use super::error::{CryptoError, to_array};
+use super::x509::{self, CertTemplate};
 
 /// XdsaSecretKey is a composite ML-DSA-65 + Ed25519 private key for creating
 /// quantum-resistant digital signatures.
 #[frb(opaque)]
 pub struct XdsaSecretKey {
-    pub(crate) inner: darkbio_crypto::xdsa::SecretKey,
+    pub(crate) inner: Box<darkbio_crypto::xdsa::SecretKey>,
 }
 
 impl XdsaSecretKey {
@@ -18,34 +22,36 @@ impl XdsaSecretKey {
     #[frb(sync)]
     pub fn generate() -> Self {
         Self {
-            inner: darkbio_crypto::xdsa::SecretKey::generate(),
+            inner: Box::new(darkbio_crypto::xdsa::SecretKey::generate()),
         }
     }
 
     /// Creates a private key from a 64-byte seed.
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 64] = bytes
-            .try_into()
-            .map_err(|_| "Invalid key length, expected 64 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 64] = to_array(bytes)?;
         Ok(Self {
-            inner: darkbio_crypto::xdsa::SecretKey::from_bytes(&bytes_array),
+            inner: Box::new(darkbio_crypto::xdsa::SecretKey::from_bytes(&bytes_array)),
         })
     }
 
     /// Parses a DER-encoded private key.
     #[frb(sync)]
-    pub fn from_der(der: Vec<u8>) -> Result<Self, String> {
+    pub fn from_der(der: Vec<u8>) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::xdsa::SecretKey::from_der(&der).map_err(|e| e.to_string())?,
+            inner: Box::new(
+                darkbio_crypto::xdsa::SecretKey::from_der(&der).map_err(CryptoError::encoding)?,
+            ),
         })
     }
 
     /// Parses a PEM-encoded private key.
     #[frb(sync)]
-    pub fn from_pem(pem: String) -> Result<Self, String> {
+    pub fn from_pem(pem: String) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::xdsa::SecretKey::from_pem(&pem).map_err(|e| e.to_string())?,
+            inner: Box::new(
+                darkbio_crypto::xdsa::SecretKey::from_pem(&pem).map_err(CryptoError::encoding)?,
+            ),
         })
     }
 
@@ -71,7 +77,7 @@ impl XdsaSecretKey {
     #[frb(sync)]
     pub fn public_key(&self) -> XdsaPublicKey {
         XdsaPublicKey {
-            inner: self.inner.public_key(),
+            inner: Box::new(self.inner.public_key()),
         }
     }
 
@@ -87,7 +93,7 @@ impl XdsaSecretKey {
     #[frb(sync)]
     pub fn sign(&self, message: Vec<u8>) -> XdsaSignature {
         XdsaSignature {
-            inner: self.inner.sign(&message),
+            inner: Box::new(self.inner.sign(&message)),
         }
     }
 }
@@ -96,51 +102,67 @@ impl XdsaSecretKey {
 /// quantum-resistant digital signatures.
 #[frb(opaque)]
 pub struct XdsaPublicKey {
-    pub(crate) inner: darkbio_crypto::xdsa::PublicKey,
+    pub(crate) inner: Box<darkbio_crypto::xdsa::PublicKey>,
 }
 
 impl XdsaPublicKey {
     /// Creates a public key from a 1984-byte array.
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 1984] = bytes
-            .try_into()
-            .map_err(|_| "Invalid key length, expected 1984 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 1984] = to_array(bytes)?;
         Ok(Self {
-            inner: darkbio_crypto::xdsa::PublicKey::from_bytes(&bytes_array)
-                .map_err(|e| e.to_string())?,
+            inner: Box::new(
+                darkbio_crypto::xdsa::PublicKey::from_bytes(&bytes_array)
+                    .map_err(CryptoError::encoding)?,
+            ),
         })
     }
 
     /// Parses a DER-encoded public key.
     #[frb(sync)]
-    pub fn from_der(der: Vec<u8>) -> Result<Self, String> {
+    pub fn from_der(der: Vec<u8>) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::xdsa::PublicKey::from_der(&der).map_err(|e| e.to_string())?,
+            inner: Box::new(
+                darkbio_crypto::xdsa::PublicKey::from_der(&der).map_err(CryptoError::encoding)?,
+            ),
         })
     }
 
     /// Parses a PEM-encoded public key.
     #[frb(sync)]
-    pub fn from_pem(pem: String) -> Result<Self, String> {
+    pub fn from_pem(pem: String) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::xdsa::PublicKey::from_pem(&pem).map_err(|e| e.to_string())?,
+            inner: Box::new(
+                darkbio_crypto::xdsa::PublicKey::from_pem(&pem).map_err(CryptoError::encoding)?,
+            ),
         })
     }
 
     /// Parses a public key from a DER-encoded certificate, verifying the signature.
     /// Returns the key along with validity start and end timestamps (Unix seconds).
+    ///
+    /// - `der`: The DER-encoded certificate
+    /// - `signer`: The issuer's xDSA public key
+    /// - `now`: Unix timestamp the certificate must be valid at (None to skip)
+    /// - `leeway_secs`: Clock skew tolerated at both ends of the validity period
     #[frb(sync)]
-    pub fn from_cert_der(der: Vec<u8>, signer: &XdsaPublicKey) -> Result<(Self, u64, u64), String> {
+    pub fn from_cert_der(
+        der: Vec<u8>,
+        signer: &XdsaPublicKey,
+        now: Option<u64>,
+        leeway_secs: u64,
+    ) -> Result<(Self, u64, u64), CryptoError> {
         let verified = darkbio_crypto::xdsa::verify_cert_der(
             &der,
             &signer.inner,
             darkbio_crypto::x509::ValidityCheck::Disabled,
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
+        if let Some(now) = now {
+            x509::check_validity(&verified.cert, now, leeway_secs)?;
+        }
         Ok((
             Self {
-                inner: verified.public_key,
+                inner: Box::new(verified.public_key),
             },
             verified.cert.not_before,
             verified.cert.not_after,
@@ -149,17 +171,29 @@ impl XdsaPublicKey {
 
     /// Parses a public key from a PEM-encoded certificate, verifying the signature.
     /// Returns the key along with validity start and end timestamps (Unix seconds).
+    ///
+    /// - `pem`: The PEM-encoded certificate
+    /// - `signer`: The issuer's xDSA public key
+    /// - `now`: Unix timestamp the certificate must be valid at (None to skip)
+    /// - `leeway_secs`: Clock skew tolerated at both ends of the validity period
     #[frb(sync)]
-    pub fn from_cert_pem(pem: String, signer: &XdsaPublicKey) -> Result<(Self, u64, u64), String> {
+    pub fn from_cert_pem(
+        pem: String,
+        signer: &XdsaPublicKey,
+        now: Option<u64>,
+        leeway_secs: u64,
+    ) -> Result<(Self, u64, u64), CryptoError> {
         let verified = darkbio_crypto::xdsa::verify_cert_pem(
             &pem,
             &signer.inner,
             darkbio_crypto::x509::ValidityCheck::Disabled,
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
+        if let Some(now) = now {
+            x509::check_validity(&verified.cert, now, leeway_secs)?;
+        }
         Ok((
             Self {
-                inner: verified.public_key,
+                inner: Box::new(verified.public_key),
             },
             verified.cert.not_before,
             verified.cert.not_after,
@@ -185,79 +219,45 @@ impl XdsaPublicKey {
     }
 
     /// Generates a DER-encoded X.509 certificate for this public key,
-    /// signed by the given xDSA secret key with the specified validity period.
+    /// signed by the given xDSA secret key.
     ///
     /// - `signer`: The xDSA secret key to sign the certificate
-    /// - `subject_name`: The subject's common name (CN)
-    /// - `issuer_name`: The issuer's common name (CN)
-    /// - `not_before`: Certificate validity start time (Unix timestamp)
-    /// - `not_after`: Certificate validity end time (Unix timestamp)
-    /// - `is_ca`: Whether this is a CA certificate
-    /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+    /// - `template`: The certificate's names, validity and extensions
     #[frb(sync)]
-    #[allow(clippy::too_many_arguments)]
     pub fn to_cert_der(
         &self,
         signer: &super::xdsa::XdsaSecretKey,
-        subject_name: String,
-        issuer_name: String,
-        not_before: u64,
-        not_after: u64,
-        is_ca: bool,
-        path_len: Option<u8>,
-    ) -> Result<Vec<u8>, String> {
-        let template = darkbio_crypto::x509::Certificate {
-            subject: darkbio_crypto::x509::Name::new().cn(subject_name),
-            issuer: darkbio_crypto::x509::Name::new().cn(issuer_name),
-            not_before,
-            not_after,
-            role: if is_ca {
-                darkbio_crypto::x509::Role::Authority { path_len }
-            } else {
-                darkbio_crypto::x509::Role::Leaf
-            },
-            extensions: Vec::new(),
+        template: CertTemplate,
+    ) -> Result<Vec<u8>, CryptoError> {
+        let default_usage = if template.is_ca {
+            KeyUsages::KeyCertSign | KeyUsages::CRLSign
+        } else {
+            KeyUsages::DigitalSignature.into()
         };
-        darkbio_crypto::xdsa::issue_cert_der(&self.inner, &signer.inner, &template)
-            .map_err(|e| e.to_string())
+        x509::issue_cert_der(
+            &self.inner.to_bytes(),
+            darkbio_crypto::xdsa::OID,
+            default_usage,
+            &signer.inner,
+            template,
+        )
     }
 
     /// Generates a PEM-encoded X.509 certificate for this public key,
-    /// signed by the given xDSA secret key with the specified validity period.
+    /// signed by the given xDSA secret key.
     ///
     /// - `signer`: The xDSA secret key to sign the certificate
-    /// - `subject_name`: The subject's common name (CN)
-    /// - `issuer_name`: The issuer's common name (CN)
-    /// - `not_before`: Certificate validity start time (Unix timestamp)
-    /// - `not_after`: Certificate validity end time (Unix timestamp)
-    /// - `is_ca`: Whether this is a CA certificate
-    /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+    /// - `template`: The certificate's names, validity and extensions
     #[frb(sync)]
-    #[allow(clippy::too_many_arguments)]
     pub fn to_cert_pem(
         &self,
         signer: &super::xdsa::XdsaSecretKey,
-        subject_name: String,
-        issuer_name: String,
-        not_before: u64,
-        not_after: u64,
-        is_ca: bool,
-        path_len: Option<u8>,
-    ) -> Result<String, String> {
-        let template = darkbio_crypto::x509::Certificate {
-            subject: darkbio_crypto::x509::Name::new().cn(subject_name),
-            issuer: darkbio_crypto::x509::Name::new().cn(issuer_name),
-            not_before,
-            not_after,
-            role: if is_ca {
-                darkbio_crypto::x509::Role::Authority { path_len }
-            } else {
-                darkbio_crypto::x509::Role::Leaf
-            },
-            extensions: Vec::new(),
-        };
-        darkbio_crypto::xdsa::issue_cert_pem(&self.inner, &signer.inner, &template)
-            .map_err(|e| e.to_string())
+        template: CertTemplate,
+    ) -> Result<String, CryptoError> {
+        Ok(darkbio_crypto::pem::encode(
+            "CERTIFICATE",
+            &self.to_cert_der(signer, template)?,
+        ))
     }
 
     /// Returns a 32-byte fingerprint uniquely identifying this key.
@@ -270,28 +270,28 @@ impl XdsaPublicKey {
 
     /// Verifies a signature against a message.
     #[frb(sync)]
-    pub fn verify(&self, message: Vec<u8>, signature: &XdsaSignature) -> Result<(), String> {
+    pub fn verify(&self, message: Vec<u8>, signature: &XdsaSignature) -> Result<(), CryptoError> {
         self.inner
             .verify(&message, &signature.inner)
-            .map_err(|e| e.to_string())
+            .map_err(|e| CryptoError::SignatureMismatch {
+                details: e.to_string(),
+            })
     }
 }
 
 /// XdsaSignature is a composite ML-DSA-65 + Ed25519 digital signature.
 #[frb(opaque)]
 pub struct XdsaSignature {
-    pub(crate) inner: darkbio_crypto::xdsa::Signature,
+    pub(crate) inner: Box<darkbio_crypto::xdsa::Signature>,
 }
 
 impl XdsaSignature {
     /// Creates a signature from a 3373-byte array.
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 3373] = bytes
-            .try_into()
-            .map_err(|_| "Invalid signature length, expected 3373 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 3373] = to_array(bytes)?;
         Ok(Self {
-            inner: darkbio_crypto::xdsa::Signature::from_bytes(&bytes_array),
+            inner: Box::new(darkbio_crypto::xdsa::Signature::from_bytes(&bytes_array)),
         })
     }
 
@@ -311,10 +311,8 @@ pub struct XdsaFingerprint {
 impl XdsaFingerprint {
     /// Creates a fingerprint from a 32-byte array.
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 32] = bytes
-            .try_into()
-            .map_err(|_| "Invalid fingerprint length, expected 32 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 32] = to_array(bytes)?;
         Ok(Self {
             inner: darkbio_crypto::xdsa::Fingerprint::from_bytes(&bytes_array),
         })
@@ -326,3 +324,73 @@ impl XdsaFingerprint {
         self.inner.to_bytes().to_vec()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::x509::CertName;
+
+    #[test]
+    fn test_from_cert_validity() {
+        let issuer = XdsaSecretKey::generate();
+        let subject = XdsaSecretKey::generate().public_key();
+        let template = || {
+            CertTemplate::leaf(
+                CertName::cn("subject".to_string()),
+                CertName::cn("issuer".to_string()),
+                1_000_000,
+                2_000_000,
+            )
+        };
+        let der = subject.to_cert_der(&issuer, template()).unwrap();
+        let pem = subject.to_cert_pem(&issuer, template()).unwrap();
+
+        let from_der = |now, leeway| {
+            XdsaPublicKey::from_cert_der(der.clone(), &issuer.public_key(), now, leeway)
+        };
+        let from_pem = |now, leeway| {
+            XdsaPublicKey::from_cert_pem(pem.clone(), &issuer.public_key(), now, leeway)
+        };
+        let (key, not_before, not_after) = from_der(None, 0).unwrap();
+        assert_eq!(key.to_bytes(), subject.to_bytes());
+        assert_eq!((not_before, not_after), (1_000_000, 2_000_000));
+
+        for check in [&from_der as &dyn Fn(_, _) -> _, &from_pem] {
+            assert!(check(Some(1_000_000), 0).is_ok());
+            assert!(check(Some(2_000_000), 0).is_ok());
+            assert!(matches!(
+                check(Some(999_990), 0),
+                Err(CryptoError::NotYetValid {
+                    nbf: 1_000_000,
+                    now: 999_990
+                })
+            ));
+            assert!(matches!(
+                check(Some(2_000_010), 0),
+                Err(CryptoError::Expired {
+                    exp: 2_000_000,
+                    now: 2_000_010
+                })
+            ));
+
+            // Leeway relaxes both ends of the validity period
+            assert!(check(Some(999_990), 10).is_ok());
+            assert!(check(Some(2_000_010), 10).is_ok());
+            assert!(matches!(
+                check(Some(999_989), 10),
+                Err(CryptoError::NotYetValid { .. })
+            ));
+            assert!(matches!(
+                check(Some(2_000_011), 10),
+                Err(CryptoError::Expired { .. })
+            ));
+        }
+
+        // The signature is checked regardless of the validity period
+        let other = XdsaSecretKey::generate();
+        assert!(matches!(
+            XdsaPublicKey::from_cert_der(der, &other.public_key(), None, 0),
+            Err(CryptoError::SignatureMismatch { .. })
+        ));
+    }
+}
@@ -5,8 +5,15 @@
 // license that can be found in the LICENSE file.
 
 use flutter_rust_bridge::frb;
+use std::fs::File;
+use std::io::{BufReader, Read, Write};
+use x509_cert::ext::pkix::KeyUsages;
 
+use super::error::{CryptoError, to_array};
+use super::stream::{StreamDecryptor, StreamEncryptor, decrypt_file, encrypt_file};
+use super::x509::{self, CertTemplate};
 use super::xdsa::XdsaPublicKey;
+use crate::frb_generated::{RustAutoOpaque, StreamSink};
 
 /// XhpkeSecretKey is an X-Wing (X25519 + ML-KEM-768) private key for
 /// post-quantum hybrid public-key encryption.
@@ -26,10 +33,8 @@ impl XhpkeSecretKey {
 
     /// Creates a private key from a 32-byte seed.
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 32] = bytes
-            .try_into()
-            .map_err(|_| "Invalid key length, expected 32 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 32] = to_array(bytes)?;
         Ok(Self {
             inner: darkbio_crypto::xhpke::SecretKey::from_bytes(&bytes_array),
         })
@@ -37,17 +42,19 @@ impl XhpkeSecretKey {
 
     /// Parses a DER-encoded private key.
     #[frb(sync)]
-    pub fn from_der(der: Vec<u8>) -> Result<Self, String> {
+    pub fn from_der(der: Vec<u8>) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::xhpke::SecretKey::from_der(&der).map_err(|e| e.to_string())?,
+            inner: darkbio_crypto::xhpke::SecretKey::from_der(&der)
+                .map_err(CryptoError::encoding)?,
         })
     }
 
     /// Parses a PEM-encoded private key.
     #[frb(sync)]
-    pub fn from_pem(pem: String) -> Result<Self, String> {
+    pub fn from_pem(pem: String) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::xhpke::SecretKey::from_pem(&pem).map_err(|e| e.to_string())?,
+            inner: darkbio_crypto::xhpke::SecretKey::from_pem(&pem)
+                .map_err(CryptoError::encoding)?,
         })
     }
 
@@ -93,14 +100,14 @@ impl XhpkeSecretKey {
         &self,
         encap_key: Vec<u8>,
         domain: Vec<u8>,
-    ) -> Result<XhpkeReceiver, String> {
-        let encap_key_array: [u8; 1120] = encap_key
-            .try_into()
-            .map_err(|_| "Invalid encapsulated key length, expected 1120 bytes".to_string())?;
+    ) -> Result<XhpkeReceiver, CryptoError> {
+        let encap_key_array: [u8; 1120] = to_array(encap_key)?;
         let receiver = self
             .inner
             .new_receiver(&encap_key_array, &domain)
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| CryptoError::DecryptionFailed {
+                details: e.to_string(),
+            })?;
         Ok(XhpkeReceiver { inner: receiver })
     }
 
@@ -117,13 +124,55 @@ impl XhpkeSecretKey {
         msg_to_open: Vec<u8>,
         msg_to_auth: Vec<u8>,
         domain: Vec<u8>,
-    ) -> Result<Vec<u8>, String> {
-        let session_key_array: [u8; 1120] = session_key
-            .try_into()
-            .map_err(|_| "Invalid session key length, expected 1120 bytes".to_string())?;
+    ) -> Result<Vec<u8>, CryptoError> {
+        let session_key_array: [u8; 1120] = to_array(session_key)?;
         self.inner
             .open(&session_key_array, &msg_to_open, &msg_to_auth, &domain)
-            .map_err(|e| e.to_string())
+            .map_err(|e| CryptoError::DecryptionFailed {
+                details: e.to_string(),
+            })
+    }
+
+    /// Decrypts a message produced by `xhpke_seal_multi`, locating the content
+    /// key wrapped for this key by its fingerprint.
+    ///
+    /// - `sealed`: The multi-recipient message to decrypt
+    /// - `msg_to_auth`: Additional authenticated data (not encrypted)
+    /// - `domain`: Application-specific domain separator
+    #[frb(sync)]
+    pub fn open_multi(
+        &self,
+        sealed: Vec<u8>,
+        msg_to_auth: Vec<u8>,
+        domain: Vec<u8>,
+    ) -> Result<Vec<u8>, CryptoError> {
+        let (recipients, ciphertext): (MultiRecipients, Vec<u8>) =
+            darkbio_crypto::cbor::decode(&sealed)?;
+
+        let fingerprint = self.inner.fingerprint().to_bytes();
+        let (_, encap_key, sealed_key) = recipients
+            .iter()
+            .find(|(recipient, _, _)| *recipient == fingerprint)
+            .ok_or_else(|| CryptoError::KeyMismatch {
+                details: "message not sealed to this key".to_string(),
+            })?;
+
+        let key = self
+            .inner
+            .open(encap_key, sealed_key, &msg_to_auth, &domain)
+            .map_err(|e| CryptoError::DecryptionFailed {
+                details: e.to_string(),
+            })?;
+        let key_array: [u8; 32] = to_array(key)?;
+
+        let payload_key = darkbio_crypto::stream::PayloadKey(key_array.into());
+        let mut reader =
+            darkbio_crypto::stream::Stream::decrypt(payload_key, ciphertext.as_slice());
+
+        let mut plaintext = Vec::new();
+        reader.read_to_end(&mut plaintext)?;
+
+        Ok(plaintext)
     }
 }
 
@@ -137,42 +186,54 @@ pub struct XhpkePublicKey {
 impl XhpkePublicKey {
     /// Creates a public key from a 1216-byte array.
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 1216] = bytes
-            .try_into()
-            .map_err(|_| "Invalid key length, expected 1216 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 1216] = to_array(bytes)?;
         Ok(Self {
             inner: darkbio_crypto::xhpke::PublicKey::from_bytes(&bytes_array)
-                .map_err(|e| e.to_string())?,
+                .map_err(CryptoError::encoding)?,
         })
     }
 
     /// Parses a DER-encoded public key.
     #[frb(sync)]
-    pub fn from_der(der: Vec<u8>) -> Result<Self, String> {
+    pub fn from_der(der: Vec<u8>) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::xhpke::PublicKey::from_der(&der).map_err(|e| e.to_string())?,
+            inner: darkbio_crypto::xhpke::PublicKey::from_der(&der)
+                .map_err(CryptoError::encoding)?,
         })
     }
 
     /// Parses a PEM-encoded public key.
     #[frb(sync)]
-    pub fn from_pem(pem: String) -> Result<Self, String> {
+    pub fn from_pem(pem: String) -> Result<Self, CryptoError> {
         Ok(Self {
-            inner: darkbio_crypto::xhpke::PublicKey::from_pem(&pem).map_err(|e| e.to_string())?,
+            inner: darkbio_crypto::xhpke::PublicKey::from_pem(&pem)
+                .map_err(CryptoError::encoding)?,
         })
     }
 
     /// Parses a public key from a DER-encoded certificate, verifying the xDSA signature.
     /// Returns the key along with validity start and end timestamps (Unix seconds).
+    ///
+    /// - `der`: The DER-encoded certificate
+    /// - `signer`: The issuer's xDSA public key
+    /// - `now`: Unix timestamp the certificate must be valid at (None to skip)
+    /// - `leeway_secs`: Clock skew tolerated at both ends of the validity period
     #[frb(sync)]
-    pub fn from_cert_der(der: Vec<u8>, signer: &XdsaPublicKey) -> Result<(Self, u64, u64), String> {
+    pub fn from_cert_der(
+        der: Vec<u8>,
+        signer: &XdsaPublicKey,
+        now: Option<u64>,
+        leeway_secs: u64,
+    ) -> Result<(Self, u64, u64), CryptoError> {
         let verified = darkbio_crypto::xhpke::verify_cert_der(
             &der,
             &signer.inner,
             darkbio_crypto::x509::ValidityCheck::Disabled,
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
+        if let Some(now) = now {
+            x509::check_validity(&verified.cert, now, leeway_secs)?;
+        }
         Ok((
             Self {
                 inner: verified.public_key,
@@ -184,14 +245,26 @@ impl XhpkePublicKey {
 
     /// Parses a public key from a PEM-encoded certificate, verifying the xDSA signature.
     /// Returns the key along with validity start and end timestamps (Unix seconds).
+    ///
+    /// - `pem`: The PEM-encoded certificate
+    /// - `signer`: The issuer's xDSA public key
+    /// - `now`: Unix timestamp the certificate must be valid at (None to skip)
+    /// - `leeway_secs`: Clock skew tolerated at both ends of the validity period
     #[frb(sync)]
-    pub fn from_cert_pem(pem: String, signer: &XdsaPublicKey) -> Result<(Self, u64, u64), String> {
+    pub fn from_cert_pem(
+        pem: String,
+        signer: &XdsaPublicKey,
+        now: Option<u64>,
+        leeway_secs: u64,
+    ) -> Result<(Self, u64, u64), CryptoError> {
         let verified = darkbio_crypto::xhpke::verify_cert_pem(
             &pem,
             &signer.inner,
             darkbio_crypto::x509::ValidityCheck::Disabled,
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
+        if let Some(now) = now {
+            x509::check_validity(&verified.cert, now, leeway_secs)?;
+        }
         Ok((
             Self {
                 inner: verified.public_key,
@@ -220,79 +293,47 @@ impl XhpkePublicKey {
     }
 
     /// Generates a DER-encoded X.509 certificate for this public key,
-    /// signed by the given xDSA secret key with the specified validity period.
+    /// signed by the given xDSA secret key.
+    ///
+    /// xHPKE certificates are always end-entity certificates.
     ///
     /// - `signer`: The xDSA secret key to sign the certificate
-    /// - `subject_name`: The subject's common name (CN)
-    /// - `issuer_name`: The issuer's common name (CN)
-    /// - `not_before`: Certificate validity start time (Unix timestamp)
-    /// - `not_after`: Certificate validity end time (Unix timestamp)
-    /// - `is_ca`: Whether this is a CA certificate
-    /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+    /// - `template`: The certificate's names, validity and extensions
     #[frb(sync)]
-    #[allow(clippy::too_many_arguments)]
     pub fn to_cert_der(
         &self,
         signer: &super::xdsa::XdsaSecretKey,
-        subject_name: String,
-        issuer_name: String,
-        not_before: u64,
-        not_after: u64,
-        is_ca: bool,
-        path_len: Option<u8>,
-    ) -> Result<Vec<u8>, String> {
-        let template = darkbio_crypto::x509::Certificate {
-            subject: darkbio_crypto::x509::Name::new().cn(subject_name),
-            issuer: darkbio_crypto::x509::Name::new().cn(issuer_name),
-            not_before,
-            not_after,
-            role: if is_ca {
-                darkbio_crypto::x509::Role::Authority { path_len }
-            } else {
-                darkbio_crypto::x509::Role::Leaf
-            },
-            extensions: Vec::new(),
-        };
-        darkbio_crypto::xhpke::issue_cert_der(&self.inner, &signer.inner, &template)
-            .map_err(|e| e.to_string())
+        template: CertTemplate,
+    ) -> Result<Vec<u8>, CryptoError> {
+        if template.is_ca {
+            return Err(darkbio_crypto::x509::Error::MustBeLeaf.into());
+        }
+        x509::issue_cert_der(
+            &self.inner.to_bytes(),
+            darkbio_crypto::xhpke::OID,
+            KeyUsages::KeyAgreement.into(),
+            &signer.inner,
+            template,
+        )
     }
 
     /// Generates a PEM-encoded X.509 certificate for this public key,
-    /// signed by the given xDSA secret key with the specified validity period.
+    /// signed by the given xDSA secret key.
+    ///
+    /// xHPKE certificates are always end-entity certificates.
     ///
     /// - `signer`: The xDSA secret key to sign the certificate
-    /// - `subject_name`: The subject's common name (CN)
-    /// - `issuer_name`: The issuer's common name (CN)
-    /// - `not_before`: Certificate validity start time (Unix timestamp)
-    /// - `not_after`: Certificate validity end time (Unix timestamp)
-    /// - `is_ca`: Whether this is a CA certificate
-    /// - `path_len`: Maximum intermediate CAs allowed (only if is_ca is true)
+    /// - `template`: The certificate's names, validity and extensions
     #[frb(sync)]
-    #[allow(clippy::too_many_arguments)]
     pub fn to_cert_pem(
         &self,
         signer: &super::xdsa::XdsaSecretKey,
-        subject_name: String,
-        issuer_name: String,
-        not_before: u64,
-        not_after: u64,
-        is_ca: bool,
-        path_len: Option<u8>,
-    ) -> Result<String, String> {
-        let template = darkbio_crypto::x509::Certificate {
-            subject: darkbio_crypto::x509::Name::new().cn(subject_name),
-            issuer: darkbio_crypto::x509::Name::new().cn(issuer_name),
-            not_before,
-            not_after,
-            role: if is_ca {
-                darkbio_crypto::x509::Role::Authority { path_len }
-            } else {
-                darkbio_crypto::x509::Role::Leaf
-            },
-            extensions: Vec::new(),
-        };
-        darkbio_crypto::xhpke::issue_cert_pem(&self.inner, &signer.inner, &template)
-            .map_err(|e| e.to_string())
+        template: CertTemplate,
+    ) -> Result<String, CryptoError> {
+        Ok(darkbio_crypto::pem::encode(
+            "CERTIFICATE",
+            &self.to_cert_der(signer, template)?,
+        ))
     }
 
     /// Returns a 32-byte fingerprint uniquely identifying this key.
@@ -310,8 +351,13 @@ impl XhpkePublicKey {
     /// Messages encrypted with the returned context must be decrypted in order
     /// by the corresponding receiver context.
     #[frb(sync)]
-    pub fn new_sender(&self, domain: Vec<u8>) -> Result<(XhpkeSender, Vec<u8>), String> {
-        let (sender, encap_key) = self.inner.new_sender(&domain).map_err(|e| e.to_string())?;
+    pub fn new_sender(&self, domain: Vec<u8>) -> Result<(XhpkeSender, Vec<u8>), CryptoError> {
+        let (sender, encap_key) =
+            self.inner
+                .new_sender(&domain)
+                .map_err(|e| CryptoError::EncryptionFailed {
+                    details: e.to_string(),
+                })?;
         Ok((XhpkeSender { inner: sender }, encap_key.to_vec()))
     }
 
@@ -328,11 +374,13 @@ impl XhpkePublicKey {
         msg_to_seal: Vec<u8>,
         msg_to_auth: Vec<u8>,
         domain: Vec<u8>,
-    ) -> Result<(Vec<u8>, Vec<u8>), String> {
+    ) -> Result<(Vec<u8>, Vec<u8>), CryptoError> {
         let (session_key, ciphertext) = self
             .inner
             .seal(&msg_to_seal, &msg_to_auth, &domain)
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| CryptoError::EncryptionFailed {
+                details: e.to_string(),
+            })?;
         Ok((session_key.to_vec(), ciphertext))
     }
 }
@@ -352,10 +400,16 @@ pub struct XhpkeSender {
 impl XhpkeSender {
     /// Encrypts a message using the next nonce in the sequence.
     #[frb(sync)]
-    pub fn seal(&mut self, msg_to_seal: Vec<u8>, msg_to_auth: Vec<u8>) -> Result<Vec<u8>, String> {
+    pub fn seal(
+        &mut self,
+        msg_to_seal: Vec<u8>,
+        msg_to_auth: Vec<u8>,
+    ) -> Result<Vec<u8>, CryptoError> {
         self.inner
             .seal(&msg_to_seal, &msg_to_auth)
-            .map_err(|e| e.to_string())
+            .map_err(|e| CryptoError::EncryptionFailed {
+                details: e.to_string(),
+            })
     }
 }
 
@@ -373,10 +427,16 @@ pub struct XhpkeReceiver {
 impl XhpkeReceiver {
     /// Decrypts a message using the next nonce in the sequence.
     #[frb(sync)]
-    pub fn open(&mut self, msg_to_open: Vec<u8>, msg_to_auth: Vec<u8>) -> Result<Vec<u8>, String> {
+    pub fn open(
+        &mut self,
+        msg_to_open: Vec<u8>,
+        msg_to_auth: Vec<u8>,
+    ) -> Result<Vec<u8>, CryptoError> {
         self.inner
             .open(&msg_to_open, &msg_to_auth)
-            .map_err(|e| e.to_string())
+            .map_err(|e| CryptoError::DecryptionFailed {
+                details: e.to_string(),
+            })
     }
 }
 
@@ -389,10 +449,8 @@ pub struct XhpkeFingerprint {
 impl XhpkeFingerprint {
     /// Creates a fingerprint from a 32-byte array.
     #[frb(sync)]
-    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
-        let bytes_array: [u8; 32] = bytes
-            .try_into()
-            .map_err(|_| "Invalid fingerprint length, expected 32 bytes".to_string())?;
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, CryptoError> {
+        let bytes_array: [u8; 32] = to_array(bytes)?;
         Ok(Self {
             inner: darkbio_crypto::xhpke::Fingerprint::from_bytes(&bytes_array),
         })
@@ -404,3 +462,414 @@ impl XhpkeFingerprint {
         self.inner.to_bytes().to_vec()
     }
 }
+
+/// MultiRecipients lists the content key wrapped for each recipient of a
+/// multi-recipient message as (fingerprint, encapsulated key, sealed key).
+type MultiRecipients = darkbio_crypto::cbor::Array<(
+    [u8; 32],
+    [u8; darkbio_crypto::xhpke::ENCAP_KEY_SIZE],
+    Vec<u8>,
+)>;
+
+/// Encrypts a message to multiple public keys at once. The message is
+/// encrypted a single time with STREAM under a random content key, which is
+/// then sealed to each recipient with xHPKE, labelled by its fingerprint.
+///
+/// Returns the CBOR encoded `[[fingerprint, encap_key, sealed_key], ...]`
+/// recipient list followed by the ciphertext.
+///
+/// - `recipients`: The recipients' public keys
+/// - `msg_to_seal`: The plaintext to encrypt
+/// - `msg_to_auth`: Additional authenticated data (not encrypted)
+/// - `domain`: Application-specific domain separator
+#[frb(sync)]
+pub fn xhpke_seal_multi(
+    recipients: Vec<RustAutoOpaque<XhpkePublicKey>>,
+    msg_to_seal: Vec<u8>,
+    msg_to_auth: Vec<u8>,
+    domain: Vec<u8>,
+) -> Result<Vec<u8>, CryptoError> {
+    if recipients.is_empty() {
+        return Err(CryptoError::EncryptionFailed {
+            details: "no recipients".to_string(),
+        });
+    }
+    let key = darkbio_crypto::rand::generate(32);
+
+    let mut wraps = Vec::with_capacity(recipients.len());
+    for recipient in &recipients {
+        let recipient = recipient.blocking_read();
+        let (encap_key, sealed_key) =
+            recipient
+                .inner
+                .seal(&key, &msg_to_auth, &domain)
+                .map_err(|e| CryptoError::EncryptionFailed {
+                    details: e.to_string(),
+                })?;
+        wraps.push((
+            recipient.inner.fingerprint().to_bytes(),
+            encap_key,
+            sealed_key,
+        ));
+    }
+    let key_array: [u8; 32] = to_array(key)?;
+
+    let payload_key = darkbio_crypto::stream::PayloadKey(key_array.into());
+
+    let mut ciphertext = Vec::new();
+    let mut writer = darkbio_crypto::stream::Stream::encrypt(payload_key, &mut ciphertext);
+    writer.write_all(&msg_to_seal)?;
+    writer.finish()?;
+
+    darkbio_crypto::cbor::encode((MultiRecipients::from(wraps), ciphertext))
+        .map_err(CryptoError::from)
+}
+
+/// Size of the header prefixing xHPKE sealed streams: the 1120-byte
+/// encapsulated key followed by the 48-byte sealed STREAM payload key.
+const STREAM_HEADER_SIZE: usize = darkbio_crypto::xhpke::ENCAP_KEY_SIZE + 32 + 16;
+
+/// Encrypts a large message to a public key by sealing a fresh random STREAM
+/// payload key with xHPKE and encrypting the message itself with STREAM.
+///
+/// Returns the 1168-byte header followed by the STREAM ciphertext.
+///
+/// - `public_key`: The recipient's public key
+/// - `plaintext`: The data to encrypt
+/// - `domain`: Application-specific domain separator
+#[frb(sync)]
+pub fn xhpke_seal_stream(
+    public_key: &XhpkePublicKey,
+    plaintext: Vec<u8>,
+    domain: Vec<u8>,
+) -> Result<Vec<u8>, CryptoError> {
+    let (payload_key, mut sealed) = seal_stream_key(public_key, &domain)?;
+
+    let mut writer = darkbio_crypto::stream::Stream::encrypt(payload_key, &mut sealed);
+    writer.write_all(&plaintext)?;
+    writer.finish()?;
+
+    Ok(sealed)
+}
+
+/// Decrypts a message produced by `xhpke_seal_stream`.
+///
+/// - `secret_key`: The recipient's secret key
+/// - `sealed`: The header and STREAM ciphertext to decrypt
+/// - `domain`: Application-specific domain separator
+#[frb(sync)]
+pub fn xhpke_open_stream(
+    secret_key: &XhpkeSecretKey,
+    sealed: Vec<u8>,
+    domain: Vec<u8>,
+) -> Result<Vec<u8>, CryptoError> {
+    if sealed.len() < STREAM_HEADER_SIZE {
+        return Err(CryptoError::InvalidEncoding {
+            details: "sealed stream too short".to_string(),
+        });
+    }
+    let (header, ciphertext) = sealed.split_at(STREAM_HEADER_SIZE);
+    let payload_key = open_stream_key(secret_key, header, &domain)?;
+
+    let mut reader = darkbio_crypto::stream::Stream::decrypt(payload_key, ciphertext);
+
+    let mut plaintext = Vec::new();
+    reader.read_to_end(&mut plaintext)?;
+
+    Ok(plaintext)
+}
+
+/// Creates an incremental encryptor to a public key, using a fresh random
+/// STREAM payload key sealed with xHPKE.
+///
+/// Returns the encryptor and the 1168-byte header that must be transmitted
+/// before the ciphertext it produces.
+#[frb(sync)]
+pub fn xhpke_new_stream_encryptor(
+    public_key: &XhpkePublicKey,
+    domain: Vec<u8>,
+) -> Result<(StreamEncryptor, Vec<u8>), CryptoError> {
+    let (payload_key, header) = seal_stream_key(public_key, &domain)?;
+    Ok((StreamEncryptor::from_payload_key(payload_key), header))
+}
+
+/// Creates an incremental decryptor from the 1168-byte header produced by
+/// `xhpke_new_stream_encryptor` or `xhpke_seal_stream`.
+#[frb(sync)]
+pub fn xhpke_new_stream_decryptor(
+    secret_key: &XhpkeSecretKey,
+    header: Vec<u8>,
+    domain: Vec<u8>,
+) -> Result<StreamDecryptor, CryptoError> {
+    let header_array: [u8; STREAM_HEADER_SIZE] = to_array(header)?;
+    let payload_key = open_stream_key(secret_key, &header_array, &domain)?;
+    Ok(StreamDecryptor::from_payload_key(payload_key))
+}
+
+/// Encrypts the file at `in_path` into `out_path` to a public key, in the
+/// same format as `xhpke_seal_stream`, without moving any data across the FFI
+/// boundary.
+///
+/// - `public_key`: The recipient's public key
+/// - `in_path`: Path of the plaintext file to encrypt
+/// - `out_path`: Path of the sealed file to create or overwrite
+/// - `domain`: Application-specific domain separator
+/// - `progress`: Sink receiving the number of plaintext bytes processed so far
+pub fn xhpke_seal_stream_file(
+    public_key: &XhpkePublicKey,
+    in_path: String,
+    out_path: String,
+    domain: Vec<u8>,
+    progress: StreamSink<u64>,
+) -> Result<(), CryptoError> {
+    let (payload_key, header) = seal_stream_key(public_key, &domain)?;
+    encrypt_file(payload_key, &header, &in_path, &out_path, |n| {
+        let _ = progress.add(n);
+    })
+}
+
+/// Decrypts a file produced by `xhpke_seal_stream_file` into `out_path`. The
+/// plaintext is only moved into place after the final chunk authenticates.
+///
+/// - `secret_key`: The recipient's secret key
+/// - `in_path`: Path of the sealed file to decrypt
+/// - `out_path`: Path of the plaintext file to create or overwrite
+/// - `domain`: Application-specific domain separator
+/// - `progress`: Sink receiving the number of plaintext bytes processed so far
+pub fn xhpke_open_stream_file(
+    secret_key: &XhpkeSecretKey,
+    in_path: String,
+    out_path: String,
+    domain: Vec<u8>,
+    progress: StreamSink<u64>,
+) -> Result<(), CryptoError> {
+    let mut input = BufReader::new(File::open(&in_path)?);
+
+    let mut header = [0u8; STREAM_HEADER_SIZE];
+    input.read_exact(&mut header)?;
+    let payload_key = open_stream_key(secret_key, &header, &domain)?;
+
+    decrypt_file(payload_key, input, &out_path, |n| {
+        let _ = progress.add(n);
+    })
+}
+
+/// Generates a random STREAM payload key and seals it to a public key,
+/// returning the key and the header carrying it.
+fn seal_stream_key(
+    public_key: &XhpkePublicKey,
+    domain: &[u8],
+) -> Result<(darkbio_crypto::stream::PayloadKey, Vec<u8>), CryptoError> {
+    let key = darkbio_crypto::rand::generate(32);
+    let (encap_key, sealed_key) =
+        public_key
+            .inner
+            .seal(&key, &[], domain)
+            .map_err(|e| CryptoError::EncryptionFailed {
+                details: e.to_string(),
+            })?;
+
+    let mut header = Vec::with_capacity(STREAM_HEADER_SIZE);
+    header.extend_from_slice(&encap_key);
+    header.extend_from_slice(&sealed_key);
+
+    let key_array: [u8; 32] = to_array(key)?;
+    Ok((darkbio_crypto::stream::PayloadKey(key_array.into()), header))
+}
+
+/// Opens the STREAM payload key carried in a sealed stream header.
+fn open_stream_key(
+    secret_key: &XhpkeSecretKey,
+    header: &[u8],
+    domain: &[u8],
+) -> Result<darkbio_crypto::stream::PayloadKey, CryptoError> {
+    let (encap_key, sealed_key) = header.split_at(darkbio_crypto::xhpke::ENCAP_KEY_SIZE);
+    let key = secret_key
+        .inner
+        .open(encap_key.try_into().unwrap(), sealed_key, &[], domain)
+        .map_err(|e| CryptoError::DecryptionFailed {
+            details: e.to_string(),
+        })?;
+
+    let key_array: [u8; 32] = to_array(key)?;
+    Ok(darkbio_crypto::stream::PayloadKey(key_array.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::x509::CertName;
+    use crate::api::xdsa::XdsaSecretKey;
+
+    #[test]
+    fn test_seal_stream_roundtrip() {
+        let secret = XhpkeSecretKey::generate();
+        let plaintext: Vec<u8> = (0..100_000).map(|i| i as u8).collect();
+
+        let sealed =
+            xhpke_seal_stream(&secret.public_key(), plaintext.clone(), b"domain".to_vec()).unwrap();
+        assert_eq!(
+            xhpke_open_stream(&secret, sealed.clone(), b"domain".to_vec()).unwrap(),
+            plaintext
+        );
+
+        // The header is also accepted by the incremental decryptor
+        let (header, ciphertext) = sealed.split_at(STREAM_HEADER_SIZE);
+        let mut decryptor =
+            xhpke_new_stream_decryptor(&secret, header.to_vec(), b"domain".to_vec()).unwrap();
+        let mut decrypted = decryptor.push(ciphertext.to_vec()).unwrap();
+        decrypted.extend(decryptor.finish().unwrap());
+        assert_eq!(decrypted, plaintext);
+
+        assert!(matches!(
+            xhpke_open_stream(&secret, sealed.clone(), b"other".to_vec()),
+            Err(CryptoError::DecryptionFailed { .. })
+        ));
+        assert!(matches!(
+            xhpke_open_stream(
+                &XhpkeSecretKey::generate(),
+                sealed.clone(),
+                b"domain".to_vec()
+            ),
+            Err(CryptoError::DecryptionFailed { .. })
+        ));
+        assert!(matches!(
+            xhpke_open_stream(
+                &secret,
+                sealed[..STREAM_HEADER_SIZE - 1].to_vec(),
+                b"domain".to_vec()
+            ),
+            Err(CryptoError::InvalidEncoding { .. })
+        ));
+        assert!(matches!(
+            xhpke_open_stream(
+                &secret,
+                sealed[..sealed.len() - 1].to_vec(),
+                b"domain".to_vec()
+            ),
+            Err(CryptoError::DecryptionFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_stream_encryptor_roundtrip() {
+        let secret = XhpkeSecretKey::generate();
+
+        let (mut encryptor, header) =
+            xhpke_new_stream_encryptor(&secret.public_key(), b"domain".to_vec()).unwrap();
+        assert_eq!(header.len(), STREAM_HEADER_SIZE);
+
+        let mut sealed = header;
+        sealed.extend(encryptor.push(b"hello ".to_vec()).unwrap());
+        sealed.extend(encryptor.push(b"world".to_vec()).unwrap());
+        sealed.extend(encryptor.finish().unwrap());
+
+        assert_eq!(
+            xhpke_open_stream(&secret, sealed, b"domain".to_vec()).unwrap(),
+            b"hello world"
+        );
+    }
+
+    #[test]
+    fn test_seal_multi_roundtrip() {
+        let alice = XhpkeSecretKey::generate();
+        let bob = XhpkeSecretKey::generate();
+        let recipients = vec![
+            RustAutoOpaque::new(alice.public_key()),
+            RustAutoOpaque::new(bob.public_key()),
+        ];
+
+        let sealed = xhpke_seal_multi(
+            recipients,
+            b"message".to_vec(),
+            b"aad".to_vec(),
+            b"domain".to_vec(),
+        )
+        .unwrap();
+
+        for secret in [&alice, &bob] {
+            assert_eq!(
+                secret
+                    .open_multi(sealed.clone(), b"aad".to_vec(), b"domain".to_vec())
+                    .unwrap(),
+                b"message"
+            );
+        }
+        assert!(matches!(
+            alice.open_multi(sealed.clone(), b"other".to_vec(), b"domain".to_vec()),
+            Err(CryptoError::DecryptionFailed { .. })
+        ));
+        assert!(matches!(
+            XhpkeSecretKey::generate().open_multi(sealed, b"aad".to_vec(), b"domain".to_vec()),
+            Err(CryptoError::KeyMismatch { .. })
+        ));
+        assert!(matches!(
+            xhpke_seal_multi(vec![], b"message".to_vec(), vec![], vec![]),
+            Err(CryptoError::EncryptionFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_from_cert_validity() {
+        let issuer = XdsaSecretKey::generate();
+        let subject = XhpkeSecretKey::generate().public_key();
+        let template = || {
+            CertTemplate::leaf(
+                CertName::cn("subject".to_string()),
+                CertName::cn("issuer".to_string()),
+                1_000_000,
+                2_000_000,
+            )
+        };
+        let der = subject.to_cert_der(&issuer, template()).unwrap();
+        let pem = subject.to_cert_pem(&issuer, template()).unwrap();
+
+        let from_der = |now, leeway| {
+            XhpkePublicKey::from_cert_der(der.clone(), &issuer.public_key(), now, leeway)
+        };
+        let from_pem = |now, leeway| {
+            XhpkePublicKey::from_cert_pem(pem.clone(), &issuer.public_key(), now, leeway)
+        };
+        let (key, not_before, not_after) = from_der(None, 0).unwrap();
+        assert_eq!(key.to_bytes(), subject.to_bytes());
+        assert_eq!((not_before, not_after), (1_000_000, 2_000_000));
+
+        for check in [&from_der as &dyn Fn(_, _) -> _, &from_pem] {
+            assert!(check(Some(1_000_000), 0).is_ok());
+            assert!(check(Some(2_000_000), 0).is_ok());
+            assert!(matches!(
+                check(Some(999_990), 0),
+                Err(CryptoError::NotYetValid {
+                    nbf: 1_000_000,
+                    now: 999_990
+                })
+            ));
+            assert!(matches!(
+                check(Some(2_000_010), 0),
+                Err(CryptoError::Expired {
+                    exp: 2_000_000,
+                    now: 2_000_010
+                })
+            ));
+
+            // Leeway relaxes both ends of the validity period
+            assert!(check(Some(999_990), 10).is_ok());
+            assert!(check(Some(2_000_010), 10).is_ok());
+            assert!(matches!(
+                check(Some(999_989), 10),
+                Err(CryptoError::NotYetValid { .. })
+            ));
+            assert!(matches!(
+                check(Some(2_000_011), 10),
+                Err(CryptoError::Expired { .. })
+            ));
+        }
+
+        // The signature is checked regardless of the validity period
+        let other = XdsaSecretKey::generate();
+        assert!(matches!(
+            XhpkePublicKey::from_cert_der(der, &other.public_key(), None, 0),
+            Err(CryptoError::SignatureMismatch { .. })
+        ));
+    }
+}
@@ -25,7 +25,10 @@
 
 // Section: imports
 
+use crate::api::cddl::*;
+use crate::api::cwt::*;
 use crate::api::rsa::*;
+use crate::api::stream::*;
 use crate::api::xdsa::*;
 use crate::api::xhpke::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
@@ -40,7 +43,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1684258094;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1906905369;
 
 // Section: executor
 
@@ -48,6 +51,214 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__argon2__Argon2Params_recommended_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Argon2Params_recommended",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::argon2::Argon2Params::recommended())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cddl__CddlSchema_compile_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CddlSchema_compile",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cddl::CddlSchema::compile(api_source)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cddl__CddlSchema_validate_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CddlSchema_validate",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>,
+            >>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_rule = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    crate::api::cddl::CddlSchema::validate(&*api_that_guard, api_data, api_rule)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__x509__CertName_cn_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CertName_cn",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_common_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::x509::CertName::cn(api_common_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__x509__CertTemplate_leaf_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CertTemplate_leaf",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subject = <crate::api::x509::CertName>::sse_decode(&mut deserializer);
+            let api_issuer = <crate::api::x509::CertName>::sse_decode(&mut deserializer);
+            let api_not_before = <u64>::sse_decode(&mut deserializer);
+            let api_not_after = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::x509::CertTemplate::leaf(
+                    api_subject,
+                    api_issuer,
+                    api_not_before,
+                    api_not_after,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__CwtValidation_at_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CwtValidation_at",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_now = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::cwt::CwtValidation::at(api_now))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__rsa__RsaFingerprint_from_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -71,7 +282,7 @@ fn wire__crate__api__rsa__RsaFingerprint_from_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let output_ok = crate::api::rsa::RsaFingerprint::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
@@ -197,7 +408,7 @@ fn wire__crate__api__rsa__RsaPublicKey_from_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let output_ok = crate::api::rsa::RsaPublicKey::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
@@ -227,7 +438,7 @@ fn wire__crate__api__rsa__RsaPublicKey_from_der_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let output_ok = crate::api::rsa::RsaPublicKey::from_der(api_der)?;
                 Ok(output_ok)
             })())
@@ -257,7 +468,7 @@ fn wire__crate__api__rsa__RsaPublicKey_from_pem_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pem = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let output_ok = crate::api::rsa::RsaPublicKey::from_pem(api_pem)?;
                 Ok(output_ok)
             })())
@@ -434,7 +645,7 @@ fn wire__crate__api__rsa__RsaPublicKey_verify_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let mut api_that_guard = None;
                 let mut api_signature_guard = None;
                 let decode_indices_ =
@@ -538,7 +749,7 @@ fn wire__crate__api__rsa__RsaSecretKey_from_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let output_ok = crate::api::rsa::RsaSecretKey::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
@@ -568,7 +779,7 @@ fn wire__crate__api__rsa__RsaSecretKey_from_der_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let output_ok = crate::api::rsa::RsaSecretKey::from_der(api_der)?;
                 Ok(output_ok)
             })())
@@ -598,7 +809,7 @@ fn wire__crate__api__rsa__RsaSecretKey_from_pem_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pem = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let output_ok = crate::api::rsa::RsaSecretKey::from_pem(api_pem)?;
                 Ok(output_ok)
             })())
@@ -896,7 +1107,7 @@ fn wire__crate__api__rsa__RsaSignature_from_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let output_ok = crate::api::rsa::RsaSignature::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
@@ -950,14 +1161,14 @@ fn wire__crate__api__rsa__RsaSignature_to_bytes_impl(
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaFingerprint_from_bytes_impl(
+fn wire__crate__api__cwt__StatusList_bits_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaFingerprint_from_bytes",
+            debug_name: "StatusList_bits",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -971,23 +1182,40 @@ fn wire__crate__api__xdsa__XdsaFingerprint_from_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xdsa::XdsaFingerprint::from_bytes(api_bytes)?;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::cwt::StatusList::bits(&*api_that_guard))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaFingerprint_to_bytes_impl(
+fn wire__crate__api__cwt__StatusList_get_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaFingerprint_to_bytes",
+            debug_name: "StatusList_get",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1002,10 +1230,11 @@ fn wire__crate__api__xdsa__XdsaFingerprint_to_bytes_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>,
             >>::sse_decode(&mut deserializer);
+            let api_idx = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1020,22 +1249,20 @@ fn wire__crate__api__xdsa__XdsaFingerprint_to_bytes_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaFingerprint::to_bytes(
-                    &*api_that_guard,
-                ))?;
+                let output_ok = crate::api::cwt::StatusList::get(&*api_that_guard, api_idx)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_fingerprint_impl(
+fn wire__crate__api__cwt__StatusList_is_empty_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_fingerprint",
+            debug_name: "StatusList_is_empty",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1050,7 +1277,7 @@ fn wire__crate__api__xdsa__XdsaPublicKey_fingerprint_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
@@ -1068,22 +1295,21 @@ fn wire__crate__api__xdsa__XdsaPublicKey_fingerprint_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaPublicKey::fingerprint(
-                    &*api_that_guard,
-                ))?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::cwt::StatusList::is_empty(&*api_that_guard))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_from_bytes_impl(
+fn wire__crate__api__cwt__StatusList_issue_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_from_bytes",
+            debug_name: "StatusList_issue",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1097,23 +1323,61 @@ fn wire__crate__api__xdsa__XdsaPublicKey_from_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>,
+            >>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_iat = <u64>::sse_decode(&mut deserializer);
+            let api_exp = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_ttl = <Option<u64>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xdsa::XdsaPublicKey::from_bytes(api_bytes)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
+                let mut api_signer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            1,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        1 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::cwt::StatusList::issue(
+                    &*api_that_guard,
+                    &*api_signer_guard,
+                    api_domain,
+                    api_iat,
+                    api_exp,
+                    api_ttl,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_from_cert_der_impl(
+fn wire__crate__api__cwt__StatusList_len_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_from_cert_der",
+            debug_name: "StatusList_len",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1127,43 +1391,40 @@ fn wire__crate__api__xdsa__XdsaPublicKey_from_cert_der_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_signer_guard = None;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            0,
-                            false,
+                            &api_that, 0, false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_signer_guard = api_signer_guard.unwrap();
+                let api_that_guard = api_that_guard.unwrap();
                 let output_ok =
-                    crate::api::xdsa::XdsaPublicKey::from_cert_der(api_der, &*api_signer_guard)?;
+                    Result::<_, ()>::Ok(crate::api::cwt::StatusList::len(&*api_that_guard))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_from_cert_pem_impl(
+fn wire__crate__api__cwt__StatusList_new_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_from_cert_pem",
+            debug_name: "StatusList_new",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1177,43 +1438,25 @@ fn wire__crate__api__xdsa__XdsaPublicKey_from_cert_pem_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pem = <String>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
-            >>::sse_decode(&mut deserializer);
+            let api_uri = <String>::sse_decode(&mut deserializer);
+            let api_bits = <u8>::sse_decode(&mut deserializer);
+            let api_len = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_signer_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            0,
-                            false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_signer_guard = api_signer_guard.unwrap();
-                let output_ok =
-                    crate::api::xdsa::XdsaPublicKey::from_cert_pem(api_pem, &*api_signer_guard)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cwt::StatusList::new(api_uri, api_bits, api_len)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_from_der_impl(
+fn wire__crate__api__cwt__StatusList_set_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_from_der",
+            debug_name: "StatusList_set",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1227,23 +1470,42 @@ fn wire__crate__api__xdsa__XdsaPublicKey_from_der_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>,
+            >>::sse_decode(&mut deserializer);
+            let api_idx = <usize>::sse_decode(&mut deserializer);
+            let api_status = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xdsa::XdsaPublicKey::from_der(api_der)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    crate::api::cwt::StatusList::set(&mut *api_that_guard, api_idx, api_status)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_from_pem_impl(
+fn wire__crate__api__cwt__StatusList_uri_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_from_pem",
+            debug_name: "StatusList_uri",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1257,23 +1519,40 @@ fn wire__crate__api__xdsa__XdsaPublicKey_from_pem_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pem = <String>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xdsa::XdsaPublicKey::from_pem(api_pem)?;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::cwt::StatusList::uri(&*api_that_guard))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_to_bytes_impl(
+fn wire__crate__api__cwt__StatusList_verify_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_to_bytes",
+            debug_name: "StatusList_verify",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1287,41 +1566,49 @@ fn wire__crate__api__xdsa__XdsaPublicKey_to_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
+            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_verifier = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_now = <Option<u64>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_verifier_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
+                            &api_verifier,
+                            0,
+                            false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => api_verifier_guard = Some(api_verifier.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaPublicKey::to_bytes(
-                    &*api_that_guard,
-                ))?;
+                let api_verifier_guard = api_verifier_guard.unwrap();
+                let output_ok = crate::api::cwt::StatusList::verify(
+                    api_token,
+                    &*api_verifier_guard,
+                    api_domain,
+                    api_now,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_to_cert_der_impl(
+fn wire__crate__api__stream__StreamDecryptor_finish_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_to_cert_der",
+            debug_name: "StreamDecryptor_finish",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1336,64 +1623,38 @@ fn wire__crate__api__xdsa__XdsaPublicKey_to_cert_der_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>,
             >>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_subject_name = <String>::sse_decode(&mut deserializer);
-            let api_issuer_name = <String>::sse_decode(&mut deserializer);
-            let api_not_before = <u64>::sse_decode(&mut deserializer);
-            let api_not_after = <u64>::sse_decode(&mut deserializer);
-            let api_is_ca = <bool>::sse_decode(&mut deserializer);
-            let api_path_len = <Option<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let mut api_that_guard = None;
-                let mut api_signer_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            1,
-                            false,
+                            &api_that, 0, true,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        1 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
                         _ => unreachable!(),
                     }
                 }
-                let api_that_guard = api_that_guard.unwrap();
-                let api_signer_guard = api_signer_guard.unwrap();
-                let output_ok = crate::api::xdsa::XdsaPublicKey::to_cert_der(
-                    &*api_that_guard,
-                    &*api_signer_guard,
-                    api_subject_name,
-                    api_issuer_name,
-                    api_not_before,
-                    api_not_after,
-                    api_is_ca,
-                    api_path_len,
-                )?;
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::api::stream::StreamDecryptor::finish(&mut *api_that_guard)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_to_cert_pem_impl(
+fn wire__crate__api__stream__StreamDecryptor_new_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_to_cert_pem",
+            debug_name: "StreamDecryptor_new",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1407,65 +1668,23 @@ fn wire__crate__api__xdsa__XdsaPublicKey_to_cert_pem_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_subject_name = <String>::sse_decode(&mut deserializer);
-            let api_issuer_name = <String>::sse_decode(&mut deserializer);
-            let api_not_before = <u64>::sse_decode(&mut deserializer);
-            let api_not_after = <u64>::sse_decode(&mut deserializer);
-            let api_is_ca = <bool>::sse_decode(&mut deserializer);
-            let api_path_len = <Option<u8>>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_that_guard = None;
-                let mut api_signer_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            1,
-                            false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        1 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let api_signer_guard = api_signer_guard.unwrap();
-                let output_ok = crate::api::xdsa::XdsaPublicKey::to_cert_pem(
-                    &*api_that_guard,
-                    &*api_signer_guard,
-                    api_subject_name,
-                    api_issuer_name,
-                    api_not_before,
-                    api_not_after,
-                    api_is_ca,
-                    api_path_len,
-                )?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::stream::StreamDecryptor::new(api_key)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_to_der_impl(
+fn wire__crate__api__stream__StreamDecryptor_push_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_to_der",
+            debug_name: "StreamDecryptor_push",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1480,39 +1699,40 @@ fn wire__crate__api__xdsa__XdsaPublicKey_to_der_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>,
             >>::sse_decode(&mut deserializer);
+            let api_chunk = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
+                            &api_that, 0, true,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
                         _ => unreachable!(),
                     }
                 }
-                let api_that_guard = api_that_guard.unwrap();
+                let mut api_that_guard = api_that_guard.unwrap();
                 let output_ok =
-                    Result::<_, ()>::Ok(crate::api::xdsa::XdsaPublicKey::to_der(&*api_that_guard))?;
+                    crate::api::stream::StreamDecryptor::push(&mut *api_that_guard, api_chunk)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_to_pem_impl(
+fn wire__crate__api__stream__StreamEncryptor_finish_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_to_pem",
+            debug_name: "StreamEncryptor_finish",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1527,39 +1747,38 @@ fn wire__crate__api__xdsa__XdsaPublicKey_to_pem_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
+                            &api_that, 0, true,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
                         _ => unreachable!(),
                     }
                 }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::xdsa::XdsaPublicKey::to_pem(&*api_that_guard))?;
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::api::stream::StreamEncryptor::finish(&mut *api_that_guard)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaPublicKey_verify_impl(
+fn wire__crate__api__stream__StreamEncryptor_new_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaPublicKey_verify",
+            debug_name: "StreamEncryptor_new",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1573,55 +1792,23 @@ fn wire__crate__api__xdsa__XdsaPublicKey_verify_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_message = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_signature = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>,
-            >>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_that_guard = None;
-                let mut api_signature_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signature,
-                            1,
-                            false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        1 => api_signature_guard = Some(api_signature.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let api_signature_guard = api_signature_guard.unwrap();
-                let output_ok = crate::api::xdsa::XdsaPublicKey::verify(
-                    &*api_that_guard,
-                    api_message,
-                    &*api_signature_guard,
-                )?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::stream::StreamEncryptor::new(api_key)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSecretKey_fingerprint_impl(
+fn wire__crate__api__stream__StreamEncryptor_push_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSecretKey_fingerprint",
+            debug_name: "StreamEncryptor_push",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1636,40 +1823,40 @@ fn wire__crate__api__xdsa__XdsaSecretKey_fingerprint_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>,
             >>::sse_decode(&mut deserializer);
+            let api_chunk = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
+                            &api_that, 0, true,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
                         _ => unreachable!(),
                     }
                 }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::fingerprint(
-                    &*api_that_guard,
-                ))?;
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    crate::api::stream::StreamEncryptor::push(&mut *api_that_guard, api_chunk)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSecretKey_from_bytes_impl(
+fn wire__crate__api__stream__StreamReader_from_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSecretKey_from_bytes",
+            debug_name: "StreamReader_from_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1683,23 +1870,25 @@ fn wire__crate__api__xdsa__XdsaSecretKey_from_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_ciphertext = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xdsa::XdsaSecretKey::from_bytes(api_bytes)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok =
+                    crate::api::stream::StreamReader::from_bytes(api_key, api_ciphertext)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSecretKey_from_der_impl(
+fn wire__crate__api__stream__StreamReader_is_empty_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSecretKey_from_der",
+            debug_name: "StreamReader_is_empty",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1713,23 +1902,41 @@ fn wire__crate__api__xdsa__XdsaSecretKey_from_der_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xdsa::XdsaSecretKey::from_der(api_der)?;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::stream::StreamReader::is_empty(
+                    &*api_that_guard,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSecretKey_from_pem_impl(
+fn wire__crate__api__stream__StreamReader_len_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSecretKey_from_pem",
+            debug_name: "StreamReader_len",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1743,23 +1950,40 @@ fn wire__crate__api__xdsa__XdsaSecretKey_from_pem_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pem = <String>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xdsa::XdsaSecretKey::from_pem(api_pem)?;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::stream::StreamReader::len(&*api_that_guard))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSecretKey_generate_impl(
+fn wire__crate__api__stream__StreamReader_open_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSecretKey_generate",
+            debug_name: "StreamReader_open",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1773,22 +1997,79 @@ fn wire__crate__api__xdsa__XdsaSecretKey_generate_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::generate())?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::stream::StreamReader::open(api_key, api_path)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSecretKey_public_key_impl(
+fn wire__crate__api__stream__StreamReader_read_at_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "StreamReader_read_at",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>,
+            >>::sse_decode(&mut deserializer);
+            let api_offset = <u64>::sse_decode(&mut deserializer);
+            let api_len = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, true,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let mut api_that_guard = api_that_guard.unwrap();
+                    let output_ok = crate::api::stream::StreamReader::read_at(
+                        &mut *api_that_guard,
+                        api_offset,
+                        api_len,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__xdsa__XdsaFingerprint_from_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSecretKey_public_key",
+            debug_name: "XdsaFingerprint_from_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1802,41 +2083,23 @@ fn wire__crate__api__xdsa__XdsaSecretKey_public_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
-            >>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::public_key(
-                    &*api_that_guard,
-                ))?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xdsa::XdsaFingerprint::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSecretKey_sign_impl(
+fn wire__crate__api__xdsa__XdsaFingerprint_to_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSecretKey_sign",
+            debug_name: "XdsaFingerprint_to_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1851,9 +2114,8 @@ fn wire__crate__api__xdsa__XdsaSecretKey_sign_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>,
             >>::sse_decode(&mut deserializer);
-            let api_message = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -1870,23 +2132,22 @@ fn wire__crate__api__xdsa__XdsaSecretKey_sign_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::sign(
+                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaFingerprint::to_bytes(
                     &*api_that_guard,
-                    api_message,
                 ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSecretKey_to_bytes_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_fingerprint_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSecretKey_to_bytes",
+            debug_name: "XdsaPublicKey_fingerprint",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1901,7 +2162,7 @@ fn wire__crate__api__xdsa__XdsaSecretKey_to_bytes_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
@@ -1919,7 +2180,7 @@ fn wire__crate__api__xdsa__XdsaSecretKey_to_bytes_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::to_bytes(
+                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaPublicKey::fingerprint(
                     &*api_that_guard,
                 ))?;
                 Ok(output_ok)
@@ -1927,14 +2188,14 @@ fn wire__crate__api__xdsa__XdsaSecretKey_to_bytes_impl(
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSecretKey_to_der_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_from_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSecretKey_to_der",
+            debug_name: "XdsaPublicKey_from_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1948,40 +2209,23 @@ fn wire__crate__api__xdsa__XdsaSecretKey_to_der_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
-            >>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::to_der(&*api_that_guard))?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xdsa::XdsaPublicKey::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSecretKey_to_pem_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_from_cert_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSecretKey_to_pem",
+            debug_name: "XdsaPublicKey_from_cert_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1995,40 +2239,49 @@ fn wire__crate__api__xdsa__XdsaSecretKey_to_pem_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_now = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_leeway_secs = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
+                            &api_signer,
+                            0,
+                            false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::to_pem(&*api_that_guard))?;
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::xdsa::XdsaPublicKey::from_cert_der(
+                    api_der,
+                    &*api_signer_guard,
+                    api_now,
+                    api_leeway_secs,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSignature_from_bytes_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_from_cert_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSignature_from_bytes",
+            debug_name: "XdsaPublicKey_from_cert_pem",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2042,23 +2295,49 @@ fn wire__crate__api__xdsa__XdsaSignature_from_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_pem = <String>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_now = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_leeway_secs = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xdsa::XdsaSignature::from_bytes(api_bytes)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::xdsa::XdsaPublicKey::from_cert_pem(
+                    api_pem,
+                    &*api_signer_guard,
+                    api_now,
+                    api_leeway_secs,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xdsa__XdsaSignature_to_bytes_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_from_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XdsaSignature_to_bytes",
+            debug_name: "XdsaPublicKey_from_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2072,41 +2351,23 @@ fn wire__crate__api__xdsa__XdsaSignature_to_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>,
-            >>::sse_decode(&mut deserializer);
+            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSignature::to_bytes(
-                    &*api_that_guard,
-                ))?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xdsa::XdsaPublicKey::from_der(api_der)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeFingerprint_from_bytes_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_from_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeFingerprint_from_bytes",
+            debug_name: "XdsaPublicKey_from_pem",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2120,23 +2381,23 @@ fn wire__crate__api__xhpke__XhpkeFingerprint_from_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_pem = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xhpke::XhpkeFingerprint::from_bytes(api_bytes)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xdsa::XdsaPublicKey::from_pem(api_pem)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeFingerprint_to_bytes_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_to_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeFingerprint_to_bytes",
+            debug_name: "XdsaPublicKey_to_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2151,7 +2412,7 @@ fn wire__crate__api__xhpke__XhpkeFingerprint_to_bytes_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
@@ -2169,22 +2430,22 @@ fn wire__crate__api__xhpke__XhpkeFingerprint_to_bytes_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::xhpke::XhpkeFingerprint::to_bytes(&*api_that_guard),
-                )?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaPublicKey::to_bytes(
+                    &*api_that_guard,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkePublicKey_fingerprint_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_to_cert_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_fingerprint",
+            debug_name: "XdsaPublicKey_to_cert_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2199,70 +2460,54 @@ fn wire__crate__api__xhpke__XhpkePublicKey_fingerprint_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
             >>::sse_decode(&mut deserializer);
+            let api_template = <crate::api::x509::CertTemplate>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let mut api_that_guard = None;
+                let mut api_signer_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
                             &api_that, 0, false,
                         ),
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            1,
+                            false,
+                        ),
                     ]);
                 for i in decode_indices_ {
                     match i {
                         0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        1 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::xhpke::XhpkePublicKey::fingerprint(&*api_that_guard),
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::xdsa::XdsaPublicKey::to_cert_der(
+                    &*api_that_guard,
+                    &*api_signer_guard,
+                    api_template,
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkePublicKey_from_bytes_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_from_bytes",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xhpke::XhpkePublicKey::from_bytes(api_bytes)?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__xhpke__XhpkePublicKey_from_cert_der_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_to_cert_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_from_cert_der",
+            debug_name: "XdsaPublicKey_to_cert_pem",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2276,43 +2521,55 @@ fn wire__crate__api__xhpke__XhpkePublicKey_from_cert_der_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
+            let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_template = <crate::api::x509::CertTemplate>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
                 let mut api_signer_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
                             &api_signer,
-                            0,
+                            1,
                             false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        1 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
+                let api_that_guard = api_that_guard.unwrap();
                 let api_signer_guard = api_signer_guard.unwrap();
-                let output_ok =
-                    crate::api::xhpke::XhpkePublicKey::from_cert_der(api_der, &*api_signer_guard)?;
+                let output_ok = crate::api::xdsa::XdsaPublicKey::to_cert_pem(
+                    &*api_that_guard,
+                    &*api_signer_guard,
+                    api_template,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkePublicKey_from_cert_pem_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_to_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_from_cert_pem",
+            debug_name: "XdsaPublicKey_to_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2326,103 +2583,40 @@ fn wire__crate__api__xhpke__XhpkePublicKey_from_cert_pem_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pem = <String>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
+            let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_signer_guard = None;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            0,
-                            false,
+                            &api_that, 0, false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_signer_guard = api_signer_guard.unwrap();
+                let api_that_guard = api_that_guard.unwrap();
                 let output_ok =
-                    crate::api::xhpke::XhpkePublicKey::from_cert_pem(api_pem, &*api_signer_guard)?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__xhpke__XhpkePublicKey_from_der_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_from_der",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xhpke::XhpkePublicKey::from_der(api_der)?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__xhpke__XhpkePublicKey_from_pem_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_from_pem",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pem = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xhpke::XhpkePublicKey::from_pem(api_pem)?;
+                    Result::<_, ()>::Ok(crate::api::xdsa::XdsaPublicKey::to_der(&*api_that_guard))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkePublicKey_new_sender_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_to_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_new_sender",
+            debug_name: "XdsaPublicKey_to_pem",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2437,11 +2631,10 @@ fn wire__crate__api__xhpke__XhpkePublicKey_new_sender_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
             >>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2457,20 +2650,20 @@ fn wire__crate__api__xhpke__XhpkePublicKey_new_sender_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok =
-                    crate::api::xhpke::XhpkePublicKey::new_sender(&*api_that_guard, api_domain)?;
+                    Result::<_, ()>::Ok(crate::api::xdsa::XdsaPublicKey::to_pem(&*api_that_guard))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkePublicKey_seal_impl(
+fn wire__crate__api__xdsa__XdsaPublicKey_verify_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_seal",
+            debug_name: "XdsaPublicKey_verify",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2485,46 +2678,54 @@ fn wire__crate__api__xhpke__XhpkePublicKey_seal_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_message = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_signature = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>,
             >>::sse_decode(&mut deserializer);
-            let api_msg_to_seal = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
                 let mut api_that_guard = None;
+                let mut api_signature_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
                             &api_that, 0, false,
                         ),
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signature,
+                            1,
+                            false,
+                        ),
                     ]);
                 for i in decode_indices_ {
                     match i {
                         0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        1 => api_signature_guard = Some(api_signature.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = crate::api::xhpke::XhpkePublicKey::seal(
+                let api_signature_guard = api_signature_guard.unwrap();
+                let output_ok = crate::api::xdsa::XdsaPublicKey::verify(
                     &*api_that_guard,
-                    api_msg_to_seal,
-                    api_msg_to_auth,
-                    api_domain,
+                    api_message,
+                    &*api_signature_guard,
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkePublicKey_to_bytes_impl(
+fn wire__crate__api__xdsa__XdsaSecretKey_fingerprint_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_to_bytes",
+            debug_name: "XdsaSecretKey_fingerprint",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2539,7 +2740,7 @@ fn wire__crate__api__xhpke__XhpkePublicKey_to_bytes_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
@@ -2557,7 +2758,7 @@ fn wire__crate__api__xhpke__XhpkePublicKey_to_bytes_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkePublicKey::to_bytes(
+                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::fingerprint(
                     &*api_that_guard,
                 ))?;
                 Ok(output_ok)
@@ -2565,14 +2766,14 @@ fn wire__crate__api__xhpke__XhpkePublicKey_to_bytes_impl(
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkePublicKey_to_cert_der_impl(
+fn wire__crate__api__xdsa__XdsaSecretKey_from_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_to_cert_der",
+            debug_name: "XdsaSecretKey_from_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2586,65 +2787,23 @@ fn wire__crate__api__xhpke__XhpkePublicKey_to_cert_der_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_subject_name = <String>::sse_decode(&mut deserializer);
-            let api_issuer_name = <String>::sse_decode(&mut deserializer);
-            let api_not_before = <u64>::sse_decode(&mut deserializer);
-            let api_not_after = <u64>::sse_decode(&mut deserializer);
-            let api_is_ca = <bool>::sse_decode(&mut deserializer);
-            let api_path_len = <Option<u8>>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_that_guard = None;
-                let mut api_signer_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            1,
-                            false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        1 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let api_signer_guard = api_signer_guard.unwrap();
-                let output_ok = crate::api::xhpke::XhpkePublicKey::to_cert_der(
-                    &*api_that_guard,
-                    &*api_signer_guard,
-                    api_subject_name,
-                    api_issuer_name,
-                    api_not_before,
-                    api_not_after,
-                    api_is_ca,
-                    api_path_len,
-                )?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xdsa::XdsaSecretKey::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkePublicKey_to_cert_pem_impl(
+fn wire__crate__api__xdsa__XdsaSecretKey_from_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_to_cert_pem",
+            debug_name: "XdsaSecretKey_from_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2658,65 +2817,82 @@ fn wire__crate__api__xhpke__XhpkePublicKey_to_cert_pem_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_subject_name = <String>::sse_decode(&mut deserializer);
-            let api_issuer_name = <String>::sse_decode(&mut deserializer);
-            let api_not_before = <u64>::sse_decode(&mut deserializer);
-            let api_not_after = <u64>::sse_decode(&mut deserializer);
-            let api_is_ca = <bool>::sse_decode(&mut deserializer);
-            let api_path_len = <Option<u8>>::sse_decode(&mut deserializer);
+            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_that_guard = None;
-                let mut api_signer_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            1,
-                            false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        1 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let api_signer_guard = api_signer_guard.unwrap();
-                let output_ok = crate::api::xhpke::XhpkePublicKey::to_cert_pem(
-                    &*api_that_guard,
-                    &*api_signer_guard,
-                    api_subject_name,
-                    api_issuer_name,
-                    api_not_before,
-                    api_not_after,
-                    api_is_ca,
-                    api_path_len,
-                )?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xdsa::XdsaSecretKey::from_der(api_der)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkePublicKey_to_der_impl(
+fn wire__crate__api__xdsa__XdsaSecretKey_from_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_to_der",
+            debug_name: "XdsaSecretKey_from_pem",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pem = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xdsa::XdsaSecretKey::from_pem(api_pem)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__xdsa__XdsaSecretKey_generate_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "XdsaSecretKey_generate",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::generate())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__xdsa__XdsaSecretKey_public_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "XdsaSecretKey_public_key",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2731,7 +2907,7 @@ fn wire__crate__api__xhpke__XhpkePublicKey_to_der_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
@@ -2749,7 +2925,7 @@ fn wire__crate__api__xhpke__XhpkePublicKey_to_der_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkePublicKey::to_der(
+                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::public_key(
                     &*api_that_guard,
                 ))?;
                 Ok(output_ok)
@@ -2757,14 +2933,14 @@ fn wire__crate__api__xhpke__XhpkePublicKey_to_der_impl(
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkePublicKey_to_pem_impl(
+fn wire__crate__api__xdsa__XdsaSecretKey_sign_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkePublicKey_to_pem",
+            debug_name: "XdsaSecretKey_sign",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2779,8 +2955,9 @@ fn wire__crate__api__xhpke__XhpkePublicKey_to_pem_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
             >>::sse_decode(&mut deserializer);
+            let api_message = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -2797,22 +2974,23 @@ fn wire__crate__api__xhpke__XhpkePublicKey_to_pem_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkePublicKey::to_pem(
+                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::sign(
                     &*api_that_guard,
+                    api_message,
                 ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeReceiver_open_impl(
+fn wire__crate__api__xdsa__XdsaSecretKey_to_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeReceiver_open",
+            debug_name: "XdsaSecretKey_to_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2827,44 +3005,40 @@ fn wire__crate__api__xhpke__XhpkeReceiver_open_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
             >>::sse_decode(&mut deserializer);
-            let api_msg_to_open = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
+                            &api_that, 0, false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = crate::api::xhpke::XhpkeReceiver::open(
-                    &mut *api_that_guard,
-                    api_msg_to_open,
-                    api_msg_to_auth,
-                )?;
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::to_bytes(
+                    &*api_that_guard,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSecretKey_fingerprint_impl(
+fn wire__crate__api__xdsa__XdsaSecretKey_to_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_fingerprint",
+            debug_name: "XdsaSecretKey_to_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2879,7 +3053,7 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_fingerprint_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
@@ -2897,22 +3071,21 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_fingerprint_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::xhpke::XhpkeSecretKey::fingerprint(&*api_that_guard),
-                )?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::to_der(&*api_that_guard))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSecretKey_from_bytes_impl(
+fn wire__crate__api__xdsa__XdsaSecretKey_to_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_from_bytes",
+            debug_name: "XdsaSecretKey_to_pem",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2926,23 +3099,40 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_from_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xhpke::XhpkeSecretKey::from_bytes(api_bytes)?;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::xdsa::XdsaSecretKey::to_pem(&*api_that_guard))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSecretKey_from_der_impl(
+fn wire__crate__api__xdsa__XdsaSignature_from_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_from_der",
+            debug_name: "XdsaSignature_from_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2956,23 +3146,23 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_from_der_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xhpke::XhpkeSecretKey::from_der(api_der)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xdsa::XdsaSignature::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSecretKey_from_pem_impl(
+fn wire__crate__api__xdsa__XdsaSignature_to_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_from_pem",
+            debug_name: "XdsaSignature_to_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2986,23 +3176,41 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_from_pem_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pem = <String>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::xhpke::XhpkeSecretKey::from_pem(api_pem)?;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::xdsa::XdsaSignature::to_bytes(
+                    &*api_that_guard,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSecretKey_generate_impl(
+fn wire__crate__api__xhpke__XhpkeFingerprint_from_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_generate",
+            debug_name: "XhpkeFingerprint_from_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3016,22 +3224,23 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_generate_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkeSecretKey::generate())?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xhpke::XhpkeFingerprint::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSecretKey_new_receiver_impl(
+fn wire__crate__api__xhpke__XhpkeFingerprint_to_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_new_receiver",
+            debug_name: "XhpkeFingerprint_to_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3046,12 +3255,10 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_new_receiver_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>,
             >>::sse_decode(&mut deserializer);
-            let api_encap_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -3066,24 +3273,22 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_new_receiver_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = crate::api::xhpke::XhpkeSecretKey::new_receiver(
-                    &*api_that_guard,
-                    api_encap_key,
-                    api_domain,
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::xhpke::XhpkeFingerprint::to_bytes(&*api_that_guard),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSecretKey_open_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_fingerprint_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_open",
+            debug_name: "XhpkePublicKey_fingerprint",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3098,14 +3303,10 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_open_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
             >>::sse_decode(&mut deserializer);
-            let api_session_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_open = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -3120,26 +3321,22 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_open_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = crate::api::xhpke::XhpkeSecretKey::open(
-                    &*api_that_guard,
-                    api_session_key,
-                    api_msg_to_open,
-                    api_msg_to_auth,
-                    api_domain,
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::xhpke::XhpkePublicKey::fingerprint(&*api_that_guard),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSecretKey_public_key_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_from_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_public_key",
+            debug_name: "XhpkePublicKey_from_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3153,89 +3350,23 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_public_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
-            >>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::xhpke::XhpkeSecretKey::public_key(&*api_that_guard),
-                )?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__xhpke__XhpkeSecretKey_to_bytes_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_to_bytes",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkeSecretKey::to_bytes(
-                    &*api_that_guard,
-                ))?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xhpke::XhpkePublicKey::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSecretKey_to_der_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_from_cert_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_to_der",
+            debug_name: "XhpkePublicKey_from_cert_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3249,41 +3380,49 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_to_der_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_now = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_leeway_secs = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
+                            &api_signer,
+                            0,
+                            false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkeSecretKey::to_der(
-                    &*api_that_guard,
-                ))?;
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::xhpke::XhpkePublicKey::from_cert_der(
+                    api_der,
+                    &*api_signer_guard,
+                    api_now,
+                    api_leeway_secs,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSecretKey_to_pem_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_from_cert_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSecretKey_to_pem",
+            debug_name: "XhpkePublicKey_from_cert_pem",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3297,41 +3436,49 @@ fn wire__crate__api__xhpke__XhpkeSecretKey_to_pem_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            let api_pem = <String>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_now = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_leeway_secs = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
+                            &api_signer,
+                            0,
+                            false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkeSecretKey::to_pem(
-                    &*api_that_guard,
-                ))?;
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::xhpke::XhpkePublicKey::from_cert_pem(
+                    api_pem,
+                    &*api_signer_guard,
+                    api_now,
+                    api_leeway_secs,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhpke__XhpkeSender_seal_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_from_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "XhpkeSender_seal",
+            debug_name: "XhpkePublicKey_from_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3345,45 +3492,23 @@ fn wire__crate__api__xhpke__XhpkeSender_seal_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>,
-            >>::sse_decode(&mut deserializer);
-            let api_msg_to_seal = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = crate::api::xhpke::XhpkeSender::seal(
-                    &mut *api_that_guard,
-                    api_msg_to_seal,
-                    api_msg_to_auth,
-                )?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xhpke::XhpkePublicKey::from_der(api_der)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__argon2__argon2_key_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_from_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "argon2_key",
+            debug_name: "XhpkePublicKey_from_pem",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3397,35 +3522,23 @@ fn wire__crate__api__argon2__argon2_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_password = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_salt = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_time = <u32>::sse_decode(&mut deserializer);
-            let api_memory = <u32>::sse_decode(&mut deserializer);
-            let api_threads = <u32>::sse_decode(&mut deserializer);
-            let api_key_length = <usize>::sse_decode(&mut deserializer);
+            let api_pem = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::argon2::argon2_key(
-                    api_password,
-                    api_salt,
-                    api_time,
-                    api_memory,
-                    api_threads,
-                    api_key_length,
-                ))?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xhpke::XhpkePublicKey::from_pem(api_pem)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cbor__cbor_verify_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_new_sender_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cbor_verify",
+            debug_name: "XhpkePublicKey_new_sender",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3439,23 +3552,41 @@ fn wire__crate__api__cbor__cbor_verify_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::cbor::cbor_verify(api_data)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    crate::api::xhpke::XhpkePublicKey::new_sender(&*api_that_guard, api_domain)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cose__cose_decrypt_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_seal_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_decrypt",
+            debug_name: "XhpkePublicKey_seal",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3469,34 +3600,32 @@ fn wire__crate__api__cose__cose_decrypt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_msg_to_open = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_recipient = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_msg_to_seal = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
             let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_recipient_guard = None;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_recipient,
-                            0,
-                            false,
+                            &api_that, 0, false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_recipient_guard = api_recipient_guard.unwrap();
-                let output_ok = crate::api::cose::cose_decrypt(
-                    api_msg_to_open,
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::api::xhpke::XhpkePublicKey::seal(
+                    &*api_that_guard,
+                    api_msg_to_seal,
                     api_msg_to_auth,
-                    &*api_recipient_guard,
                     api_domain,
                 )?;
                 Ok(output_ok)
@@ -3504,14 +3633,14 @@ fn wire__crate__api__cose__cose_decrypt_impl(
         },
     )
 }
-fn wire__crate__api__cose__cose_encrypt_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_to_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_encrypt",
+            debug_name: "XhpkePublicKey_to_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3525,49 +3654,41 @@ fn wire__crate__api__cose__cose_encrypt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sign1 = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_recipient = <RustOpaqueMoi<
+            let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
             >>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_recipient_guard = None;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_recipient,
-                            0,
-                            false,
+                            &api_that, 0, false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_recipient_guard = api_recipient_guard.unwrap();
-                let output_ok = crate::api::cose::cose_encrypt(
-                    api_sign1,
-                    api_msg_to_auth,
-                    &*api_recipient_guard,
-                    api_domain,
-                )?;
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkePublicKey::to_bytes(
+                    &*api_that_guard,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cose__cose_open_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_to_cert_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_open",
+            debug_name: "XhpkePublicKey_to_cert_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3581,63 +3702,55 @@ fn wire__crate__api__cose__cose_open_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_msg_to_open = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_recipient = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
             >>::sse_decode(&mut deserializer);
-            let api_sender = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
             >>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_max_drift_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_template = <crate::api::x509::CertTemplate>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_recipient_guard = None;
-                let mut api_sender_guard = None;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
+                let mut api_signer_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_recipient,
-                            0,
-                            false,
+                            &api_that, 0, false,
                         ),
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_sender,
+                            &api_signer,
                             1,
                             false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
-                        1 => api_sender_guard = Some(api_sender.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        1 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_recipient_guard = api_recipient_guard.unwrap();
-                let api_sender_guard = api_sender_guard.unwrap();
-                let output_ok = crate::api::cose::cose_open(
-                    api_msg_to_open,
-                    api_msg_to_auth,
-                    &*api_recipient_guard,
-                    &*api_sender_guard,
-                    api_domain,
-                    api_max_drift_secs,
+                let api_that_guard = api_that_guard.unwrap();
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::xhpke::XhpkePublicKey::to_cert_der(
+                    &*api_that_guard,
+                    &*api_signer_guard,
+                    api_template,
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cose__cose_peek_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_to_cert_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_peek",
+            debug_name: "XhpkePublicKey_to_cert_pem",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3651,23 +3764,55 @@ fn wire__crate__api__cose__cose_peek_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_signature = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_template = <crate::api::x509::CertTemplate>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::cose::cose_peek(api_signature)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
+                let mut api_signer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            1,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        1 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::xhpke::XhpkePublicKey::to_cert_pem(
+                    &*api_that_guard,
+                    &*api_signer_guard,
+                    api_template,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cose__cose_recipient_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_to_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_recipient",
+            debug_name: "XhpkePublicKey_to_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3681,23 +3826,41 @@ fn wire__crate__api__cose__cose_recipient_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ciphertext = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::cose::cose_recipient(api_ciphertext)?;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkePublicKey::to_der(
+                    &*api_that_guard,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cose__cose_seal_impl(
+fn wire__crate__api__xhpke__XhpkePublicKey_to_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_seal",
+            debug_name: "XhpkePublicKey_to_pem",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3711,61 +3874,41 @@ fn wire__crate__api__cose__cose_seal_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_msg_to_seal = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_recipient = <RustOpaqueMoi<
+            let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
             >>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_signer_guard = None;
-                let mut api_recipient_guard = None;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            0,
-                            false,
-                        ),
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_recipient,
-                            1,
-                            false,
+                            &api_that, 0, false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
-                        1 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_signer_guard = api_signer_guard.unwrap();
-                let api_recipient_guard = api_recipient_guard.unwrap();
-                let output_ok = crate::api::cose::cose_seal(
-                    api_msg_to_seal,
-                    api_msg_to_auth,
-                    &*api_signer_guard,
-                    &*api_recipient_guard,
-                    api_domain,
-                )?;
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkePublicKey::to_pem(
+                    &*api_that_guard,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cose__cose_sign_impl(
+fn wire__crate__api__xhpke__XhpkeReceiver_open_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_sign",
+            debug_name: "XhpkeReceiver_open",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3779,49 +3922,45 @@ fn wire__crate__api__cose__cose_sign_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_msg_to_embed = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>,
             >>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_open = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_signer_guard = None;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            0,
-                            false,
+                            &api_that, 0, true,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
                         _ => unreachable!(),
                     }
                 }
-                let api_signer_guard = api_signer_guard.unwrap();
-                let output_ok = crate::api::cose::cose_sign(
-                    api_msg_to_embed,
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::api::xhpke::XhpkeReceiver::open(
+                    &mut *api_that_guard,
+                    api_msg_to_open,
                     api_msg_to_auth,
-                    &*api_signer_guard,
-                    api_domain,
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cose__cose_sign_detached_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_fingerprint_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_sign_detached",
+            debug_name: "XhpkeSecretKey_fingerprint",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3835,47 +3974,41 @@ fn wire__crate__api__cose__cose_sign_detached_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
             >>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_signer_guard = None;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            0,
-                            false,
+                            &api_that, 0, false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_signer_guard = api_signer_guard.unwrap();
-                let output_ok = crate::api::cose::cose_sign_detached(
-                    api_msg_to_auth,
-                    &*api_signer_guard,
-                    api_domain,
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::xhpke::XhpkeSecretKey::fingerprint(&*api_that_guard),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cose__cose_signer_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_from_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_signer",
+            debug_name: "XhpkeSecretKey_from_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3889,23 +4022,23 @@ fn wire__crate__api__cose__cose_signer_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_signature = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::cose::cose_signer(api_signature)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xhpke::XhpkeSecretKey::from_bytes(api_bytes)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cose__cose_verify_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_from_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_verify",
+            debug_name: "XhpkeSecretKey_from_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3919,51 +4052,23 @@ fn wire__crate__api__cose__cose_verify_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_msg_to_check = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_verifier = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_max_drift_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_der = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_verifier_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_verifier,
-                            0,
-                            false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_verifier_guard = Some(api_verifier.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_verifier_guard = api_verifier_guard.unwrap();
-                let output_ok = crate::api::cose::cose_verify(
-                    api_msg_to_check,
-                    api_msg_to_auth,
-                    &*api_verifier_guard,
-                    api_domain,
-                    api_max_drift_secs,
-                )?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xhpke::XhpkeSecretKey::from_der(api_der)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cose__cose_verify_detached_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_from_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cose_verify_detached",
+            debug_name: "XhpkeSecretKey_from_pem",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3977,51 +4082,23 @@ fn wire__crate__api__cose__cose_verify_detached_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_msg_to_check = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_verifier = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_max_drift_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_pem = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_verifier_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_verifier,
-                            0,
-                            false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_verifier_guard = Some(api_verifier.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_verifier_guard = api_verifier_guard.unwrap();
-                let output_ok = crate::api::cose::cose_verify_detached(
-                    api_msg_to_check,
-                    api_msg_to_auth,
-                    &*api_verifier_guard,
-                    api_domain,
-                    api_max_drift_secs,
-                )?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::xhpke::XhpkeSecretKey::from_pem(api_pem)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cwt__cwt_issue_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_generate_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cwt_issue",
+            debug_name: "XhpkeSecretKey_generate",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4035,44 +4112,22 @@ fn wire__crate__api__cwt__cwt_issue_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_claims_cbor = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_signer = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_signer_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_signer,
-                            0,
-                            false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_signer_guard = api_signer_guard.unwrap();
-                let output_ok =
-                    crate::api::cwt::cwt_issue(api_claims_cbor, &*api_signer_guard, api_domain)?;
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkeSecretKey::generate())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cwt__cwt_peek_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_new_receiver_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cwt_peek",
+            debug_name: "XhpkeSecretKey_new_receiver",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4086,23 +4141,45 @@ fn wire__crate__api__cwt__cwt_peek_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_encap_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::cwt::cwt_peek(api_token)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::api::xhpke::XhpkeSecretKey::new_receiver(
+                    &*api_that_guard,
+                    api_encap_key,
+                    api_domain,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cwt__cwt_signer_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_open_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cwt_signer",
+            debug_name: "XhpkeSecretKey_open",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4116,23 +4193,49 @@ fn wire__crate__api__cwt__cwt_signer_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_session_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_open = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::cwt::cwt_signer(api_token)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::api::xhpke::XhpkeSecretKey::open(
+                    &*api_that_guard,
+                    api_session_key,
+                    api_msg_to_open,
+                    api_msg_to_auth,
+                    api_domain,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cwt__cwt_verify_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_open_multi_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cwt_verify",
+            debug_name: "XhpkeSecretKey_open_multi",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4146,49 +4249,47 @@ fn wire__crate__api__cwt__cwt_verify_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_verifier = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
             >>::sse_decode(&mut deserializer);
+            let api_sealed = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
             let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_now = <Option<u64>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let mut api_verifier_guard = None;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                         flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_verifier,
-                            0,
-                            false,
+                            &api_that, 0, false,
                         ),
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_verifier_guard = Some(api_verifier.lockable_decode_sync_ref()),
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
                         _ => unreachable!(),
                     }
                 }
-                let api_verifier_guard = api_verifier_guard.unwrap();
-                let output_ok = crate::api::cwt::cwt_verify(
-                    api_token,
-                    &*api_verifier_guard,
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::api::xhpke::XhpkeSecretKey::open_multi(
+                    &*api_that_guard,
+                    api_sealed,
+                    api_msg_to_auth,
                     api_domain,
-                    api_now,
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__hkdf__hkdf_expand_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_public_key_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "hkdf_expand",
+            debug_name: "XhpkeSecretKey_public_key",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4202,25 +4303,41 @@ fn wire__crate__api__hkdf__hkdf_expand_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_prk = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_info = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_length = <usize>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::hkdf::hkdf_expand(api_prk, api_info, api_length)?;
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::xhpke::XhpkeSecretKey::public_key(&*api_that_guard),
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__hkdf__hkdf_extract_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_to_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "hkdf_extract",
+            debug_name: "XhpkeSecretKey_to_bytes",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4234,25 +4351,41 @@ fn wire__crate__api__hkdf__hkdf_extract_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_secret = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_salt = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::hkdf::hkdf_extract(api_secret, api_salt))?;
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkeSecretKey::to_bytes(
+                    &*api_that_guard,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__hkdf__hkdf_key_impl(
+fn wire__crate__api__xhpke__XhpkeSecretKey_to_der_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "hkdf_key",
+            debug_name: "XhpkeSecretKey_to_der",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4266,31 +4399,43 @@ fn wire__crate__api__hkdf__hkdf_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_secret = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_salt = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_info = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_length = <usize>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::hkdf::hkdf_key(
-                    api_secret, api_salt, api_info, api_length,
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkeSecretKey::to_der(
+                    &*api_that_guard,
                 ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__init_app_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__xhpke__XhpkeSecretKey_to_pem_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "XhpkeSecretKey_to_pem",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -4302,26 +4447,41 @@ fn wire__crate__api__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__rand__random_bytes_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::xhpke::XhpkeSecretKey::to_pem(
+                    &*api_that_guard,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__xhpke__XhpkeSender_seal_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "random_bytes",
+            debug_name: "XhpkeSender_seal",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4335,23 +4495,87 @@ fn wire__crate__api__rand__random_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_length = <usize>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>,
+            >>::sse_decode(&mut deserializer);
+            let api_msg_to_seal = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::api::xhpke::XhpkeSender::seal(
+                    &mut *api_that_guard,
+                    api_msg_to_seal,
+                    api_msg_to_auth,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__argon2__argon2_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "argon2_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_password = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_salt = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_time = <u32>::sse_decode(&mut deserializer);
+            let api_memory = <u32>::sse_decode(&mut deserializer);
+            let api_threads = <u32>::sse_decode(&mut deserializer);
+            let api_key_length = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::rand::random_bytes(api_length))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::argon2::argon2_key(
+                    api_password,
+                    api_salt,
+                    api_time,
+                    api_memory,
+                    api_threads,
+                    api_key_length,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__stream__stream_decrypt_impl(
+fn wire__crate__api__cbor__cbor_decode_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stream_decrypt",
+            debug_name: "cbor_decode",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4365,24 +4589,23 @@ fn wire__crate__api__stream__stream_decrypt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_ciphertext = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::stream::stream_decrypt(api_key, api_ciphertext)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cbor::cbor_decode(api_data)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__stream__stream_encrypt_impl(
+fn wire__crate__api__cbor__cbor_encode_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stream_encrypt",
+            debug_name: "cbor_encode",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4396,1032 +4619,6644 @@ fn wire__crate__api__stream__stream_encrypt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_plaintext = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_value = <crate::api::cbor::CborValue>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::stream::stream_encrypt(api_key, api_plaintext)?;
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cbor::cbor_encode(api_value)?;
                 Ok(output_ok)
             })())
         },
     )
 }
+fn wire__crate__api__cbor__cbor_from_diagnostic_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cbor_from_diagnostic",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cbor::cbor_from_diagnostic(api_text)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cbor__cbor_to_diagnostic_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cbor_to_diagnostic",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cbor::cbor_to_diagnostic(api_data)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cbor__cbor_validate_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cbor_validate",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_cddl = <String>::sse_decode(&mut deserializer);
+            let api_rule = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cbor::cbor_validate(api_data, api_cddl, api_rule)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cbor__cbor_verify_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cbor_verify",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cbor::cbor_verify(api_data)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_add_signature_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_add_signature",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_sign = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::cose::cose_add_signature(
+                    api_msg_to_sign,
+                    api_msg_to_auth,
+                    &*api_signer_guard,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_decrypt_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_decrypt",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_open = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_recipient = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_recipient_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_recipient,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_recipient_guard = api_recipient_guard.unwrap();
+                let output_ok = crate::api::cose::cose_decrypt(
+                    api_msg_to_open,
+                    api_msg_to_auth,
+                    &*api_recipient_guard,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_encrypt_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_encrypt",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sign1 = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_recipient = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_recipient_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_recipient,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_recipient_guard = api_recipient_guard.unwrap();
+                let output_ok = crate::api::cose::cose_encrypt(
+                    api_sign1,
+                    api_msg_to_auth,
+                    &*api_recipient_guard,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_inspect_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_inspect",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_signature = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cose::cose_inspect(api_signature)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_open_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_open",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_open = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_recipient = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_sender = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_max_drift_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_recipient_guard = None;
+                let mut api_sender_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_recipient,
+                            0,
+                            false,
+                        ),
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_sender,
+                            1,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
+                        1 => api_sender_guard = Some(api_sender.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_recipient_guard = api_recipient_guard.unwrap();
+                let api_sender_guard = api_sender_guard.unwrap();
+                let output_ok = crate::api::cose::cose_open(
+                    api_msg_to_open,
+                    api_msg_to_auth,
+                    &*api_recipient_guard,
+                    &*api_sender_guard,
+                    api_domain,
+                    api_max_drift_secs,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_open_multi_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_open_multi",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_open = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_recipient = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_sender = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_max_drift_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_recipient_guard = None;
+                let mut api_sender_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_recipient,
+                            0,
+                            false,
+                        ),
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_sender,
+                            1,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
+                        1 => api_sender_guard = Some(api_sender.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_recipient_guard = api_recipient_guard.unwrap();
+                let api_sender_guard = api_sender_guard.unwrap();
+                let output_ok = crate::api::cose::cose_open_multi(
+                    api_msg_to_open,
+                    api_msg_to_auth,
+                    &*api_recipient_guard,
+                    &*api_sender_guard,
+                    api_domain,
+                    api_max_drift_secs,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_peek_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_peek",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_signature = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cose::cose_peek(api_signature)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_recipient_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_recipient",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ciphertext = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cose::cose_recipient(api_ciphertext)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_recipients_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_recipients",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ciphertext = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cose::cose_recipients(api_ciphertext)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_seal_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_seal",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_seal = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_recipient = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
+                let mut api_recipient_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            0,
+                            false,
+                        ),
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_recipient,
+                            1,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        1 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_signer_guard = api_signer_guard.unwrap();
+                let api_recipient_guard = api_recipient_guard.unwrap();
+                let output_ok = crate::api::cose::cose_seal(
+                    api_msg_to_seal,
+                    api_msg_to_auth,
+                    &*api_signer_guard,
+                    &*api_recipient_guard,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_seal_multi_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_seal_multi",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_seal = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_recipients =
+                <Vec<RustAutoOpaqueMoi<XhpkePublicKey>>>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::cose::cose_seal_multi(
+                    api_msg_to_seal,
+                    api_msg_to_auth,
+                    &*api_signer_guard,
+                    api_recipients,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_sign_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_sign",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_embed = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::cose::cose_sign(
+                    api_msg_to_embed,
+                    api_msg_to_auth,
+                    &*api_signer_guard,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_sign_detached_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_sign_detached",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::cose::cose_sign_detached(
+                    api_msg_to_auth,
+                    &*api_signer_guard,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_sign_multi_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_sign_multi",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_embed = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_signers =
+                <Vec<RustAutoOpaqueMoi<XdsaSecretKey>>>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cose::cose_sign_multi(
+                    api_msg_to_embed,
+                    api_msg_to_auth,
+                    api_signers,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_signer_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_signer",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_signature = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cose::cose_signer(api_signature)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_signers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_signers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_signature = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cose::cose_signers(api_signature)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_verify_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_verify",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_check = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_verifier = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_max_drift_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_schema = <Option<RustAutoOpaqueMoi<CddlSchema>>>::sse_decode(&mut deserializer);
+            let api_rule = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_verifier_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_verifier,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_verifier_guard = Some(api_verifier.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_verifier_guard = api_verifier_guard.unwrap();
+                let output_ok = crate::api::cose::cose_verify(
+                    api_msg_to_check,
+                    api_msg_to_auth,
+                    &*api_verifier_guard,
+                    api_domain,
+                    api_max_drift_secs,
+                    api_schema,
+                    api_rule,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_verify_detached_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_verify_detached",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_check = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_verifier = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_max_drift_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_verifier_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_verifier,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_verifier_guard = Some(api_verifier.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_verifier_guard = api_verifier_guard.unwrap();
+                let output_ok = crate::api::cose::cose_verify_detached(
+                    api_msg_to_check,
+                    api_msg_to_auth,
+                    &*api_verifier_guard,
+                    api_domain,
+                    api_max_drift_secs,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_verify_info_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_verify_info",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_check = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_verifier = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_max_drift_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_verifier_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_verifier,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_verifier_guard = Some(api_verifier.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_verifier_guard = api_verifier_guard.unwrap();
+                let output_ok = crate::api::cose::cose_verify_info(
+                    api_msg_to_check,
+                    api_msg_to_auth,
+                    &*api_verifier_guard,
+                    api_domain,
+                    api_max_drift_secs,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cose__cose_verify_threshold_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cose_verify_threshold",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_msg_to_check = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_msg_to_auth = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_verifiers =
+                <Vec<RustAutoOpaqueMoi<XdsaPublicKey>>>::sse_decode(&mut deserializer);
+            let api_threshold = <usize>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_max_drift_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cose::cose_verify_threshold(
+                    api_msg_to_check,
+                    api_msg_to_auth,
+                    api_verifiers,
+                    api_threshold,
+                    api_domain,
+                    api_max_drift_secs,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_issue_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_issue",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_claims_cbor = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok =
+                    crate::api::cwt::cwt_issue(api_claims_cbor, &*api_signer_guard, api_domain)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_issue_claims_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_issue_claims",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_claims = <crate::api::cwt::CwtClaims>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok =
+                    crate::api::cwt::cwt_issue_claims(api_claims, &*api_signer_guard, api_domain)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_issue_sd_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_issue_sd",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_claims = <crate::api::cwt::CwtClaims>::sse_decode(&mut deserializer);
+            let api_disclosable = <Vec<i64>>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_signer_guard = api_signer_guard.unwrap();
+                let output_ok = crate::api::cwt::cwt_issue_sd(
+                    api_claims,
+                    api_disclosable,
+                    &*api_signer_guard,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_issue_sealed_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_issue_sealed",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_claims = <crate::api::cwt::CwtClaims>::sse_decode(&mut deserializer);
+            let api_signer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_recipient = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_signer_guard = None;
+                let mut api_recipient_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_signer,
+                            0,
+                            false,
+                        ),
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_recipient,
+                            1,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_signer_guard = Some(api_signer.lockable_decode_sync_ref()),
+                        1 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_signer_guard = api_signer_guard.unwrap();
+                let api_recipient_guard = api_recipient_guard.unwrap();
+                let output_ok = crate::api::cwt::cwt_issue_sealed(
+                    api_claims,
+                    &*api_signer_guard,
+                    &*api_recipient_guard,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_open_sealed_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_open_sealed",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_recipient = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_issuer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_validation = <crate::api::cwt::CwtValidation>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_recipient_guard = None;
+                let mut api_issuer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_recipient,
+                            0,
+                            false,
+                        ),
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_issuer,
+                            1,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_recipient_guard = Some(api_recipient.lockable_decode_sync_ref()),
+                        1 => api_issuer_guard = Some(api_issuer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_recipient_guard = api_recipient_guard.unwrap();
+                let api_issuer_guard = api_issuer_guard.unwrap();
+                let output_ok = crate::api::cwt::cwt_open_sealed(
+                    api_token,
+                    &*api_recipient_guard,
+                    &*api_issuer_guard,
+                    api_domain,
+                    api_validation,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_peek_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_peek",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cwt::cwt_peek(api_token)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_peek_claims_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_peek_claims",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cwt::cwt_peek_claims(api_token)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_present_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_present",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_holder = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_audience = <String>::sse_decode(&mut deserializer);
+            let api_nonce = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_holder_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_holder,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_holder_guard = Some(api_holder.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_holder_guard = api_holder_guard.unwrap();
+                let output_ok = crate::api::cwt::cwt_present(
+                    api_token,
+                    &*api_holder_guard,
+                    api_audience,
+                    api_nonce,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_present_sd_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_present_sd",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_disclosures = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_reveal = <Vec<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok =
+                    crate::api::cwt::cwt_present_sd(api_token, api_disclosures, api_reveal)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_signer_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_signer",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::cwt::cwt_signer(api_token)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_verify_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_verify",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_verifier = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_validation = <crate::api::cwt::CwtValidation>::sse_decode(&mut deserializer);
+            let api_status_list =
+                <Option<RustAutoOpaqueMoi<StatusList>>>::sse_decode(&mut deserializer);
+            let api_schema = <Option<RustAutoOpaqueMoi<CddlSchema>>>::sse_decode(&mut deserializer);
+            let api_rule = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_verifier_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_verifier,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_verifier_guard = Some(api_verifier.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_verifier_guard = api_verifier_guard.unwrap();
+                let output_ok = crate::api::cwt::cwt_verify(
+                    api_token,
+                    &*api_verifier_guard,
+                    api_domain,
+                    api_validation,
+                    api_status_list,
+                    api_schema,
+                    api_rule,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_verify_chain_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_verify_chain",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tokens = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_root = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_now = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_root_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_root, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_root_guard = Some(api_root.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_root_guard = api_root_guard.unwrap();
+                let output_ok = crate::api::cwt::cwt_verify_chain(
+                    api_tokens,
+                    &*api_root_guard,
+                    api_domain,
+                    api_now,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_verify_claims_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_verify_claims",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_verifier = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_validation = <crate::api::cwt::CwtValidation>::sse_decode(&mut deserializer);
+            let api_status_list =
+                <Option<RustAutoOpaqueMoi<StatusList>>>::sse_decode(&mut deserializer);
+            let api_schema = <Option<RustAutoOpaqueMoi<CddlSchema>>>::sse_decode(&mut deserializer);
+            let api_rule = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_verifier_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_verifier,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_verifier_guard = Some(api_verifier.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_verifier_guard = api_verifier_guard.unwrap();
+                let output_ok = crate::api::cwt::cwt_verify_claims(
+                    api_token,
+                    &*api_verifier_guard,
+                    api_domain,
+                    api_validation,
+                    api_status_list,
+                    api_schema,
+                    api_rule,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_verify_presentation_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_verify_presentation",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_presentation = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_issuer = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_audience = <String>::sse_decode(&mut deserializer);
+            let api_nonce = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_validation = <crate::api::cwt::CwtValidation>::sse_decode(&mut deserializer);
+            let api_max_drift_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_issuer_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_issuer,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_issuer_guard = Some(api_issuer.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_issuer_guard = api_issuer_guard.unwrap();
+                let output_ok = crate::api::cwt::cwt_verify_presentation(
+                    api_presentation,
+                    &*api_issuer_guard,
+                    api_audience,
+                    api_nonce,
+                    api_domain,
+                    api_validation,
+                    api_max_drift_secs,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__cwt__cwt_verify_sd_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cwt_verify_sd",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_presentation = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_verifier = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_validation = <crate::api::cwt::CwtValidation>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_verifier_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_verifier,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_verifier_guard = Some(api_verifier.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_verifier_guard = api_verifier_guard.unwrap();
+                let output_ok = crate::api::cwt::cwt_verify_sd(
+                    api_presentation,
+                    &*api_verifier_guard,
+                    api_domain,
+                    api_validation,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hkdf__hkdf_expand_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hkdf_expand",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_prk = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_info = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_length = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::hkdf::hkdf_expand(api_prk, api_info, api_length)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hkdf__hkdf_extract_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hkdf_extract",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_salt = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::hkdf::hkdf_extract(api_secret, api_salt))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hkdf__hkdf_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hkdf_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_salt = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_info = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_length = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::hkdf::hkdf_key(
+                    api_secret, api_salt, api_info, api_length,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__rand__random_bytes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "random_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_length = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::rand::random_bytes(api_length))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__stream__stream_decrypt_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_decrypt",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_ciphertext = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::stream::stream_decrypt(api_key, api_ciphertext)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__stream__stream_decrypt_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_decrypt_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_in_path = <String>::sse_decode(&mut deserializer);
+            let api_out_path = <String>::sse_decode(&mut deserializer);
+            let api_progress =
+                <StreamSink<u64, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                    let output_ok = crate::api::stream::stream_decrypt_file(
+                        api_key,
+                        api_in_path,
+                        api_out_path,
+                        api_progress,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__stream__stream_encrypt_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_encrypt",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_plaintext = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::stream::stream_encrypt(api_key, api_plaintext)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__stream__stream_encrypt_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_encrypt_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_in_path = <String>::sse_decode(&mut deserializer);
+            let api_out_path = <String>::sse_decode(&mut deserializer);
+            let api_progress =
+                <StreamSink<u64, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                    let output_ok = crate::api::stream::stream_encrypt_file(
+                        api_key,
+                        api_in_path,
+                        api_out_path,
+                        api_progress,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__stream__stream_open_with_password_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_open_with_password",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_password = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sealed = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_min_params = <crate::api::argon2::Argon2Params>::sse_decode(&mut deserializer);
+            let api_max_params = <crate::api::argon2::Argon2Params>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::stream::stream_open_with_password(
+                    api_password,
+                    api_sealed,
+                    api_min_params,
+                    api_max_params,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__stream__stream_seal_with_password_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_seal_with_password",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_password = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_plaintext = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_params = <crate::api::argon2::Argon2Params>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::stream::stream_seal_with_password(
+                    api_password,
+                    api_plaintext,
+                    api_params,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__x509__x509_parse_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "x509_parse",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_cert = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::x509::x509_parse(api_cert)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__x509__x509_verify_chain_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "x509_verify_chain",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_leaf = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_intermediates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_roots = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_now = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let output_ok = crate::api::x509::x509_verify_chain(
+                    api_leaf,
+                    api_intermediates,
+                    api_roots,
+                    api_now,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__xhpke__xhpke_new_stream_decryptor_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhpke_new_stream_decryptor",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret_key = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_header = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_secret_key_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_secret_key,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_secret_key_guard = Some(api_secret_key.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_secret_key_guard = api_secret_key_guard.unwrap();
+                let output_ok = crate::api::xhpke::xhpke_new_stream_decryptor(
+                    &*api_secret_key_guard,
+                    api_header,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__xhpke__xhpke_new_stream_encryptor_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhpke_new_stream_encryptor",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_public_key = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_public_key_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_public_key,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_public_key_guard = Some(api_public_key.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_public_key_guard = api_public_key_guard.unwrap();
+                let output_ok = crate::api::xhpke::xhpke_new_stream_encryptor(
+                    &*api_public_key_guard,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__xhpke__xhpke_open_stream_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhpke_open_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret_key = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_sealed = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_secret_key_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_secret_key,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_secret_key_guard = Some(api_secret_key.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_secret_key_guard = api_secret_key_guard.unwrap();
+                let output_ok = crate::api::xhpke::xhpke_open_stream(
+                    &*api_secret_key_guard,
+                    api_sealed,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__xhpke__xhpke_open_stream_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhpke_open_stream_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret_key = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_in_path = <String>::sse_decode(&mut deserializer);
+            let api_out_path = <String>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_progress =
+                <StreamSink<u64, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                    let mut api_secret_key_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_secret_key,
+                                0,
+                                false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => {
+                                api_secret_key_guard =
+                                    Some(api_secret_key.lockable_decode_sync_ref())
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_secret_key_guard = api_secret_key_guard.unwrap();
+                    let output_ok = crate::api::xhpke::xhpke_open_stream_file(
+                        &*api_secret_key_guard,
+                        api_in_path,
+                        api_out_path,
+                        api_domain,
+                        api_progress,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__xhpke__xhpke_seal_stream_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhpke_seal_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_public_key = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_plaintext = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                let mut api_public_key_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_public_key,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_public_key_guard = Some(api_public_key.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_public_key_guard = api_public_key_guard.unwrap();
+                let output_ok = crate::api::xhpke::xhpke_seal_stream(
+                    &*api_public_key_guard,
+                    api_plaintext,
+                    api_domain,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__xhpke__xhpke_seal_stream_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhpke_seal_stream_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_public_key = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_in_path = <String>::sse_decode(&mut deserializer);
+            let api_out_path = <String>::sse_decode(&mut deserializer);
+            let api_domain = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_progress =
+                <StreamSink<u64, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::CryptoError>((move || {
+                    let mut api_public_key_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_public_key,
+                                0,
+                                false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => {
+                                api_public_key_guard =
+                                    Some(api_public_key.lockable_decode_sync_ref())
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_public_key_guard = api_public_key_guard.unwrap();
+                    let output_ok = crate::api::xhpke::xhpke_seal_stream_file(
+                        &*api_public_key_guard,
+                        api_in_path,
+                        api_out_path,
+                        api_domain,
+                        api_progress,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaFingerprint>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaPublicKey>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSecretKey>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for RustAutoOpaqueMoi<CddlSchema> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_decode(inner);
+    }
+}
+
+impl SseDecode for RustAutoOpaqueMoi<StatusList> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_decode(inner);
+    }
+}
+
+impl SseDecode for RustAutoOpaqueMoi<XdsaPublicKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_decode(inner);
+    }
+}
+
+impl SseDecode for RustAutoOpaqueMoi<XdsaSecretKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_decode(inner);
+    }
+}
+
+impl SseDecode for RustAutoOpaqueMoi<XhpkePublicKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_decode(inner);
+    }
+}
+
+impl SseDecode for CddlSchema {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for RsaFingerprint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaFingerprint>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for RsaPublicKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaPublicKey>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for RsaSecretKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSecretKey>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for RsaSignature {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for StatusList {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for StreamDecryptor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for StreamEncryptor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for StreamReader {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for XdsaFingerprint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for XdsaPublicKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for XdsaSecretKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for XdsaSignature {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for XhpkeFingerprint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for XhpkePublicKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for XhpkeReceiver {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for XhpkeSecretKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for XhpkeSender {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaFingerprint>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaPublicKey>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSecretKey>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for StreamSink<u64, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::argon2::Argon2Params {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_time = <u32>::sse_decode(deserializer);
+        let mut var_memory = <u32>::sse_decode(deserializer);
+        let mut var_threads = <u32>::sse_decode(deserializer);
+        return crate::api::argon2::Argon2Params {
+            time: var_time,
+            memory: var_memory,
+            threads: var_threads,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for Box<crate::api::cwt::CwtClaims> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <crate::api::cwt::CwtClaims>::sse_decode(deserializer);
+        return Box::new(inner);
+    }
+}
+
+impl SseDecode for crate::api::cbor::CborMapEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <i64>::sse_decode(deserializer);
+        let mut var_value = <crate::api::cbor::CborValue>::sse_decode(deserializer);
+        return crate::api::cbor::CborMapEntry {
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cbor::CborValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::cbor::CborValue::Null;
+            }
+            1 => {
+                let mut var_field0 = <bool>::sse_decode(deserializer);
+                return crate::api::cbor::CborValue::Bool(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <i64>::sse_decode(deserializer);
+                return crate::api::cbor::CborValue::Int(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <u64>::sse_decode(deserializer);
+                return crate::api::cbor::CborValue::Uint(var_field0);
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::cbor::CborValue::Text(var_field0);
+            }
+            5 => {
+                let mut var_field0 = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::cbor::CborValue::Bytes(var_field0);
+            }
+            6 => {
+                let mut var_field0 = <Vec<crate::api::cbor::CborValue>>::sse_decode(deserializer);
+                return crate::api::cbor::CborValue::Array(var_field0);
+            }
+            7 => {
+                let mut var_field0 =
+                    <Vec<crate::api::cbor::CborMapEntry>>::sse_decode(deserializer);
+                return crate::api::cbor::CborValue::Map(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::x509::CertChain {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_leafKey = <crate::api::x509::CertPublicKey>::sse_decode(deserializer);
+        let mut var_path = <Vec<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::x509::CertChain {
+            leaf_key: var_leafKey,
+            path: var_path,
+        };
+    }
+}
+
+impl SseDecode for crate::api::x509::CertExtension {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_oid = <String>::sse_decode(deserializer);
+        let mut var_critical = <bool>::sse_decode(deserializer);
+        let mut var_value = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::x509::CertExtension {
+            oid: var_oid,
+            critical: var_critical,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::x509::CertInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_subject = <crate::api::x509::CertName>::sse_decode(deserializer);
+        let mut var_issuer = <crate::api::x509::CertName>::sse_decode(deserializer);
+        let mut var_serial = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_notBefore = <u64>::sse_decode(deserializer);
+        let mut var_notAfter = <u64>::sse_decode(deserializer);
+        let mut var_isCa = <bool>::sse_decode(deserializer);
+        let mut var_pathLen = <Option<u8>>::sse_decode(deserializer);
+        let mut var_dnsNames = <Vec<String>>::sse_decode(deserializer);
+        let mut var_uris = <Vec<String>>::sse_decode(deserializer);
+        let mut var_emails = <Vec<String>>::sse_decode(deserializer);
+        let mut var_keyUsage = <Vec<crate::api::x509::CertKeyUsage>>::sse_decode(deserializer);
+        let mut var_extendedKeyUsage = <Vec<String>>::sse_decode(deserializer);
+        let mut var_extensions = <Vec<crate::api::x509::CertExtension>>::sse_decode(deserializer);
+        let mut var_keyType = <crate::api::x509::CertKeyType>::sse_decode(deserializer);
+        let mut var_fingerprint = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_subjectKeyId = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_issuerKeyId = <Option<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::x509::CertInfo {
+            subject: var_subject,
+            issuer: var_issuer,
+            serial: var_serial,
+            not_before: var_notBefore,
+            not_after: var_notAfter,
+            is_ca: var_isCa,
+            path_len: var_pathLen,
+            dns_names: var_dnsNames,
+            uris: var_uris,
+            emails: var_emails,
+            key_usage: var_keyUsage,
+            extended_key_usage: var_extendedKeyUsage,
+            extensions: var_extensions,
+            key_type: var_keyType,
+            fingerprint: var_fingerprint,
+            subject_key_id: var_subjectKeyId,
+            issuer_key_id: var_issuerKeyId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::x509::CertKeyType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::x509::CertKeyType::Xdsa,
+            1 => crate::api::x509::CertKeyType::Xhpke,
+            _ => unreachable!("Invalid variant for CertKeyType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::x509::CertKeyUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::x509::CertKeyUsage::DigitalSignature,
+            1 => crate::api::x509::CertKeyUsage::NonRepudiation,
+            2 => crate::api::x509::CertKeyUsage::KeyEncipherment,
+            3 => crate::api::x509::CertKeyUsage::DataEncipherment,
+            4 => crate::api::x509::CertKeyUsage::KeyAgreement,
+            5 => crate::api::x509::CertKeyUsage::KeyCertSign,
+            6 => crate::api::x509::CertKeyUsage::CrlSign,
+            7 => crate::api::x509::CertKeyUsage::EncipherOnly,
+            8 => crate::api::x509::CertKeyUsage::DecipherOnly,
+            _ => unreachable!("Invalid variant for CertKeyUsage: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::x509::CertName {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_commonName = <Option<String>>::sse_decode(deserializer);
+        let mut var_organization = <Option<String>>::sse_decode(deserializer);
+        let mut var_organizationalUnit = <Option<String>>::sse_decode(deserializer);
+        let mut var_country = <Option<String>>::sse_decode(deserializer);
+        let mut var_province = <Option<String>>::sse_decode(deserializer);
+        let mut var_locality = <Option<String>>::sse_decode(deserializer);
+        let mut var_serialNumber = <Option<String>>::sse_decode(deserializer);
+        return crate::api::x509::CertName {
+            common_name: var_commonName,
+            organization: var_organization,
+            organizational_unit: var_organizationalUnit,
+            country: var_country,
+            province: var_province,
+            locality: var_locality,
+            serial_number: var_serialNumber,
+        };
+    }
+}
+
+impl SseDecode for crate::api::x509::CertPublicKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <XdsaPublicKey>::sse_decode(deserializer);
+                return crate::api::x509::CertPublicKey::Xdsa(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <XhpkePublicKey>::sse_decode(deserializer);
+                return crate::api::x509::CertPublicKey::Xhpke(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::x509::CertTemplate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_subject = <crate::api::x509::CertName>::sse_decode(deserializer);
+        let mut var_issuer = <crate::api::x509::CertName>::sse_decode(deserializer);
+        let mut var_notBefore = <u64>::sse_decode(deserializer);
+        let mut var_notAfter = <u64>::sse_decode(deserializer);
+        let mut var_isCa = <bool>::sse_decode(deserializer);
+        let mut var_pathLen = <Option<u8>>::sse_decode(deserializer);
+        let mut var_dnsNames = <Vec<String>>::sse_decode(deserializer);
+        let mut var_uris = <Vec<String>>::sse_decode(deserializer);
+        let mut var_emails = <Vec<String>>::sse_decode(deserializer);
+        let mut var_keyUsage = <Vec<crate::api::x509::CertKeyUsage>>::sse_decode(deserializer);
+        let mut var_extendedKeyUsage = <Vec<String>>::sse_decode(deserializer);
+        let mut var_extensions = <Vec<crate::api::x509::CertExtension>>::sse_decode(deserializer);
+        return crate::api::x509::CertTemplate {
+            subject: var_subject,
+            issuer: var_issuer,
+            not_before: var_notBefore,
+            not_after: var_notAfter,
+            is_ca: var_isCa,
+            path_len: var_pathLen,
+            dns_names: var_dnsNames,
+            uris: var_uris,
+            emails: var_emails,
+            key_usage: var_keyUsage,
+            extended_key_usage: var_extendedKeyUsage,
+            extensions: var_extensions,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cose::CoseSign1Info {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_payload = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_signer = <XdsaFingerprint>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        let mut var_algorithm = <i64>::sse_decode(deserializer);
+        let mut var_domain = <Option<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::cose::CoseSign1Info {
+            payload: var_payload,
+            signer: var_signer,
+            timestamp: var_timestamp,
+            algorithm: var_algorithm,
+            domain: var_domain,
+        };
+    }
+}
+
+impl SseDecode for crate::api::error::CryptoError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_expected = <usize>::sse_decode(deserializer);
+                let mut var_got = <usize>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::InvalidLength {
+                    expected: var_expected,
+                    got: var_got,
+                };
+            }
+            1 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::InvalidEncoding {
+                    details: var_details,
+                };
+            }
+            2 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::InvalidArgument {
+                    details: var_details,
+                };
+            }
+            3 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::CborInvalid {
+                    details: var_details,
+                };
+            }
+            4 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::SignatureMismatch {
+                    details: var_details,
+                };
+            }
+            5 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::KeyMismatch {
+                    details: var_details,
+                };
+            }
+            6 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::EncryptionFailed {
+                    details: var_details,
+                };
+            }
+            7 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::DecryptionFailed {
+                    details: var_details,
+                };
+            }
+            8 => {
+                let mut var_exp = <u64>::sse_decode(deserializer);
+                let mut var_now = <u64>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::Expired {
+                    exp: var_exp,
+                    now: var_now,
+                };
+            }
+            9 => {
+                let mut var_nbf = <u64>::sse_decode(deserializer);
+                let mut var_now = <u64>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::NotYetValid {
+                    nbf: var_nbf,
+                    now: var_now,
+                };
+            }
+            10 => {
+                let mut var_drift = <u64>::sse_decode(deserializer);
+                let mut var_maxDrift = <u64>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::StaleSignature {
+                    drift: var_drift,
+                    max_drift: var_maxDrift,
+                };
+            }
+            11 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::InvalidClaims {
+                    details: var_details,
+                };
+            }
+            12 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::InvalidCertificate {
+                    details: var_details,
+                };
+            }
+            13 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::Io {
+                    details: var_details,
+                };
+            }
+            14 => {
+                let mut var_key = <i64>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::MissingClaim { key: var_key };
+            }
+            15 => {
+                let mut var_expected = <String>::sse_decode(deserializer);
+                let mut var_got = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::IssuerMismatch {
+                    expected: var_expected,
+                    got: var_got,
+                };
+            }
+            16 => {
+                let mut var_got = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::AudienceMismatch { got: var_got };
+            }
+            17 => {
+                let mut var_iat = <u64>::sse_decode(deserializer);
+                let mut var_maxAge = <u64>::sse_decode(deserializer);
+                let mut var_now = <u64>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::TokenTooOld {
+                    iat: var_iat,
+                    max_age: var_maxAge,
+                    now: var_now,
+                };
+            }
+            18 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::ConfirmKeyMismatch {
+                    details: var_details,
+                };
+            }
+            19 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::NonceMismatch {
+                    details: var_details,
+                };
+            }
+            20 => {
+                let mut var_idx = <u64>::sse_decode(deserializer);
+                let mut var_status = <u8>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::Revoked {
+                    idx: var_idx,
+                    status: var_status,
+                };
+            }
+            21 => {
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::InvalidSchema {
+                    details: var_details,
+                };
+            }
+            22 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                let mut var_details = <String>::sse_decode(deserializer);
+                return crate::api::error::CryptoError::SchemaMismatch {
+                    path: var_path,
+                    details: var_details,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::cwt::CwtClaims {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_iss = <Option<String>>::sse_decode(deserializer);
+        let mut var_sub = <Option<String>>::sse_decode(deserializer);
+        let mut var_aud = <Option<String>>::sse_decode(deserializer);
+        let mut var_exp = <Option<u64>>::sse_decode(deserializer);
+        let mut var_nbf = <Option<u64>>::sse_decode(deserializer);
+        let mut var_iat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_cti = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_cnf = <Option<crate::api::cwt::CwtConfirmKey>>::sse_decode(deserializer);
+        let mut var_eatNonce = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_ueid = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_oemid = <Option<crate::api::cwt::EatOemid>>::sse_decode(deserializer);
+        let mut var_hwModel = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_hwVersion = <Option<String>>::sse_decode(deserializer);
+        let mut var_uptime = <Option<u64>>::sse_decode(deserializer);
+        let mut var_oemBoot = <Option<bool>>::sse_decode(deserializer);
+        let mut var_dbgstat = <Option<crate::api::cwt::EatDebugState>>::sse_decode(deserializer);
+        let mut var_submods = <Vec<crate::api::cwt::EatSubmodule>>::sse_decode(deserializer);
+        let mut var_bootCount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_bootSeed = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_swName = <Option<String>>::sse_decode(deserializer);
+        let mut var_swVersion = <Option<String>>::sse_decode(deserializer);
+        let mut var_measurements = <Vec<crate::api::cwt::EatMeasurement>>::sse_decode(deserializer);
+        let mut var_status = <Option<crate::api::cwt::CwtStatus>>::sse_decode(deserializer);
+        let mut var_custom = <Vec<crate::api::cwt::CwtCustomClaim>>::sse_decode(deserializer);
+        return crate::api::cwt::CwtClaims {
+            iss: var_iss,
+            sub: var_sub,
+            aud: var_aud,
+            exp: var_exp,
+            nbf: var_nbf,
+            iat: var_iat,
+            cti: var_cti,
+            cnf: var_cnf,
+            eat_nonce: var_eatNonce,
+            ueid: var_ueid,
+            oemid: var_oemid,
+            hw_model: var_hwModel,
+            hw_version: var_hwVersion,
+            uptime: var_uptime,
+            oem_boot: var_oemBoot,
+            dbgstat: var_dbgstat,
+            submods: var_submods,
+            boot_count: var_bootCount,
+            boot_seed: var_bootSeed,
+            sw_name: var_swName,
+            sw_version: var_swVersion,
+            measurements: var_measurements,
+            status: var_status,
+            custom: var_custom,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cwt::CwtConfirmKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <XdsaPublicKey>::sse_decode(deserializer);
+                return crate::api::cwt::CwtConfirmKey::Xdsa(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <XhpkePublicKey>::sse_decode(deserializer);
+                return crate::api::cwt::CwtConfirmKey::Xhpke(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::cwt::CwtConfirmKeyType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::cwt::CwtConfirmKeyType::Xdsa,
+            1 => crate::api::cwt::CwtConfirmKeyType::Xhpke,
+            _ => unreachable!("Invalid variant for CwtConfirmKeyType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::cwt::CwtCustomClaim {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <i64>::sse_decode(deserializer);
+        let mut var_value = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::cwt::CwtCustomClaim {
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cwt::CwtStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_idx = <u64>::sse_decode(deserializer);
+        let mut var_uri = <String>::sse_decode(deserializer);
+        return crate::api::cwt::CwtStatus {
+            idx: var_idx,
+            uri: var_uri,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cwt::CwtValidation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_now = <Option<u64>>::sse_decode(deserializer);
+        let mut var_leewaySecs = <u64>::sse_decode(deserializer);
+        let mut var_maxAgeSecs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_issuer = <Option<String>>::sse_decode(deserializer);
+        let mut var_audiences = <Vec<String>>::sse_decode(deserializer);
+        let mut var_requiredClaims = <Vec<i64>>::sse_decode(deserializer);
+        let mut var_confirmKey =
+            <Option<crate::api::cwt::CwtConfirmKeyType>>::sse_decode(deserializer);
+        let mut var_eatNonce = <Option<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::cwt::CwtValidation {
+            now: var_now,
+            leeway_secs: var_leewaySecs,
+            max_age_secs: var_maxAgeSecs,
+            issuer: var_issuer,
+            audiences: var_audiences,
+            required_claims: var_requiredClaims,
+            confirm_key: var_confirmKey,
+            eat_nonce: var_eatNonce,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cwt::EatDebugState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::cwt::EatDebugState::Enabled,
+            1 => crate::api::cwt::EatDebugState::Disabled,
+            2 => crate::api::cwt::EatDebugState::DisabledSinceBoot,
+            3 => crate::api::cwt::EatDebugState::DisabledPermanently,
+            4 => crate::api::cwt::EatDebugState::DisabledFullyPermanently,
+            _ => unreachable!("Invalid variant for EatDebugState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::cwt::EatMeasurement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_contentType = <u64>::sse_decode(deserializer);
+        let mut var_content = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::cwt::EatMeasurement {
+            content_type: var_contentType,
+            content: var_content,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cwt::EatOemid {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::cwt::EatOemid::Random(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::cwt::EatOemid::Ieee(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <u64>::sse_decode(deserializer);
+                return crate::api::cwt::EatOemid::Pen(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::cwt::EatSubmodule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_value = <crate::api::cwt::EatSubmoduleValue>::sse_decode(deserializer);
+        return crate::api::cwt::EatSubmodule {
+            name: var_name,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cwt::EatSubmoduleValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <Box<crate::api::cwt::CwtClaims>>::sse_decode(deserializer);
+                return crate::api::cwt::EatSubmoduleValue::Claims(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::cwt::EatSubmoduleValue::Embedded(var_field0);
+            }
+            2 => {
+                let mut var_alg = <i64>::sse_decode(deserializer);
+                let mut var_digest = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::cwt::EatSubmoduleValue::Detached {
+                    alg: var_alg,
+                    digest: var_digest,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for Vec<RustAutoOpaqueMoi<XdsaPublicKey>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<RustAutoOpaqueMoi<XdsaPublicKey>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<RustAutoOpaqueMoi<XdsaSecretKey>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<RustAutoOpaqueMoi<XdsaSecretKey>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<RustAutoOpaqueMoi<XhpkePublicKey>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<RustAutoOpaqueMoi<XhpkePublicKey>>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<XdsaFingerprint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<XdsaFingerprint>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<XhpkeFingerprint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<XhpkeFingerprint>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cbor::CborMapEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cbor::CborMapEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cbor::CborValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cbor::CborValue>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::x509::CertExtension> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::x509::CertExtension>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::x509::CertKeyUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::x509::CertKeyUsage>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cwt::CwtCustomClaim> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cwt::CwtCustomClaim>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cwt::EatMeasurement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cwt::EatMeasurement>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cwt::EatSubmodule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cwt::EatSubmodule>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<u8>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Option<RustAutoOpaqueMoi<CddlSchema>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<RustAutoOpaqueMoi<CddlSchema>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<RustAutoOpaqueMoi<StatusList>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<RustAutoOpaqueMoi<StatusList>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cwt::CwtConfirmKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cwt::CwtConfirmKey>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cwt::CwtConfirmKeyType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cwt::CwtConfirmKeyType>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cwt::CwtStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cwt::CwtStatus>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cwt::EatDebugState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cwt::EatDebugState>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cwt::EatOemid> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cwt::EatOemid>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u8>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for (StreamEncryptor, Vec<u8>) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <StreamEncryptor>::sse_decode(deserializer);
+        let mut var_field1 = <Vec<u8>>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (XdsaPublicKey, u64, u64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <XdsaPublicKey>::sse_decode(deserializer);
+        let mut var_field1 = <u64>::sse_decode(deserializer);
+        let mut var_field2 = <u64>::sse_decode(deserializer);
+        return (var_field0, var_field1, var_field2);
+    }
+}
+
+impl SseDecode for (XhpkePublicKey, u64, u64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <XhpkePublicKey>::sse_decode(deserializer);
+        let mut var_field1 = <u64>::sse_decode(deserializer);
+        let mut var_field2 = <u64>::sse_decode(deserializer);
+        return (var_field0, var_field1, var_field2);
+    }
+}
+
+impl SseDecode for (XhpkeSender, Vec<u8>) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <XhpkeSender>::sse_decode(deserializer);
+        let mut var_field1 = <Vec<u8>>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (Vec<u8>, Vec<u8>) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_field1 = <Vec<u8>>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::api::cwt::SdCwt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_token = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_disclosures = <Vec<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::cwt::SdCwt {
+            token: var_token,
+            disclosures: var_disclosures,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        48 => {
+            wire__crate__api__stream__StreamReader_read_at_impl(port, ptr, rust_vec_len, data_len)
+        }
+        149 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
+        152 => {
+            wire__crate__api__stream__stream_decrypt_file_impl(port, ptr, rust_vec_len, data_len)
+        }
+        154 => {
+            wire__crate__api__stream__stream_encrypt_file_impl(port, ptr, rust_vec_len, data_len)
+        }
+        162 => {
+            wire__crate__api__xhpke__xhpke_open_stream_file_impl(port, ptr, rust_vec_len, data_len)
+        }
+        164 => {
+            wire__crate__api__xhpke__xhpke_seal_stream_file_impl(port, ptr, rust_vec_len, data_len)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__argon2__Argon2Params_recommended_impl(ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__cddl__CddlSchema_compile_impl(ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__cddl__CddlSchema_validate_impl(ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__x509__CertName_cn_impl(ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__x509__CertTemplate_leaf_impl(ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__cwt__CwtValidation_at_impl(ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__rsa__RsaFingerprint_from_bytes_impl(ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__rsa__RsaFingerprint_to_bytes_impl(ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__rsa__RsaPublicKey_fingerprint_impl(ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__rsa__RsaPublicKey_from_bytes_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__rsa__RsaPublicKey_from_der_impl(ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__rsa__RsaPublicKey_from_pem_impl(ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__rsa__RsaPublicKey_to_bytes_impl(ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__rsa__RsaPublicKey_to_der_impl(ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__rsa__RsaPublicKey_to_pem_impl(ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__rsa__RsaPublicKey_verify_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__rsa__RsaSecretKey_fingerprint_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__rsa__RsaSecretKey_from_bytes_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__rsa__RsaSecretKey_from_der_impl(ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__rsa__RsaSecretKey_from_pem_impl(ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__rsa__RsaSecretKey_generate_impl(ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__rsa__RsaSecretKey_public_key_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__rsa__RsaSecretKey_sign_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__rsa__RsaSecretKey_to_bytes_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__rsa__RsaSecretKey_to_der_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__rsa__RsaSecretKey_to_pem_impl(ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__rsa__RsaSignature_from_bytes_impl(ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__rsa__RsaSignature_to_bytes_impl(ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__cwt__StatusList_bits_impl(ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__cwt__StatusList_get_impl(ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__cwt__StatusList_is_empty_impl(ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__cwt__StatusList_issue_impl(ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__cwt__StatusList_len_impl(ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__cwt__StatusList_new_impl(ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__cwt__StatusList_set_impl(ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__cwt__StatusList_uri_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__cwt__StatusList_verify_impl(ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__stream__StreamDecryptor_finish_impl(ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__stream__StreamDecryptor_new_impl(ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__stream__StreamDecryptor_push_impl(ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__stream__StreamEncryptor_finish_impl(ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__stream__StreamEncryptor_new_impl(ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__stream__StreamEncryptor_push_impl(ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__stream__StreamReader_from_bytes_impl(ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__stream__StreamReader_is_empty_impl(ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__stream__StreamReader_len_impl(ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__stream__StreamReader_open_impl(ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__xdsa__XdsaFingerprint_from_bytes_impl(ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__xdsa__XdsaFingerprint_to_bytes_impl(ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__xdsa__XdsaPublicKey_fingerprint_impl(ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__xdsa__XdsaPublicKey_from_bytes_impl(ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__xdsa__XdsaPublicKey_from_cert_der_impl(ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__xdsa__XdsaPublicKey_from_cert_pem_impl(ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__xdsa__XdsaPublicKey_from_der_impl(ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__xdsa__XdsaPublicKey_from_pem_impl(ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__xdsa__XdsaPublicKey_to_bytes_impl(ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__xdsa__XdsaPublicKey_to_cert_der_impl(ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__xdsa__XdsaPublicKey_to_cert_pem_impl(ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__xdsa__XdsaPublicKey_to_der_impl(ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__xdsa__XdsaPublicKey_to_pem_impl(ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__xdsa__XdsaPublicKey_verify_impl(ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__xdsa__XdsaSecretKey_fingerprint_impl(ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__xdsa__XdsaSecretKey_from_bytes_impl(ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__xdsa__XdsaSecretKey_from_der_impl(ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__xdsa__XdsaSecretKey_from_pem_impl(ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__xdsa__XdsaSecretKey_generate_impl(ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__xdsa__XdsaSecretKey_public_key_impl(ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__xdsa__XdsaSecretKey_sign_impl(ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__xdsa__XdsaSecretKey_to_bytes_impl(ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__xdsa__XdsaSecretKey_to_der_impl(ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__xdsa__XdsaSecretKey_to_pem_impl(ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__xdsa__XdsaSignature_from_bytes_impl(ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__xdsa__XdsaSignature_to_bytes_impl(ptr, rust_vec_len, data_len),
+        75 => {
+            wire__crate__api__xhpke__XhpkeFingerprint_from_bytes_impl(ptr, rust_vec_len, data_len)
+        }
+        76 => wire__crate__api__xhpke__XhpkeFingerprint_to_bytes_impl(ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__xhpke__XhpkePublicKey_fingerprint_impl(ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__xhpke__XhpkePublicKey_from_bytes_impl(ptr, rust_vec_len, data_len),
+        79 => {
+            wire__crate__api__xhpke__XhpkePublicKey_from_cert_der_impl(ptr, rust_vec_len, data_len)
+        }
+        80 => {
+            wire__crate__api__xhpke__XhpkePublicKey_from_cert_pem_impl(ptr, rust_vec_len, data_len)
+        }
+        81 => wire__crate__api__xhpke__XhpkePublicKey_from_der_impl(ptr, rust_vec_len, data_len),
+        82 => wire__crate__api__xhpke__XhpkePublicKey_from_pem_impl(ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__xhpke__XhpkePublicKey_new_sender_impl(ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__xhpke__XhpkePublicKey_seal_impl(ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__xhpke__XhpkePublicKey_to_bytes_impl(ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__xhpke__XhpkePublicKey_to_cert_der_impl(ptr, rust_vec_len, data_len),
+        87 => wire__crate__api__xhpke__XhpkePublicKey_to_cert_pem_impl(ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__xhpke__XhpkePublicKey_to_der_impl(ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__xhpke__XhpkePublicKey_to_pem_impl(ptr, rust_vec_len, data_len),
+        90 => wire__crate__api__xhpke__XhpkeReceiver_open_impl(ptr, rust_vec_len, data_len),
+        91 => wire__crate__api__xhpke__XhpkeSecretKey_fingerprint_impl(ptr, rust_vec_len, data_len),
+        92 => wire__crate__api__xhpke__XhpkeSecretKey_from_bytes_impl(ptr, rust_vec_len, data_len),
+        93 => wire__crate__api__xhpke__XhpkeSecretKey_from_der_impl(ptr, rust_vec_len, data_len),
+        94 => wire__crate__api__xhpke__XhpkeSecretKey_from_pem_impl(ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__xhpke__XhpkeSecretKey_generate_impl(ptr, rust_vec_len, data_len),
+        96 => {
+            wire__crate__api__xhpke__XhpkeSecretKey_new_receiver_impl(ptr, rust_vec_len, data_len)
+        }
+        97 => wire__crate__api__xhpke__XhpkeSecretKey_open_impl(ptr, rust_vec_len, data_len),
+        98 => wire__crate__api__xhpke__XhpkeSecretKey_open_multi_impl(ptr, rust_vec_len, data_len),
+        99 => wire__crate__api__xhpke__XhpkeSecretKey_public_key_impl(ptr, rust_vec_len, data_len),
+        100 => wire__crate__api__xhpke__XhpkeSecretKey_to_bytes_impl(ptr, rust_vec_len, data_len),
+        101 => wire__crate__api__xhpke__XhpkeSecretKey_to_der_impl(ptr, rust_vec_len, data_len),
+        102 => wire__crate__api__xhpke__XhpkeSecretKey_to_pem_impl(ptr, rust_vec_len, data_len),
+        103 => wire__crate__api__xhpke__XhpkeSender_seal_impl(ptr, rust_vec_len, data_len),
+        104 => wire__crate__api__argon2__argon2_key_impl(ptr, rust_vec_len, data_len),
+        105 => wire__crate__api__cbor__cbor_decode_impl(ptr, rust_vec_len, data_len),
+        106 => wire__crate__api__cbor__cbor_encode_impl(ptr, rust_vec_len, data_len),
+        107 => wire__crate__api__cbor__cbor_from_diagnostic_impl(ptr, rust_vec_len, data_len),
+        108 => wire__crate__api__cbor__cbor_to_diagnostic_impl(ptr, rust_vec_len, data_len),
+        109 => wire__crate__api__cbor__cbor_validate_impl(ptr, rust_vec_len, data_len),
+        110 => wire__crate__api__cbor__cbor_verify_impl(ptr, rust_vec_len, data_len),
+        111 => wire__crate__api__cose__cose_add_signature_impl(ptr, rust_vec_len, data_len),
+        112 => wire__crate__api__cose__cose_decrypt_impl(ptr, rust_vec_len, data_len),
+        113 => wire__crate__api__cose__cose_encrypt_impl(ptr, rust_vec_len, data_len),
+        114 => wire__crate__api__cose__cose_inspect_impl(ptr, rust_vec_len, data_len),
+        115 => wire__crate__api__cose__cose_open_impl(ptr, rust_vec_len, data_len),
+        116 => wire__crate__api__cose__cose_open_multi_impl(ptr, rust_vec_len, data_len),
+        117 => wire__crate__api__cose__cose_peek_impl(ptr, rust_vec_len, data_len),
+        118 => wire__crate__api__cose__cose_recipient_impl(ptr, rust_vec_len, data_len),
+        119 => wire__crate__api__cose__cose_recipients_impl(ptr, rust_vec_len, data_len),
+        120 => wire__crate__api__cose__cose_seal_impl(ptr, rust_vec_len, data_len),
+        121 => wire__crate__api__cose__cose_seal_multi_impl(ptr, rust_vec_len, data_len),
+        122 => wire__crate__api__cose__cose_sign_impl(ptr, rust_vec_len, data_len),
+        123 => wire__crate__api__cose__cose_sign_detached_impl(ptr, rust_vec_len, data_len),
+        124 => wire__crate__api__cose__cose_sign_multi_impl(ptr, rust_vec_len, data_len),
+        125 => wire__crate__api__cose__cose_signer_impl(ptr, rust_vec_len, data_len),
+        126 => wire__crate__api__cose__cose_signers_impl(ptr, rust_vec_len, data_len),
+        127 => wire__crate__api__cose__cose_verify_impl(ptr, rust_vec_len, data_len),
+        128 => wire__crate__api__cose__cose_verify_detached_impl(ptr, rust_vec_len, data_len),
+        129 => wire__crate__api__cose__cose_verify_info_impl(ptr, rust_vec_len, data_len),
+        130 => wire__crate__api__cose__cose_verify_threshold_impl(ptr, rust_vec_len, data_len),
+        131 => wire__crate__api__cwt__cwt_issue_impl(ptr, rust_vec_len, data_len),
+        132 => wire__crate__api__cwt__cwt_issue_claims_impl(ptr, rust_vec_len, data_len),
+        133 => wire__crate__api__cwt__cwt_issue_sd_impl(ptr, rust_vec_len, data_len),
+        134 => wire__crate__api__cwt__cwt_issue_sealed_impl(ptr, rust_vec_len, data_len),
+        135 => wire__crate__api__cwt__cwt_open_sealed_impl(ptr, rust_vec_len, data_len),
+        136 => wire__crate__api__cwt__cwt_peek_impl(ptr, rust_vec_len, data_len),
+        137 => wire__crate__api__cwt__cwt_peek_claims_impl(ptr, rust_vec_len, data_len),
+        138 => wire__crate__api__cwt__cwt_present_impl(ptr, rust_vec_len, data_len),
+        139 => wire__crate__api__cwt__cwt_present_sd_impl(ptr, rust_vec_len, data_len),
+        140 => wire__crate__api__cwt__cwt_signer_impl(ptr, rust_vec_len, data_len),
+        141 => wire__crate__api__cwt__cwt_verify_impl(ptr, rust_vec_len, data_len),
+        142 => wire__crate__api__cwt__cwt_verify_chain_impl(ptr, rust_vec_len, data_len),
+        143 => wire__crate__api__cwt__cwt_verify_claims_impl(ptr, rust_vec_len, data_len),
+        144 => wire__crate__api__cwt__cwt_verify_presentation_impl(ptr, rust_vec_len, data_len),
+        145 => wire__crate__api__cwt__cwt_verify_sd_impl(ptr, rust_vec_len, data_len),
+        146 => wire__crate__api__hkdf__hkdf_expand_impl(ptr, rust_vec_len, data_len),
+        147 => wire__crate__api__hkdf__hkdf_extract_impl(ptr, rust_vec_len, data_len),
+        148 => wire__crate__api__hkdf__hkdf_key_impl(ptr, rust_vec_len, data_len),
+        150 => wire__crate__api__rand__random_bytes_impl(ptr, rust_vec_len, data_len),
+        151 => wire__crate__api__stream__stream_decrypt_impl(ptr, rust_vec_len, data_len),
+        153 => wire__crate__api__stream__stream_encrypt_impl(ptr, rust_vec_len, data_len),
+        155 => {
+            wire__crate__api__stream__stream_open_with_password_impl(ptr, rust_vec_len, data_len)
+        }
+        156 => {
+            wire__crate__api__stream__stream_seal_with_password_impl(ptr, rust_vec_len, data_len)
+        }
+        157 => wire__crate__api__x509__x509_parse_impl(ptr, rust_vec_len, data_len),
+        158 => wire__crate__api__x509__x509_verify_chain_impl(ptr, rust_vec_len, data_len),
+        159 => {
+            wire__crate__api__xhpke__xhpke_new_stream_decryptor_impl(ptr, rust_vec_len, data_len)
+        }
+        160 => {
+            wire__crate__api__xhpke__xhpke_new_stream_encryptor_impl(ptr, rust_vec_len, data_len)
+        }
+        161 => wire__crate__api__xhpke__xhpke_open_stream_impl(ptr, rust_vec_len, data_len),
+        163 => wire__crate__api__xhpke__xhpke_seal_stream_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<CddlSchema> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<CddlSchema> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<CddlSchema>> for CddlSchema {
+    fn into_into_dart(self) -> FrbWrapper<CddlSchema> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<RsaFingerprint> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RsaFingerprint> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RsaFingerprint>> for RsaFingerprint {
+    fn into_into_dart(self) -> FrbWrapper<RsaFingerprint> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<RsaPublicKey> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RsaPublicKey> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RsaPublicKey>> for RsaPublicKey {
+    fn into_into_dart(self) -> FrbWrapper<RsaPublicKey> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<RsaSecretKey> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RsaSecretKey> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RsaSecretKey>> for RsaSecretKey {
+    fn into_into_dart(self) -> FrbWrapper<RsaSecretKey> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<RsaSignature> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RsaSignature> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RsaSignature>> for RsaSignature {
+    fn into_into_dart(self) -> FrbWrapper<RsaSignature> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<StatusList> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StatusList> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StatusList>> for StatusList {
+    fn into_into_dart(self) -> FrbWrapper<StatusList> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamDecryptor> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamDecryptor> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamDecryptor>> for StreamDecryptor {
+    fn into_into_dart(self) -> FrbWrapper<StreamDecryptor> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamEncryptor> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamEncryptor> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamEncryptor>> for StreamEncryptor {
+    fn into_into_dart(self) -> FrbWrapper<StreamEncryptor> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamReader> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamReader> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamReader>> for StreamReader {
+    fn into_into_dart(self) -> FrbWrapper<StreamReader> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<XdsaFingerprint> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XdsaFingerprint> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XdsaFingerprint>> for XdsaFingerprint {
+    fn into_into_dart(self) -> FrbWrapper<XdsaFingerprint> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<XdsaPublicKey> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XdsaPublicKey> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XdsaPublicKey>> for XdsaPublicKey {
+    fn into_into_dart(self) -> FrbWrapper<XdsaPublicKey> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<XdsaSecretKey> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XdsaSecretKey> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XdsaSecretKey>> for XdsaSecretKey {
+    fn into_into_dart(self) -> FrbWrapper<XdsaSecretKey> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<XdsaSignature> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XdsaSignature> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XdsaSignature>> for XdsaSignature {
+    fn into_into_dart(self) -> FrbWrapper<XdsaSignature> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<XhpkeFingerprint> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XhpkeFingerprint> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XhpkeFingerprint>> for XhpkeFingerprint {
+    fn into_into_dart(self) -> FrbWrapper<XhpkeFingerprint> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<XhpkePublicKey> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XhpkePublicKey> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XhpkePublicKey>> for XhpkePublicKey {
+    fn into_into_dart(self) -> FrbWrapper<XhpkePublicKey> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<XhpkeReceiver> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XhpkeReceiver> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XhpkeReceiver>> for XhpkeReceiver {
+    fn into_into_dart(self) -> FrbWrapper<XhpkeReceiver> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<XhpkeSecretKey> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XhpkeSecretKey> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XhpkeSecretKey>> for XhpkeSecretKey {
+    fn into_into_dart(self) -> FrbWrapper<XhpkeSecretKey> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<XhpkeSender> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XhpkeSender> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XhpkeSender>> for XhpkeSender {
+    fn into_into_dart(self) -> FrbWrapper<XhpkeSender> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::argon2::Argon2Params {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.time.into_into_dart().into_dart(),
+            self.memory.into_into_dart().into_dart(),
+            self.threads.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::argon2::Argon2Params
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::argon2::Argon2Params>
+    for crate::api::argon2::Argon2Params
+{
+    fn into_into_dart(self) -> crate::api::argon2::Argon2Params {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cbor::CborMapEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cbor::CborMapEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cbor::CborMapEntry>
+    for crate::api::cbor::CborMapEntry
+{
+    fn into_into_dart(self) -> crate::api::cbor::CborMapEntry {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cbor::CborValue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::cbor::CborValue::Null => [0.into_dart()].into_dart(),
+            crate::api::cbor::CborValue::Bool(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cbor::CborValue::Int(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cbor::CborValue::Uint(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cbor::CborValue::Text(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cbor::CborValue::Bytes(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cbor::CborValue::Array(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cbor::CborValue::Map(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cbor::CborValue {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cbor::CborValue>
+    for crate::api::cbor::CborValue
+{
+    fn into_into_dart(self) -> crate::api::cbor::CborValue {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::x509::CertChain {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.leaf_key.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::x509::CertChain {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::x509::CertChain>
+    for crate::api::x509::CertChain
+{
+    fn into_into_dart(self) -> crate::api::x509::CertChain {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::x509::CertExtension {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.oid.into_into_dart().into_dart(),
+            self.critical.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::x509::CertExtension
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::x509::CertExtension>
+    for crate::api::x509::CertExtension
+{
+    fn into_into_dart(self) -> crate::api::x509::CertExtension {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::x509::CertInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.subject.into_into_dart().into_dart(),
+            self.issuer.into_into_dart().into_dart(),
+            self.serial.into_into_dart().into_dart(),
+            self.not_before.into_into_dart().into_dart(),
+            self.not_after.into_into_dart().into_dart(),
+            self.is_ca.into_into_dart().into_dart(),
+            self.path_len.into_into_dart().into_dart(),
+            self.dns_names.into_into_dart().into_dart(),
+            self.uris.into_into_dart().into_dart(),
+            self.emails.into_into_dart().into_dart(),
+            self.key_usage.into_into_dart().into_dart(),
+            self.extended_key_usage.into_into_dart().into_dart(),
+            self.extensions.into_into_dart().into_dart(),
+            self.key_type.into_into_dart().into_dart(),
+            self.fingerprint.into_into_dart().into_dart(),
+            self.subject_key_id.into_into_dart().into_dart(),
+            self.issuer_key_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::x509::CertInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::x509::CertInfo> for crate::api::x509::CertInfo {
+    fn into_into_dart(self) -> crate::api::x509::CertInfo {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::x509::CertKeyType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Xdsa => 0.into_dart(),
+            Self::Xhpke => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::x509::CertKeyType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::x509::CertKeyType>
+    for crate::api::x509::CertKeyType
+{
+    fn into_into_dart(self) -> crate::api::x509::CertKeyType {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::x509::CertKeyUsage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::DigitalSignature => 0.into_dart(),
+            Self::NonRepudiation => 1.into_dart(),
+            Self::KeyEncipherment => 2.into_dart(),
+            Self::DataEncipherment => 3.into_dart(),
+            Self::KeyAgreement => 4.into_dart(),
+            Self::KeyCertSign => 5.into_dart(),
+            Self::CrlSign => 6.into_dart(),
+            Self::EncipherOnly => 7.into_dart(),
+            Self::DecipherOnly => 8.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::x509::CertKeyUsage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::x509::CertKeyUsage>
+    for crate::api::x509::CertKeyUsage
+{
+    fn into_into_dart(self) -> crate::api::x509::CertKeyUsage {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::x509::CertName {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.common_name.into_into_dart().into_dart(),
+            self.organization.into_into_dart().into_dart(),
+            self.organizational_unit.into_into_dart().into_dart(),
+            self.country.into_into_dart().into_dart(),
+            self.province.into_into_dart().into_dart(),
+            self.locality.into_into_dart().into_dart(),
+            self.serial_number.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::x509::CertName {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::x509::CertName> for crate::api::x509::CertName {
+    fn into_into_dart(self) -> crate::api::x509::CertName {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::x509::CertPublicKey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::x509::CertPublicKey::Xdsa(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::x509::CertPublicKey::Xhpke(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::x509::CertPublicKey
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::x509::CertPublicKey>
+    for crate::api::x509::CertPublicKey
+{
+    fn into_into_dart(self) -> crate::api::x509::CertPublicKey {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::x509::CertTemplate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.subject.into_into_dart().into_dart(),
+            self.issuer.into_into_dart().into_dart(),
+            self.not_before.into_into_dart().into_dart(),
+            self.not_after.into_into_dart().into_dart(),
+            self.is_ca.into_into_dart().into_dart(),
+            self.path_len.into_into_dart().into_dart(),
+            self.dns_names.into_into_dart().into_dart(),
+            self.uris.into_into_dart().into_dart(),
+            self.emails.into_into_dart().into_dart(),
+            self.key_usage.into_into_dart().into_dart(),
+            self.extended_key_usage.into_into_dart().into_dart(),
+            self.extensions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::x509::CertTemplate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::x509::CertTemplate>
+    for crate::api::x509::CertTemplate
+{
+    fn into_into_dart(self) -> crate::api::x509::CertTemplate {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cose::CoseSign1Info {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.payload.into_into_dart().into_dart(),
+            self.signer.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.algorithm.into_into_dart().into_dart(),
+            self.domain.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cose::CoseSign1Info
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cose::CoseSign1Info>
+    for crate::api::cose::CoseSign1Info
+{
+    fn into_into_dart(self) -> crate::api::cose::CoseSign1Info {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::error::CryptoError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::error::CryptoError::InvalidLength { expected, got } => [
+                0.into_dart(),
+                expected.into_into_dart().into_dart(),
+                got.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::error::CryptoError::InvalidEncoding { details } => {
+                [1.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::InvalidArgument { details } => {
+                [2.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::CborInvalid { details } => {
+                [3.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::SignatureMismatch { details } => {
+                [4.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::KeyMismatch { details } => {
+                [5.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::EncryptionFailed { details } => {
+                [6.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::DecryptionFailed { details } => {
+                [7.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::Expired { exp, now } => [
+                8.into_dart(),
+                exp.into_into_dart().into_dart(),
+                now.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::error::CryptoError::NotYetValid { nbf, now } => [
+                9.into_dart(),
+                nbf.into_into_dart().into_dart(),
+                now.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::error::CryptoError::StaleSignature { drift, max_drift } => [
+                10.into_dart(),
+                drift.into_into_dart().into_dart(),
+                max_drift.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::error::CryptoError::InvalidClaims { details } => {
+                [11.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::InvalidCertificate { details } => {
+                [12.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::Io { details } => {
+                [13.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::MissingClaim { key } => {
+                [14.into_dart(), key.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::IssuerMismatch { expected, got } => [
+                15.into_dart(),
+                expected.into_into_dart().into_dart(),
+                got.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::error::CryptoError::AudienceMismatch { got } => {
+                [16.into_dart(), got.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::TokenTooOld { iat, max_age, now } => [
+                17.into_dart(),
+                iat.into_into_dart().into_dart(),
+                max_age.into_into_dart().into_dart(),
+                now.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::error::CryptoError::ConfirmKeyMismatch { details } => {
+                [18.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::NonceMismatch { details } => {
+                [19.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::Revoked { idx, status } => [
+                20.into_dart(),
+                idx.into_into_dart().into_dart(),
+                status.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::error::CryptoError::InvalidSchema { details } => {
+                [21.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::CryptoError::SchemaMismatch { path, details } => [
+                22.into_dart(),
+                path.into_into_dart().into_dart(),
+                details.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::error::CryptoError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::error::CryptoError>
+    for crate::api::error::CryptoError
+{
+    fn into_into_dart(self) -> crate::api::error::CryptoError {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::CwtClaims {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.iss.into_into_dart().into_dart(),
+            self.sub.into_into_dart().into_dart(),
+            self.aud.into_into_dart().into_dart(),
+            self.exp.into_into_dart().into_dart(),
+            self.nbf.into_into_dart().into_dart(),
+            self.iat.into_into_dart().into_dart(),
+            self.cti.into_into_dart().into_dart(),
+            self.cnf.into_into_dart().into_dart(),
+            self.eat_nonce.into_into_dart().into_dart(),
+            self.ueid.into_into_dart().into_dart(),
+            self.oemid.into_into_dart().into_dart(),
+            self.hw_model.into_into_dart().into_dart(),
+            self.hw_version.into_into_dart().into_dart(),
+            self.uptime.into_into_dart().into_dart(),
+            self.oem_boot.into_into_dart().into_dart(),
+            self.dbgstat.into_into_dart().into_dart(),
+            self.submods.into_into_dart().into_dart(),
+            self.boot_count.into_into_dart().into_dart(),
+            self.boot_seed.into_into_dart().into_dart(),
+            self.sw_name.into_into_dart().into_dart(),
+            self.sw_version.into_into_dart().into_dart(),
+            self.measurements.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.custom.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cwt::CwtClaims {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::CwtClaims> for crate::api::cwt::CwtClaims {
+    fn into_into_dart(self) -> crate::api::cwt::CwtClaims {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::CwtConfirmKey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::cwt::CwtConfirmKey::Xdsa(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cwt::CwtConfirmKey::Xhpke(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cwt::CwtConfirmKey
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::CwtConfirmKey>
+    for crate::api::cwt::CwtConfirmKey
+{
+    fn into_into_dart(self) -> crate::api::cwt::CwtConfirmKey {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::CwtConfirmKeyType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Xdsa => 0.into_dart(),
+            Self::Xhpke => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cwt::CwtConfirmKeyType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::CwtConfirmKeyType>
+    for crate::api::cwt::CwtConfirmKeyType
+{
+    fn into_into_dart(self) -> crate::api::cwt::CwtConfirmKeyType {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::CwtCustomClaim {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cwt::CwtCustomClaim
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::CwtCustomClaim>
+    for crate::api::cwt::CwtCustomClaim
+{
+    fn into_into_dart(self) -> crate::api::cwt::CwtCustomClaim {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::CwtStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.idx.into_into_dart().into_dart(),
+            self.uri.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cwt::CwtStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::CwtStatus> for crate::api::cwt::CwtStatus {
+    fn into_into_dart(self) -> crate::api::cwt::CwtStatus {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::CwtValidation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.now.into_into_dart().into_dart(),
+            self.leeway_secs.into_into_dart().into_dart(),
+            self.max_age_secs.into_into_dart().into_dart(),
+            self.issuer.into_into_dart().into_dart(),
+            self.audiences.into_into_dart().into_dart(),
+            self.required_claims.into_into_dart().into_dart(),
+            self.confirm_key.into_into_dart().into_dart(),
+            self.eat_nonce.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cwt::CwtValidation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::CwtValidation>
+    for crate::api::cwt::CwtValidation
+{
+    fn into_into_dart(self) -> crate::api::cwt::CwtValidation {
+        self
+    }
+}
 
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaFingerprint>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaPublicKey>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSecretKey>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>
-);
-
-// Section: dart2rust
-
-impl SseDecode for RsaFingerprint {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaFingerprint>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::EatDebugState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Enabled => 0.into_dart(),
+            Self::Disabled => 1.into_dart(),
+            Self::DisabledSinceBoot => 2.into_dart(),
+            Self::DisabledPermanently => 3.into_dart(),
+            Self::DisabledFullyPermanently => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cwt::EatDebugState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::EatDebugState>
+    for crate::api::cwt::EatDebugState
+{
+    fn into_into_dart(self) -> crate::api::cwt::EatDebugState {
+        self
     }
 }
 
-impl SseDecode for RsaPublicKey {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaPublicKey>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::EatMeasurement {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.content_type.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cwt::EatMeasurement
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::EatMeasurement>
+    for crate::api::cwt::EatMeasurement
+{
+    fn into_into_dart(self) -> crate::api::cwt::EatMeasurement {
+        self
     }
 }
 
-impl SseDecode for RsaSecretKey {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSecretKey>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::EatOemid {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::cwt::EatOemid::Random(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cwt::EatOemid::Ieee(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cwt::EatOemid::Pen(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cwt::EatOemid {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::EatOemid> for crate::api::cwt::EatOemid {
+    fn into_into_dart(self) -> crate::api::cwt::EatOemid {
+        self
     }
 }
 
-impl SseDecode for RsaSignature {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::EatSubmodule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cwt::EatSubmodule {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::EatSubmodule>
+    for crate::api::cwt::EatSubmodule
+{
+    fn into_into_dart(self) -> crate::api::cwt::EatSubmodule {
+        self
     }
 }
 
-impl SseDecode for XdsaFingerprint {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::EatSubmoduleValue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::cwt::EatSubmoduleValue::Claims(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cwt::EatSubmoduleValue::Embedded(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::cwt::EatSubmoduleValue::Detached { alg, digest } => [
+                2.into_dart(),
+                alg.into_into_dart().into_dart(),
+                digest.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cwt::EatSubmoduleValue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::EatSubmoduleValue>
+    for crate::api::cwt::EatSubmoduleValue
+{
+    fn into_into_dart(self) -> crate::api::cwt::EatSubmoduleValue {
+        self
     }
 }
 
-impl SseDecode for XdsaPublicKey {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cwt::SdCwt {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.token.into_into_dart().into_dart(),
+            self.disclosures.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::cwt::SdCwt {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cwt::SdCwt> for crate::api::cwt::SdCwt {
+    fn into_into_dart(self) -> crate::api::cwt::SdCwt {
+        self
     }
 }
 
-impl SseDecode for XdsaSecretKey {
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
 
-impl SseDecode for XdsaSignature {
+impl SseEncode for RustAutoOpaqueMoi<CddlSchema> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_encode(self), serializer);
     }
 }
 
-impl SseDecode for XhpkeFingerprint {
+impl SseEncode for RustAutoOpaqueMoi<StatusList> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_encode(self), serializer);
     }
 }
 
-impl SseDecode for XhpkePublicKey {
+impl SseEncode for RustAutoOpaqueMoi<XdsaPublicKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_encode(self), serializer);
     }
 }
 
-impl SseDecode for XhpkeReceiver {
+impl SseEncode for RustAutoOpaqueMoi<XdsaSecretKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_encode(self), serializer);
     }
 }
 
-impl SseDecode for XhpkeSecretKey {
+impl SseEncode for RustAutoOpaqueMoi<XhpkePublicKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_encode(self), serializer);
     }
 }
 
-impl SseDecode for XhpkeSender {
+impl SseEncode for CddlSchema {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaFingerprint>>
-{
+impl SseEncode for RsaFingerprint {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaFingerprint>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaPublicKey>>
-{
+impl SseEncode for RsaPublicKey {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaPublicKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSecretKey>>
-{
+impl SseEncode for RsaSecretKey {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSecretKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>>
-{
+impl SseEncode for RsaSignature {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>>
-{
+impl SseEncode for StatusList {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>>
-{
+impl SseEncode for StreamDecryptor {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>>
-{
+impl SseEncode for StreamEncryptor {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>>
-{
+impl SseEncode for StreamReader {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>>
-{
+impl SseEncode for XdsaFingerprint {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>>
-{
+impl SseEncode for XdsaPublicKey {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>>
-{
+impl SseEncode for XdsaSecretKey {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>>
-{
+impl SseEncode for XdsaSignature {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>>
-{
+impl SseEncode for XhpkeFingerprint {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for String {
+impl SseEncode for XhpkePublicKey {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for XhpkeReceiver {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for XhpkeSecretKey {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for Option<u64> {
+impl SseEncode for XhpkeSender {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for Option<u8> {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u8>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for (XdsaPublicKey, u64, u64) {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaFingerprint>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <XdsaPublicKey>::sse_decode(deserializer);
-        let mut var_field1 = <u64>::sse_decode(deserializer);
-        let mut var_field2 = <u64>::sse_decode(deserializer);
-        return (var_field0, var_field1, var_field2);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for (XhpkePublicKey, u64, u64) {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaPublicKey>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <XhpkePublicKey>::sse_decode(deserializer);
-        let mut var_field1 = <u64>::sse_decode(deserializer);
-        let mut var_field2 = <u64>::sse_decode(deserializer);
-        return (var_field0, var_field1, var_field2);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for (XhpkeSender, Vec<u8>) {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSecretKey>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <XhpkeSender>::sse_decode(deserializer);
-        let mut var_field1 = <Vec<u8>>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for (Vec<u8>, Vec<u8>) {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <Vec<u8>>::sse_decode(deserializer);
-        let mut var_field1 = <Vec<u8>>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        97 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__rsa__RsaFingerprint_from_bytes_impl(ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__rsa__RsaFingerprint_to_bytes_impl(ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__rsa__RsaPublicKey_fingerprint_impl(ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__rsa__RsaPublicKey_from_bytes_impl(ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__rsa__RsaPublicKey_from_der_impl(ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__rsa__RsaPublicKey_from_pem_impl(ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__rsa__RsaPublicKey_to_bytes_impl(ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__rsa__RsaPublicKey_to_der_impl(ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__rsa__RsaPublicKey_to_pem_impl(ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__rsa__RsaPublicKey_verify_impl(ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__rsa__RsaSecretKey_fingerprint_impl(ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__rsa__RsaSecretKey_from_bytes_impl(ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__rsa__RsaSecretKey_from_der_impl(ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__rsa__RsaSecretKey_from_pem_impl(ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__rsa__RsaSecretKey_generate_impl(ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__rsa__RsaSecretKey_public_key_impl(ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__rsa__RsaSecretKey_sign_impl(ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__rsa__RsaSecretKey_to_bytes_impl(ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__rsa__RsaSecretKey_to_der_impl(ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__rsa__RsaSecretKey_to_pem_impl(ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__rsa__RsaSignature_from_bytes_impl(ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__rsa__RsaSignature_to_bytes_impl(ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__xdsa__XdsaFingerprint_from_bytes_impl(ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__xdsa__XdsaFingerprint_to_bytes_impl(ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__xdsa__XdsaPublicKey_fingerprint_impl(ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__xdsa__XdsaPublicKey_from_bytes_impl(ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__xdsa__XdsaPublicKey_from_cert_der_impl(ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__xdsa__XdsaPublicKey_from_cert_pem_impl(ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__xdsa__XdsaPublicKey_from_der_impl(ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__xdsa__XdsaPublicKey_from_pem_impl(ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__xdsa__XdsaPublicKey_to_bytes_impl(ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__xdsa__XdsaPublicKey_to_cert_der_impl(ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__xdsa__XdsaPublicKey_to_cert_pem_impl(ptr, rust_vec_len, data_len),
-        34 => wire__crate__api__xdsa__XdsaPublicKey_to_der_impl(ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__xdsa__XdsaPublicKey_to_pem_impl(ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__xdsa__XdsaPublicKey_verify_impl(ptr, rust_vec_len, data_len),
-        37 => wire__crate__api__xdsa__XdsaSecretKey_fingerprint_impl(ptr, rust_vec_len, data_len),
-        38 => wire__crate__api__xdsa__XdsaSecretKey_from_bytes_impl(ptr, rust_vec_len, data_len),
-        39 => wire__crate__api__xdsa__XdsaSecretKey_from_der_impl(ptr, rust_vec_len, data_len),
-        40 => wire__crate__api__xdsa__XdsaSecretKey_from_pem_impl(ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__xdsa__XdsaSecretKey_generate_impl(ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__xdsa__XdsaSecretKey_public_key_impl(ptr, rust_vec_len, data_len),
-        43 => wire__crate__api__xdsa__XdsaSecretKey_sign_impl(ptr, rust_vec_len, data_len),
-        44 => wire__crate__api__xdsa__XdsaSecretKey_to_bytes_impl(ptr, rust_vec_len, data_len),
-        45 => wire__crate__api__xdsa__XdsaSecretKey_to_der_impl(ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__xdsa__XdsaSecretKey_to_pem_impl(ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__xdsa__XdsaSignature_from_bytes_impl(ptr, rust_vec_len, data_len),
-        48 => wire__crate__api__xdsa__XdsaSignature_to_bytes_impl(ptr, rust_vec_len, data_len),
-        49 => {
-            wire__crate__api__xhpke__XhpkeFingerprint_from_bytes_impl(ptr, rust_vec_len, data_len)
-        }
-        50 => wire__crate__api__xhpke__XhpkeFingerprint_to_bytes_impl(ptr, rust_vec_len, data_len),
-        51 => wire__crate__api__xhpke__XhpkePublicKey_fingerprint_impl(ptr, rust_vec_len, data_len),
-        52 => wire__crate__api__xhpke__XhpkePublicKey_from_bytes_impl(ptr, rust_vec_len, data_len),
-        53 => {
-            wire__crate__api__xhpke__XhpkePublicKey_from_cert_der_impl(ptr, rust_vec_len, data_len)
-        }
-        54 => {
-            wire__crate__api__xhpke__XhpkePublicKey_from_cert_pem_impl(ptr, rust_vec_len, data_len)
-        }
-        55 => wire__crate__api__xhpke__XhpkePublicKey_from_der_impl(ptr, rust_vec_len, data_len),
-        56 => wire__crate__api__xhpke__XhpkePublicKey_from_pem_impl(ptr, rust_vec_len, data_len),
-        57 => wire__crate__api__xhpke__XhpkePublicKey_new_sender_impl(ptr, rust_vec_len, data_len),
-        58 => wire__crate__api__xhpke__XhpkePublicKey_seal_impl(ptr, rust_vec_len, data_len),
-        59 => wire__crate__api__xhpke__XhpkePublicKey_to_bytes_impl(ptr, rust_vec_len, data_len),
-        60 => wire__crate__api__xhpke__XhpkePublicKey_to_cert_der_impl(ptr, rust_vec_len, data_len),
-        61 => wire__crate__api__xhpke__XhpkePublicKey_to_cert_pem_impl(ptr, rust_vec_len, data_len),
-        62 => wire__crate__api__xhpke__XhpkePublicKey_to_der_impl(ptr, rust_vec_len, data_len),
-        63 => wire__crate__api__xhpke__XhpkePublicKey_to_pem_impl(ptr, rust_vec_len, data_len),
-        64 => wire__crate__api__xhpke__XhpkeReceiver_open_impl(ptr, rust_vec_len, data_len),
-        65 => wire__crate__api__xhpke__XhpkeSecretKey_fingerprint_impl(ptr, rust_vec_len, data_len),
-        66 => wire__crate__api__xhpke__XhpkeSecretKey_from_bytes_impl(ptr, rust_vec_len, data_len),
-        67 => wire__crate__api__xhpke__XhpkeSecretKey_from_der_impl(ptr, rust_vec_len, data_len),
-        68 => wire__crate__api__xhpke__XhpkeSecretKey_from_pem_impl(ptr, rust_vec_len, data_len),
-        69 => wire__crate__api__xhpke__XhpkeSecretKey_generate_impl(ptr, rust_vec_len, data_len),
-        70 => {
-            wire__crate__api__xhpke__XhpkeSecretKey_new_receiver_impl(ptr, rust_vec_len, data_len)
-        }
-        71 => wire__crate__api__xhpke__XhpkeSecretKey_open_impl(ptr, rust_vec_len, data_len),
-        72 => wire__crate__api__xhpke__XhpkeSecretKey_public_key_impl(ptr, rust_vec_len, data_len),
-        73 => wire__crate__api__xhpke__XhpkeSecretKey_to_bytes_impl(ptr, rust_vec_len, data_len),
-        74 => wire__crate__api__xhpke__XhpkeSecretKey_to_der_impl(ptr, rust_vec_len, data_len),
-        75 => wire__crate__api__xhpke__XhpkeSecretKey_to_pem_impl(ptr, rust_vec_len, data_len),
-        76 => wire__crate__api__xhpke__XhpkeSender_seal_impl(ptr, rust_vec_len, data_len),
-        77 => wire__crate__api__argon2__argon2_key_impl(ptr, rust_vec_len, data_len),
-        78 => wire__crate__api__cbor__cbor_verify_impl(ptr, rust_vec_len, data_len),
-        79 => wire__crate__api__cose__cose_decrypt_impl(ptr, rust_vec_len, data_len),
-        80 => wire__crate__api__cose__cose_encrypt_impl(ptr, rust_vec_len, data_len),
-        81 => wire__crate__api__cose__cose_open_impl(ptr, rust_vec_len, data_len),
-        82 => wire__crate__api__cose__cose_peek_impl(ptr, rust_vec_len, data_len),
-        83 => wire__crate__api__cose__cose_recipient_impl(ptr, rust_vec_len, data_len),
-        84 => wire__crate__api__cose__cose_seal_impl(ptr, rust_vec_len, data_len),
-        85 => wire__crate__api__cose__cose_sign_impl(ptr, rust_vec_len, data_len),
-        86 => wire__crate__api__cose__cose_sign_detached_impl(ptr, rust_vec_len, data_len),
-        87 => wire__crate__api__cose__cose_signer_impl(ptr, rust_vec_len, data_len),
-        88 => wire__crate__api__cose__cose_verify_impl(ptr, rust_vec_len, data_len),
-        89 => wire__crate__api__cose__cose_verify_detached_impl(ptr, rust_vec_len, data_len),
-        90 => wire__crate__api__cwt__cwt_issue_impl(ptr, rust_vec_len, data_len),
-        91 => wire__crate__api__cwt__cwt_peek_impl(ptr, rust_vec_len, data_len),
-        92 => wire__crate__api__cwt__cwt_signer_impl(ptr, rust_vec_len, data_len),
-        93 => wire__crate__api__cwt__cwt_verify_impl(ptr, rust_vec_len, data_len),
-        94 => wire__crate__api__hkdf__hkdf_expand_impl(ptr, rust_vec_len, data_len),
-        95 => wire__crate__api__hkdf__hkdf_extract_impl(ptr, rust_vec_len, data_len),
-        96 => wire__crate__api__hkdf__hkdf_key_impl(ptr, rust_vec_len, data_len),
-        98 => wire__crate__api__rand__random_bytes_impl(ptr, rust_vec_len, data_len),
-        99 => wire__crate__api__stream__stream_decrypt_impl(ptr, rust_vec_len, data_len),
-        100 => wire__crate__api__stream__stream_encrypt_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-// Section: rust2dart
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<RsaFingerprint> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RsaFingerprint> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RsaFingerprint>> for RsaFingerprint {
-    fn into_into_dart(self) -> FrbWrapper<RsaFingerprint> {
-        self.into()
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<RsaPublicKey> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RsaPublicKey> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RsaPublicKey>> for RsaPublicKey {
-    fn into_into_dart(self) -> FrbWrapper<RsaPublicKey> {
-        self.into()
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<RsaSecretKey> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for StreamSink<u64, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RsaSecretKey> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RsaSecretKey>> for RsaSecretKey {
-    fn into_into_dart(self) -> FrbWrapper<RsaSecretKey> {
-        self.into()
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<RsaSignature> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::argon2::Argon2Params {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.time, serializer);
+        <u32>::sse_encode(self.memory, serializer);
+        <u32>::sse_encode(self.threads, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RsaSignature> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RsaSignature>> for RsaSignature {
-    fn into_into_dart(self) -> FrbWrapper<RsaSignature> {
-        self.into()
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<XdsaFingerprint> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Box<crate::api::cwt::CwtClaims> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::cwt::CwtClaims>::sse_encode(*self, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XdsaFingerprint> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XdsaFingerprint>> for XdsaFingerprint {
-    fn into_into_dart(self) -> FrbWrapper<XdsaFingerprint> {
-        self.into()
+impl SseEncode for crate::api::cbor::CborMapEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.key, serializer);
+        <crate::api::cbor::CborValue>::sse_encode(self.value, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<XdsaPublicKey> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::cbor::CborValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::cbor::CborValue::Null => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::cbor::CborValue::Bool(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <bool>::sse_encode(field0, serializer);
+            }
+            crate::api::cbor::CborValue::Int(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <i64>::sse_encode(field0, serializer);
+            }
+            crate::api::cbor::CborValue::Uint(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <u64>::sse_encode(field0, serializer);
+            }
+            crate::api::cbor::CborValue::Text(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::cbor::CborValue::Bytes(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <Vec<u8>>::sse_encode(field0, serializer);
+            }
+            crate::api::cbor::CborValue::Array(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <Vec<crate::api::cbor::CborValue>>::sse_encode(field0, serializer);
+            }
+            crate::api::cbor::CborValue::Map(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <Vec<crate::api::cbor::CborMapEntry>>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XdsaPublicKey> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XdsaPublicKey>> for XdsaPublicKey {
-    fn into_into_dart(self) -> FrbWrapper<XdsaPublicKey> {
-        self.into()
+impl SseEncode for crate::api::x509::CertChain {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::x509::CertPublicKey>::sse_encode(self.leaf_key, serializer);
+        <Vec<Vec<u8>>>::sse_encode(self.path, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<XdsaSecretKey> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::x509::CertExtension {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.oid, serializer);
+        <bool>::sse_encode(self.critical, serializer);
+        <Vec<u8>>::sse_encode(self.value, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XdsaSecretKey> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XdsaSecretKey>> for XdsaSecretKey {
-    fn into_into_dart(self) -> FrbWrapper<XdsaSecretKey> {
-        self.into()
+impl SseEncode for crate::api::x509::CertInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::x509::CertName>::sse_encode(self.subject, serializer);
+        <crate::api::x509::CertName>::sse_encode(self.issuer, serializer);
+        <Vec<u8>>::sse_encode(self.serial, serializer);
+        <u64>::sse_encode(self.not_before, serializer);
+        <u64>::sse_encode(self.not_after, serializer);
+        <bool>::sse_encode(self.is_ca, serializer);
+        <Option<u8>>::sse_encode(self.path_len, serializer);
+        <Vec<String>>::sse_encode(self.dns_names, serializer);
+        <Vec<String>>::sse_encode(self.uris, serializer);
+        <Vec<String>>::sse_encode(self.emails, serializer);
+        <Vec<crate::api::x509::CertKeyUsage>>::sse_encode(self.key_usage, serializer);
+        <Vec<String>>::sse_encode(self.extended_key_usage, serializer);
+        <Vec<crate::api::x509::CertExtension>>::sse_encode(self.extensions, serializer);
+        <crate::api::x509::CertKeyType>::sse_encode(self.key_type, serializer);
+        <Vec<u8>>::sse_encode(self.fingerprint, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.subject_key_id, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.issuer_key_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::x509::CertKeyType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::x509::CertKeyType::Xdsa => 0,
+                crate::api::x509::CertKeyType::Xhpke => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<XdsaSignature> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::x509::CertKeyUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::x509::CertKeyUsage::DigitalSignature => 0,
+                crate::api::x509::CertKeyUsage::NonRepudiation => 1,
+                crate::api::x509::CertKeyUsage::KeyEncipherment => 2,
+                crate::api::x509::CertKeyUsage::DataEncipherment => 3,
+                crate::api::x509::CertKeyUsage::KeyAgreement => 4,
+                crate::api::x509::CertKeyUsage::KeyCertSign => 5,
+                crate::api::x509::CertKeyUsage::CrlSign => 6,
+                crate::api::x509::CertKeyUsage::EncipherOnly => 7,
+                crate::api::x509::CertKeyUsage::DecipherOnly => 8,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XdsaSignature> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XdsaSignature>> for XdsaSignature {
-    fn into_into_dart(self) -> FrbWrapper<XdsaSignature> {
-        self.into()
+impl SseEncode for crate::api::x509::CertName {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.common_name, serializer);
+        <Option<String>>::sse_encode(self.organization, serializer);
+        <Option<String>>::sse_encode(self.organizational_unit, serializer);
+        <Option<String>>::sse_encode(self.country, serializer);
+        <Option<String>>::sse_encode(self.province, serializer);
+        <Option<String>>::sse_encode(self.locality, serializer);
+        <Option<String>>::sse_encode(self.serial_number, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<XhpkeFingerprint> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::x509::CertPublicKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::x509::CertPublicKey::Xdsa(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <XdsaPublicKey>::sse_encode(field0, serializer);
+            }
+            crate::api::x509::CertPublicKey::Xhpke(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <XhpkePublicKey>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XhpkeFingerprint> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XhpkeFingerprint>> for XhpkeFingerprint {
-    fn into_into_dart(self) -> FrbWrapper<XhpkeFingerprint> {
-        self.into()
+impl SseEncode for crate::api::x509::CertTemplate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::x509::CertName>::sse_encode(self.subject, serializer);
+        <crate::api::x509::CertName>::sse_encode(self.issuer, serializer);
+        <u64>::sse_encode(self.not_before, serializer);
+        <u64>::sse_encode(self.not_after, serializer);
+        <bool>::sse_encode(self.is_ca, serializer);
+        <Option<u8>>::sse_encode(self.path_len, serializer);
+        <Vec<String>>::sse_encode(self.dns_names, serializer);
+        <Vec<String>>::sse_encode(self.uris, serializer);
+        <Vec<String>>::sse_encode(self.emails, serializer);
+        <Vec<crate::api::x509::CertKeyUsage>>::sse_encode(self.key_usage, serializer);
+        <Vec<String>>::sse_encode(self.extended_key_usage, serializer);
+        <Vec<crate::api::x509::CertExtension>>::sse_encode(self.extensions, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cose::CoseSign1Info {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.payload, serializer);
+        <XdsaFingerprint>::sse_encode(self.signer, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
+        <i64>::sse_encode(self.algorithm, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.domain, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<XhpkePublicKey> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::error::CryptoError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::error::CryptoError::InvalidLength { expected, got } => {
+                <i32>::sse_encode(0, serializer);
+                <usize>::sse_encode(expected, serializer);
+                <usize>::sse_encode(got, serializer);
+            }
+            crate::api::error::CryptoError::InvalidEncoding { details } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::InvalidArgument { details } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::CborInvalid { details } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::SignatureMismatch { details } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::KeyMismatch { details } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::EncryptionFailed { details } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::DecryptionFailed { details } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::Expired { exp, now } => {
+                <i32>::sse_encode(8, serializer);
+                <u64>::sse_encode(exp, serializer);
+                <u64>::sse_encode(now, serializer);
+            }
+            crate::api::error::CryptoError::NotYetValid { nbf, now } => {
+                <i32>::sse_encode(9, serializer);
+                <u64>::sse_encode(nbf, serializer);
+                <u64>::sse_encode(now, serializer);
+            }
+            crate::api::error::CryptoError::StaleSignature { drift, max_drift } => {
+                <i32>::sse_encode(10, serializer);
+                <u64>::sse_encode(drift, serializer);
+                <u64>::sse_encode(max_drift, serializer);
+            }
+            crate::api::error::CryptoError::InvalidClaims { details } => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::InvalidCertificate { details } => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::Io { details } => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::MissingClaim { key } => {
+                <i32>::sse_encode(14, serializer);
+                <i64>::sse_encode(key, serializer);
+            }
+            crate::api::error::CryptoError::IssuerMismatch { expected, got } => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(expected, serializer);
+                <String>::sse_encode(got, serializer);
+            }
+            crate::api::error::CryptoError::AudienceMismatch { got } => {
+                <i32>::sse_encode(16, serializer);
+                <String>::sse_encode(got, serializer);
+            }
+            crate::api::error::CryptoError::TokenTooOld { iat, max_age, now } => {
+                <i32>::sse_encode(17, serializer);
+                <u64>::sse_encode(iat, serializer);
+                <u64>::sse_encode(max_age, serializer);
+                <u64>::sse_encode(now, serializer);
+            }
+            crate::api::error::CryptoError::ConfirmKeyMismatch { details } => {
+                <i32>::sse_encode(18, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::NonceMismatch { details } => {
+                <i32>::sse_encode(19, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::Revoked { idx, status } => {
+                <i32>::sse_encode(20, serializer);
+                <u64>::sse_encode(idx, serializer);
+                <u8>::sse_encode(status, serializer);
+            }
+            crate::api::error::CryptoError::InvalidSchema { details } => {
+                <i32>::sse_encode(21, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            crate::api::error::CryptoError::SchemaMismatch { path, details } => {
+                <i32>::sse_encode(22, serializer);
+                <String>::sse_encode(path, serializer);
+                <String>::sse_encode(details, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XhpkePublicKey> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XhpkePublicKey>> for XhpkePublicKey {
-    fn into_into_dart(self) -> FrbWrapper<XhpkePublicKey> {
-        self.into()
+impl SseEncode for crate::api::cwt::CwtClaims {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.iss, serializer);
+        <Option<String>>::sse_encode(self.sub, serializer);
+        <Option<String>>::sse_encode(self.aud, serializer);
+        <Option<u64>>::sse_encode(self.exp, serializer);
+        <Option<u64>>::sse_encode(self.nbf, serializer);
+        <Option<u64>>::sse_encode(self.iat, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.cti, serializer);
+        <Option<crate::api::cwt::CwtConfirmKey>>::sse_encode(self.cnf, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.eat_nonce, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.ueid, serializer);
+        <Option<crate::api::cwt::EatOemid>>::sse_encode(self.oemid, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.hw_model, serializer);
+        <Option<String>>::sse_encode(self.hw_version, serializer);
+        <Option<u64>>::sse_encode(self.uptime, serializer);
+        <Option<bool>>::sse_encode(self.oem_boot, serializer);
+        <Option<crate::api::cwt::EatDebugState>>::sse_encode(self.dbgstat, serializer);
+        <Vec<crate::api::cwt::EatSubmodule>>::sse_encode(self.submods, serializer);
+        <Option<u64>>::sse_encode(self.boot_count, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.boot_seed, serializer);
+        <Option<String>>::sse_encode(self.sw_name, serializer);
+        <Option<String>>::sse_encode(self.sw_version, serializer);
+        <Vec<crate::api::cwt::EatMeasurement>>::sse_encode(self.measurements, serializer);
+        <Option<crate::api::cwt::CwtStatus>>::sse_encode(self.status, serializer);
+        <Vec<crate::api::cwt::CwtCustomClaim>>::sse_encode(self.custom, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cwt::CwtConfirmKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::cwt::CwtConfirmKey::Xdsa(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <XdsaPublicKey>::sse_encode(field0, serializer);
+            }
+            crate::api::cwt::CwtConfirmKey::Xhpke(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <XhpkePublicKey>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<XhpkeReceiver> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::cwt::CwtConfirmKeyType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::cwt::CwtConfirmKeyType::Xdsa => 0,
+                crate::api::cwt::CwtConfirmKeyType::Xhpke => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XhpkeReceiver> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XhpkeReceiver>> for XhpkeReceiver {
-    fn into_into_dart(self) -> FrbWrapper<XhpkeReceiver> {
-        self.into()
+impl SseEncode for crate::api::cwt::CwtCustomClaim {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.key, serializer);
+        <Vec<u8>>::sse_encode(self.value, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<XhpkeSecretKey> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::cwt::CwtStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.idx, serializer);
+        <String>::sse_encode(self.uri, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XhpkeSecretKey> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XhpkeSecretKey>> for XhpkeSecretKey {
-    fn into_into_dart(self) -> FrbWrapper<XhpkeSecretKey> {
-        self.into()
+impl SseEncode for crate::api::cwt::CwtValidation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.now, serializer);
+        <u64>::sse_encode(self.leeway_secs, serializer);
+        <Option<u64>>::sse_encode(self.max_age_secs, serializer);
+        <Option<String>>::sse_encode(self.issuer, serializer);
+        <Vec<String>>::sse_encode(self.audiences, serializer);
+        <Vec<i64>>::sse_encode(self.required_claims, serializer);
+        <Option<crate::api::cwt::CwtConfirmKeyType>>::sse_encode(self.confirm_key, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.eat_nonce, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<XhpkeSender> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::cwt::EatDebugState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::cwt::EatDebugState::Enabled => 0,
+                crate::api::cwt::EatDebugState::Disabled => 1,
+                crate::api::cwt::EatDebugState::DisabledSinceBoot => 2,
+                crate::api::cwt::EatDebugState::DisabledPermanently => 3,
+                crate::api::cwt::EatDebugState::DisabledFullyPermanently => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<XhpkeSender> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<XhpkeSender>> for XhpkeSender {
-    fn into_into_dart(self) -> FrbWrapper<XhpkeSender> {
-        self.into()
+impl SseEncode for crate::api::cwt::EatMeasurement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.content_type, serializer);
+        <Vec<u8>>::sse_encode(self.content, serializer);
     }
 }
 
-impl SseEncode for RsaFingerprint {
+impl SseEncode for crate::api::cwt::EatOemid {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaFingerprint>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        match self {
+            crate::api::cwt::EatOemid::Random(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <Vec<u8>>::sse_encode(field0, serializer);
+            }
+            crate::api::cwt::EatOemid::Ieee(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<u8>>::sse_encode(field0, serializer);
+            }
+            crate::api::cwt::EatOemid::Pen(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <u64>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for RsaPublicKey {
+impl SseEncode for crate::api::cwt::EatSubmodule {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaPublicKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.name, serializer);
+        <crate::api::cwt::EatSubmoduleValue>::sse_encode(self.value, serializer);
     }
 }
 
-impl SseEncode for RsaSecretKey {
+impl SseEncode for crate::api::cwt::EatSubmoduleValue {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSecretKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        match self {
+            crate::api::cwt::EatSubmoduleValue::Claims(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <Box<crate::api::cwt::CwtClaims>>::sse_encode(field0, serializer);
+            }
+            crate::api::cwt::EatSubmoduleValue::Embedded(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<u8>>::sse_encode(field0, serializer);
+            }
+            crate::api::cwt::EatSubmoduleValue::Detached { alg, digest } => {
+                <i32>::sse_encode(2, serializer);
+                <i64>::sse_encode(alg, serializer);
+                <Vec<u8>>::sse_encode(digest, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for RsaSignature {
+impl SseEncode for i64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for XdsaFingerprint {
+impl SseEncode for Vec<RustAutoOpaqueMoi<XdsaPublicKey>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <RustAutoOpaqueMoi<XdsaPublicKey>>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for XdsaPublicKey {
+impl SseEncode for Vec<RustAutoOpaqueMoi<XdsaSecretKey>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <RustAutoOpaqueMoi<XdsaSecretKey>>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for XdsaSecretKey {
+impl SseEncode for Vec<RustAutoOpaqueMoi<XhpkePublicKey>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <RustAutoOpaqueMoi<XhpkePublicKey>>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for XdsaSignature {
+impl SseEncode for Vec<XdsaFingerprint> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <XdsaFingerprint>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for XhpkeFingerprint {
+impl SseEncode for Vec<XhpkeFingerprint> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <XhpkeFingerprint>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for XhpkePublicKey {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for XhpkeReceiver {
+impl SseEncode for Vec<crate::api::cbor::CborMapEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cbor::CborMapEntry>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for XhpkeSecretKey {
+impl SseEncode for Vec<crate::api::cbor::CborValue> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cbor::CborValue>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for XhpkeSender {
+impl SseEncode for Vec<crate::api::x509::CertExtension> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::x509::CertExtension>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaFingerprint>>
-{
+impl SseEncode for Vec<crate::api::x509::CertKeyUsage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::x509::CertKeyUsage>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaPublicKey>>
-{
+impl SseEncode for Vec<crate::api::cwt::CwtCustomClaim> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cwt::CwtCustomClaim>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSecretKey>>
-{
+impl SseEncode for Vec<crate::api::cwt::EatMeasurement> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cwt::EatMeasurement>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>>
-{
+impl SseEncode for Vec<crate::api::cwt::EatSubmodule> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cwt::EatSubmodule>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaFingerprint>>
-{
+impl SseEncode for Vec<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<u8>>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaPublicKey>>
-{
+impl SseEncode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i64>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSecretKey>>
-{
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XdsaSignature>>
-{
+impl SseEncode for Option<RustAutoOpaqueMoi<CddlSchema>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <RustAutoOpaqueMoi<CddlSchema>>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeFingerprint>>
-{
+impl SseEncode for Option<RustAutoOpaqueMoi<StatusList>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <RustAutoOpaqueMoi<StatusList>>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkePublicKey>>
-{
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeReceiver>>
-{
+impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSecretKey>>
-{
+impl SseEncode for Option<crate::api::cwt::CwtConfirmKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cwt::CwtConfirmKey>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<XhpkeSender>>
-{
+impl SseEncode for Option<crate::api::cwt::CwtConfirmKeyType> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cwt::CwtConfirmKeyType>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Option<crate::api::cwt::CwtStatus> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cwt::CwtStatus>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Option<crate::api::cwt::EatDebugState> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cwt::EatDebugState>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Option<crate::api::cwt::EatOemid> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cwt::EatOemid>::sse_encode(value, serializer);
         }
     }
 }
@@ -5446,6 +11281,24 @@ impl SseEncode for Option<u8> {
     }
 }
 
+impl SseEncode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for (StreamEncryptor, Vec<u8>) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <StreamEncryptor>::sse_encode(self.0, serializer);
+        <Vec<u8>>::sse_encode(self.1, serializer);
+    }
+}
+
 impl SseEncode for (XdsaPublicKey, u64, u64) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5480,6 +11333,14 @@ impl SseEncode for (Vec<u8>, Vec<u8>) {
     }
 }
 
+impl SseEncode for crate::api::cwt::SdCwt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.token, serializer);
+        <Vec<Vec<u8>>>::sse_encode(self.disclosures, serializer);
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5531,7 +11392,10 @@ mod io {
     // Section: imports
 
     use super::*;
+    use crate::api::cddl::*;
+    use crate::api::cwt::*;
     use crate::api::rsa::*;
+    use crate::api::stream::*;
     use crate::api::xdsa::*;
     use crate::api::xhpke::*;
     use flutter_rust_bridge::for_generated::byteorder::{
@@ -5544,6 +11408,20 @@ mod io {
 
     flutter_rust_bridge::frb_generated_boilerplate_io!();
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_darkbio_crypto_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_darkbio_crypto_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_darkbio_crypto_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
         ptr: *const std::ffi::c_void,
@@ -5600,6 +11478,62 @@ mod io {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>>::decrement_strong_count(ptr as _);
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_darkbio_crypto_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_darkbio_crypto_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>>::decrement_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_darkbio_crypto_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_darkbio_crypto_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>>::decrement_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_darkbio_crypto_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_darkbio_crypto_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>>::decrement_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_darkbio_crypto_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_darkbio_crypto_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_darkbio_crypto_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
         ptr: *const std::ffi::c_void,
@@ -5738,7 +11672,10 @@ mod web {
     // Section: imports
 
     use super::*;
+    use crate::api::cddl::*;
+    use crate::api::cwt::*;
     use crate::api::rsa::*;
+    use crate::api::stream::*;
     use crate::api::xdsa::*;
     use crate::api::xhpke::*;
     use flutter_rust_bridge::for_generated::byteorder::{
@@ -5753,6 +11690,20 @@ mod web {
 
     flutter_rust_bridge::frb_generated_boilerplate_web!();
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCddlSchema(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CddlSchema>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsaFingerprint(
         ptr: *const std::ffi::c_void,
@@ -5809,6 +11760,62 @@ mod web {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsaSignature>>::decrement_strong_count(ptr as _);
     }
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStatusList(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StatusList>>::decrement_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDecryptor(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamDecryptor>>::decrement_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamEncryptor(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamEncryptor>>::decrement_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamReader(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StreamReader>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerXdsaFingerprint(
         ptr: *const std::ffi::c_void,